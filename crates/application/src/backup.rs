@@ -0,0 +1,441 @@
+//! Versioned JSON document holding a whole group, used to keep offline
+//! backups and to move a group between instances.
+//!
+//! A document is written by
+//! [GetGroupBackupQuery](crate::queries::get_group_backup::GetGroupBackupQuery)
+//! and restored by
+//! [RestoreGroupBackupCommand](crate::commands::restore_group_backup::RestoreGroupBackupCommand).
+//!
+//! # Version 1
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "exportedAt": "2025-01-08T10:00:00Z",
+//!   "group": {
+//!     "id": "…", "name": "Bali Trip", "currency": "EUR",
+//!     "ownerId": "…", "memberIds": ["…"], "createdAt": "…"
+//!   },
+//!   "users": [{ "id": "…", "name": "Alice" }],
+//!   "categories": [{ "id": "…", "name": "Fuel", "color": "#FF8800", "emoji": "⛽", "createdAt": "…" }],
+//!   "expenseEntries": [{
+//!     "id": "…", "expenseId": "…", "title": "Dinner", "note": null,
+//!     "category": "food", "categoryId": null,
+//!     "payerId": "…", "payers": [{ "userId": "…", "amountCents": 3000 }],
+//!     "participantIds": ["…"],
+//!     "split": { "mode": "equal" },
+//!     "status": { "status": "active" },
+//!     "totalCents": 3000, "currency": "EUR", "exchangeRate": "1",
+//!     "authorId": "…", "occurredAt": "…", "createdAt": "…"
+//!   }],
+//!   "settlements": [{
+//!     "id": "…", "fromUserId": "…", "toUserId": "…", "amountCents": 1500,
+//!     "status": { "status": "active" },
+//!     "authorId": "…", "occurredAt": "…", "createdAt": "…"
+//!   }]
+//! }
+//! ```
+//!
+//! - `users` lists every user the document refers to, former members included.
+//! - `expenseEntries` holds every version of every expense, whatever its
+//!   status, sorted by creation date.
+//! - `split` is one of `{"mode": "equal"}`,
+//!   `{"mode": "shares", "shares": [{"userId", "weight"}]}`,
+//!   `{"mode": "exact", "amounts": [{"userId", "amountCents"}]}` or
+//!   `{"mode": "percentages", "percentages": [{"userId", "weight"}]}`.
+//! - an expense entry `status` is one of `{"status": "active"}`,
+//!   `{"status": "inactive", "overwrittenBy"}` or
+//!   `{"status": "deleted", "deletedBy", "deletedAt"}`; a settlement one is
+//!   `{"status": "active"}` or `{"status": "cancelled", "cancelledBy", "cancelledAt"}`.
+//!
+//! Recurring expenses, attachments and member roles are not part of the
+//! document: restored members are regular members.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use domain::{
+    entities::{Category, ExpenseEntry, Group, Settlement, User},
+    types::{
+        expense_entry_status::ExpenseEntryStatus, expense_split::ExpenseSplit, money::Money,
+        settlement_status::SettlementStatus, user_id::UserId,
+    },
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Version of the documents written by this app.
+pub const CURRENT_VERSION: u32 = 1;
+
+/// Versions of the documents this app can restore.
+const SUPPORTED_VERSIONS: [u32; 1] = [1];
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("unsupported document version: {0}")]
+    UnsupportedVersion(u32),
+
+    #[error("invalid document: {0}")]
+    Invalid(String),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupDocument {
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub group: GroupRecord,
+    pub users: Vec<UserRecord>,
+    pub categories: Vec<CategoryRecord>,
+    pub expense_entries: Vec<ExpenseEntryRecord>,
+    pub settlements: Vec<SettlementRecord>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupRecord {
+    pub id: Uuid,
+    pub name: String,
+    pub currency: String,
+    pub owner_id: Uuid,
+    pub member_ids: Vec<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRecord {
+    pub id: Uuid,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryRecord {
+    pub id: Uuid,
+    pub name: String,
+    pub color: String,
+    pub emoji: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpenseEntryRecord {
+    pub id: Uuid,
+    pub expense_id: Uuid,
+    pub title: String,
+    pub note: Option<String>,
+    pub category: String,
+    pub category_id: Option<Uuid>,
+    pub payer_id: Uuid,
+    pub payers: Vec<AmountRecord>,
+    pub participant_ids: Vec<Uuid>,
+    pub split: SplitRecord,
+    pub status: ExpenseEntryStatusRecord,
+    pub total_cents: i64,
+    pub currency: String,
+    pub exchange_rate: String,
+    pub author_id: Uuid,
+    pub occurred_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmountRecord {
+    pub user_id: Uuid,
+    pub amount_cents: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeightRecord {
+    pub user_id: Uuid,
+    pub weight: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "camelCase")]
+pub enum SplitRecord {
+    Equal,
+    Shares { shares: Vec<WeightRecord> },
+    Exact { amounts: Vec<AmountRecord> },
+    Percentages { percentages: Vec<WeightRecord> },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(
+    tag = "status",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum ExpenseEntryStatusRecord {
+    Active,
+    Inactive {
+        overwritten_by: Uuid,
+    },
+    Deleted {
+        deleted_by: Uuid,
+        deleted_at: DateTime<Utc>,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettlementRecord {
+    pub id: Uuid,
+    pub from_user_id: Uuid,
+    pub to_user_id: Uuid,
+    pub amount_cents: i64,
+    pub status: SettlementStatusRecord,
+    pub author_id: Uuid,
+    pub occurred_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(
+    tag = "status",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum SettlementStatusRecord {
+    Active,
+    Cancelled {
+        cancelled_by: Uuid,
+        cancelled_at: DateTime<Utc>,
+    },
+}
+
+impl BackupDocument {
+    /// Reads a document, checking its version before its content so that
+    /// documents of unsupported versions are told apart from invalid ones.
+    pub fn from_json(value: serde_json::Value) -> Result<Self, Error> {
+        #[derive(Deserialize)]
+        struct Header {
+            version: u32,
+        }
+
+        let header: Header =
+            Header::deserialize(&value).map_err(|err| Error::Invalid(err.to_string()))?;
+        if !SUPPORTED_VERSIONS.contains(&header.version) {
+            return Err(Error::UnsupportedVersion(header.version));
+        }
+        serde_json::from_value(value).map_err(|err| Error::Invalid(err.to_string()))
+    }
+
+    /// Builds the document of a group; `users` must contain every user
+    /// referenced by the group and its records.
+    pub(crate) fn new(
+        group: &Group,
+        users: &HashMap<UserId, User>,
+        categories: &[Category],
+        expense_entries: &[ExpenseEntry],
+        settlements: &[Settlement],
+    ) -> Self {
+        let mut users: Vec<UserRecord> = users
+            .values()
+            .map(|user| UserRecord {
+                id: user.id.value(),
+                name: user.name.value(),
+            })
+            .collect();
+        users.sort_by_key(|user| user.id);
+
+        Self {
+            version: CURRENT_VERSION,
+            exported_at: Utc::now(),
+            group: GroupRecord {
+                id: group.id.value(),
+                name: group.name.value(),
+                currency: group.currency.value(),
+                owner_id: group.owner_id.value(),
+                member_ids: sorted_ids(&group.members.keys().copied().collect()),
+                created_at: group.created_at,
+            },
+            users,
+            categories: categories.iter().map(CategoryRecord::from).collect(),
+            expense_entries: expense_entries
+                .iter()
+                .map(ExpenseEntryRecord::from)
+                .collect(),
+            settlements: settlements.iter().map(SettlementRecord::from).collect(),
+        }
+    }
+}
+
+/// Returns every user referenced by a group and its records.
+pub(crate) fn referenced_users(
+    group: &Group,
+    expense_entries: &[ExpenseEntry],
+    settlements: &[Settlement],
+) -> HashSet<UserId> {
+    let mut user_ids: HashSet<UserId> = group.members.keys().copied().collect();
+    user_ids.insert(group.owner_id);
+    for entry in expense_entries {
+        user_ids.insert(entry.payer_id);
+        user_ids.insert(entry.author_id);
+        user_ids.extend(entry.payers.users());
+        user_ids.extend(entry.participants.iter().copied());
+        user_ids.extend(entry.split.users().unwrap_or_default());
+        if let ExpenseEntryStatus::Deleted { deleted_by, .. } = entry.status {
+            user_ids.insert(deleted_by);
+        }
+    }
+    for settlement in settlements {
+        user_ids.insert(settlement.from);
+        user_ids.insert(settlement.to);
+        user_ids.insert(settlement.author_id);
+        if let SettlementStatus::Cancelled { cancelled_by, .. } = settlement.status {
+            user_ids.insert(cancelled_by);
+        }
+    }
+    user_ids
+}
+
+impl From<&Category> for CategoryRecord {
+    fn from(category: &Category) -> Self {
+        Self {
+            id: category.id.value(),
+            name: category.name.value(),
+            color: category.color.value(),
+            emoji: category.emoji.value(),
+            created_at: category.created_at,
+        }
+    }
+}
+
+impl From<&ExpenseEntry> for ExpenseEntryRecord {
+    fn from(entry: &ExpenseEntry) -> Self {
+        Self {
+            id: entry.id.value(),
+            expense_id: entry.expense_id.value(),
+            title: entry.title.value(),
+            note: entry.note.as_ref().map(|note| note.value()),
+            category: entry.category.value().to_string(),
+            category_id: entry.category_id.map(|id| id.value()),
+            payer_id: entry.payer_id.value(),
+            payers: amount_records(entry.payers.amounts()),
+            participant_ids: sorted_ids(&entry.participants),
+            split: SplitRecord::from(&entry.split),
+            status: match entry.status {
+                ExpenseEntryStatus::Active => ExpenseEntryStatusRecord::Active,
+                ExpenseEntryStatus::Inactive { overwritten_by } => {
+                    ExpenseEntryStatusRecord::Inactive {
+                        overwritten_by: overwritten_by.value(),
+                    }
+                }
+                ExpenseEntryStatus::Deleted {
+                    deleted_by,
+                    deleted_at,
+                } => ExpenseEntryStatusRecord::Deleted {
+                    deleted_by: deleted_by.value(),
+                    deleted_at,
+                },
+            },
+            total_cents: entry.total.cents(),
+            currency: entry.currency.value(),
+            exchange_rate: entry.exchange_rate.to_string(),
+            author_id: entry.author_id.value(),
+            occurred_at: entry.occurred_at,
+            created_at: entry.created_at,
+        }
+    }
+}
+
+impl From<&ExpenseSplit> for SplitRecord {
+    fn from(split: &ExpenseSplit) -> Self {
+        match split {
+            ExpenseSplit::Equal => SplitRecord::Equal,
+            ExpenseSplit::Shares { shares } => SplitRecord::Shares {
+                shares: weight_records(shares),
+            },
+            ExpenseSplit::Exact { amounts } => SplitRecord::Exact {
+                amounts: amount_records(amounts),
+            },
+            ExpenseSplit::Percentages { percentages } => SplitRecord::Percentages {
+                percentages: weight_records(percentages),
+            },
+        }
+    }
+}
+
+impl From<&Settlement> for SettlementRecord {
+    fn from(settlement: &Settlement) -> Self {
+        Self {
+            id: settlement.id.value(),
+            from_user_id: settlement.from.value(),
+            to_user_id: settlement.to.value(),
+            amount_cents: settlement.amount.cents(),
+            status: match settlement.status {
+                SettlementStatus::Active => SettlementStatusRecord::Active,
+                SettlementStatus::Cancelled {
+                    cancelled_by,
+                    cancelled_at,
+                } => SettlementStatusRecord::Cancelled {
+                    cancelled_by: cancelled_by.value(),
+                    cancelled_at,
+                },
+            },
+            author_id: settlement.author_id.value(),
+            occurred_at: settlement.occurred_at,
+            created_at: settlement.created_at,
+        }
+    }
+}
+
+fn sorted_ids(user_ids: &HashSet<UserId>) -> Vec<Uuid> {
+    let mut ids: Vec<Uuid> = user_ids.iter().map(|id| id.value()).collect();
+    ids.sort();
+    ids
+}
+
+fn amount_records(amounts: &HashMap<UserId, Money>) -> Vec<AmountRecord> {
+    let mut records: Vec<AmountRecord> = amounts
+        .iter()
+        .map(|(user_id, amount)| AmountRecord {
+            user_id: user_id.value(),
+            amount_cents: amount.cents(),
+        })
+        .collect();
+    records.sort_by_key(|record| record.user_id);
+    records
+}
+
+fn weight_records(weights: &HashMap<UserId, u64>) -> Vec<WeightRecord> {
+    let mut records: Vec<WeightRecord> = weights
+        .iter()
+        .map(|(user_id, weight)| WeightRecord {
+            user_id: user_id.value(),
+            weight: *weight,
+        })
+        .collect();
+    records.sort_by_key(|record| record.user_id);
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{BackupDocument, Error};
+
+    #[test]
+    fn from_json_unsupported_version() {
+        let err = BackupDocument::from_json(json!({ "version": 42, "group": {} })).unwrap_err();
+        assert_eq!(Error::UnsupportedVersion(42), err);
+    }
+
+    #[test]
+    fn from_json_missing_version() {
+        let err = BackupDocument::from_json(json!({ "group": {} })).unwrap_err();
+        assert!(matches!(err, Error::Invalid(_)));
+    }
+
+    #[test]
+    fn from_json_invalid_content() {
+        let err = BackupDocument::from_json(json!({ "version": 1, "group": {} })).unwrap_err();
+        assert!(matches!(err, Error::Invalid(_)));
+    }
+}
@@ -0,0 +1,90 @@
+use chrono::Utc;
+use domain::{
+    entities::InviteStatus,
+    types::{group_id::GroupId, invite_token::InviteToken, user_id::UserId},
+};
+
+use crate::commands::add_group_member::{AddGroupMemberCommand, AddGroupMemberError};
+
+/// Makes the current user join the group of an invite, with the role of the
+/// invite.
+///
+/// The membership is created on behalf of the invite author, so that the
+/// same rules as adding a member apply: an invite stops working once its
+/// author is not allowed to add members anymore.
+pub struct AcceptInviteCommand {
+    pub token: InviteToken,
+    pub current_user_id: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AcceptInviteError {
+    #[error("invite not found")]
+    InviteNotFound,
+
+    #[error("invite has been revoked")]
+    InviteRevoked,
+
+    #[error("invite has expired")]
+    InviteExpired,
+
+    #[error("invite has reached its maximum number of uses")]
+    InviteExhausted,
+
+    #[error("invite author is not allowed to add members anymore")]
+    AuthorNotAllowed,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("user is already member of this group")]
+    AlreadyMember,
+
+    #[error("user not found")]
+    UserNotFound,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl AcceptInviteCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<GroupId, AcceptInviteError> {
+        let Some(invite) = database::queries::invite::get_by_token(tx, &self.token).await? else {
+            return Err(AcceptInviteError::InviteNotFound);
+        };
+
+        match invite.status(Utc::now()) {
+            InviteStatus::Active => {}
+            InviteStatus::Revoked => return Err(AcceptInviteError::InviteRevoked),
+            InviteStatus::Expired => return Err(AcceptInviteError::InviteExpired),
+            InviteStatus::Exhausted => return Err(AcceptInviteError::InviteExhausted),
+        }
+
+        AddGroupMemberCommand {
+            group_id: invite.group_id,
+            user_to_add: self.current_user_id.into(),
+            role: invite.role,
+            current_user_id: invite.author_id,
+        }
+        .handle(tx)
+        .await
+        .map_err(|err| match err {
+            AddGroupMemberError::NotAdmin => AcceptInviteError::AuthorNotAllowed,
+            AddGroupMemberError::GroupNotFound => AcceptInviteError::InviteNotFound,
+            AddGroupMemberError::GroupArchived => AcceptInviteError::GroupArchived,
+            AddGroupMemberError::AlreadyMember => AcceptInviteError::AlreadyMember,
+            AddGroupMemberError::UserNotFound => AcceptInviteError::UserNotFound,
+            AddGroupMemberError::Database(err) => AcceptInviteError::Database(err),
+        })?;
+
+        // another user may have used the last use of the invite meanwhile
+        if !database::queries::invite::increment_uses(tx, &invite.id).await? {
+            return Err(AcceptInviteError::InviteExhausted);
+        }
+
+        Ok(invite.group_id)
+    }
+}
@@ -1,19 +1,45 @@
-use domain::types::{group_id::GroupId, user_id::UserId};
+use domain::types::{group_id::GroupId, handle::Handle, member_role::MemberRole, user_id::UserId};
+use email_address::EmailAddress;
+
+use crate::permissions::{self, Permission};
 
 pub struct AddGroupMemberCommand {
     pub group_id: GroupId,
-    pub user_id_to_add: UserId,
+    pub user_to_add: UserReference,
+    /// Role given to the new member.
+    pub role: MemberRole,
     pub current_user_id: UserId,
 }
 
+/// Way of designating the user to add.
+///
+/// Handles and emails are only resolved once the current user is known to
+/// be allowed to add members, so that other users cannot find out whether
+/// an email is registered.
+#[derive(Debug, Clone)]
+pub enum UserReference {
+    Id(UserId),
+    Handle(Handle),
+    Email(EmailAddress),
+}
+
+impl From<UserId> for UserReference {
+    fn from(id: UserId) -> Self {
+        Self::Id(id)
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum AddGroupMemberError {
-    #[error("only group owner can add member")]
-    NotOwner,
+    #[error("only group owner and admins can add member")]
+    NotAdmin,
 
     #[error("group not found")]
     GroupNotFound,
 
+    #[error("group is archived")]
+    GroupArchived,
+
     #[error("user is already member of this group")]
     AlreadyMember,
 
@@ -33,20 +59,44 @@ impl AddGroupMemberCommand {
             return Err(AddGroupMemberError::GroupNotFound);
         };
 
-        if !database::queries::user::exists_by_id(tx, &self.user_id_to_add).await? {
-            return Err(AddGroupMemberError::UserNotFound);
+        if permissions::check(&group, &self.current_user_id, Permission::ManageMembers).is_err() {
+            return Err(AddGroupMemberError::NotAdmin);
         }
 
-        if !group.is_user_owner(&self.current_user_id) {
-            return Err(AddGroupMemberError::NotOwner);
+        if group.is_archived() {
+            return Err(AddGroupMemberError::GroupArchived);
         }
 
-        if group.is_user_owner(&self.user_id_to_add) || group.is_user_member(&self.user_id_to_add) {
+        let Some(user_id_to_add) = resolve(tx, &self.user_to_add).await? else {
+            return Err(AddGroupMemberError::UserNotFound);
+        };
+
+        if group.contains_user(&user_id_to_add) {
             return Err(AddGroupMemberError::AlreadyMember);
         }
 
-        database::queries::group::add_member(tx, &self.group_id, &self.user_id_to_add).await?;
+        database::queries::group::add_member(tx, &self.group_id, &user_id_to_add, &self.role)
+            .await?;
 
         Ok(())
     }
 }
+
+async fn resolve(
+    tx: &mut database::Transaction<'_>,
+    user: &UserReference,
+) -> Result<Option<UserId>, database::Error> {
+    match user {
+        // guests only belong to the group they were created in
+        UserReference::Id(id) => Ok(database::queries::user::get_by_id(tx, id)
+            .await?
+            .filter(|user| !user.is_guest())
+            .map(|user| user.id)),
+        UserReference::Handle(handle) => Ok(database::queries::user::get_by_handle(tx, handle)
+            .await?
+            .map(|user| user.id)),
+        UserReference::Email(email) => Ok(database::queries::user::get_by_email(tx, email)
+            .await?
+            .map(|user| user.id)),
+    }
+}
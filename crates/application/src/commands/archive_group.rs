@@ -0,0 +1,50 @@
+use chrono::Utc;
+use domain::types::{group_id::GroupId, user_id::UserId};
+
+use crate::permissions::{self, Permission};
+
+/// Archives a group once it is not used anymore, such as after a trip.
+/// An archived group is read-only: its expenses, settlements, settings and
+/// members cannot change, and its recurring expenses are paused until it is
+/// unarchived. Only the group owner can archive it.
+pub struct ArchiveGroupCommand {
+    pub group_id: GroupId,
+    pub current_user_id: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ArchiveGroupError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("only group owner can archive it")]
+    NotOwner,
+
+    #[error("group is already archived")]
+    AlreadyArchived,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl ArchiveGroupCommand {
+    pub async fn handle(self, tx: &mut database::Transaction<'_>) -> Result<(), ArchiveGroupError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(ArchiveGroupError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::Own).is_err() {
+            return Err(ArchiveGroupError::NotOwner);
+        }
+
+        if group.is_archived() {
+            return Err(ArchiveGroupError::AlreadyArchived);
+        }
+
+        if !database::queries::group::set_archived_at(tx, &self.group_id, Some(Utc::now())).await? {
+            return Err(ArchiveGroupError::GroupNotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use chrono::Utc;
+use domain::types::{group_id::GroupId, settlement_id::SettlementId, user_id::UserId};
+
+use crate::permissions::{self, Permission};
+
+pub struct CancelSettlementCommand {
+    pub group_id: GroupId,
+    pub settlement_id: SettlementId,
+    pub current_user_id: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CancelSettlementError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("settlement not found")]
+    SettlementNotFound,
+
+    #[error("only settlement author, group owner and admins can cancel a settlement")]
+    Forbidden,
+
+    #[error("settlement is already cancelled")]
+    AlreadyCancelled,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl CancelSettlementCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<(), CancelSettlementError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(CancelSettlementError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::Read).is_err() {
+            return Err(CancelSettlementError::Forbidden);
+        }
+
+        if group.is_archived() {
+            return Err(CancelSettlementError::GroupArchived);
+        }
+
+        let Some(settlement) =
+            database::queries::settlement::get_by_id(tx, &self.settlement_id).await?
+        else {
+            return Err(CancelSettlementError::SettlementNotFound);
+        };
+        if settlement.group_id != self.group_id {
+            return Err(CancelSettlementError::SettlementNotFound);
+        }
+
+        let allowed = if settlement.author_id == self.current_user_id {
+            permissions::check(&group, &self.current_user_id, Permission::WriteExpenses)
+        } else {
+            permissions::check(
+                &group,
+                &self.current_user_id,
+                Permission::ModerateSettlements,
+            )
+        };
+        if allowed.is_err() {
+            return Err(CancelSettlementError::Forbidden);
+        }
+
+        if !settlement.is_active() {
+            return Err(CancelSettlementError::AlreadyCancelled);
+        }
+
+        database::queries::settlement::cancel(
+            tx,
+            &self.settlement_id,
+            &self.current_user_id,
+            Utc::now(),
+        )
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use domain::types::{group_id::GroupId, member_role::MemberRole, user_id::UserId};
+
+use crate::permissions::{self, Permission};
+
+/// Changes the role of a group member. Only the group owner and admins can
+/// change roles.
+pub struct ChangeMemberRoleCommand {
+    pub group_id: GroupId,
+    pub member_id: UserId,
+    pub role: MemberRole,
+    pub current_user_id: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ChangeMemberRoleError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("only group owner and admins can change member roles")]
+    NotAdmin,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("user is not member of this group")]
+    NotMember,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl ChangeMemberRoleCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<(), ChangeMemberRoleError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(ChangeMemberRoleError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::ManageMembers).is_err() {
+            return Err(ChangeMemberRoleError::NotAdmin);
+        }
+
+        if group.is_archived() {
+            return Err(ChangeMemberRoleError::GroupArchived);
+        }
+
+        if !group.is_user_member(&self.member_id) {
+            return Err(ChangeMemberRoleError::NotMember);
+        }
+
+        if !database::queries::group::set_member_role(
+            tx,
+            &self.group_id,
+            &self.member_id,
+            &self.role,
+        )
+        .await?
+        {
+            return Err(ChangeMemberRoleError::NotMember);
+        }
+
+        Ok(())
+    }
+}
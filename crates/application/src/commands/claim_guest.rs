@@ -0,0 +1,94 @@
+use domain::types::{group_id::GroupId, user_id::UserId};
+
+use crate::permissions::{self, Denied, Permission};
+
+/// Hands a guest of a group over to a registered member of it: the expenses,
+/// recurring expenses and settlements of the guest become theirs, so that
+/// the history and balances of the group carry over, and the guest is
+/// deleted.
+///
+/// Only the owner and admins of the group can do so, as the member takes
+/// over the balance of the guest. The member must not pay nor take part in
+/// anything in the group yet, otherwise they would be merged with the guest
+/// in expenses involving both of them.
+pub struct ClaimGuestCommand {
+    pub group_id: GroupId,
+    pub guest_id: UserId,
+    pub member_id: UserId,
+    pub current_user_id: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ClaimGuestError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("only group owner and admins can hand guests over to members")]
+    Forbidden,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("user to claim is not a guest of this group")]
+    NotGuest,
+
+    #[error("member not found")]
+    MemberNotFound,
+
+    #[error("member already takes part in expenses or settlements of this group")]
+    AlreadyTakesPart,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl ClaimGuestCommand {
+    pub async fn handle(self, tx: &mut database::Transaction<'_>) -> Result<(), ClaimGuestError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(ClaimGuestError::GroupNotFound);
+        };
+
+        match permissions::check(&group, &self.current_user_id, Permission::ManageMembers) {
+            Ok(()) => {}
+            // hides the group from users outside of it
+            Err(Denied::NotInGroup) => return Err(ClaimGuestError::GroupNotFound),
+            Err(Denied::RoleNotAllowed) => return Err(ClaimGuestError::Forbidden),
+        }
+
+        if group.is_archived() {
+            return Err(ClaimGuestError::GroupArchived);
+        }
+
+        if !group.is_user_member(&self.guest_id) {
+            return Err(ClaimGuestError::NotGuest);
+        }
+
+        if !group.contains_user(&self.member_id) {
+            return Err(ClaimGuestError::MemberNotFound);
+        }
+        let is_registered = database::queries::user::get_by_id(tx, &self.member_id)
+            .await?
+            .is_some_and(|member| !member.is_guest());
+        if !is_registered {
+            return Err(ClaimGuestError::MemberNotFound);
+        }
+
+        if database::queries::user::takes_part_in_group(tx, &self.group_id, &self.member_id).await?
+        {
+            return Err(ClaimGuestError::AlreadyTakesPart);
+        }
+
+        if !database::queries::user::claim_guest(
+            tx,
+            &self.group_id,
+            &self.guest_id,
+            &self.member_id,
+        )
+        .await?
+        {
+            return Err(ClaimGuestError::NotGuest);
+        }
+
+        Ok(())
+    }
+}
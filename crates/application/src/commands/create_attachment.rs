@@ -0,0 +1,92 @@
+use chrono::Utc;
+use domain::{
+    entities::Attachment,
+    types::{
+        attachment_content_type::AttachmentContentType, attachment_file_name::AttachmentFileName,
+        attachment_id::AttachmentId, expense_id::ExpenseId, group_id::GroupId, user_id::UserId,
+    },
+};
+
+use crate::permissions::{self, Permission};
+
+/// Records a file attached to an active expense.
+///
+/// Only the metadata of the file is saved; its content must be stored by
+/// the caller under the returned id before committing the transaction.
+pub struct CreateAttachmentCommand {
+    pub group_id: GroupId,
+    pub expense_id: ExpenseId,
+    pub current_user_id: UserId,
+    pub file_name: AttachmentFileName,
+    pub content_type: AttachmentContentType,
+    /// Size of the file content, in bytes.
+    pub size_bytes: u64,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateAttachmentError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("expense not found")]
+    ExpenseNotFound,
+
+    #[error("must be group member to attach a file to an expense")]
+    Forbidden,
+
+    #[error("attached file cannot be empty")]
+    EmptyFile,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl CreateAttachmentCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<AttachmentId, CreateAttachmentError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(CreateAttachmentError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::WriteExpenses).is_err() {
+            return Err(CreateAttachmentError::Forbidden);
+        }
+
+        if group.is_archived() {
+            return Err(CreateAttachmentError::GroupArchived);
+        }
+
+        let Some(entry) =
+            database::queries::expense_entry::get_active_by_expense_id(tx, &self.expense_id)
+                .await?
+        else {
+            return Err(CreateAttachmentError::ExpenseNotFound);
+        };
+        if entry.group_id != self.group_id {
+            return Err(CreateAttachmentError::ExpenseNotFound);
+        }
+
+        if self.size_bytes == 0 {
+            return Err(CreateAttachmentError::EmptyFile);
+        }
+
+        let attachment = Attachment::new(
+            AttachmentId::new_random(),
+            self.expense_id,
+            self.group_id,
+            self.file_name,
+            self.content_type,
+            self.size_bytes,
+            self.current_user_id,
+            Utc::now(),
+        );
+        database::queries::attachment::create(tx, &attachment).await?;
+
+        Ok(attachment.id)
+    }
+}
@@ -0,0 +1,87 @@
+use chrono::Utc;
+use domain::{
+    entities::Budget,
+    types::{
+        budget_id::BudgetId, budget_period::BudgetPeriod, category_id::CategoryId,
+        group_id::GroupId, money::Money, user_id::UserId,
+    },
+};
+
+use crate::{
+    commands::create_expense::category_belongs_to_group,
+    permissions::{self, Permission},
+};
+
+/// Adds a budget to a group, on all its expenses or on the ones of a custom
+/// category. Only the group owner and admins can manage budgets.
+pub struct CreateBudgetCommand {
+    pub group_id: GroupId,
+    pub current_user_id: UserId,
+    /// Optional custom category; must belong to the group.
+    pub category_id: Option<CategoryId>,
+    /// Limit in the group currency.
+    pub amount: Money,
+    pub period: BudgetPeriod,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateBudgetError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("only group owner and admins can manage budgets")]
+    NotAdmin,
+
+    #[error("category not found in group")]
+    CategoryNotFound,
+
+    #[error("amount must be > 0")]
+    InvalidAmount,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl CreateBudgetCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<BudgetId, CreateBudgetError> {
+        if !self.amount.is_positive() {
+            return Err(CreateBudgetError::InvalidAmount);
+        }
+
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(CreateBudgetError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::ManageSettings).is_err() {
+            return Err(CreateBudgetError::NotAdmin);
+        }
+
+        if group.is_archived() {
+            return Err(CreateBudgetError::GroupArchived);
+        }
+
+        if let Some(category_id) = &self.category_id
+            && !category_belongs_to_group(tx, category_id, &self.group_id).await?
+        {
+            return Err(CreateBudgetError::CategoryNotFound);
+        }
+
+        let budget = Budget::new(
+            BudgetId::new_random(),
+            self.group_id,
+            self.category_id,
+            self.amount,
+            self.period,
+            Utc::now(),
+        );
+        database::queries::budget::create(tx, &budget).await?;
+
+        Ok(budget.id)
+    }
+}
@@ -0,0 +1,80 @@
+use chrono::Utc;
+use domain::{
+    entities::Category,
+    types::{
+        category_color::CategoryColor, category_emoji::CategoryEmoji, category_id::CategoryId,
+        category_name::CategoryName, group_id::GroupId, user_id::UserId,
+    },
+};
+
+use crate::permissions::{self, Permission};
+
+/// Adds a custom category to a group.
+/// Only the group owner and admins can manage categories.
+pub struct CreateCategoryCommand {
+    pub group_id: GroupId,
+    pub current_user_id: UserId,
+    pub name: CategoryName,
+    pub color: CategoryColor,
+    pub emoji: CategoryEmoji,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateCategoryError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("only group owner and admins can manage categories")]
+    NotAdmin,
+
+    #[error("another category of the group has the same name")]
+    NameNotAvailable,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl CreateCategoryCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<CategoryId, CreateCategoryError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(CreateCategoryError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::ManageSettings).is_err() {
+            return Err(CreateCategoryError::NotAdmin);
+        }
+
+        if group.is_archived() {
+            return Err(CreateCategoryError::GroupArchived);
+        }
+
+        if database::queries::category::exists_by_name_for_group(
+            tx,
+            &self.group_id,
+            &self.name,
+            None,
+        )
+        .await?
+        {
+            return Err(CreateCategoryError::NameNotAvailable);
+        }
+
+        let category = Category::new(
+            CategoryId::new_random(),
+            self.group_id,
+            self.name,
+            self.color,
+            self.emoji,
+            Utc::now(),
+        );
+        database::queries::category::create(tx, &category).await?;
+
+        Ok(category.id)
+    }
+}
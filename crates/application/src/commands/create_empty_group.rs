@@ -1,14 +1,16 @@
-use std::collections::HashSet;
+use std::collections::HashMap;
 
 use chrono::Utc;
 use domain::{
     entities::Group,
-    types::{group_id::GroupId, groupname::Groupname, user_id::UserId},
+    types::{currency::Currency, group_id::GroupId, groupname::Groupname, user_id::UserId},
 };
 
 pub struct CreateEmptyGroupCommand {
     pub groupname: Groupname,
     pub owner_id: UserId,
+    /// Currency in which balances of the group are expressed.
+    pub currency: Currency,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -42,8 +44,9 @@ impl CreateEmptyGroupCommand {
         let group = Group::new(
             id,
             self.groupname,
+            self.currency,
             self.owner_id,
-            HashSet::new(),
+            HashMap::new(),
             Utc::now(),
         );
         database::queries::group::create(tx, &group).await?;
@@ -1,21 +1,58 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use chrono::{DateTime, Utc};
 use domain::{
-    entities::{ExpenseEntry, Group},
+    entities::{BudgetAlert, ExpenseEntry, Group},
     types::{
+        budget_threshold::BudgetThreshold, category_id::CategoryId, currency::Currency,
+        exchange_rate::ExchangeRate, expense_category::ExpenseCategory,
         expense_entry_id::ExpenseEntryId, expense_entry_status::ExpenseEntryStatus,
-        expense_id::ExpenseId, group_id::GroupId, money::Money, user_id::UserId,
+        expense_id::ExpenseId, expense_note::ExpenseNote, expense_payers::ExpensePayers,
+        expense_split::ExpenseSplit, expense_title::ExpenseTitle, group_id::GroupId, money::Money,
+        user_id::UserId,
     },
 };
 
+use crate::{
+    permissions::{self, Denied, Permission},
+    queries::get_budgets_for_group::{consumes, consumption},
+};
+
 pub struct CreateExpenseCommand {
     pub group_id: GroupId,
     pub payer_id: UserId,
+    /// Amount paid by each user, if several users paid the expense; must
+    /// include `payer_id` and sum up to the total. If `None`, `payer_id`
+    /// paid the whole total.
+    pub payers: Option<HashMap<UserId, Money>>,
     pub author_id: UserId,
-    pub participants: IncludeParticipants,
+    pub title: ExpenseTitle,
+    pub note: Option<ExpenseNote>,
+    pub category: ExpenseCategory,
+    /// Optional custom category; must belong to the group.
+    pub category_id: Option<CategoryId>,
+    pub split: SplitSpecification,
     pub occured_at: DateTime<Utc>,
     pub total: Money,
+    /// Currency in which the expense was paid; defaults to the group currency.
+    pub currency: Option<Currency>,
+    /// Rate to the group currency; required if `currency` differs from it.
+    pub exchange_rate: Option<ExchangeRate>,
+}
+
+pub enum SplitSpecification {
+    /// Total is split equally between the payer and the participants.
+    Equal { participants: IncludeParticipants },
+
+    /// Total is split proportionally to the number of shares of each user.
+    Shares { shares: HashMap<UserId, u64> },
+
+    /// Each user bears an exact amount; amounts must sum up to the total.
+    Exact { amounts: HashMap<UserId, Money> },
+
+    /// Each user bears a whole percentage of the total; percentages must
+    /// sum up to 100.
+    Percentages { percentages: HashMap<UserId, u64> },
 }
 
 pub enum IncludeParticipants {
@@ -31,6 +68,9 @@ pub enum CreateExpenseError {
     #[error("group not found")]
     GroupNotFound,
 
+    #[error("group is archived")]
+    GroupArchived,
+
     #[error("total must be > 0")]
     InvalidTotal,
 
@@ -40,13 +80,49 @@ pub enum CreateExpenseError {
     #[error("author not found in group")]
     AuthorNotInGroup,
 
+    #[error("group viewers cannot add expenses")]
+    ReadOnlyMember,
+
     #[error("at least one participant is not found in group")]
     ParticipantNotFound,
 
+    #[error("category not found in group")]
+    CategoryNotFound,
+
+    #[error("invalid split: {0}")]
+    InvalidSplit(#[from] domain::types::expense_split::Error),
+
+    #[error("invalid currency: {0}")]
+    InvalidCurrency(#[from] CurrencyError),
+
+    #[error("invalid payers: {0}")]
+    InvalidPayers(#[from] PayersError),
+
     #[error("database error: {0}")]
     Database(#[from] database::Error),
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum PayersError {
+    #[error("payer must be one of the payers")]
+    MissingPayer,
+
+    #[error(transparent)]
+    Invalid(#[from] domain::types::expense_payers::Error),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CurrencyError {
+    #[error("an exchange rate to the group currency is required")]
+    MissingExchangeRate,
+
+    #[error("exchange rate must be 1 for an expense in the group currency")]
+    UnexpectedExchangeRate,
+
+    #[error("total is too large once converted to the group currency")]
+    TotalTooLarge,
+}
+
 impl CreateExpenseCommand {
     pub async fn handle(
         self,
@@ -60,55 +136,205 @@ impl CreateExpenseCommand {
             return Err(CreateExpenseError::GroupNotFound);
         };
 
-        if !group.contains_user(&self.payer_id) {
-            return Err(CreateExpenseError::PayerIsNotGroupMember);
+        if group.is_archived() {
+            return Err(CreateExpenseError::GroupArchived);
         }
-        if !group.contains_user(&self.author_id) {
-            return Err(CreateExpenseError::AuthorNotInGroup);
+
+        let payers = resolve_payers(&self.payer_id, self.payers, self.total)?;
+        if !payers
+            .users()
+            .iter()
+            .all(|payer| group.contains_user(payer))
+        {
+            return Err(CreateExpenseError::PayerIsNotGroupMember);
         }
+        permissions::check(&group, &self.author_id, Permission::WriteExpenses).map_err(
+            |denied| match denied {
+                Denied::NotInGroup => CreateExpenseError::AuthorNotInGroup,
+                Denied::RoleNotAllowed => CreateExpenseError::ReadOnlyMember,
+            },
+        )?;
 
-        let mut participants = self.get_participants(&group)?;
-        participants.remove(&self.payer_id);
+        let (participants, split) = self
+            .split
+            .resolve(&group, &self.payer_id)
+            .ok_or(CreateExpenseError::ParticipantNotFound)?;
+        split.validate(self.total)?;
+        let (currency, exchange_rate) =
+            resolve_currency(&group, self.currency, self.exchange_rate, self.total)?;
+        if let Some(category_id) = &self.category_id
+            && !category_belongs_to_group(tx, category_id, &self.group_id).await?
+        {
+            return Err(CreateExpenseError::CategoryNotFound);
+        }
 
         let expense_entry = ExpenseEntry::new(
             ExpenseEntryId::new_random(),
             ExpenseId::new_random(),
             self.group_id,
+            self.title,
+            self.note,
+            self.category,
+            self.category_id,
             self.payer_id,
+            payers,
             participants,
+            split,
             ExpenseEntryStatus::Active,
             self.total,
+            currency,
+            exchange_rate,
             self.author_id,
             self.occured_at,
             Utc::now(),
         )
         .expect("valid expense entry");
         database::queries::expense_entry::create(tx, &expense_entry).await?;
+        record_budget_alerts(tx, &expense_entry).await?;
         Ok(expense_entry.expense_id)
     }
+}
+
+impl SplitSpecification {
+    /// Returns the participants (payer excluded) and the split of the expense.
+    /// Returns `None` if at least one user does not belong to the group.
+    pub(crate) fn resolve(
+        self,
+        group: &Group,
+        payer_id: &UserId,
+    ) -> Option<(HashSet<UserId>, ExpenseSplit)> {
+        let split = match self {
+            SplitSpecification::Equal { participants } => {
+                let mut participants = participants.resolve(group)?;
+                participants.remove(payer_id);
+                return Some((participants, ExpenseSplit::Equal));
+            }
+            SplitSpecification::Shares { shares } => ExpenseSplit::Shares { shares },
+            SplitSpecification::Exact { amounts } => ExpenseSplit::Exact { amounts },
+            SplitSpecification::Percentages { percentages } => {
+                ExpenseSplit::Percentages { percentages }
+            }
+        };
+
+        let mut participants = split.users().expect("non-equal split lists its users");
+        if !all_participants_in_group(group, &participants) {
+            return None;
+        }
+        participants.remove(payer_id);
+        Some((participants, split))
+    }
+}
 
-    fn get_participants(&self, group: &Group) -> Result<HashSet<UserId>, CreateExpenseError> {
-        match &self.participants {
+impl IncludeParticipants {
+    /// Returns the participants as a set of users.
+    /// Returns `None` if at least one participant does not belong to the group.
+    fn resolve(&self, group: &Group) -> Option<HashSet<UserId>> {
+        match self {
             IncludeParticipants::All => {
                 let mut participants = HashSet::new();
                 participants.insert(group.owner_id);
-                group.members.iter().for_each(|p| {
-                    participants.insert(*p);
-                });
-                Ok(participants)
+                participants.extend(group.members.keys().copied());
+                Some(participants)
             }
             IncludeParticipants::List { participants } => {
                 if !all_participants_in_group(group, participants) {
-                    return Err(CreateExpenseError::ParticipantNotFound);
+                    return None;
                 }
-                Ok(participants.clone())
+                Some(participants.clone())
+            }
+        }
+    }
+}
+
+/// Returns the users who paid an expense, with the amount each of them paid.
+pub(crate) fn resolve_payers(
+    payer_id: &UserId,
+    payers: Option<HashMap<UserId, Money>>,
+    total: Money,
+) -> Result<ExpensePayers, PayersError> {
+    let Some(payers) = payers else {
+        return Ok(ExpensePayers::single(*payer_id, total));
+    };
+
+    let payers = ExpensePayers::new(payers)?;
+    if !payers.contains(payer_id) {
+        return Err(PayersError::MissingPayer);
+    }
+    payers.validate(total)?;
+    Ok(payers)
+}
+
+/// Returns the currency of an expense and its rate to the group currency.
+pub(crate) fn resolve_currency(
+    group: &Group,
+    currency: Option<Currency>,
+    exchange_rate: Option<ExchangeRate>,
+    total: Money,
+) -> Result<(Currency, ExchangeRate), CurrencyError> {
+    let currency = currency.unwrap_or(group.currency);
+    let exchange_rate = if currency == group.currency {
+        match exchange_rate {
+            Some(exchange_rate) if !exchange_rate.is_identity() => {
+                return Err(CurrencyError::UnexpectedExchangeRate);
+            }
+            _ => ExchangeRate::IDENTITY,
+        }
+    } else {
+        exchange_rate.ok_or(CurrencyError::MissingExchangeRate)?
+    };
+
+    if exchange_rate.convert(total).is_none() {
+        return Err(CurrencyError::TotalTooLarge);
+    }
+    Ok((currency, exchange_rate))
+}
+
+/// Returns whether the custom category exists and belongs to the group.
+pub(crate) async fn category_belongs_to_group(
+    tx: &mut database::Transaction<'_>,
+    category_id: &CategoryId,
+    group_id: &GroupId,
+) -> Result<bool, database::Error> {
+    let category = database::queries::category::get_by_id(tx, category_id).await?;
+    Ok(category.is_some_and(|category| category.group_id == *group_id))
+}
+
+/// Raises an alert for every threshold reached by the budgets consumed by a
+/// new expense, during the period in which it occurred.
+/// A threshold raises a single alert per period of a budget.
+async fn record_budget_alerts(
+    tx: &mut database::Transaction<'_>,
+    expense_entry: &ExpenseEntry,
+) -> Result<(), database::Error> {
+    let budgets = database::queries::budget::get_all_for_group(tx, &expense_entry.group_id).await?;
+    let now = Utc::now();
+    for budget in budgets
+        .iter()
+        .filter(|budget| consumes(budget, expense_entry))
+    {
+        let Some(window) = budget.period.window(expense_entry.occurred_at) else {
+            continue;
+        };
+        let consumed = consumption(tx, budget, &window).await?;
+        for threshold in BudgetThreshold::ALL {
+            if threshold.is_reached(budget.amount, consumed) {
+                let alert = BudgetAlert::new(
+                    budget.id,
+                    threshold,
+                    window.start,
+                    expense_entry.expense_id,
+                    consumed,
+                    now,
+                );
+                database::queries::budget::create_alert(tx, &alert).await?;
             }
         }
     }
+    Ok(())
 }
 
 fn all_participants_in_group(group: &Group, participants: &HashSet<UserId>) -> bool {
-    let mut members: HashSet<UserId> = HashSet::from_iter(group.members.clone());
-    members.insert(group.owner_id);
-    members.is_superset(participants)
+    participants
+        .iter()
+        .all(|participant| group.contains_user(participant))
 }
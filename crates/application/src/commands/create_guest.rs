@@ -0,0 +1,70 @@
+use chrono::Utc;
+use domain::{
+    entities::User,
+    types::{
+        group_id::GroupId, member_role::MemberRole, role::Role, user_id::UserId, username::Username,
+    },
+};
+
+use crate::{
+    commands::create_user::{create_with_available_handle, id_suffix},
+    permissions::{self, Permission},
+};
+
+/// Adds to a group a member known by name only, who has no account. Guests
+/// can pay and take part in expenses until a registered user claims them.
+pub struct CreateGuestCommand {
+    pub group_id: GroupId,
+    pub name: Username,
+    pub current_user_id: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateGuestError {
+    #[error("only group owner and admins can add guests")]
+    NotAdmin,
+
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl CreateGuestCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<UserId, CreateGuestError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(CreateGuestError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::ManageMembers).is_err() {
+            return Err(CreateGuestError::NotAdmin);
+        }
+
+        if group.is_archived() {
+            return Err(CreateGuestError::GroupArchived);
+        }
+
+        let id = UserId::new_random();
+        let base = format!("guest_{}", id_suffix(&id));
+        let guest = User::new(
+            id,
+            self.name,
+            base.parse().expect("valid handle"),
+            None,
+            Role::User,
+            Utc::now(),
+        );
+
+        create_with_available_handle(tx, guest, &base).await?;
+        database::queries::group::add_member(tx, &self.group_id, &id, &MemberRole::Member).await?;
+
+        Ok(id)
+    }
+}
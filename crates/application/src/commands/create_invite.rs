@@ -0,0 +1,86 @@
+use chrono::{DateTime, Utc};
+use domain::{
+    entities::Invite,
+    types::{
+        group_id::GroupId, invite_id::InviteId, invite_token::InviteToken, member_role::MemberRole,
+        user_id::UserId,
+    },
+};
+
+use crate::permissions::{self, Permission};
+
+/// Creates an invite letting anyone knowing its random token join a group.
+/// Only the group owner and admins can manage invites.
+pub struct CreateInviteCommand {
+    pub group_id: GroupId,
+    pub current_user_id: UserId,
+    /// Role given to the users accepting the invite.
+    pub role: MemberRole,
+    /// Number of times the invite can be accepted, `Some(1)` for a
+    /// single-use invite and `None` for no limit.
+    pub max_uses: Option<u32>,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateInviteError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("only group owner and admins can manage invites")]
+    NotAdmin,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("expiry must be in the future")]
+    InvalidExpiry,
+
+    #[error("max uses must be > 0")]
+    InvalidMaxUses,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl CreateInviteCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<Invite, CreateInviteError> {
+        let now = Utc::now();
+        if self.expires_at <= now {
+            return Err(CreateInviteError::InvalidExpiry);
+        }
+        if self.max_uses == Some(0) {
+            return Err(CreateInviteError::InvalidMaxUses);
+        }
+
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(CreateInviteError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::ManageMembers).is_err() {
+            return Err(CreateInviteError::NotAdmin);
+        }
+
+        if group.is_archived() {
+            return Err(CreateInviteError::GroupArchived);
+        }
+
+        let invite = Invite::new(
+            InviteId::new_random(),
+            self.group_id,
+            InviteToken::new_random(),
+            self.role,
+            self.max_uses,
+            0,
+            self.current_user_id,
+            self.expires_at,
+            now,
+        );
+        database::queries::invite::create(tx, &invite).await?;
+
+        Ok(invite)
+    }
+}
@@ -0,0 +1,141 @@
+use chrono::Utc;
+use domain::{
+    entities::RecurringExpense,
+    types::{
+        category_id::CategoryId, currency::Currency, exchange_rate::ExchangeRate,
+        expense_category::ExpenseCategory, expense_note::ExpenseNote, expense_title::ExpenseTitle,
+        group_id::GroupId, money::Money, recurrence::Recurrence,
+        recurring_expense_id::RecurringExpenseId, user_id::UserId,
+    },
+};
+
+use crate::{
+    commands::create_expense::{
+        CurrencyError, SplitSpecification, category_belongs_to_group, resolve_currency,
+    },
+    permissions::{self, Denied, Permission},
+};
+
+/// Defines an expense that is added to the group following a schedule.
+///
+/// Occurrences are added by [MaterializeRecurringExpensesCommand](crate::commands::materialize_recurring_expenses::MaterializeRecurringExpensesCommand),
+/// including the ones scheduled before now.
+pub struct CreateRecurringExpenseCommand {
+    pub group_id: GroupId,
+    pub payer_id: UserId,
+    pub author_id: UserId,
+    pub title: ExpenseTitle,
+    pub note: Option<ExpenseNote>,
+    pub category: ExpenseCategory,
+    /// Optional custom category; must belong to the group.
+    pub category_id: Option<CategoryId>,
+    /// Split of each occurrence; [IncludeParticipants::All](crate::commands::create_expense::IncludeParticipants::All)
+    /// means the members of the group at definition time.
+    pub split: SplitSpecification,
+    pub total: Money,
+    /// Currency in which each occurrence is paid; defaults to the group currency.
+    pub currency: Option<Currency>,
+    /// Rate to the group currency; required if `currency` differs from it.
+    pub exchange_rate: Option<ExchangeRate>,
+    pub recurrence: Recurrence,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateRecurringExpenseError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("total must be > 0")]
+    InvalidTotal,
+
+    #[error("must be group member to pay an expense")]
+    PayerIsNotGroupMember,
+
+    #[error("author not found in group")]
+    AuthorNotInGroup,
+
+    #[error("group viewers cannot add recurring expenses")]
+    ReadOnlyMember,
+
+    #[error("at least one participant is not found in group")]
+    ParticipantNotFound,
+
+    #[error("category not found in group")]
+    CategoryNotFound,
+
+    #[error("invalid split: {0}")]
+    InvalidSplit(#[from] domain::types::expense_split::Error),
+
+    #[error("invalid currency: {0}")]
+    InvalidCurrency(#[from] CurrencyError),
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl CreateRecurringExpenseCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<RecurringExpenseId, CreateRecurringExpenseError> {
+        if self.total.is_negative() {
+            return Err(CreateRecurringExpenseError::InvalidTotal);
+        }
+
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(CreateRecurringExpenseError::GroupNotFound);
+        };
+
+        if !group.contains_user(&self.payer_id) {
+            return Err(CreateRecurringExpenseError::PayerIsNotGroupMember);
+        }
+        permissions::check(&group, &self.author_id, Permission::WriteExpenses).map_err(
+            |denied| match denied {
+                Denied::NotInGroup => CreateRecurringExpenseError::AuthorNotInGroup,
+                Denied::RoleNotAllowed => CreateRecurringExpenseError::ReadOnlyMember,
+            },
+        )?;
+
+        if group.is_archived() {
+            return Err(CreateRecurringExpenseError::GroupArchived);
+        }
+
+        let (participants, split) = self
+            .split
+            .resolve(&group, &self.payer_id)
+            .ok_or(CreateRecurringExpenseError::ParticipantNotFound)?;
+        split.validate(self.total)?;
+        let (currency, exchange_rate) =
+            resolve_currency(&group, self.currency, self.exchange_rate, self.total)?;
+        if let Some(category_id) = &self.category_id
+            && !category_belongs_to_group(tx, category_id, &self.group_id).await?
+        {
+            return Err(CreateRecurringExpenseError::CategoryNotFound);
+        }
+
+        let next_occurrence_at = self.recurrence.next_after(None);
+        let recurring_expense = RecurringExpense::new(
+            RecurringExpenseId::new_random(),
+            self.group_id,
+            self.title,
+            self.note,
+            self.category,
+            self.category_id,
+            self.payer_id,
+            participants,
+            split,
+            self.total,
+            currency,
+            exchange_rate,
+            self.author_id,
+            self.recurrence,
+            next_occurrence_at,
+            Utc::now(),
+        );
+        database::queries::recurring_expense::create(tx, &recurring_expense).await?;
+        Ok(recurring_expense.id)
+    }
+}
@@ -1,13 +1,15 @@
 use chrono::Utc;
 use domain::{
     entities::User,
-    types::{role::Role, user_id::UserId, username::Username},
+    types::{handle::Handle, role::Role, user_id::UserId, username::Username},
 };
 use email_address::EmailAddress;
 
 pub struct CreateUserCommand {
     pub email: EmailAddress,
     pub name: Username,
+    /// Handle chosen by the user, derived from their name if `None`.
+    pub handle: Option<Handle>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -15,6 +17,9 @@ pub enum CreateUserError {
     #[error("email already taken")]
     EmailAlreadyTaken,
 
+    #[error("handle already taken")]
+    HandleAlreadyTaken,
+
     #[error("database error: {0}")]
     Database(#[from] database::Error),
 }
@@ -30,10 +35,85 @@ impl CreateUserCommand {
 
         let id = UserId::new_random();
         let created_at = Utc::now();
-        let user = User::new(id, self.name, self.email, Role::User, created_at);
+        let email = Some(self.email);
+        let res = match self.handle {
+            Some(handle) => {
+                if database::queries::user::handle_exists(tx, &handle).await? {
+                    return Err(CreateUserError::HandleAlreadyTaken);
+                }
+                let user = User::new(id, self.name, handle, email, Role::User, created_at);
+                database::queries::user::create(tx, &user).await
+            }
+            None => {
+                let base = handle_base(&id, &self.name);
+                let user = User::new(
+                    id,
+                    self.name,
+                    base.parse().expect("valid handle"),
+                    email,
+                    Role::User,
+                    created_at,
+                );
+                create_with_available_handle(tx, user, &base).await
+            }
+        };
+
+        // another user may have signed up with the same email or handle since
+        // they were checked
+        match res {
+            Ok(()) => Ok(id),
+            Err(err) if err.is_unique_violation("user.email") => {
+                Err(CreateUserError::EmailAlreadyTaken)
+            }
+            Err(err) if err.is_unique_violation("user.handle") => {
+                Err(CreateUserError::HandleAlreadyTaken)
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+/// Base of the handle of a user, the same way handles of existing users were
+/// backfilled: the lowercase name. Names that are not valid handles fall back
+/// to a handle built from the end of the user id.
+fn handle_base(id: &UserId, name: &Username) -> String {
+    match name.value().parse::<Handle>() {
+        Ok(handle) => handle.value(),
+        Err(_) => format!("user_{}", id_suffix(id)),
+    }
+}
+
+/// Last 8 hexadecimal characters of the user id.
+pub(crate) fn id_suffix(id: &UserId) -> String {
+    let id = id.value().simple().to_string();
+    id[id.len() - 8..].to_string()
+}
 
-        database::queries::user::create(tx, &user).await?;
+/// Creates `user` with the provided base as handle if no user has it yet,
+/// otherwise with the base followed by the first numeric suffix that is
+/// free. Handles taken by concurrent creations are skipped as well. The base
+/// must be a valid handle short enough for a suffix.
+pub(crate) async fn create_with_available_handle(
+    tx: &mut database::Transaction<'_>,
+    mut user: User,
+    base: &str,
+) -> Result<(), database::Error> {
+    let mut n = 1;
+    loop {
+        let candidate = match n {
+            1 => base.to_string(),
+            n => format!("{base}_{n}"),
+        };
+        n += 1;
 
-        Ok(id)
+        let handle: Handle = candidate.parse().expect("valid handle");
+        if database::queries::user::handle_exists(tx, &handle).await? {
+            continue;
+        }
+        user.handle = handle;
+        match database::queries::user::create(tx, &user).await {
+            Err(err) if err.is_unique_violation("user.handle") => continue,
+            res => return res,
+        }
     }
 }
@@ -0,0 +1,68 @@
+use domain::types::{
+    attachment_id::AttachmentId, expense_id::ExpenseId, group_id::GroupId, user_id::UserId,
+};
+
+use crate::permissions::{self, Permission};
+
+/// Removes a file attached to an expense.
+///
+/// Only the metadata of the file is deleted; its content must be removed
+/// by the caller once the transaction is committed.
+pub struct DeleteAttachmentCommand {
+    pub group_id: GroupId,
+    pub expense_id: ExpenseId,
+    pub attachment_id: AttachmentId,
+    pub current_user_id: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DeleteAttachmentError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("attachment not found")]
+    AttachmentNotFound,
+
+    #[error("must be group member to remove an attachment")]
+    Forbidden,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl DeleteAttachmentCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<(), DeleteAttachmentError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(DeleteAttachmentError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::WriteExpenses).is_err() {
+            return Err(DeleteAttachmentError::Forbidden);
+        }
+
+        if group.is_archived() {
+            return Err(DeleteAttachmentError::GroupArchived);
+        }
+
+        let Some(attachment) =
+            database::queries::attachment::get_by_id(tx, &self.attachment_id).await?
+        else {
+            return Err(DeleteAttachmentError::AttachmentNotFound);
+        };
+        if attachment.group_id != self.group_id || attachment.expense_id != self.expense_id {
+            return Err(DeleteAttachmentError::AttachmentNotFound);
+        }
+
+        if !database::queries::attachment::delete(tx, &self.attachment_id).await? {
+            return Err(DeleteAttachmentError::AttachmentNotFound);
+        }
+
+        Ok(())
+    }
+}
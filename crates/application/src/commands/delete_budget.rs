@@ -0,0 +1,58 @@
+use domain::types::{budget_id::BudgetId, group_id::GroupId, user_id::UserId};
+
+use crate::permissions::{self, Permission};
+
+/// Deletes a budget along with its alerts.
+/// Only the group owner and admins can manage budgets.
+pub struct DeleteBudgetCommand {
+    pub group_id: GroupId,
+    pub budget_id: BudgetId,
+    pub current_user_id: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DeleteBudgetError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("budget not found")]
+    BudgetNotFound,
+
+    #[error("only group owner and admins can manage budgets")]
+    NotAdmin,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl DeleteBudgetCommand {
+    pub async fn handle(self, tx: &mut database::Transaction<'_>) -> Result<(), DeleteBudgetError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(DeleteBudgetError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::ManageSettings).is_err() {
+            return Err(DeleteBudgetError::NotAdmin);
+        }
+
+        if group.is_archived() {
+            return Err(DeleteBudgetError::GroupArchived);
+        }
+
+        let Some(budget) = database::queries::budget::get_by_id(tx, &self.budget_id).await? else {
+            return Err(DeleteBudgetError::BudgetNotFound);
+        };
+        if budget.group_id != self.group_id {
+            return Err(DeleteBudgetError::BudgetNotFound);
+        }
+
+        if !database::queries::budget::delete(tx, &self.budget_id).await? {
+            return Err(DeleteBudgetError::BudgetNotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use domain::types::{category_id::CategoryId, group_id::GroupId, user_id::UserId};
+
+use crate::permissions::{self, Permission};
+
+/// Deletes a custom category.
+/// Expenses of that category, including their previous versions, become
+/// uncategorized. Only the group owner and admins can manage categories.
+pub struct DeleteCategoryCommand {
+    pub group_id: GroupId,
+    pub category_id: CategoryId,
+    pub current_user_id: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DeleteCategoryError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("category not found")]
+    CategoryNotFound,
+
+    #[error("only group owner and admins can manage categories")]
+    NotAdmin,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl DeleteCategoryCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<(), DeleteCategoryError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(DeleteCategoryError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::ManageSettings).is_err() {
+            return Err(DeleteCategoryError::NotAdmin);
+        }
+
+        if group.is_archived() {
+            return Err(DeleteCategoryError::GroupArchived);
+        }
+
+        let Some(category) = database::queries::category::get_by_id(tx, &self.category_id).await?
+        else {
+            return Err(DeleteCategoryError::CategoryNotFound);
+        };
+        if category.group_id != self.group_id {
+            return Err(DeleteCategoryError::CategoryNotFound);
+        }
+
+        if !database::queries::category::delete(tx, &self.category_id).await? {
+            return Err(DeleteCategoryError::CategoryNotFound);
+        }
+
+        Ok(())
+    }
+}
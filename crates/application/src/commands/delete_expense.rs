@@ -0,0 +1,114 @@
+use chrono::Utc;
+use domain::{
+    entities::ExpenseEntry,
+    types::{
+        expense_entry_id::ExpenseEntryId, expense_entry_status::ExpenseEntryStatus,
+        expense_id::ExpenseId, group_id::GroupId, user_id::UserId,
+    },
+};
+
+use crate::permissions::{self, Permission};
+
+/// Deletes an expense while keeping its history.
+///
+/// A tombstone entry, copying the content of the active entry, is created
+/// with the [ExpenseEntryStatus::Deleted] status and the previously active
+/// entry is marked as overwritten by it.
+///
+/// Files attached to the expense are kept, so that they are back when the
+/// expense is restored, until they are purged by
+/// [PurgeDeletedExpenseAttachmentsCommand](crate::commands::purge_deleted_expense_attachments::PurgeDeletedExpenseAttachmentsCommand).
+pub struct DeleteExpenseCommand {
+    pub group_id: GroupId,
+    pub expense_id: ExpenseId,
+    pub current_user_id: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DeleteExpenseError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("expense not found")]
+    ExpenseNotFound,
+
+    #[error("must be group member to delete an expense")]
+    Forbidden,
+
+    #[error("expense has been updated concurrently")]
+    ConcurrentUpdate,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl DeleteExpenseCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<(), DeleteExpenseError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(DeleteExpenseError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::WriteExpenses).is_err() {
+            return Err(DeleteExpenseError::Forbidden);
+        }
+
+        if group.is_archived() {
+            return Err(DeleteExpenseError::GroupArchived);
+        }
+
+        let Some(current_entry) =
+            database::queries::expense_entry::get_active_by_expense_id(tx, &self.expense_id)
+                .await?
+        else {
+            return Err(DeleteExpenseError::ExpenseNotFound);
+        };
+        if current_entry.group_id != self.group_id {
+            return Err(DeleteExpenseError::ExpenseNotFound);
+        }
+
+        let now = Utc::now();
+        let tombstone = ExpenseEntry::new(
+            ExpenseEntryId::new_random(),
+            current_entry.expense_id,
+            current_entry.group_id,
+            current_entry.title,
+            current_entry.note,
+            current_entry.category,
+            current_entry.category_id,
+            current_entry.payer_id,
+            current_entry.payers,
+            current_entry.participants,
+            current_entry.split,
+            ExpenseEntryStatus::Deleted {
+                deleted_by: self.current_user_id,
+                deleted_at: now,
+            },
+            current_entry.total,
+            current_entry.currency,
+            current_entry.exchange_rate,
+            self.current_user_id,
+            current_entry.occurred_at,
+            now,
+        )
+        .expect("valid expense entry");
+
+        let overwritten = database::queries::expense_entry::mark_as_overwritten(
+            tx,
+            &current_entry.id,
+            &tombstone.id,
+        )
+        .await?;
+        if !overwritten {
+            return Err(DeleteExpenseError::ConcurrentUpdate);
+        }
+
+        database::queries::expense_entry::create(tx, &tombstone).await?;
+        Ok(())
+    }
+}
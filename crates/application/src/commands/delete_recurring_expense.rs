@@ -0,0 +1,62 @@
+use domain::types::{group_id::GroupId, recurring_expense_id::RecurringExpenseId, user_id::UserId};
+
+use crate::permissions::{self, Permission};
+
+/// Stops a recurring expense. Expenses it already added are kept.
+pub struct DeleteRecurringExpenseCommand {
+    pub group_id: GroupId,
+    pub recurring_expense_id: RecurringExpenseId,
+    pub current_user_id: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DeleteRecurringExpenseError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("recurring expense not found")]
+    RecurringExpenseNotFound,
+
+    #[error("forbidden")]
+    Forbidden,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl DeleteRecurringExpenseCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<(), DeleteRecurringExpenseError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(DeleteRecurringExpenseError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::WriteExpenses).is_err() {
+            return Err(DeleteRecurringExpenseError::Forbidden);
+        }
+
+        if group.is_archived() {
+            return Err(DeleteRecurringExpenseError::GroupArchived);
+        }
+
+        let Some(recurring_expense) =
+            database::queries::recurring_expense::get_by_id(tx, &self.recurring_expense_id).await?
+        else {
+            return Err(DeleteRecurringExpenseError::RecurringExpenseNotFound);
+        };
+        if recurring_expense.group_id != self.group_id {
+            return Err(DeleteRecurringExpenseError::RecurringExpenseNotFound);
+        }
+
+        if !database::queries::recurring_expense::delete(tx, &self.recurring_expense_id).await? {
+            return Err(DeleteRecurringExpenseError::RecurringExpenseNotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+
+use domain::{
+    entities::Group,
+    types::{
+        currency::Currency, exchange_rate::ExchangeRate, expense_id::ExpenseId,
+        expense_title::ExpenseTitle, group_id::GroupId, money::Money, settlement_id::SettlementId,
+        user_id::UserId,
+    },
+};
+
+use crate::{
+    commands::{
+        create_expense::{CreateExpenseCommand, CreateExpenseError, SplitSpecification},
+        record_settlement::{RecordSettlementCommand, RecordSettlementError},
+    },
+    import::{ImportFormat, ParseError, ParsedExpense, ParsedPayment, RowContent, RowError},
+    permissions::{self, Permission},
+};
+
+/// Imports the expenses and payments of a file exported by another app.
+///
+/// Every row is validated by the same rules as the ones created in this app.
+/// Rows are imported all together, or not at all if any of them is invalid.
+pub struct ImportExpensesCommand {
+    pub group_id: GroupId,
+    pub current_user_id: UserId,
+    pub format: ImportFormat,
+    pub content: String,
+    /// Group member standing for each person named in the file.
+    pub mapping: HashMap<String, UserId>,
+    /// Rates to the group currency, for rows in another currency which do
+    /// not provide theirs.
+    pub exchange_rates: HashMap<Currency, ExchangeRate>,
+    /// If set, rows are validated but nothing is saved.
+    pub dry_run: bool,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ImportExpensesError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("forbidden")]
+    Forbidden,
+
+    #[error("invalid file: {0}")]
+    InvalidFile(#[from] ParseError),
+
+    #[error("no group member given for {0}")]
+    UnmappedPerson(String),
+
+    #[error("user given for {0} is not a group member")]
+    PersonNotInGroup(String),
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+pub struct Output {
+    /// Whether the rows were saved; never set for a dry run.
+    pub committed: bool,
+    /// Outcome of every row, in file order.
+    pub rows: Vec<ImportedRow>,
+}
+
+pub struct ImportedRow {
+    /// Line of the row in the file; the header is on line 1.
+    pub line: u64,
+    pub description: String,
+    pub outcome: RowOutcome,
+}
+
+/// Outcome of a row; ids are those of records which were not saved if
+/// the import was not committed.
+pub enum RowOutcome {
+    Expense { expense_id: ExpenseId },
+    Settlement { settlement_id: SettlementId },
+    Ignored { reason: &'static str },
+    Failed(RowError),
+}
+
+impl ImportExpensesCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<Output, ImportExpensesError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(ImportExpensesError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::WriteExpenses).is_err() {
+            return Err(ImportExpensesError::Forbidden);
+        }
+
+        if group.is_archived() {
+            return Err(ImportExpensesError::GroupArchived);
+        }
+
+        let file = self.format.parse(&self.content)?;
+        for person in &file.persons {
+            let Some(user_id) = self.mapping.get(person) else {
+                return Err(ImportExpensesError::UnmappedPerson(person.clone()));
+            };
+            if !group.contains_user(user_id) {
+                return Err(ImportExpensesError::PersonNotInGroup(person.clone()));
+            }
+        }
+
+        // rows are validated by actually saving them, then rolled back
+        // unless all of them are valid
+        let mut import_tx = database::begin_nested(tx).await?;
+        let mut rows = Vec::with_capacity(file.rows.len());
+        for row in file.rows {
+            let outcome = match row.content {
+                Ok(RowContent::Expense(expense)) => self
+                    .import_expense(&mut import_tx, &group, &row.description, expense)
+                    .await
+                    .map(|expense_id| RowOutcome::Expense { expense_id }),
+                Ok(RowContent::Payment(payment)) => self
+                    .import_payment(&mut import_tx, &group, payment)
+                    .await
+                    .map(|settlement_id| RowOutcome::Settlement { settlement_id }),
+                Ok(RowContent::Ignored { reason }) => Ok(RowOutcome::Ignored { reason }),
+                Err(err) => Err(err),
+            };
+            let outcome = match outcome {
+                Ok(outcome) => outcome,
+                Err(
+                    RowError::Expense(CreateExpenseError::Database(err))
+                    | RowError::Settlement(RecordSettlementError::Database(err)),
+                ) => return Err(err.into()),
+                Err(err) => RowOutcome::Failed(err),
+            };
+            rows.push(ImportedRow {
+                line: row.line,
+                description: row.description,
+                outcome,
+            });
+        }
+
+        let committed = !self.dry_run
+            && !rows
+                .iter()
+                .any(|row| matches!(row.outcome, RowOutcome::Failed(_)));
+        if committed {
+            import_tx.commit().await.map_err(database::Error::from)?;
+        } else {
+            import_tx.rollback().await.map_err(database::Error::from)?;
+        }
+
+        Ok(Output { committed, rows })
+    }
+
+    async fn import_expense(
+        &self,
+        tx: &mut database::Transaction<'_>,
+        group: &Group,
+        description: &str,
+        expense: ParsedExpense,
+    ) -> Result<ExpenseId, RowError> {
+        let exchange_rate = self.exchange_rate(group, expense.currency, expense.exchange_rate)?;
+        let mut payers: HashMap<UserId, Money> = HashMap::new();
+        for (person, amount) in &expense.payers {
+            *payers.entry(self.mapping[person]).or_default() += *amount;
+        }
+        let mut amounts: HashMap<UserId, Money> = HashMap::new();
+        for (person, amount) in expense.amounts {
+            *amounts.entry(self.mapping[&person]).or_default() += amount;
+        }
+
+        let expense_id = CreateExpenseCommand {
+            group_id: self.group_id,
+            payer_id: self.mapping[&expense.payers[0].0],
+            payers: Some(payers),
+            author_id: self.current_user_id,
+            title: description.parse::<ExpenseTitle>()?,
+            note: None,
+            category: expense.category,
+            category_id: None,
+            split: SplitSpecification::Exact { amounts },
+            occured_at: expense.occurred_at,
+            total: expense.total,
+            currency: expense.currency,
+            exchange_rate,
+        }
+        .handle(tx)
+        .await?;
+        Ok(expense_id)
+    }
+
+    async fn import_payment(
+        &self,
+        tx: &mut database::Transaction<'_>,
+        group: &Group,
+        payment: ParsedPayment,
+    ) -> Result<SettlementId, RowError> {
+        // settlements are always in the group currency
+        let amount = match payment.currency {
+            Some(currency) if currency != group.currency => self
+                .exchange_rate(group, payment.currency, payment.exchange_rate)?
+                .and_then(|exchange_rate| exchange_rate.convert(payment.amount))
+                .ok_or(RowError::InvalidAmount(payment.amount.to_string()))?,
+            _ => payment.amount,
+        };
+
+        let settlement_id = RecordSettlementCommand {
+            group_id: self.group_id,
+            from: self.mapping[&payment.from],
+            to: self.mapping[&payment.to],
+            amount,
+            author_id: self.current_user_id,
+            occurred_at: payment.occurred_at,
+        }
+        .handle(tx)
+        .await?;
+        Ok(settlement_id)
+    }
+
+    /// Returns the rate to the group currency of a row, falling back to the
+    /// rates of the command for rows in another currency.
+    fn exchange_rate(
+        &self,
+        group: &Group,
+        currency: Option<Currency>,
+        exchange_rate: Option<ExchangeRate>,
+    ) -> Result<Option<ExchangeRate>, RowError> {
+        match (currency, exchange_rate) {
+            (Some(currency), None) if currency != group.currency => self
+                .exchange_rates
+                .get(&currency)
+                .copied()
+                .map(Some)
+                .ok_or(RowError::MissingExchangeRate(currency)),
+            (_, exchange_rate) => Ok(exchange_rate),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use domain::types::{group_id::GroupId, user_id::UserId};
+
+use crate::queries::get_group_balances::compute_group_balances;
+
+/// Lets a member leave a group once their balance is settled.
+///
+/// Expense entries and settlements involving the member are kept, see
+/// [`crate::commands::remove_group_member::RemoveGroupMemberCommand`].
+/// The owner must transfer ownership of the group before leaving it.
+pub struct LeaveGroupCommand {
+    pub group_id: GroupId,
+    pub current_user_id: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LeaveGroupError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group owner cannot leave it")]
+    OwnerCannotLeave,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("member balance is not settled")]
+    BalanceNotSettled,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl LeaveGroupCommand {
+    pub async fn handle(self, tx: &mut database::Transaction<'_>) -> Result<(), LeaveGroupError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(LeaveGroupError::GroupNotFound);
+        };
+
+        if group.is_user_owner(&self.current_user_id) {
+            return Err(LeaveGroupError::OwnerCannotLeave);
+        }
+
+        // hides the group from users outside of it
+        if !group.is_user_member(&self.current_user_id) {
+            return Err(LeaveGroupError::GroupNotFound);
+        }
+
+        if group.is_archived() {
+            return Err(LeaveGroupError::GroupArchived);
+        }
+
+        let balances = compute_group_balances(tx, &group).await?;
+        if !balances.balance_of(&self.current_user_id).is_zero() {
+            return Err(LeaveGroupError::BalanceNotSettled);
+        }
+
+        if !database::queries::group::remove_member(tx, &self.group_id, &self.current_user_id)
+            .await?
+        {
+            return Err(LeaveGroupError::GroupNotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,207 @@
+use chrono::{DateTime, Utc};
+use domain::{
+    entities::RecurringExpense,
+    types::{
+        expense_id::ExpenseId, expense_split::ExpenseSplit,
+        recurring_expense_id::RecurringExpenseId,
+    },
+};
+
+use crate::commands::create_expense::{
+    CreateExpenseCommand, CreateExpenseError, IncludeParticipants, SplitSpecification,
+};
+
+/// Adds to their group every occurrence of recurring expenses due at `now`.
+///
+/// Occurrences missed while the server was down are all added, each one
+/// dated at its scheduled time. Every occurrence is recorded, so running the
+/// command several times never adds the same occurrence twice.
+///
+/// An occurrence that is no longer valid (e.g. its payer left the group) or
+/// that cannot be added is skipped and the schedule moves on. Each occurrence
+/// runs in its own savepoint, so a failing recurring expense does not prevent
+/// the others from being materialized.
+pub struct MaterializeRecurringExpensesCommand {
+    pub now: DateTime<Utc>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MaterializeRecurringExpensesError {
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl MaterializeRecurringExpensesCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<Output, MaterializeRecurringExpensesError> {
+        let mut output = Output {
+            created: vec![],
+            skipped: vec![],
+        };
+
+        let due = database::queries::recurring_expense::get_all_due(tx, self.now).await?;
+        for recurring_expense in due {
+            let mut next_occurrence_at = recurring_expense.next_occurrence_at;
+            while let Some(occurred_at) = next_occurrence_at
+                && occurred_at <= self.now
+            {
+                let next = recurring_expense.recurrence.next_after(Some(occurred_at));
+                let skipped = |reason| SkippedOccurrence {
+                    recurring_expense_id: recurring_expense.id,
+                    occurred_at,
+                    reason,
+                };
+
+                let mut occurrence_tx = database::begin_nested(tx).await?;
+                let materialized =
+                    match materialize(&mut occurrence_tx, &recurring_expense, occurred_at, next)
+                        .await
+                    {
+                        Ok(materialized) => {
+                            occurrence_tx
+                                .commit()
+                                .await
+                                .map_err(database::Error::from)?;
+                            materialized
+                        }
+                        // the occurrence is left for a later run, without
+                        // holding back other recurring expenses
+                        Err(err) => {
+                            occurrence_tx
+                                .rollback()
+                                .await
+                                .map_err(database::Error::from)?;
+                            output
+                                .skipped
+                                .push(skipped(CreateExpenseError::Database(err)));
+                            break;
+                        }
+                    };
+                match materialized {
+                    // another run already claimed this occurrence
+                    Materialized::AlreadyClaimed => break,
+                    Materialized::Created(expense_id) => output.created.push(expense_id),
+                    Materialized::Skipped(reason) => output.skipped.push(skipped(reason)),
+                }
+
+                next_occurrence_at = next;
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+enum Materialized {
+    AlreadyClaimed,
+    Created(ExpenseId),
+    Skipped(CreateExpenseError),
+}
+
+/// Claims the occurrence of a recurring expense scheduled at `occurred_at`,
+/// adds it to its group and records it.
+///
+/// The expense is added in a savepoint, so that a failure leaves no partial
+/// expense behind and the occurrence is recorded as skipped.
+async fn materialize(
+    tx: &mut database::Transaction<'_>,
+    recurring_expense: &RecurringExpense,
+    occurred_at: DateTime<Utc>,
+    next: Option<DateTime<Utc>>,
+) -> Result<Materialized, database::Error> {
+    if !database::queries::recurring_expense::update_next_occurrence(
+        tx,
+        &recurring_expense.id,
+        Some(occurred_at),
+        next,
+    )
+    .await?
+    {
+        return Ok(Materialized::AlreadyClaimed);
+    }
+
+    let mut expense_tx = database::begin_nested(tx).await?;
+    let materialized = match occurrence(recurring_expense, occurred_at)
+        .handle(&mut expense_tx)
+        .await
+    {
+        Ok(expense_id) => {
+            expense_tx.commit().await?;
+            Materialized::Created(expense_id)
+        }
+        Err(reason) => {
+            expense_tx.rollback().await?;
+            Materialized::Skipped(reason)
+        }
+    };
+
+    let expense_id = match &materialized {
+        Materialized::Created(expense_id) => Some(expense_id),
+        _ => None,
+    };
+    database::queries::recurring_expense::record_occurrence(
+        tx,
+        &recurring_expense.id,
+        occurred_at,
+        expense_id,
+    )
+    .await?;
+
+    Ok(materialized)
+}
+
+/// Builds the command adding the occurrence of a recurring expense
+/// scheduled at `occurred_at`.
+fn occurrence(
+    recurring_expense: &RecurringExpense,
+    occurred_at: DateTime<Utc>,
+) -> CreateExpenseCommand {
+    let split = match &recurring_expense.split {
+        ExpenseSplit::Equal => SplitSpecification::Equal {
+            participants: IncludeParticipants::List {
+                participants: recurring_expense.participants.clone(),
+            },
+        },
+        ExpenseSplit::Shares { shares } => SplitSpecification::Shares {
+            shares: shares.clone(),
+        },
+        ExpenseSplit::Exact { amounts } => SplitSpecification::Exact {
+            amounts: amounts.clone(),
+        },
+        ExpenseSplit::Percentages { percentages } => SplitSpecification::Percentages {
+            percentages: percentages.clone(),
+        },
+    };
+
+    CreateExpenseCommand {
+        group_id: recurring_expense.group_id,
+        payer_id: recurring_expense.payer_id,
+        payers: None,
+        author_id: recurring_expense.author_id,
+        title: recurring_expense.title.clone(),
+        note: recurring_expense.note.clone(),
+        category: recurring_expense.category,
+        category_id: recurring_expense.category_id,
+        split,
+        occured_at: occurred_at,
+        total: recurring_expense.total,
+        currency: Some(recurring_expense.currency),
+        exchange_rate: Some(recurring_expense.exchange_rate),
+    }
+}
+
+#[derive(Debug)]
+pub struct Output {
+    /// Expenses added, in the order of their occurrence.
+    pub created: Vec<ExpenseId>,
+    pub skipped: Vec<SkippedOccurrence>,
+}
+
+#[derive(Debug)]
+pub struct SkippedOccurrence {
+    pub recurring_expense_id: RecurringExpenseId,
+    pub occurred_at: DateTime<Utc>,
+    pub reason: CreateExpenseError,
+}
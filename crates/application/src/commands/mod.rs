@@ -1,4 +1,35 @@
+pub mod accept_invite;
 pub mod add_group_member;
+pub mod archive_group;
+pub mod cancel_settlement;
+pub mod change_member_role;
+pub mod claim_guest;
+pub mod create_attachment;
+pub mod create_budget;
+pub mod create_category;
 pub mod create_empty_group;
 pub mod create_expense;
+pub mod create_guest;
+pub mod create_invite;
+pub mod create_recurring_expense;
 pub mod create_user;
+pub mod delete_attachment;
+pub mod delete_budget;
+pub mod delete_category;
+pub mod delete_expense;
+pub mod delete_recurring_expense;
+pub mod import_expenses;
+pub mod leave_group;
+pub mod materialize_recurring_expenses;
+pub mod purge_deleted_expense_attachments;
+pub mod record_settlement;
+pub mod remove_group_member;
+pub mod restore_expense;
+pub mod restore_group_backup;
+pub mod revoke_invite;
+pub mod transfer_group_ownership;
+pub mod unarchive_group;
+pub mod update_budget;
+pub mod update_category;
+pub mod update_expense;
+pub mod update_recurring_expense;
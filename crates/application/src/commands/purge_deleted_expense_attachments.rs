@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+
+/// Deletes the attachments of expenses deleted before `deleted_before`.
+///
+/// Attachments of a deleted expense are kept for a while, so that they are
+/// back if the expense is restored. Past that delay, a restored expense comes
+/// back without them.
+///
+/// Only attachment records are deleted: their content is removed by the
+/// periodic cleanup of orphaned files.
+pub struct PurgeDeletedExpenseAttachmentsCommand {
+    pub deleted_before: DateTime<Utc>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PurgeDeletedExpenseAttachmentsError {
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl PurgeDeletedExpenseAttachmentsCommand {
+    /// Returns the number of deleted attachments.
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<u64, PurgeDeletedExpenseAttachmentsError> {
+        Ok(
+            database::queries::attachment::delete_all_of_expenses_deleted_before(
+                tx,
+                self.deleted_before,
+            )
+            .await?,
+        )
+    }
+}
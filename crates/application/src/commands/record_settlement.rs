@@ -0,0 +1,100 @@
+use chrono::{DateTime, Utc};
+use domain::{
+    entities::Settlement,
+    types::{
+        group_id::GroupId, money::Money, settlement_id::SettlementId,
+        settlement_status::SettlementStatus, user_id::UserId,
+    },
+};
+
+use crate::permissions::{self, Denied, Permission};
+
+pub struct RecordSettlementCommand {
+    pub group_id: GroupId,
+    pub from: UserId,
+    pub to: UserId,
+    pub amount: Money,
+    pub author_id: UserId,
+    pub occurred_at: DateTime<Utc>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RecordSettlementError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("amount must be > 0")]
+    InvalidAmount,
+
+    #[error("sender and receiver must be different users")]
+    SameUser,
+
+    #[error("sender not found in group")]
+    SenderNotInGroup,
+
+    #[error("receiver not found in group")]
+    ReceiverNotInGroup,
+
+    #[error("author not found in group")]
+    AuthorNotInGroup,
+
+    #[error("group viewers cannot record settlements")]
+    ReadOnlyMember,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl RecordSettlementCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<SettlementId, RecordSettlementError> {
+        if !self.amount.is_positive() {
+            return Err(RecordSettlementError::InvalidAmount);
+        }
+        if self.from == self.to {
+            return Err(RecordSettlementError::SameUser);
+        }
+
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(RecordSettlementError::GroupNotFound);
+        };
+
+        permissions::check(&group, &self.author_id, Permission::WriteExpenses).map_err(
+            |denied| match denied {
+                Denied::NotInGroup => RecordSettlementError::AuthorNotInGroup,
+                Denied::RoleNotAllowed => RecordSettlementError::ReadOnlyMember,
+            },
+        )?;
+
+        if group.is_archived() {
+            return Err(RecordSettlementError::GroupArchived);
+        }
+
+        if !group.contains_user(&self.from) {
+            return Err(RecordSettlementError::SenderNotInGroup);
+        }
+        if !group.contains_user(&self.to) {
+            return Err(RecordSettlementError::ReceiverNotInGroup);
+        }
+
+        let settlement = Settlement::new(
+            SettlementId::new_random(),
+            self.group_id,
+            self.from,
+            self.to,
+            self.amount,
+            SettlementStatus::Active,
+            self.author_id,
+            self.occurred_at,
+            Utc::now(),
+        )
+        .expect("valid settlement");
+        database::queries::settlement::create(tx, &settlement).await?;
+        Ok(settlement.id)
+    }
+}
@@ -0,0 +1,86 @@
+use domain::types::{group_id::GroupId, user_id::UserId};
+
+use crate::{
+    permissions::{self, Permission},
+    queries::get_group_balances::compute_group_balances,
+};
+
+/// Removes a member from a group. Only the group owner and admins can
+/// remove members.
+///
+/// Expense entries and settlements involving the removed member are kept,
+/// so the group history and balances stay consistent. The member must
+/// therefore be settled up, unless the removal is forced by the group owner.
+pub struct RemoveGroupMemberCommand {
+    pub group_id: GroupId,
+    pub user_id_to_remove: UserId,
+    pub current_user_id: UserId,
+    /// Removes the member even if their balance is not settled.
+    /// Only allowed for the group owner.
+    pub force: bool,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RemoveGroupMemberError {
+    #[error("only group owner and admins can remove member")]
+    NotAdmin,
+
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("user is not member of this group")]
+    NotMember,
+
+    #[error("member balance is not settled")]
+    BalanceNotSettled,
+
+    #[error("only group owner can remove a member whose balance is not settled")]
+    ForceNotOwner,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl RemoveGroupMemberCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<(), RemoveGroupMemberError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(RemoveGroupMemberError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::ManageMembers).is_err() {
+            return Err(RemoveGroupMemberError::NotAdmin);
+        }
+
+        if group.is_archived() {
+            return Err(RemoveGroupMemberError::GroupArchived);
+        }
+
+        if !group.is_user_member(&self.user_id_to_remove) {
+            return Err(RemoveGroupMemberError::NotMember);
+        }
+
+        let balances = compute_group_balances(tx, &group).await?;
+        if !balances.balance_of(&self.user_id_to_remove).is_zero() {
+            if !self.force {
+                return Err(RemoveGroupMemberError::BalanceNotSettled);
+            }
+            if permissions::check(&group, &self.current_user_id, Permission::Own).is_err() {
+                return Err(RemoveGroupMemberError::ForceNotOwner);
+            }
+        }
+
+        if !database::queries::group::remove_member(tx, &self.group_id, &self.user_id_to_remove)
+            .await?
+        {
+            return Err(RemoveGroupMemberError::NotMember);
+        }
+
+        Ok(())
+    }
+}
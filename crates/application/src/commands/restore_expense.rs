@@ -0,0 +1,103 @@
+use chrono::Utc;
+use domain::{
+    entities::ExpenseEntry,
+    types::{
+        expense_entry_id::ExpenseEntryId, expense_entry_status::ExpenseEntryStatus,
+        expense_id::ExpenseId, group_id::GroupId, user_id::UserId,
+    },
+};
+
+use crate::permissions::{self, Permission};
+
+/// Restores a deleted expense.
+///
+/// A new active entry is created from the content of the latest tombstone
+/// entry of the expense. Tombstones are kept as is for audit.
+pub struct RestoreExpenseCommand {
+    pub group_id: GroupId,
+    pub expense_id: ExpenseId,
+    pub current_user_id: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RestoreExpenseError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("expense not found")]
+    ExpenseNotFound,
+
+    #[error("must be group member to restore an expense")]
+    Forbidden,
+
+    #[error("expense is not deleted")]
+    NotDeleted,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl RestoreExpenseCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<ExpenseEntryId, RestoreExpenseError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(RestoreExpenseError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::WriteExpenses).is_err() {
+            return Err(RestoreExpenseError::Forbidden);
+        }
+
+        if group.is_archived() {
+            return Err(RestoreExpenseError::GroupArchived);
+        }
+
+        let expense_entries =
+            database::queries::expense_entry::get_all_by_expense_id(tx, &self.expense_id).await?;
+        let Some(latest_entry) = expense_entries
+            .into_iter()
+            .max_by_key(|entry| (entry.created_at, entry.id))
+        else {
+            return Err(RestoreExpenseError::ExpenseNotFound);
+        };
+        if latest_entry.group_id != self.group_id {
+            return Err(RestoreExpenseError::ExpenseNotFound);
+        }
+
+        if !matches!(latest_entry.status, ExpenseEntryStatus::Deleted { .. }) {
+            return Err(RestoreExpenseError::NotDeleted);
+        }
+
+        let restored_entry = ExpenseEntry::new(
+            ExpenseEntryId::new_random(),
+            latest_entry.expense_id,
+            latest_entry.group_id,
+            latest_entry.title,
+            latest_entry.note,
+            latest_entry.category,
+            latest_entry.category_id,
+            latest_entry.payer_id,
+            latest_entry.payers,
+            latest_entry.participants,
+            latest_entry.split,
+            ExpenseEntryStatus::Active,
+            latest_entry.total,
+            latest_entry.currency,
+            latest_entry.exchange_rate,
+            self.current_user_id,
+            latest_entry.occurred_at,
+            Utc::now(),
+        )
+        .expect("valid expense entry");
+
+        // a concurrent restore is rejected by the database, as only one entry
+        // per expense can be active
+        database::queries::expense_entry::create(tx, &restored_entry).await?;
+        Ok(restored_entry.id)
+    }
+}
@@ -0,0 +1,386 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    str::FromStr,
+};
+
+use chrono::Utc;
+use domain::{
+    entities::{Category, ExpenseEntry, Group, Settlement},
+    types::{
+        category_id::CategoryId, expense_entry_id::ExpenseEntryId,
+        expense_entry_status::ExpenseEntryStatus, expense_id::ExpenseId,
+        expense_payers::ExpensePayers, expense_split::ExpenseSplit, group_id::GroupId,
+        groupname::Groupname, member_role::MemberRole, money::Money, settlement_id::SettlementId,
+        settlement_status::SettlementStatus, user_id::UserId,
+    },
+};
+use uuid::Uuid;
+
+use crate::backup::{
+    AmountRecord, BackupDocument, CategoryRecord, ExpenseEntryRecord, ExpenseEntryStatusRecord,
+    SettlementRecord, SettlementStatusRecord, SplitRecord, WeightRecord,
+};
+
+/// Recreates a group from a [backup document](crate::backup), under new ids.
+///
+/// The current user becomes the owner of the restored group, whose members
+/// are the users standing for the owner and members of the backed up group.
+/// Only the current user and the users already sharing a group with them can
+/// stand for users of the document; guests cannot, as they belong to a
+/// single group.
+pub struct RestoreGroupBackupCommand {
+    pub current_user_id: UserId,
+    pub document: serde_json::Value,
+    /// User of this instance standing for each user of the document, by
+    /// their id in the document.
+    pub user_mapping: HashMap<Uuid, UserId>,
+    /// Name of the restored group; defaults to the one in the document.
+    pub name: Option<Groupname>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RestoreGroupBackupError {
+    #[error(transparent)]
+    InvalidDocument(#[from] crate::backup::Error),
+
+    #[error("no user given for user {0} of the document")]
+    UnmappedUser(Uuid),
+
+    #[error("several users of the document are mapped to user {0}")]
+    UserMappedTwice(UserId),
+
+    #[error("user {0} not found")]
+    UserNotFound(UserId),
+
+    #[error("user {0} is a guest and cannot join another group")]
+    GuestUser(UserId),
+
+    #[error("user {0} does not share any group with the current user")]
+    UserNotCoMember(UserId),
+
+    #[error("another group for owner with the same name already exists")]
+    NameNotAvailable,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl RestoreGroupBackupCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<GroupId, RestoreGroupBackupError> {
+        let document = BackupDocument::from_json(self.document)?;
+
+        let mut users = HashMap::new();
+        let mut targets = HashSet::new();
+        for user in &document.users {
+            let Some(target) = self.user_mapping.get(&user.id) else {
+                return Err(RestoreGroupBackupError::UnmappedUser(user.id));
+            };
+            if !targets.insert(*target) {
+                return Err(RestoreGroupBackupError::UserMappedTwice(*target));
+            }
+            users.insert(user.id, *target);
+        }
+        let existing = database::queries::user::get_all_in_ids(tx, targets.clone()).await?;
+        if let Some(missing) = targets.iter().find(|id| !existing.contains_key(id)) {
+            return Err(RestoreGroupBackupError::UserNotFound(*missing));
+        }
+        if let Some(guest) = existing.values().find(|user| user.is_guest()) {
+            return Err(RestoreGroupBackupError::GuestUser(guest.id));
+        }
+        let co_members =
+            database::queries::user::get_co_member_ids(tx, &self.current_user_id).await?;
+        if let Some(stranger) = targets
+            .iter()
+            .find(|id| **id != self.current_user_id && !co_members.contains(id))
+        {
+            return Err(RestoreGroupBackupError::UserNotCoMember(*stranger));
+        }
+
+        let name = match self.name {
+            Some(name) => name,
+            None => parse(&document.group.name)?,
+        };
+        if database::queries::group::exists_by_name_for_owner(tx, &name, &self.current_user_id)
+            .await?
+        {
+            return Err(RestoreGroupBackupError::NameNotAvailable);
+        }
+
+        let remapper = Remapper::new(&document, users)?;
+        let group = remapper.group(&document, name, self.current_user_id)?;
+        let categories = document
+            .categories
+            .iter()
+            .map(|record| remapper.category(record))
+            .collect::<Result<Vec<_>, _>>()?;
+        let expense_entries = document
+            .expense_entries
+            .iter()
+            .map(|record| remapper.expense_entry(record))
+            .collect::<Result<Vec<_>, _>>()?;
+        let settlements = document
+            .settlements
+            .iter()
+            .map(|record| remapper.settlement(record))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        database::queries::group::create(tx, &group).await?;
+        for category in &categories {
+            database::queries::category::create(tx, category).await?;
+        }
+        for expense_entry in &expense_entries {
+            database::queries::expense_entry::create(tx, expense_entry).await?;
+        }
+        for settlement in &settlements {
+            database::queries::settlement::create(tx, settlement).await?;
+        }
+
+        Ok(group.id)
+    }
+}
+
+/// Gives new ids to the records of a document, and replaces its users by
+/// the ones standing for them.
+struct Remapper {
+    group_id: GroupId,
+    users: HashMap<Uuid, UserId>,
+    categories: HashMap<Uuid, CategoryId>,
+    expense_entries: HashMap<Uuid, ExpenseEntryId>,
+    expenses: HashMap<Uuid, ExpenseId>,
+}
+
+impl Remapper {
+    fn new(
+        document: &BackupDocument,
+        users: HashMap<Uuid, UserId>,
+    ) -> Result<Self, crate::backup::Error> {
+        let mut categories = HashMap::new();
+        for record in &document.categories {
+            if categories
+                .insert(record.id, CategoryId::new_random())
+                .is_some()
+            {
+                return Err(invalid(format!("category {} is duplicated", record.id)));
+            }
+        }
+
+        let mut expense_entries = HashMap::new();
+        let mut expenses = HashMap::new();
+        let mut active_expenses = HashSet::new();
+        for record in &document.expense_entries {
+            if expense_entries
+                .insert(record.id, ExpenseEntryId::new_random())
+                .is_some()
+            {
+                return Err(invalid(format!(
+                    "expense entry {} is duplicated",
+                    record.id
+                )));
+            }
+            expenses
+                .entry(record.expense_id)
+                .or_insert_with(ExpenseId::new_random);
+            if matches!(record.status, ExpenseEntryStatusRecord::Active)
+                && !active_expenses.insert(record.expense_id)
+            {
+                return Err(invalid(format!(
+                    "expense {} has several active entries",
+                    record.expense_id
+                )));
+            }
+        }
+
+        Ok(Self {
+            group_id: GroupId::new_random(),
+            users,
+            categories,
+            expense_entries,
+            expenses,
+        })
+    }
+
+    fn group(
+        &self,
+        document: &BackupDocument,
+        name: Groupname,
+        owner_id: UserId,
+    ) -> Result<Group, crate::backup::Error> {
+        let mut members = HashMap::new();
+        for id in std::iter::once(&document.group.owner_id).chain(&document.group.member_ids) {
+            members.insert(self.user(*id)?, MemberRole::Member);
+        }
+        members.remove(&owner_id);
+
+        Ok(Group::new(
+            self.group_id,
+            name,
+            parse(&document.group.currency)?,
+            owner_id,
+            members,
+            Utc::now(),
+        ))
+    }
+
+    fn category(&self, record: &CategoryRecord) -> Result<Category, crate::backup::Error> {
+        Ok(Category::new(
+            self.categories[&record.id],
+            self.group_id,
+            parse(&record.name)?,
+            parse(&record.color)?,
+            parse(&record.emoji)?,
+            record.created_at,
+        ))
+    }
+
+    fn expense_entry(
+        &self,
+        record: &ExpenseEntryRecord,
+    ) -> Result<ExpenseEntry, crate::backup::Error> {
+        let category_id = record
+            .category_id
+            .map(|id| {
+                self.categories
+                    .get(&id)
+                    .copied()
+                    .ok_or_else(|| invalid(format!("category {} is not listed", id)))
+            })
+            .transpose()?;
+        let payers = ExpensePayers::new(self.amounts(&record.payers)?)
+            .map_err(|err| invalid(err.to_string()))?;
+        let participants = record
+            .participant_ids
+            .iter()
+            .map(|id| self.user(*id))
+            .collect::<Result<_, _>>()?;
+        let split = match &record.split {
+            SplitRecord::Equal => ExpenseSplit::Equal,
+            SplitRecord::Shares { shares } => ExpenseSplit::Shares {
+                shares: self.weights(shares)?,
+            },
+            SplitRecord::Exact { amounts } => ExpenseSplit::Exact {
+                amounts: self.amounts(amounts)?,
+            },
+            SplitRecord::Percentages { percentages } => ExpenseSplit::Percentages {
+                percentages: self.weights(percentages)?,
+            },
+        };
+        let status = match &record.status {
+            ExpenseEntryStatusRecord::Active => ExpenseEntryStatus::Active,
+            ExpenseEntryStatusRecord::Inactive { overwritten_by } => ExpenseEntryStatus::Inactive {
+                overwritten_by: self
+                    .expense_entries
+                    .get(overwritten_by)
+                    .copied()
+                    .ok_or_else(|| {
+                        invalid(format!("expense entry {} is not listed", overwritten_by))
+                    })?,
+            },
+            ExpenseEntryStatusRecord::Deleted {
+                deleted_by,
+                deleted_at,
+            } => ExpenseEntryStatus::Deleted {
+                deleted_by: self.user(*deleted_by)?,
+                deleted_at: *deleted_at,
+            },
+        };
+
+        ExpenseEntry::new(
+            self.expense_entries[&record.id],
+            self.expenses[&record.expense_id],
+            self.group_id,
+            parse(&record.title)?,
+            record.note.as_deref().map(parse).transpose()?,
+            parse(&record.category)?,
+            category_id,
+            self.user(record.payer_id)?,
+            payers,
+            participants,
+            split,
+            status,
+            amount(record.total_cents)?,
+            parse(&record.currency)?,
+            parse(&record.exchange_rate)?,
+            self.user(record.author_id)?,
+            record.occurred_at,
+            record.created_at,
+        )
+        .map_err(|err| invalid(format!("expense entry {}: {}", record.id, err)))
+    }
+
+    fn settlement(&self, record: &SettlementRecord) -> Result<Settlement, crate::backup::Error> {
+        let status = match &record.status {
+            SettlementStatusRecord::Active => SettlementStatus::Active,
+            SettlementStatusRecord::Cancelled {
+                cancelled_by,
+                cancelled_at,
+            } => SettlementStatus::Cancelled {
+                cancelled_by: self.user(*cancelled_by)?,
+                cancelled_at: *cancelled_at,
+            },
+        };
+
+        Settlement::new(
+            SettlementId::new_random(),
+            self.group_id,
+            self.user(record.from_user_id)?,
+            self.user(record.to_user_id)?,
+            amount(record.amount_cents)?,
+            status,
+            self.user(record.author_id)?,
+            record.occurred_at,
+            record.created_at,
+        )
+        .map_err(|err| invalid(format!("settlement {}: {}", record.id, err)))
+    }
+
+    fn user(&self, id: Uuid) -> Result<UserId, crate::backup::Error> {
+        self.users
+            .get(&id)
+            .copied()
+            .ok_or_else(|| invalid(format!("user {} is not listed", id)))
+    }
+
+    fn amounts(
+        &self,
+        records: &[AmountRecord],
+    ) -> Result<HashMap<UserId, Money>, crate::backup::Error> {
+        records
+            .iter()
+            .map(|record| Ok((self.user(record.user_id)?, amount(record.amount_cents)?)))
+            .collect()
+    }
+
+    fn weights(
+        &self,
+        records: &[WeightRecord],
+    ) -> Result<HashMap<UserId, u64>, crate::backup::Error> {
+        records
+            .iter()
+            .map(|record| Ok((self.user(record.user_id)?, record.weight)))
+            .collect()
+    }
+}
+
+fn invalid(msg: String) -> crate::backup::Error {
+    crate::backup::Error::Invalid(msg)
+}
+
+fn amount(cents: i64) -> Result<Money, crate::backup::Error> {
+    Money::from_cents(cents)
+        .within_input_bounds()
+        .map_err(|err| invalid(format!("{} cents: {}", cents, err)))
+}
+
+fn parse<T>(value: &str) -> Result<T, crate::backup::Error>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|err| invalid(format!("{:?}: {}", value, err)))
+}
@@ -0,0 +1,63 @@
+use chrono::Utc;
+use domain::types::{group_id::GroupId, invite_id::InviteId, user_id::UserId};
+
+use crate::permissions::{self, Permission};
+
+/// Revokes an invite, so that it cannot be accepted anymore. Members who
+/// already joined through it stay in the group.
+/// Only the group owner and admins can manage invites.
+pub struct RevokeInviteCommand {
+    pub group_id: GroupId,
+    pub invite_id: InviteId,
+    pub current_user_id: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RevokeInviteError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("only group owner and admins can manage invites")]
+    NotAdmin,
+
+    #[error("invite not found")]
+    InviteNotFound,
+
+    #[error("invite is already revoked")]
+    AlreadyRevoked,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl RevokeInviteCommand {
+    pub async fn handle(self, tx: &mut database::Transaction<'_>) -> Result<(), RevokeInviteError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(RevokeInviteError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::ManageMembers).is_err() {
+            return Err(RevokeInviteError::NotAdmin);
+        }
+
+        if group.is_archived() {
+            return Err(RevokeInviteError::GroupArchived);
+        }
+
+        let Some(invite) = database::queries::invite::get_by_id(tx, &self.invite_id).await? else {
+            return Err(RevokeInviteError::InviteNotFound);
+        };
+        if invite.group_id != self.group_id {
+            return Err(RevokeInviteError::InviteNotFound);
+        }
+
+        if !database::queries::invite::revoke(tx, &self.invite_id, Utc::now()).await? {
+            return Err(RevokeInviteError::AlreadyRevoked);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+use domain::types::{group_id::GroupId, user_id::UserId};
+
+use crate::permissions::{self, Permission};
+
+/// Hands a group over to one of its members, the current owner becoming
+/// an admin of it. Only the group owner can transfer ownership.
+pub struct TransferGroupOwnershipCommand {
+    pub group_id: GroupId,
+    pub new_owner_id: UserId,
+    pub current_user_id: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TransferGroupOwnershipError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("only group owner can transfer ownership")]
+    NotOwner,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("new owner must be member of this group")]
+    NotMember,
+
+    #[error("new owner cannot be a guest")]
+    NewOwnerIsGuest,
+
+    #[error("new owner already owns another group with the same name")]
+    NameNotAvailable,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl TransferGroupOwnershipCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<(), TransferGroupOwnershipError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(TransferGroupOwnershipError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::Own).is_err() {
+            return Err(TransferGroupOwnershipError::NotOwner);
+        }
+
+        if group.is_archived() {
+            return Err(TransferGroupOwnershipError::GroupArchived);
+        }
+
+        if !group.is_user_member(&self.new_owner_id) {
+            return Err(TransferGroupOwnershipError::NotMember);
+        }
+
+        if database::queries::user::get_by_id(tx, &self.new_owner_id)
+            .await?
+            .is_none_or(|user| user.is_guest())
+        {
+            return Err(TransferGroupOwnershipError::NewOwnerIsGuest);
+        }
+
+        if database::queries::group::exists_by_name_for_owner(tx, &group.name, &self.new_owner_id)
+            .await?
+        {
+            return Err(TransferGroupOwnershipError::NameNotAvailable);
+        }
+
+        if !database::queries::group::transfer_ownership(
+            tx,
+            &self.group_id,
+            &self.current_user_id,
+            &self.new_owner_id,
+        )
+        .await?
+        {
+            return Err(TransferGroupOwnershipError::GroupNotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use domain::types::{group_id::GroupId, user_id::UserId};
+
+use crate::permissions::{self, Permission};
+
+/// Puts an archived group back in use.
+/// Only the group owner can unarchive it.
+pub struct UnarchiveGroupCommand {
+    pub group_id: GroupId,
+    pub current_user_id: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UnarchiveGroupError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("only group owner can unarchive it")]
+    NotOwner,
+
+    #[error("group is not archived")]
+    NotArchived,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl UnarchiveGroupCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<(), UnarchiveGroupError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(UnarchiveGroupError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::Own).is_err() {
+            return Err(UnarchiveGroupError::NotOwner);
+        }
+
+        if !group.is_archived() {
+            return Err(UnarchiveGroupError::NotArchived);
+        }
+
+        if !database::queries::group::set_archived_at(tx, &self.group_id, None).await? {
+            return Err(UnarchiveGroupError::GroupNotFound);
+        }
+
+        Ok(())
+    }
+}
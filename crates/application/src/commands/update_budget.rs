@@ -0,0 +1,89 @@
+use domain::types::{
+    budget_id::BudgetId, budget_period::BudgetPeriod, category_id::CategoryId, group_id::GroupId,
+    money::Money, user_id::UserId,
+};
+
+use crate::{
+    commands::create_expense::category_belongs_to_group,
+    permissions::{self, Permission},
+};
+
+/// Changes the category, amount and period of a budget.
+/// Alerts already raised are kept. Only the group owner and admins can manage budgets.
+pub struct UpdateBudgetCommand {
+    pub group_id: GroupId,
+    pub budget_id: BudgetId,
+    pub current_user_id: UserId,
+    /// Optional custom category; must belong to the group.
+    pub category_id: Option<CategoryId>,
+    /// Limit in the group currency.
+    pub amount: Money,
+    pub period: BudgetPeriod,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateBudgetError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("budget not found")]
+    BudgetNotFound,
+
+    #[error("only group owner and admins can manage budgets")]
+    NotAdmin,
+
+    #[error("category not found in group")]
+    CategoryNotFound,
+
+    #[error("amount must be > 0")]
+    InvalidAmount,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl UpdateBudgetCommand {
+    pub async fn handle(self, tx: &mut database::Transaction<'_>) -> Result<(), UpdateBudgetError> {
+        if !self.amount.is_positive() {
+            return Err(UpdateBudgetError::InvalidAmount);
+        }
+
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(UpdateBudgetError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::ManageSettings).is_err() {
+            return Err(UpdateBudgetError::NotAdmin);
+        }
+
+        if group.is_archived() {
+            return Err(UpdateBudgetError::GroupArchived);
+        }
+
+        let Some(mut budget) = database::queries::budget::get_by_id(tx, &self.budget_id).await?
+        else {
+            return Err(UpdateBudgetError::BudgetNotFound);
+        };
+        if budget.group_id != self.group_id {
+            return Err(UpdateBudgetError::BudgetNotFound);
+        }
+
+        if let Some(category_id) = &self.category_id
+            && !category_belongs_to_group(tx, category_id, &self.group_id).await?
+        {
+            return Err(UpdateBudgetError::CategoryNotFound);
+        }
+
+        budget.category_id = self.category_id;
+        budget.amount = self.amount;
+        budget.period = self.period;
+        if !database::queries::budget::update(tx, &budget).await? {
+            return Err(UpdateBudgetError::BudgetNotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use domain::types::{
+    category_color::CategoryColor, category_emoji::CategoryEmoji, category_id::CategoryId,
+    category_name::CategoryName, group_id::GroupId, user_id::UserId,
+};
+
+use crate::permissions::{self, Permission};
+
+/// Renames a custom category and changes its color and emoji.
+/// Only the group owner and admins can manage categories.
+pub struct UpdateCategoryCommand {
+    pub group_id: GroupId,
+    pub category_id: CategoryId,
+    pub current_user_id: UserId,
+    pub name: CategoryName,
+    pub color: CategoryColor,
+    pub emoji: CategoryEmoji,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateCategoryError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("category not found")]
+    CategoryNotFound,
+
+    #[error("only group owner and admins can manage categories")]
+    NotAdmin,
+
+    #[error("another category of the group has the same name")]
+    NameNotAvailable,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl UpdateCategoryCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<(), UpdateCategoryError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(UpdateCategoryError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::ManageSettings).is_err() {
+            return Err(UpdateCategoryError::NotAdmin);
+        }
+
+        if group.is_archived() {
+            return Err(UpdateCategoryError::GroupArchived);
+        }
+
+        let Some(mut category) =
+            database::queries::category::get_by_id(tx, &self.category_id).await?
+        else {
+            return Err(UpdateCategoryError::CategoryNotFound);
+        };
+        if category.group_id != self.group_id {
+            return Err(UpdateCategoryError::CategoryNotFound);
+        }
+
+        if database::queries::category::exists_by_name_for_group(
+            tx,
+            &self.group_id,
+            &self.name,
+            Some(&self.category_id),
+        )
+        .await?
+        {
+            return Err(UpdateCategoryError::NameNotAvailable);
+        }
+
+        category.name = self.name;
+        category.color = self.color;
+        category.emoji = self.emoji;
+        if !database::queries::category::update(tx, &category).await? {
+            return Err(UpdateCategoryError::CategoryNotFound);
+        }
+
+        Ok(())
+    }
+}
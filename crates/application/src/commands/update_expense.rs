@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use domain::{
+    entities::ExpenseEntry,
+    types::{
+        category_id::CategoryId, currency::Currency, exchange_rate::ExchangeRate,
+        expense_category::ExpenseCategory, expense_entry_id::ExpenseEntryId,
+        expense_entry_status::ExpenseEntryStatus, expense_id::ExpenseId, expense_note::ExpenseNote,
+        expense_title::ExpenseTitle, group_id::GroupId, money::Money, user_id::UserId,
+    },
+};
+
+use crate::{
+    commands::create_expense::{
+        CurrencyError, PayersError, SplitSpecification, category_belongs_to_group,
+        resolve_currency, resolve_payers,
+    },
+    permissions::{self, Denied, Permission},
+};
+
+/// Edits an expense by creating a new version of it.
+///
+/// The new expense entry becomes the active one and the previously active
+/// entry is marked as overwritten by the new one.
+pub struct UpdateExpenseCommand {
+    pub group_id: GroupId,
+    pub expense_id: ExpenseId,
+    pub payer_id: UserId,
+    /// Amount paid by each user, if several users paid the expense; must
+    /// include `payer_id` and sum up to the total. If `None`, `payer_id`
+    /// paid the whole total.
+    pub payers: Option<HashMap<UserId, Money>>,
+    pub author_id: UserId,
+    pub title: ExpenseTitle,
+    pub note: Option<ExpenseNote>,
+    pub category: ExpenseCategory,
+    /// Optional custom category; must belong to the group.
+    pub category_id: Option<CategoryId>,
+    pub split: SplitSpecification,
+    pub occured_at: DateTime<Utc>,
+    pub total: Money,
+    /// Currency in which the expense was paid; defaults to the group currency.
+    pub currency: Option<Currency>,
+    /// Rate to the group currency; required if `currency` differs from it.
+    pub exchange_rate: Option<ExchangeRate>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateExpenseError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("expense not found")]
+    ExpenseNotFound,
+
+    #[error("total must be > 0")]
+    InvalidTotal,
+
+    #[error("must be group member to pay an expense")]
+    PayerIsNotGroupMember,
+
+    #[error("author not found in group")]
+    AuthorNotInGroup,
+
+    #[error("group viewers cannot edit expenses")]
+    ReadOnlyMember,
+
+    #[error("at least one participant is not found in group")]
+    ParticipantNotFound,
+
+    #[error("category not found in group")]
+    CategoryNotFound,
+
+    #[error("invalid split: {0}")]
+    InvalidSplit(#[from] domain::types::expense_split::Error),
+
+    #[error("invalid currency: {0}")]
+    InvalidCurrency(#[from] CurrencyError),
+
+    #[error("invalid payers: {0}")]
+    InvalidPayers(#[from] PayersError),
+
+    #[error("expense has been updated concurrently")]
+    ConcurrentUpdate,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl UpdateExpenseCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<ExpenseEntryId, UpdateExpenseError> {
+        if self.total.is_negative() {
+            return Err(UpdateExpenseError::InvalidTotal);
+        }
+
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(UpdateExpenseError::GroupNotFound);
+        };
+
+        permissions::check(&group, &self.author_id, Permission::WriteExpenses).map_err(
+            |denied| match denied {
+                Denied::NotInGroup => UpdateExpenseError::AuthorNotInGroup,
+                Denied::RoleNotAllowed => UpdateExpenseError::ReadOnlyMember,
+            },
+        )?;
+
+        if group.is_archived() {
+            return Err(UpdateExpenseError::GroupArchived);
+        }
+
+        let Some(current_entry) =
+            database::queries::expense_entry::get_active_by_expense_id(tx, &self.expense_id)
+                .await?
+        else {
+            return Err(UpdateExpenseError::ExpenseNotFound);
+        };
+        if current_entry.group_id != self.group_id {
+            return Err(UpdateExpenseError::ExpenseNotFound);
+        }
+
+        let payers = resolve_payers(&self.payer_id, self.payers, self.total)?;
+        if !payers
+            .users()
+            .iter()
+            .all(|payer| group.contains_user(payer))
+        {
+            return Err(UpdateExpenseError::PayerIsNotGroupMember);
+        }
+
+        let (participants, split) = self
+            .split
+            .resolve(&group, &self.payer_id)
+            .ok_or(UpdateExpenseError::ParticipantNotFound)?;
+        split.validate(self.total)?;
+        let (currency, exchange_rate) =
+            resolve_currency(&group, self.currency, self.exchange_rate, self.total)?;
+        if let Some(category_id) = &self.category_id
+            && !category_belongs_to_group(tx, category_id, &self.group_id).await?
+        {
+            return Err(UpdateExpenseError::CategoryNotFound);
+        }
+
+        let new_entry = ExpenseEntry::new(
+            ExpenseEntryId::new_random(),
+            self.expense_id,
+            self.group_id,
+            self.title,
+            self.note,
+            self.category,
+            self.category_id,
+            self.payer_id,
+            payers,
+            participants,
+            split,
+            ExpenseEntryStatus::Active,
+            self.total,
+            currency,
+            exchange_rate,
+            self.author_id,
+            self.occured_at,
+            Utc::now(),
+        )
+        .expect("valid expense entry");
+
+        // the previous entry must be flipped first: only one entry per
+        // expense can be active at any time
+        let overwritten = database::queries::expense_entry::mark_as_overwritten(
+            tx,
+            &current_entry.id,
+            &new_entry.id,
+        )
+        .await?;
+        if !overwritten {
+            return Err(UpdateExpenseError::ConcurrentUpdate);
+        }
+
+        database::queries::expense_entry::create(tx, &new_entry).await?;
+        Ok(new_entry.id)
+    }
+}
@@ -0,0 +1,144 @@
+use domain::types::{
+    category_id::CategoryId, currency::Currency, exchange_rate::ExchangeRate,
+    expense_category::ExpenseCategory, expense_note::ExpenseNote, expense_title::ExpenseTitle,
+    group_id::GroupId, money::Money, recurrence::Recurrence,
+    recurring_expense_id::RecurringExpenseId, user_id::UserId,
+};
+
+use crate::{
+    commands::create_expense::{
+        CurrencyError, SplitSpecification, category_belongs_to_group, resolve_currency,
+    },
+    permissions::{self, Permission},
+};
+
+/// Replaces the template and the schedule of a recurring expense.
+///
+/// Expenses already added keep their values; the next occurrence is the
+/// first one of the new schedule after the last added occurrence.
+pub struct UpdateRecurringExpenseCommand {
+    pub group_id: GroupId,
+    pub recurring_expense_id: RecurringExpenseId,
+    pub current_user_id: UserId,
+    pub payer_id: UserId,
+    pub title: ExpenseTitle,
+    pub note: Option<ExpenseNote>,
+    pub category: ExpenseCategory,
+    /// Optional custom category; must belong to the group.
+    pub category_id: Option<CategoryId>,
+    pub split: SplitSpecification,
+    pub total: Money,
+    /// Currency in which each occurrence is paid; defaults to the group currency.
+    pub currency: Option<Currency>,
+    /// Rate to the group currency; required if `currency` differs from it.
+    pub exchange_rate: Option<ExchangeRate>,
+    pub recurrence: Recurrence,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateRecurringExpenseError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("group is archived")]
+    GroupArchived,
+
+    #[error("recurring expense not found")]
+    RecurringExpenseNotFound,
+
+    #[error("forbidden")]
+    Forbidden,
+
+    #[error("total must be > 0")]
+    InvalidTotal,
+
+    #[error("must be group member to pay an expense")]
+    PayerIsNotGroupMember,
+
+    #[error("at least one participant is not found in group")]
+    ParticipantNotFound,
+
+    #[error("category not found in group")]
+    CategoryNotFound,
+
+    #[error("invalid split: {0}")]
+    InvalidSplit(#[from] domain::types::expense_split::Error),
+
+    #[error("invalid currency: {0}")]
+    InvalidCurrency(#[from] CurrencyError),
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl UpdateRecurringExpenseCommand {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<(), UpdateRecurringExpenseError> {
+        if self.total.is_negative() {
+            return Err(UpdateRecurringExpenseError::InvalidTotal);
+        }
+
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(UpdateRecurringExpenseError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user_id, Permission::WriteExpenses).is_err() {
+            return Err(UpdateRecurringExpenseError::Forbidden);
+        }
+
+        if group.is_archived() {
+            return Err(UpdateRecurringExpenseError::GroupArchived);
+        }
+
+        let Some(mut recurring_expense) =
+            database::queries::recurring_expense::get_by_id(tx, &self.recurring_expense_id).await?
+        else {
+            return Err(UpdateRecurringExpenseError::RecurringExpenseNotFound);
+        };
+        if recurring_expense.group_id != self.group_id {
+            return Err(UpdateRecurringExpenseError::RecurringExpenseNotFound);
+        }
+
+        if !group.contains_user(&self.payer_id) {
+            return Err(UpdateRecurringExpenseError::PayerIsNotGroupMember);
+        }
+
+        let (participants, split) = self
+            .split
+            .resolve(&group, &self.payer_id)
+            .ok_or(UpdateRecurringExpenseError::ParticipantNotFound)?;
+        split.validate(self.total)?;
+        let (currency, exchange_rate) =
+            resolve_currency(&group, self.currency, self.exchange_rate, self.total)?;
+        if let Some(category_id) = &self.category_id
+            && !category_belongs_to_group(tx, category_id, &self.group_id).await?
+        {
+            return Err(UpdateRecurringExpenseError::CategoryNotFound);
+        }
+
+        let last_occurrence_at =
+            database::queries::recurring_expense::get_last_occurrence_at(tx, &recurring_expense.id)
+                .await?;
+
+        recurring_expense.title = self.title;
+        recurring_expense.note = self.note;
+        recurring_expense.category = self.category;
+        recurring_expense.category_id = self.category_id;
+        recurring_expense.payer_id = self.payer_id;
+        recurring_expense.participants = participants;
+        recurring_expense.split = split;
+        recurring_expense.total = self.total;
+        recurring_expense.currency = currency;
+        recurring_expense.exchange_rate = exchange_rate;
+        recurring_expense.next_occurrence_at = self.recurrence.next_after(last_occurrence_at);
+        recurring_expense.recurrence = self.recurrence;
+
+        if !database::queries::recurring_expense::update(tx, &recurring_expense).await? {
+            return Err(UpdateRecurringExpenseError::RecurringExpenseNotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,335 @@
+//! Parsing of the CSV files exported by other expense sharing apps.
+//!
+//! Each format is parsed into rows independent of the app they come from,
+//! which are then imported by
+//! [ImportExpensesCommand](crate::commands::import_expenses::ImportExpensesCommand).
+
+mod splitwise;
+mod tricount;
+
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use csv::StringRecord;
+use domain::types::{
+    currency::Currency, exchange_rate::ExchangeRate, expense_category::ExpenseCategory,
+    money::Money,
+};
+
+use crate::commands::{
+    create_expense::CreateExpenseError, record_settlement::RecordSettlementError,
+};
+
+/// App which exported the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    Splitwise,
+    Tricount,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum ParseError {
+    #[error("invalid CSV: {0}")]
+    InvalidCsv(String),
+
+    #[error("missing column: {0}")]
+    MissingColumn(&'static str),
+}
+
+/// Error making a single row impossible to import.
+#[derive(Debug, thiserror::Error)]
+pub enum RowError {
+    #[error("invalid date: {0}")]
+    InvalidDate(String),
+
+    #[error("invalid amount {0:?}")]
+    InvalidAmount(String),
+
+    #[error("invalid currency {0:?}")]
+    InvalidCurrency(String),
+
+    #[error("invalid exchange rate {0:?}")]
+    InvalidExchangeRate(String),
+
+    #[error("invalid title: {0}")]
+    InvalidTitle(#[from] domain::types::expense_title::Error),
+
+    #[error("no one paid the expense")]
+    NoPayer,
+
+    #[error("a payment must be made by one person to another one")]
+    InvalidPayment,
+
+    #[error("no exchange rate to the group currency provided for {0}")]
+    MissingExchangeRate(Currency),
+
+    #[error("invalid expense: {0}")]
+    Expense(#[from] CreateExpenseError),
+
+    #[error("invalid payment: {0}")]
+    Settlement(#[from] RecordSettlementError),
+}
+
+/// Content of an exported file.
+pub(crate) struct ParsedFile {
+    /// Names of all the people appearing in the file.
+    pub persons: BTreeSet<String>,
+    pub rows: Vec<ParsedRow>,
+}
+
+pub(crate) struct ParsedRow {
+    /// Line of the row in the file; the header is on line 1.
+    pub line: u64,
+    pub description: String,
+    pub content: Result<RowContent, RowError>,
+}
+
+pub(crate) enum RowContent {
+    Expense(ParsedExpense),
+    Payment(ParsedPayment),
+    /// Row without equivalent in this app, such as an income.
+    Ignored {
+        reason: &'static str,
+    },
+}
+
+pub(crate) struct ParsedExpense {
+    pub occurred_at: DateTime<Utc>,
+    pub category: ExpenseCategory,
+    pub total: Money,
+    pub currency: Option<Currency>,
+    pub exchange_rate: Option<ExchangeRate>,
+    /// Amount paid by each person, starting with the main payer; sums up to
+    /// the total.
+    pub payers: Vec<(String, Money)>,
+    /// Amount borne by each person; sums up to the total.
+    pub amounts: Vec<(String, Money)>,
+}
+
+pub(crate) struct ParsedPayment {
+    pub occurred_at: DateTime<Utc>,
+    pub from: String,
+    pub to: String,
+    pub amount: Money,
+    pub currency: Option<Currency>,
+    pub exchange_rate: Option<ExchangeRate>,
+}
+
+impl ImportFormat {
+    pub(crate) fn parse(&self, content: &str) -> Result<ParsedFile, ParseError> {
+        let mut reader = csv::ReaderBuilder::new()
+            .flexible(true)
+            .from_reader(content.trim_start_matches('\u{feff}').as_bytes());
+        let headers = reader
+            .headers()
+            .map_err(|err| ParseError::InvalidCsv(err.to_string()))?
+            .iter()
+            .map(|header| header.trim().to_string())
+            .collect::<Vec<_>>();
+        let records = reader
+            .records()
+            .map(|record| {
+                let record = record.map_err(|err| ParseError::InvalidCsv(err.to_string()))?;
+                let line = record.position().map(|p| p.line()).unwrap_or_default();
+                Ok((line, record))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match self {
+            ImportFormat::Splitwise => splitwise::parse(&headers, records),
+            ImportFormat::Tricount => tricount::parse(&headers, records),
+        }
+    }
+}
+
+/// Returns the position of a column, ignoring case.
+fn find_column(headers: &[String], name: &'static str) -> Option<usize> {
+    headers
+        .iter()
+        .position(|header| header.eq_ignore_ascii_case(name))
+}
+
+fn required_column(headers: &[String], name: &'static str) -> Result<usize, ParseError> {
+    find_column(headers, name).ok_or(ParseError::MissingColumn(name))
+}
+
+/// Returns the trimmed value of a field; missing fields are empty.
+fn field(record: &StringRecord, index: usize) -> &str {
+    record.get(index).unwrap_or_default().trim()
+}
+
+/// Parses a date, with or without time; times are considered to be UTC.
+fn parse_date(value: &str) -> Result<DateTime<Utc>, RowError> {
+    const DATE_TIME_FORMATS: [&str; 5] = [
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%d %H:%M",
+        "%d/%m/%Y %H:%M:%S",
+        "%d/%m/%Y %H:%M",
+    ];
+    const DATE_FORMATS: [&str; 2] = ["%Y-%m-%d", "%d/%m/%Y"];
+
+    let value = value.trim();
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+        return Ok(date_time.to_utc());
+    }
+    DATE_TIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            DATE_FORMATS.iter().find_map(|format| {
+                NaiveDate::parse_from_str(value, format)
+                    .ok()
+                    .and_then(|date| date.and_hms_opt(0, 0, 0))
+            })
+        })
+        .map(|date_time| date_time.and_utc())
+        .ok_or_else(|| RowError::InvalidDate(value.to_string()))
+}
+
+/// Parses an amount, accepting a comma as decimal separator; an empty value
+/// is zero.
+fn parse_amount(value: &str) -> Result<Money, RowError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(Money::zero());
+    }
+    let normalized = if value.contains('.') {
+        value.to_string()
+    } else {
+        value.replace(',', ".")
+    };
+    normalized
+        .strip_prefix('+')
+        .unwrap_or(&normalized)
+        .parse::<Money>()
+        .and_then(Money::within_input_bounds)
+        .map_err(|_| RowError::InvalidAmount(value.to_string()))
+}
+
+fn parse_currency(value: &str) -> Result<Option<Currency>, RowError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    value
+        .to_uppercase()
+        .parse()
+        .map(Some)
+        .map_err(|_| RowError::InvalidCurrency(value.to_string()))
+}
+
+/// Parses an exchange rate, truncating the decimals this app does not keep.
+fn parse_exchange_rate(value: &str) -> Result<Option<ExchangeRate>, RowError> {
+    const MAX_DECIMALS: usize = 6;
+
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    let normalized = value.replace(',', ".");
+    let truncated = match normalized.split_once('.') {
+        Some((units, decimals))
+            if decimals.len() > MAX_DECIMALS && decimals.bytes().all(|b| b.is_ascii_digit()) =>
+        {
+            format!("{}.{}", units, &decimals[..MAX_DECIMALS])
+        }
+        _ => normalized,
+    };
+    truncated
+        .parse()
+        .map(Some)
+        .map_err(|_| RowError::InvalidExchangeRate(value.to_string()))
+}
+
+/// Maps a category name of another app to the closest category of this app.
+fn guess_category(name: &str) -> ExpenseCategory {
+    const KEYWORDS: [(&str, ExpenseCategory); 23] = [
+        ("groceries", ExpenseCategory::Groceries),
+        ("supermarket", ExpenseCategory::Groceries),
+        ("food", ExpenseCategory::Food),
+        ("dining", ExpenseCategory::Food),
+        ("restaurant", ExpenseCategory::Food),
+        ("liquor", ExpenseCategory::Food),
+        ("transport", ExpenseCategory::Transport),
+        ("taxi", ExpenseCategory::Transport),
+        ("fuel", ExpenseCategory::Transport),
+        ("parking", ExpenseCategory::Transport),
+        ("plane", ExpenseCategory::Transport),
+        ("train", ExpenseCategory::Transport),
+        ("hotel", ExpenseCategory::Lodging),
+        ("rent", ExpenseCategory::Lodging),
+        ("accommodation", ExpenseCategory::Lodging),
+        ("entertainment", ExpenseCategory::Entertainment),
+        ("sports", ExpenseCategory::Entertainment),
+        ("utilities", ExpenseCategory::Utilities),
+        ("electricity", ExpenseCategory::Utilities),
+        ("medical", ExpenseCategory::Health),
+        ("health", ExpenseCategory::Health),
+        ("shopping", ExpenseCategory::Shopping),
+        ("clothing", ExpenseCategory::Shopping),
+    ];
+
+    let name = name.to_lowercase();
+    KEYWORDS
+        .iter()
+        .find(|(keyword, _)| name.contains(keyword))
+        .map(|(_, category)| *category)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use domain::types::{expense_category::ExpenseCategory, money::Money};
+
+    use super::{RowError, guess_category, parse_amount, parse_date, parse_exchange_rate};
+
+    #[rstest::rstest]
+    #[case("2024-03-15")]
+    #[case("15/03/2024")]
+    #[case("2024-03-15 00:00:00")]
+    #[case("2024-03-15T00:00:00Z")]
+    fn parse_date_ok(#[case] value: &str) {
+        let expected = Utc.with_ymd_and_hms(2024, 3, 15, 0, 0, 0).unwrap();
+        assert_eq!(expected, parse_date(value).unwrap());
+    }
+
+    #[rstest::rstest]
+    #[case("12.50", 1_250)]
+    #[case("12,50", 1_250)]
+    #[case("-3", -300)]
+    #[case("+3", 300)]
+    #[case("", 0)]
+    fn parse_amount_ok(#[case] value: &str, #[case] expected_cents: i64) {
+        assert_eq!(
+            Money::from_cents(expected_cents),
+            parse_amount(value).unwrap()
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("12.5.0")]
+    #[case("10000000000.01")]
+    fn parse_amount_err(#[case] value: &str) {
+        assert!(matches!(
+            parse_amount(value),
+            Err(RowError::InvalidAmount(v)) if v == value
+        ));
+    }
+
+    #[test]
+    fn parse_exchange_rate_truncates_decimals() {
+        let rate = parse_exchange_rate("1.08695652").unwrap().unwrap();
+        assert_eq!(1_086_956, rate.micros());
+    }
+
+    #[rstest::rstest]
+    #[case("Food and drink - Dining out", ExpenseCategory::Food)]
+    #[case("Food and drink - Groceries", ExpenseCategory::Groceries)]
+    #[case("Transportation - Taxi", ExpenseCategory::Transport)]
+    #[case("General", ExpenseCategory::Other)]
+    fn guess_category_ok(#[case] name: &str, #[case] expected: ExpenseCategory) {
+        assert_eq!(expected, guess_category(name));
+    }
+}
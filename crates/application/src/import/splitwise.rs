@@ -0,0 +1,220 @@
+//! Splitwise exports a group as one row per expense or payment, followed by
+//! one column per person holding how the row changes their balance.
+
+use csv::StringRecord;
+use domain::types::money::Money;
+
+use crate::import::{
+    ParseError, ParsedExpense, ParsedFile, ParsedPayment, ParsedRow, RowContent, RowError, field,
+    guess_category, parse_amount, parse_currency, parse_date, required_column,
+};
+
+/// Category Splitwise gives to payments between people.
+const PAYMENT_CATEGORY: &str = "Payment";
+
+struct Columns {
+    date: usize,
+    description: usize,
+    category: usize,
+    cost: usize,
+    currency: usize,
+    persons: Vec<(usize, String)>,
+}
+
+pub(super) fn parse(
+    headers: &[String],
+    records: Vec<(u64, StringRecord)>,
+) -> Result<ParsedFile, ParseError> {
+    let currency = required_column(headers, "Currency")?;
+    let columns = Columns {
+        date: required_column(headers, "Date")?,
+        description: required_column(headers, "Description")?,
+        category: required_column(headers, "Category")?,
+        cost: required_column(headers, "Cost")?,
+        currency,
+        // every column after the currency is a person
+        persons: headers
+            .iter()
+            .enumerate()
+            .skip(currency + 1)
+            .filter(|(_, name)| !name.is_empty())
+            .map(|(index, name)| (index, name.clone()))
+            .collect(),
+    };
+
+    let rows = records
+        .iter()
+        // blank lines and the trailing total balance row have no date
+        .filter(|(_, record)| !field(record, columns.date).is_empty())
+        .map(|(line, record)| ParsedRow {
+            line: *line,
+            description: field(record, columns.description).to_string(),
+            content: parse_row(&columns, record),
+        })
+        .collect();
+
+    Ok(ParsedFile {
+        persons: columns
+            .persons
+            .iter()
+            .map(|(_, name)| name.clone())
+            .collect(),
+        rows,
+    })
+}
+
+fn parse_row(columns: &Columns, record: &StringRecord) -> Result<RowContent, RowError> {
+    let occurred_at = parse_date(field(record, columns.date))?;
+    let total = parse_amount(field(record, columns.cost))?;
+    let currency = parse_currency(field(record, columns.currency))?;
+    let balances = columns
+        .persons
+        .iter()
+        .map(|(index, name)| Ok((name, parse_amount(field(record, *index))?)))
+        .collect::<Result<Vec<_>, RowError>>()?;
+
+    let creditors: Vec<_> = balances
+        .iter()
+        .filter(|(_, balance)| balance.is_positive())
+        .collect();
+    if creditors.is_empty() {
+        return Err(RowError::NoPayer);
+    }
+    let debtors = balances
+        .iter()
+        .filter(|(_, balance)| balance.is_negative())
+        .map(|(name, balance)| (name.to_string(), -*balance));
+
+    let category = field(record, columns.category);
+    if category.eq_ignore_ascii_case(PAYMENT_CATEGORY) {
+        let debtors: Vec<_> = debtors.collect();
+        let ([(payer, _)], [(receiver, _)]) = (creditors.as_slice(), debtors.as_slice()) else {
+            return Err(RowError::InvalidPayment);
+        };
+        return Ok(RowContent::Payment(ParsedPayment {
+            occurred_at,
+            from: payer.to_string(),
+            to: receiver.clone(),
+            amount: total,
+            currency,
+            exchange_rate: None,
+        }));
+    }
+
+    // payers together bear what they paid minus what the others owe them,
+    // split between them proportionally to what they are owed; a single
+    // payer therefore paid the whole total
+    let owed: Money = creditors.iter().map(|(_, balance)| *balance).sum();
+    let weights: Vec<u64> = creditors
+        .iter()
+        .map(|(_, balance)| balance.cents().unsigned_abs())
+        .collect();
+    let mut payers = Vec::with_capacity(creditors.len());
+    let mut amounts: Vec<(String, Money)> = debtors.collect();
+    for ((name, balance), borne) in creditors.iter().zip((total - owed).allocate(&weights)) {
+        payers.push((name.to_string(), *balance + borne));
+        if !borne.is_zero() {
+            amounts.push((name.to_string(), borne));
+        }
+    }
+
+    Ok(RowContent::Expense(ParsedExpense {
+        occurred_at,
+        category: guess_category(category),
+        total,
+        currency,
+        exchange_rate: None,
+        payers,
+        amounts,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::types::{currency::Currency, expense_category::ExpenseCategory, money::Money};
+
+    use crate::import::{ImportFormat, RowContent, RowError};
+
+    const EXPORT: &str = "\
+Date,Description,Category,Cost,Currency,Alice,Bob,Carol
+2024-03-15,Dinner,Food and drink - Dining out,90.00,EUR,60.00,-30.00,-30.00
+2024-03-16,Taxi,Transportation - Taxi,20.00,EUR,-10.00,20.00,-10.00
+2024-03-17,Bob paid Alice,Payment,30.00,EUR,-30.00,30.00,0.00
+2024-03-18,Groceries,General,30.00,EUR,10.00,5.00,-15.00
+2024-03-19,Nothing,General,0.00,EUR,0.00,0.00,0.00
+
+,Total balance,,,EUR,30.00,25.00,-55.00
+";
+
+    #[test]
+    fn parse_ok() {
+        let file = ImportFormat::Splitwise.parse(EXPORT).unwrap();
+
+        assert_eq!(
+            vec!["Alice", "Bob", "Carol"],
+            file.persons.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(5, file.rows.len());
+
+        let row = &file.rows[0];
+        assert_eq!(2, row.line);
+        assert_eq!("Dinner", row.description);
+        let Ok(RowContent::Expense(expense)) = &row.content else {
+            panic!("expected an expense");
+        };
+        assert_eq!(ExpenseCategory::Food, expense.category);
+        assert_eq!(Money::from_euros(90), expense.total);
+        assert_eq!(Some(Currency::default()), expense.currency);
+        assert_eq!(
+            vec![("Alice".to_string(), Money::from_euros(90))],
+            expense.payers
+        );
+        let mut amounts = expense.amounts.clone();
+        amounts.sort();
+        assert_eq!(
+            vec![
+                ("Alice".to_string(), Money::from_euros(30)),
+                ("Bob".to_string(), Money::from_euros(30)),
+                ("Carol".to_string(), Money::from_euros(30)),
+            ],
+            amounts
+        );
+
+        let Ok(RowContent::Payment(payment)) = &file.rows[2].content else {
+            panic!("expected a payment");
+        };
+        assert_eq!("Bob", payment.from);
+        assert_eq!("Alice", payment.to);
+        assert_eq!(Money::from_euros(30), payment.amount);
+
+        let Ok(RowContent::Expense(expense)) = &file.rows[3].content else {
+            panic!("expected an expense");
+        };
+        assert_eq!(
+            vec![
+                ("Alice".to_string(), Money::from_euros(20)),
+                ("Bob".to_string(), Money::from_euros(10)),
+            ],
+            expense.payers
+        );
+        assert_eq!(
+            vec![
+                ("Carol".to_string(), Money::from_euros(15)),
+                ("Alice".to_string(), Money::from_euros(10)),
+                ("Bob".to_string(), Money::from_euros(5)),
+            ],
+            expense.amounts
+        );
+
+        assert!(matches!(file.rows[4].content, Err(RowError::NoPayer)));
+    }
+
+    #[test]
+    fn missing_column() {
+        let err = ImportFormat::Splitwise
+            .parse("Date,Description,Cost,Currency,Alice\n")
+            .err()
+            .unwrap();
+        assert_eq!("missing column: Category", err.to_string());
+    }
+}
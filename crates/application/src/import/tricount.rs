@@ -0,0 +1,208 @@
+//! Tricount exports a tricount as one row per transaction, naming who paid
+//! it, followed by one "Impacted to" column per person holding the amount
+//! they bear, in the currency of the transaction.
+
+use std::collections::BTreeSet;
+
+use csv::StringRecord;
+
+use crate::import::{
+    ParseError, ParsedExpense, ParsedFile, ParsedPayment, ParsedRow, RowContent, RowError, field,
+    find_column, guess_category, parse_amount, parse_currency, parse_date, parse_exchange_rate,
+    required_column,
+};
+
+const IMPACTED_PREFIX: &str = "impacted to ";
+
+struct Columns {
+    title: usize,
+    amount: usize,
+    currency: Option<usize>,
+    exchange_rate: Option<usize>,
+    transaction_type: Option<usize>,
+    category: Option<usize>,
+    paid_by: usize,
+    date: usize,
+    impacted: Vec<(usize, String)>,
+}
+
+pub(super) fn parse(
+    headers: &[String],
+    records: Vec<(u64, StringRecord)>,
+) -> Result<ParsedFile, ParseError> {
+    let columns = Columns {
+        title: required_column(headers, "Title")?,
+        amount: required_column(headers, "Amount")?,
+        currency: find_column(headers, "Currency"),
+        exchange_rate: find_column(headers, "Exchange rate"),
+        transaction_type: find_column(headers, "Type of transaction"),
+        category: find_column(headers, "Category"),
+        paid_by: required_column(headers, "Paid by")?,
+        date: find_column(headers, "Date & time")
+            .or_else(|| find_column(headers, "Date"))
+            .ok_or(ParseError::MissingColumn("Date"))?,
+        impacted: headers
+            .iter()
+            .enumerate()
+            .filter_map(|(index, header)| {
+                let prefix = header.get(..IMPACTED_PREFIX.len())?;
+                let name = header[IMPACTED_PREFIX.len()..].trim();
+                (prefix.eq_ignore_ascii_case(IMPACTED_PREFIX) && !name.is_empty())
+                    .then(|| (index, name.to_string()))
+            })
+            .collect(),
+    };
+
+    let rows: Vec<ParsedRow> = records
+        .iter()
+        .filter(|(_, record)| !record.iter().all(|value| value.trim().is_empty()))
+        .map(|(line, record)| ParsedRow {
+            line: *line,
+            description: field(record, columns.title).to_string(),
+            content: parse_row(&columns, record),
+        })
+        .collect();
+
+    // payers do not necessarily have a column
+    let mut persons: BTreeSet<String> = columns
+        .impacted
+        .iter()
+        .map(|(_, name)| name.clone())
+        .collect();
+    persons.extend(
+        records
+            .iter()
+            .map(|(_, record)| field(record, columns.paid_by))
+            .filter(|name| !name.is_empty())
+            .map(String::from),
+    );
+
+    Ok(ParsedFile { persons, rows })
+}
+
+fn parse_row(columns: &Columns, record: &StringRecord) -> Result<RowContent, RowError> {
+    let optional_field = |index: Option<usize>| index.map(|i| field(record, i)).unwrap_or("");
+
+    let transaction_type = optional_field(columns.transaction_type).to_lowercase();
+    if transaction_type.contains("income") {
+        return Ok(RowContent::Ignored {
+            reason: "incomes cannot be imported",
+        });
+    }
+
+    let occurred_at = parse_date(field(record, columns.date))?;
+    // expenses are exported as negative amounts by recent versions
+    let total = parse_amount(field(record, columns.amount))?.abs();
+    let currency = parse_currency(optional_field(columns.currency))?;
+    let exchange_rate = parse_exchange_rate(optional_field(columns.exchange_rate))?;
+    let payer = field(record, columns.paid_by);
+    if payer.is_empty() {
+        return Err(RowError::NoPayer);
+    }
+    let amounts = columns
+        .impacted
+        .iter()
+        .map(|(index, name)| Ok((name.clone(), parse_amount(field(record, *index))?.abs())))
+        .filter(|amount| !matches!(amount, Ok((_, amount)) if amount.is_zero()))
+        .collect::<Result<Vec<_>, RowError>>()?;
+
+    if transaction_type.contains("transfer") || transaction_type.contains("reimbursement") {
+        let [(receiver, _)] = amounts.as_slice() else {
+            return Err(RowError::InvalidPayment);
+        };
+        return Ok(RowContent::Payment(ParsedPayment {
+            occurred_at,
+            from: payer.to_string(),
+            to: receiver.clone(),
+            amount: total,
+            currency,
+            exchange_rate,
+        }));
+    }
+
+    Ok(RowContent::Expense(ParsedExpense {
+        occurred_at,
+        category: guess_category(optional_field(columns.category)),
+        total,
+        currency,
+        exchange_rate,
+        payers: vec![(payer.to_string(), total)],
+        amounts,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use domain::types::{currency::Currency, money::Money};
+
+    use crate::import::{ImportFormat, RowContent, RowError};
+
+    const EXPORT: &str = "\
+Title,Amount,Currency,Exchange rate,Date & time,Type of transaction,Paid by,Impacted to Alice,Impacted to Bob
+Hotel,-120.00,USD,0.92,2024-03-15 18:34:00,Normal,Alice,-60.00,-60.00
+Refund,40.00,EUR,1,2024-03-16 09:00:00,Money transfer,Bob,-40.00,
+Lottery,10.00,EUR,1,2024-03-17 09:00:00,Income,Carol,5.00,5.00
+Museum,-30.00,EUR,1,yesterday,Normal,Bob,-15.00,-15.00
+";
+
+    #[test]
+    fn parse_ok() {
+        let file = ImportFormat::Tricount.parse(EXPORT).unwrap();
+
+        // Carol only appears as a payer
+        assert_eq!(
+            vec!["Alice", "Bob", "Carol"],
+            file.persons.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(4, file.rows.len());
+
+        let Ok(RowContent::Expense(expense)) = &file.rows[0].content else {
+            panic!("expected an expense");
+        };
+        assert_eq!("Hotel", file.rows[0].description);
+        assert_eq!(
+            Utc.with_ymd_and_hms(2024, 3, 15, 18, 34, 0).unwrap(),
+            expense.occurred_at
+        );
+        assert_eq!(Money::from_euros(120), expense.total);
+        assert_eq!(Some("USD".parse::<Currency>().unwrap()), expense.currency);
+        assert_eq!(Some("0.92".parse().unwrap()), expense.exchange_rate);
+        assert_eq!(
+            vec![("Alice".to_string(), Money::from_euros(120))],
+            expense.payers
+        );
+        assert_eq!(
+            vec![
+                ("Alice".to_string(), Money::from_euros(60)),
+                ("Bob".to_string(), Money::from_euros(60)),
+            ],
+            expense.amounts
+        );
+
+        let Ok(RowContent::Payment(payment)) = &file.rows[1].content else {
+            panic!("expected a payment");
+        };
+        assert_eq!("Bob", payment.from);
+        assert_eq!("Alice", payment.to);
+        assert_eq!(Money::from_euros(40), payment.amount);
+
+        assert!(matches!(
+            file.rows[2].content,
+            Ok(RowContent::Ignored { .. })
+        ));
+        assert!(matches!(
+            file.rows[3].content,
+            Err(RowError::InvalidDate(_))
+        ));
+    }
+
+    #[test]
+    fn missing_column() {
+        let err = ImportFormat::Tricount
+            .parse("Title,Amount,Date,Impacted to Alice\n")
+            .err()
+            .unwrap();
+        assert_eq!("missing column: Paid by", err.to_string());
+    }
+}
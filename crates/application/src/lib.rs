@@ -1,3 +1,6 @@
+pub mod backup;
 pub mod commands;
+pub mod import;
 pub mod pagination;
+pub mod permissions;
 pub mod queries;
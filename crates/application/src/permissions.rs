@@ -0,0 +1,120 @@
+use domain::{
+    entities::Group,
+    types::{member_role::MemberRole, user_id::UserId},
+};
+
+/// Action on a group that only some of its users are allowed to perform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    /// Reading expenses, balances, settlements and any other group data.
+    Read,
+    /// Adding, editing and deleting expenses, settlements and attachments.
+    WriteExpenses,
+    /// Cancelling settlements recorded by other users.
+    ModerateSettlements,
+    /// Adding and removing members, changing their role and managing invites.
+    ManageMembers,
+    /// Managing categories and budgets.
+    ManageSettings,
+    /// Archiving, backing up and handing over the group.
+    Own,
+}
+
+/// Reason why a user is not allowed to perform an action on a group.
+#[derive(Debug, PartialEq)]
+pub enum Denied {
+    /// The user is neither the owner nor a member of the group.
+    NotInGroup,
+    /// The user is in the group, but their role does not allow the action.
+    RoleNotAllowed,
+}
+
+/// Checks whether the provided user is allowed to perform an action on the
+/// group. The owner is allowed to do everything, members depend on their role.
+pub fn check(group: &Group, user_id: &UserId, permission: Permission) -> Result<(), Denied> {
+    if group.is_user_owner(user_id) {
+        return Ok(());
+    }
+
+    let Some(role) = group.member_role(user_id) else {
+        return Err(Denied::NotInGroup);
+    };
+
+    let allowed = match permission {
+        Permission::Read => true,
+        Permission::WriteExpenses => matches!(role, MemberRole::Admin | MemberRole::Member),
+        Permission::ModerateSettlements
+        | Permission::ManageMembers
+        | Permission::ManageSettings => role == MemberRole::Admin,
+        Permission::Own => false,
+    };
+
+    if allowed {
+        Ok(())
+    } else {
+        Err(Denied::RoleNotAllowed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::Utc;
+    use domain::{
+        entities::Group,
+        types::{currency::Currency, group_id::GroupId, member_role::MemberRole, user_id::UserId},
+    };
+    use uuid::Uuid;
+
+    use super::{Denied, Permission, check};
+
+    const OWNER: Uuid = Uuid::from_u128(1);
+    const ADMIN: Uuid = Uuid::from_u128(2);
+    const MEMBER: Uuid = Uuid::from_u128(3);
+    const VIEWER: Uuid = Uuid::from_u128(4);
+    const OUTSIDER: Uuid = Uuid::from_u128(5);
+
+    fn group() -> Group {
+        let user_id = |id| UserId::new(id).unwrap();
+        Group::new(
+            GroupId::new_random(),
+            "Flat".parse().unwrap(),
+            Currency::default(),
+            user_id(OWNER),
+            HashMap::from_iter(vec![
+                (user_id(ADMIN), MemberRole::Admin),
+                (user_id(MEMBER), MemberRole::Member),
+                (user_id(VIEWER), MemberRole::Viewer),
+            ]),
+            Utc::now(),
+        )
+    }
+
+    #[rstest::rstest]
+    #[case(OWNER, Permission::Own)]
+    #[case(OWNER, Permission::ManageMembers)]
+    #[case(ADMIN, Permission::ManageMembers)]
+    #[case(ADMIN, Permission::ManageSettings)]
+    #[case(ADMIN, Permission::ModerateSettlements)]
+    #[case(ADMIN, Permission::WriteExpenses)]
+    #[case(MEMBER, Permission::WriteExpenses)]
+    #[case(MEMBER, Permission::Read)]
+    #[case(VIEWER, Permission::Read)]
+    fn allowed(#[case] user_id: Uuid, #[case] permission: Permission) {
+        let res = check(&group(), &UserId::new(user_id).unwrap(), permission);
+        assert_eq!(Ok(()), res);
+    }
+
+    #[rstest::rstest]
+    #[case(ADMIN, Permission::Own, Denied::RoleNotAllowed)]
+    #[case(MEMBER, Permission::ManageMembers, Denied::RoleNotAllowed)]
+    #[case(MEMBER, Permission::ManageSettings, Denied::RoleNotAllowed)]
+    #[case(MEMBER, Permission::ModerateSettlements, Denied::RoleNotAllowed)]
+    #[case(VIEWER, Permission::WriteExpenses, Denied::RoleNotAllowed)]
+    #[case(OUTSIDER, Permission::Read, Denied::NotInGroup)]
+    fn denied(#[case] user_id: Uuid, #[case] permission: Permission, #[case] expected: Denied) {
+        let res = check(&group(), &UserId::new(user_id).unwrap(), permission);
+        assert_eq!(Err(expected), res);
+    }
+}
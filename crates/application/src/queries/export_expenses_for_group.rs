@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use domain::types::{
+    category_id::CategoryId, category_name::CategoryName, currency::Currency, group_id::GroupId,
+    money::Money, user_id::UserId,
+};
+
+use crate::{
+    permissions::{self, Permission},
+    queries::get_expenses_for_group::{
+        GroupExpense, UserSummary, build_group_expense, get_user_ids,
+    },
+};
+
+/// Returns every active expense of a group, without pagination, along with
+/// what each member bears in them.
+///
+/// Expenses are the same as the ones listed by
+/// [GetExpensesForGroupQuery](crate::queries::get_expenses_for_group::GetExpensesForGroupQuery),
+/// sorted by occurrence date.
+pub struct ExportExpensesForGroupQuery {
+    pub group_id: GroupId,
+    pub current_user: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExportExpensesForGroupError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("forbidden")]
+    Forbidden,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl ExportExpensesForGroupQuery {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<Output, ExportExpensesForGroupError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(ExportExpensesForGroupError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user, Permission::Read).is_err() {
+            return Err(ExportExpensesForGroupError::Forbidden);
+        }
+
+        let mut expense_entries =
+            database::queries::expense_entry::get_all_active_for_group_unpaginated(
+                tx,
+                &self.group_id,
+            )
+            .await?;
+        expense_entries.sort_by(|a, b| {
+            a.occurred_at
+                .cmp(&b.occurred_at)
+                .then(a.created_at.cmp(&b.created_at))
+        });
+
+        // former members may still appear in expenses
+        let mut user_ids = get_user_ids(&expense_entries);
+        user_ids.extend(expense_entries.iter().map(|entry| entry.author_id));
+        user_ids.extend(group.members.keys().copied());
+        user_ids.insert(group.owner_id);
+        let users = database::queries::user::get_all_in_ids(tx, user_ids).await?;
+
+        let mut members: Vec<UserSummary> = users
+            .values()
+            .map(|user| UserSummary {
+                id: user.id,
+                name: user.name.clone(),
+            })
+            .collect();
+        members.sort_by(|a, b| {
+            a.name
+                .value()
+                .to_lowercase()
+                .cmp(&b.name.value().to_lowercase())
+                .then(a.id.cmp(&b.id))
+        });
+
+        let expenses = expense_entries
+            .iter()
+            .map(|entry| {
+                let author = users
+                    .get(&entry.author_id)
+                    .expect("corrupted data: author is not here");
+                ExportedExpense {
+                    expense: build_group_expense(entry, &users),
+                    author: UserSummary {
+                        id: author.id,
+                        name: author.name.clone(),
+                    },
+                    created_at: entry.created_at,
+                    shares: entry.shares_in_group_currency(),
+                }
+            })
+            .collect();
+
+        let categories = database::queries::category::get_all_for_group(tx, &self.group_id)
+            .await?
+            .into_iter()
+            .map(|category| (category.id, category.name))
+            .collect();
+
+        Ok(Output {
+            currency: group.currency,
+            members,
+            categories,
+            expenses,
+        })
+    }
+}
+
+pub struct Output {
+    /// Currency of the group, in which shares are expressed.
+    pub currency: Currency,
+    /// Every user appearing in the export, sorted by name.
+    pub members: Vec<UserSummary>,
+    /// Names of the custom categories of the group.
+    pub categories: HashMap<CategoryId, CategoryName>,
+    /// Expenses sorted by occurrence date.
+    pub expenses: Vec<ExportedExpense>,
+}
+
+pub struct ExportedExpense {
+    pub expense: GroupExpense,
+    /// Author of the current version of the expense.
+    pub author: UserSummary,
+    /// System time at which the current version was created.
+    pub created_at: DateTime<Utc>,
+    /// What each participant bears, in the group currency.
+    pub shares: HashMap<UserId, Money>,
+}
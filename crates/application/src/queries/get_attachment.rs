@@ -0,0 +1,58 @@
+use domain::{
+    entities::Attachment,
+    types::{
+        attachment_id::AttachmentId, expense_id::ExpenseId, group_id::GroupId, user_id::UserId,
+    },
+};
+
+use crate::permissions::{self, Permission};
+
+/// Returns the metadata of a file attached to an expense,
+/// provided the current user belongs to the group of the expense.
+pub struct GetAttachmentQuery {
+    pub group_id: GroupId,
+    pub expense_id: ExpenseId,
+    pub attachment_id: AttachmentId,
+    pub current_user: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetAttachmentError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("attachment not found")]
+    AttachmentNotFound,
+
+    #[error("forbidden")]
+    Forbidden,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl GetAttachmentQuery {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<Attachment, GetAttachmentError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(GetAttachmentError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user, Permission::Read).is_err() {
+            return Err(GetAttachmentError::Forbidden);
+        }
+
+        let Some(attachment) =
+            database::queries::attachment::get_by_id(tx, &self.attachment_id).await?
+        else {
+            return Err(GetAttachmentError::AttachmentNotFound);
+        };
+        if attachment.group_id != self.group_id || attachment.expense_id != self.expense_id {
+            return Err(GetAttachmentError::AttachmentNotFound);
+        }
+
+        Ok(attachment)
+    }
+}
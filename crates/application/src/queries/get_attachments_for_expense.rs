@@ -0,0 +1,62 @@
+use domain::{
+    entities::Attachment,
+    types::{expense_id::ExpenseId, group_id::GroupId, user_id::UserId},
+};
+
+use crate::permissions::{self, Permission};
+
+pub struct GetAttachmentsForExpenseQuery {
+    pub group_id: GroupId,
+    pub expense_id: ExpenseId,
+    pub current_user: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetAttachmentsForExpenseError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("expense not found")]
+    ExpenseNotFound,
+
+    #[error("forbidden")]
+    Forbidden,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl GetAttachmentsForExpenseQuery {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<Output, GetAttachmentsForExpenseError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(GetAttachmentsForExpenseError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user, Permission::Read).is_err() {
+            return Err(GetAttachmentsForExpenseError::Forbidden);
+        }
+
+        let Some(entry) =
+            database::queries::expense_entry::get_active_by_expense_id(tx, &self.expense_id)
+                .await?
+        else {
+            return Err(GetAttachmentsForExpenseError::ExpenseNotFound);
+        };
+        if entry.group_id != self.group_id {
+            return Err(GetAttachmentsForExpenseError::ExpenseNotFound);
+        }
+
+        let attachments =
+            database::queries::attachment::get_all_for_expense(tx, &self.expense_id).await?;
+
+        Ok(Output { attachments })
+    }
+}
+
+pub struct Output {
+    /// Attachments sorted by upload date.
+    pub attachments: Vec<Attachment>,
+}
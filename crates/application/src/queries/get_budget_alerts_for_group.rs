@@ -0,0 +1,47 @@
+use domain::{
+    entities::BudgetAlert,
+    types::{group_id::GroupId, user_id::UserId},
+};
+
+use crate::permissions::{self, Permission};
+
+pub struct GetBudgetAlertsForGroupQuery {
+    pub group_id: GroupId,
+    pub current_user: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetBudgetAlertsForGroupError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("forbidden")]
+    Forbidden,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl GetBudgetAlertsForGroupQuery {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<Output, GetBudgetAlertsForGroupError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(GetBudgetAlertsForGroupError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user, Permission::Read).is_err() {
+            return Err(GetBudgetAlertsForGroupError::Forbidden);
+        }
+
+        let alerts = database::queries::budget::get_alerts_for_group(tx, &self.group_id).await?;
+
+        Ok(Output { alerts })
+    }
+}
+
+pub struct Output {
+    /// Alerts of every budget of the group, most recent first.
+    pub alerts: Vec<BudgetAlert>,
+}
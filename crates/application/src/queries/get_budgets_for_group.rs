@@ -0,0 +1,113 @@
+use std::ops::Range;
+
+use chrono::{DateTime, Utc};
+use domain::{
+    entities::{Budget, ExpenseEntry},
+    types::{currency::Currency, group_id::GroupId, money::Money, user_id::UserId},
+};
+
+use crate::permissions::{self, Permission};
+
+/// Returns the budgets of a group, with how much of each of them is consumed
+/// during the period containing `at`.
+///
+/// Budgets are consumed by the totals of the active expenses of their
+/// category, converted to the group currency.
+pub struct GetBudgetsForGroupQuery {
+    pub group_id: GroupId,
+    pub current_user: UserId,
+    pub at: DateTime<Utc>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetBudgetsForGroupError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("forbidden")]
+    Forbidden,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl GetBudgetsForGroupQuery {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<Output, GetBudgetsForGroupError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(GetBudgetsForGroupError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user, Permission::Read).is_err() {
+            return Err(GetBudgetsForGroupError::Forbidden);
+        }
+
+        let budgets = database::queries::budget::get_all_for_group(tx, &self.group_id).await?;
+        let mut consumptions = Vec::with_capacity(budgets.len());
+        for budget in budgets {
+            let window = budget.period.window(self.at);
+            let consumed = match &window {
+                Some(window) => consumption(tx, &budget, window).await?,
+                None => Money::zero(),
+            };
+            consumptions.push(BudgetConsumption {
+                budget,
+                window,
+                consumed,
+            });
+        }
+
+        Ok(Output {
+            currency: group.currency,
+            budgets: consumptions,
+        })
+    }
+}
+
+pub struct Output {
+    /// Currency of the group, in which amounts are expressed.
+    pub currency: Currency,
+    /// Budgets sorted by creation date.
+    pub budgets: Vec<BudgetConsumption>,
+}
+
+pub struct BudgetConsumption {
+    pub budget: Budget,
+    /// Period containing the requested time, `None` if it is outside of the
+    /// date range of the budget.
+    pub window: Option<Range<DateTime<Utc>>>,
+    pub consumed: Money,
+}
+
+/// Returns how much of a budget is consumed during `window`.
+pub(crate) async fn consumption(
+    tx: &mut database::Transaction<'_>,
+    budget: &Budget,
+    window: &Range<DateTime<Utc>>,
+) -> Result<Money, database::Error> {
+    let expense_entries = database::queries::expense_entry::get_all_active_for_group_between(
+        tx,
+        &budget.group_id,
+        window.start,
+        window.end,
+    )
+    .await?;
+    // amounts are bounded when they are provided, so the consumption can
+    // only overflow if stored amounts are corrupted
+    Money::checked_sum(
+        expense_entries
+            .iter()
+            .filter(|entry| consumes(budget, entry))
+            .map(ExpenseEntry::total_in_group_currency),
+    )
+    .ok_or_else(|| database::Error::CorruptedData {
+        msg: format!("consumption of budget {} is too large", budget.id),
+    })
+}
+
+/// Returns whether an expense consumes a budget, whatever its date.
+pub(crate) fn consumes(budget: &Budget, expense_entry: &ExpenseEntry) -> bool {
+    budget.category_id.is_none() || budget.category_id == expense_entry.category_id
+}
@@ -0,0 +1,47 @@
+use domain::{
+    entities::Category,
+    types::{group_id::GroupId, user_id::UserId},
+};
+
+use crate::permissions::{self, Permission};
+
+pub struct GetCategoriesForGroupQuery {
+    pub group_id: GroupId,
+    pub current_user: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetCategoriesForGroupError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("forbidden")]
+    Forbidden,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl GetCategoriesForGroupQuery {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<Output, GetCategoriesForGroupError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(GetCategoriesForGroupError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user, Permission::Read).is_err() {
+            return Err(GetCategoriesForGroupError::Forbidden);
+        }
+
+        let categories = database::queries::category::get_all_for_group(tx, &self.group_id).await?;
+
+        Ok(Output { categories })
+    }
+}
+
+pub struct Output {
+    /// Categories sorted by name.
+    pub categories: Vec<Category>,
+}
@@ -0,0 +1,155 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use domain::{
+    entities::Category,
+    types::{
+        category_id::CategoryId, currency::Currency, group_id::GroupId, money::Money,
+        user_id::UserId, username::Username,
+    },
+};
+
+use crate::permissions::{self, Permission};
+
+/// Returns how much was spent per custom category and per member of a group,
+/// over a date range.
+///
+/// Amounts are what each member bears in the active expenses that occurred
+/// within `[from, to)`, converted to the group currency.
+pub struct GetCategoryReportQuery {
+    pub group_id: GroupId,
+    pub current_user: UserId,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetCategoryReportError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("forbidden")]
+    Forbidden,
+
+    #[error("start of the range must be before its end")]
+    InvalidRange,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl GetCategoryReportQuery {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<Output, GetCategoryReportError> {
+        if self.from >= self.to {
+            return Err(GetCategoryReportError::InvalidRange);
+        }
+
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(GetCategoryReportError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user, Permission::Read).is_err() {
+            return Err(GetCategoryReportError::Forbidden);
+        }
+
+        let expense_entries = database::queries::expense_entry::get_all_active_for_group_between(
+            tx,
+            &self.group_id,
+            self.from,
+            self.to,
+        )
+        .await?;
+
+        // `None` stands for uncategorized expenses
+        let mut totals: HashMap<Option<CategoryId>, HashMap<UserId, Money>> = HashMap::new();
+        for entry in &expense_entries {
+            let category_totals = totals.entry(entry.category_id).or_default();
+            for (user_id, share) in entry.shares_in_group_currency() {
+                *category_totals.entry(user_id).or_insert(Money::zero()) += share;
+            }
+        }
+
+        let categories = database::queries::category::get_all_for_group(tx, &self.group_id).await?;
+        let user_ids: HashSet<UserId> = totals.values().flat_map(|t| t.keys()).copied().collect();
+        let users = database::queries::user::get_all_in_ids(tx, user_ids).await?;
+        let member_totals = |totals: &HashMap<UserId, Money>| {
+            let mut members: Vec<MemberTotal> = totals
+                .iter()
+                .map(|(user_id, total)| {
+                    let user = users
+                        .get(user_id)
+                        .expect("corrupted data: user is not here");
+                    MemberTotal {
+                        user: UserSummary {
+                            id: user.id,
+                            name: user.name.clone(),
+                        },
+                        total: *total,
+                    }
+                })
+                .collect();
+            members.sort_by(|a, b| b.total.cmp(&a.total).then(a.user.id.cmp(&b.user.id)));
+            members
+        };
+
+        let mut overall: HashMap<UserId, Money> = HashMap::new();
+        for (user_id, total) in totals.values().flatten() {
+            *overall.entry(*user_id).or_insert(Money::zero()) += *total;
+        }
+
+        // every category of the group is listed, even without expenses;
+        // uncategorized expenses come last
+        let mut report = Vec::with_capacity(categories.len() + 1);
+        for category in categories.into_iter().map(Some).chain([None]) {
+            let category_totals = totals
+                .remove(&category.as_ref().map(|c| c.id))
+                .unwrap_or_default();
+            if category.is_none() && category_totals.is_empty() {
+                continue;
+            }
+            report.push(CategoryTotal {
+                category,
+                total: category_totals.values().copied().sum(),
+                members: member_totals(&category_totals),
+            });
+        }
+
+        Ok(Output {
+            currency: group.currency,
+            total: overall.values().copied().sum(),
+            categories: report,
+            members: member_totals(&overall),
+        })
+    }
+}
+
+pub struct Output {
+    /// Currency of the group, in which totals are expressed.
+    pub currency: Currency,
+    pub total: Money,
+    /// Categories sorted by name, followed by uncategorized expenses if any.
+    pub categories: Vec<CategoryTotal>,
+    /// Totals per member over all categories, sorted by total (DESC).
+    pub members: Vec<MemberTotal>,
+}
+
+pub struct CategoryTotal {
+    /// `None` for expenses without a custom category.
+    pub category: Option<Category>,
+    pub total: Money,
+    /// Totals per member, sorted by total (DESC).
+    pub members: Vec<MemberTotal>,
+}
+
+pub struct MemberTotal {
+    pub user: UserSummary,
+    pub total: Money,
+}
+
+pub struct UserSummary {
+    pub id: UserId,
+    pub name: Username,
+}
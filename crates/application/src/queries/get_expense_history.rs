@@ -0,0 +1,242 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use domain::{
+    entities::{Change, ExpenseEntry, ExpenseEntryDiff, User},
+    types::{
+        category_id::CategoryId, currency::Currency, exchange_rate::ExchangeRate,
+        expense_category::ExpenseCategory, expense_entry_id::ExpenseEntryId,
+        expense_entry_status::ExpenseEntryStatus, expense_id::ExpenseId, expense_note::ExpenseNote,
+        expense_payers::ExpensePayers, expense_split::ExpenseSplit, expense_title::ExpenseTitle,
+        group_id::GroupId, money::Money, user_id::UserId, username::Username,
+    },
+};
+
+use crate::permissions::{self, Permission};
+
+pub struct GetExpenseHistoryQuery {
+    pub group_id: GroupId,
+    pub expense_id: ExpenseId,
+    pub current_user: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetExpenseHistoryError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("expense not found")]
+    ExpenseNotFound,
+
+    #[error("forbidden")]
+    Forbidden,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl GetExpenseHistoryQuery {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<Output, GetExpenseHistoryError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(GetExpenseHistoryError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user, Permission::Read).is_err() {
+            return Err(GetExpenseHistoryError::Forbidden);
+        }
+
+        let expense_entries =
+            database::queries::expense_entry::get_all_by_expense_id(tx, &self.expense_id).await?;
+        if expense_entries.is_empty()
+            || expense_entries
+                .iter()
+                .any(|entry| entry.group_id != self.group_id)
+        {
+            return Err(GetExpenseHistoryError::ExpenseNotFound);
+        }
+
+        let user_ids = get_user_ids(&expense_entries);
+        let users = database::queries::user::get_all_in_ids(tx, user_ids).await?;
+
+        Ok(Output {
+            versions: build_versions(expense_entries, &users),
+        })
+    }
+}
+
+fn get_user_ids(expense_entries: &[ExpenseEntry]) -> HashSet<UserId> {
+    let mut ids = HashSet::new();
+    for entry in expense_entries {
+        ids.insert(entry.payer_id);
+        ids.extend(entry.payers.users());
+        ids.insert(entry.author_id);
+        ids.extend(entry.participants.iter().copied());
+    }
+    ids
+}
+
+/// `expense_entries` must be sorted by creation date.
+fn build_versions(
+    expense_entries: Vec<ExpenseEntry>,
+    users: &HashMap<UserId, User>,
+) -> Vec<ExpenseVersion> {
+    let user_summary = |user_id: &UserId| {
+        let user = users
+            .get(user_id)
+            .expect("corrupted data: user is not here");
+        UserSummary {
+            id: user.id,
+            name: user.name.clone(),
+        }
+    };
+    let user_summaries = |user_ids: &[UserId]| user_ids.iter().map(user_summary).collect();
+    let paid_amounts = |payers: &ExpensePayers| {
+        let mut amounts: Vec<(UserId, Money)> = payers
+            .amounts()
+            .iter()
+            .map(|(user_id, amount)| (*user_id, *amount))
+            .collect();
+        amounts.sort();
+        amounts
+            .into_iter()
+            .map(|(user_id, amount)| PaidAmount {
+                user: user_summary(&user_id),
+                amount,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut versions = Vec::with_capacity(expense_entries.len());
+    let mut previous: Option<&ExpenseEntry> = None;
+    for entry in &expense_entries {
+        let changes = previous.map(|previous| {
+            let ExpenseEntryDiff {
+                title,
+                note,
+                category,
+                category_id,
+                total,
+                currency,
+                exchange_rate,
+                payer,
+                payers,
+                split,
+                participants_added,
+                participants_removed,
+                occurred_at,
+            } = entry.diff_from(previous);
+            ExpenseChanges {
+                title,
+                note,
+                category,
+                category_id,
+                total,
+                currency,
+                exchange_rate,
+                payer: payer.map(|payer| Change {
+                    before: user_summary(&payer.before),
+                    after: user_summary(&payer.after),
+                }),
+                payers: payers.map(|payers| Change {
+                    before: paid_amounts(&payers.before),
+                    after: paid_amounts(&payers.after),
+                }),
+                split,
+                participants_added: user_summaries(&participants_added),
+                participants_removed: user_summaries(&participants_removed),
+                occurred_at,
+            }
+        });
+
+        let mut participants: Vec<UserId> = entry.participants.iter().copied().collect();
+        participants.sort();
+
+        versions.push(ExpenseVersion {
+            id: entry.id,
+            status: match entry.status {
+                ExpenseEntryStatus::Active => VersionStatus::Active,
+                ExpenseEntryStatus::Inactive { .. } => VersionStatus::Overwritten,
+                ExpenseEntryStatus::Deleted { .. } => VersionStatus::Deleted,
+            },
+            author: user_summary(&entry.author_id),
+            payer: user_summary(&entry.payer_id),
+            payers: paid_amounts(&entry.payers),
+            participants: user_summaries(&participants),
+            title: entry.title.clone(),
+            note: entry.note.clone(),
+            category: entry.category,
+            category_id: entry.category_id,
+            total: entry.total,
+            currency: entry.currency,
+            exchange_rate: entry.exchange_rate,
+            occurred_at: entry.occurred_at,
+            created_at: entry.created_at,
+            changes,
+        });
+        previous = Some(entry);
+    }
+    versions
+}
+
+pub struct Output {
+    /// Versions sorted by creation date, oldest first.
+    pub versions: Vec<ExpenseVersion>,
+}
+
+pub struct ExpenseVersion {
+    pub id: ExpenseEntryId,
+    pub status: VersionStatus,
+    pub author: UserSummary,
+    pub payer: UserSummary,
+    /// Amount paid by each payer, sorted by user id.
+    pub payers: Vec<PaidAmount>,
+    pub participants: Vec<UserSummary>,
+    pub title: ExpenseTitle,
+    pub note: Option<ExpenseNote>,
+    pub category: ExpenseCategory,
+    pub category_id: Option<CategoryId>,
+    pub total: Money,
+    pub currency: Currency,
+    pub exchange_rate: ExchangeRate,
+    pub occurred_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+
+    /// Changes since the previous version, `None` for the first version.
+    pub changes: Option<ExpenseChanges>,
+}
+
+pub enum VersionStatus {
+    Active,
+    Overwritten,
+    Deleted,
+}
+
+pub struct ExpenseChanges {
+    pub title: Option<Change<ExpenseTitle>>,
+    pub note: Option<Change<Option<ExpenseNote>>>,
+    pub category: Option<Change<ExpenseCategory>>,
+    pub category_id: Option<Change<Option<CategoryId>>>,
+    pub total: Option<Change<Money>>,
+    pub currency: Option<Change<Currency>>,
+    pub exchange_rate: Option<Change<ExchangeRate>>,
+    pub payer: Option<Change<UserSummary>>,
+    pub payers: Option<Change<Vec<PaidAmount>>>,
+    pub split: Option<Change<ExpenseSplit>>,
+    pub participants_added: Vec<UserSummary>,
+    pub participants_removed: Vec<UserSummary>,
+    pub occurred_at: Option<Change<DateTime<Utc>>>,
+}
+
+pub struct UserSummary {
+    pub id: UserId,
+    pub name: Username,
+}
+
+pub struct PaidAmount {
+    pub user: UserSummary,
+    /// Amount paid, in the expense currency.
+    pub amount: Money,
+}
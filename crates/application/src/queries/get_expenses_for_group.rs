@@ -4,11 +4,17 @@ use chrono::{DateTime, Utc};
 use domain::{
     entities::{ExpenseEntry, User},
     types::{
-        expense_id::ExpenseId, group_id::GroupId, money::Money, user_id::UserId, username::Username,
+        category_id::CategoryId, currency::Currency, exchange_rate::ExchangeRate,
+        expense_category::ExpenseCategory, expense_id::ExpenseId, expense_note::ExpenseNote,
+        expense_title::ExpenseTitle, group_id::GroupId, money::Money, user_id::UserId,
+        username::Username,
     },
 };
 
-use crate::pagination::Pagination;
+use crate::{
+    pagination::Pagination,
+    permissions::{self, Permission},
+};
 
 pub struct GetExpensesForGroupQuery {
     pub group_id: GroupId,
@@ -37,7 +43,7 @@ impl GetExpensesForGroupQuery {
             return Err(GetExpensesForGroupError::GroupNotFound);
         };
 
-        if !group.contains_user(&self.current_user) {
+        if permissions::check(&group, &self.current_user, Permission::Read).is_err() {
             return Err(GetExpensesForGroupError::Forbidden);
         }
 
@@ -62,10 +68,11 @@ impl GetExpensesForGroupQuery {
     }
 }
 
-fn get_user_ids(expense_entries: &[ExpenseEntry]) -> HashSet<UserId> {
+pub(crate) fn get_user_ids(expense_entries: &[ExpenseEntry]) -> HashSet<UserId> {
     let mut ids = HashSet::new();
     for expense_entry in expense_entries {
         ids.insert(expense_entry.payer_id);
+        ids.extend(expense_entry.payers.users());
         for participant in &expense_entry.participants {
             ids.insert(*participant);
         }
@@ -77,24 +84,64 @@ fn build_group_expenses(
     expense_entries: Vec<ExpenseEntry>,
     users: HashMap<UserId, User>,
 ) -> Vec<GroupExpense> {
-    let mut expenses = Vec::new();
-    for expense_entry in expense_entries {
-        let payer = users
-            .get(&expense_entry.payer_id)
+    expense_entries
+        .iter()
+        .map(|expense_entry| build_group_expense(expense_entry, &users))
+        .collect()
+}
+
+pub(crate) fn build_group_expense(
+    expense_entry: &ExpenseEntry,
+    users: &HashMap<UserId, User>,
+) -> GroupExpense {
+    let payer = users
+        .get(&expense_entry.payer_id)
+        .expect("corrupted data: payer is not here")
+        .clone();
+    GroupExpense {
+        id: expense_entry.expense_id,
+        payer: UserSummary {
+            id: payer.id,
+            name: payer.name,
+        },
+        payers: get_payers(expense_entry, users),
+        participants: get_participants(expense_entry, users),
+        title: expense_entry.title.clone(),
+        note: expense_entry.note.clone(),
+        category: expense_entry.category,
+        category_id: expense_entry.category_id,
+        total: expense_entry.total,
+        currency: expense_entry.currency,
+        exchange_rate: expense_entry.exchange_rate,
+        total_in_group_currency: expense_entry.total_in_group_currency(),
+        occurred_at: expense_entry.occurred_at,
+    }
+}
+
+fn get_payers(expense_entry: &ExpenseEntry, users: &HashMap<UserId, User>) -> Vec<PaidAmount> {
+    let mut amounts: Vec<(UserId, Money)> = expense_entry
+        .payers
+        .amounts()
+        .iter()
+        .map(|(user_id, amount)| (*user_id, *amount))
+        .collect();
+    amounts.sort();
+
+    let mut payers = Vec::new();
+    for (user_id, amount) in amounts {
+        let user = users
+            .get(&user_id)
             .expect("corrupted data: payer is not here")
             .clone();
-        expenses.push(GroupExpense {
-            id: expense_entry.expense_id,
-            payer: UserSummary {
-                id: payer.id,
-                name: payer.name,
+        payers.push(PaidAmount {
+            user: UserSummary {
+                id: user.id,
+                name: user.name,
             },
-            participants: get_participants(&expense_entry, &users),
-            total: expense_entry.total,
-            occurred_at: expense_entry.occurred_at,
+            amount,
         });
     }
-    expenses
+    payers
 }
 
 fn get_participants(
@@ -122,9 +169,20 @@ pub struct Output {
 
 pub struct GroupExpense {
     pub id: ExpenseId,
+    /// Main payer of the expense.
     pub payer: UserSummary,
+    /// Amount paid by each payer, sorted by user id.
+    pub payers: Vec<PaidAmount>,
     pub participants: Vec<UserSummary>,
+    pub title: ExpenseTitle,
+    pub note: Option<ExpenseNote>,
+    pub category: ExpenseCategory,
+    pub category_id: Option<CategoryId>,
+    /// Total in the expense currency.
     pub total: Money,
+    pub currency: Currency,
+    pub exchange_rate: ExchangeRate,
+    pub total_in_group_currency: Money,
     pub occurred_at: DateTime<Utc>,
 }
 
@@ -132,3 +190,9 @@ pub struct UserSummary {
     pub id: UserId,
     pub name: Username,
 }
+
+pub struct PaidAmount {
+    pub user: UserSummary,
+    /// Amount paid, in the expense currency.
+    pub amount: Money,
+}
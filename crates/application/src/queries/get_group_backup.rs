@@ -0,0 +1,61 @@
+use domain::types::{group_id::GroupId, user_id::UserId};
+
+use crate::{
+    backup::{BackupDocument, referenced_users},
+    permissions::{self, Permission},
+};
+
+/// Returns the [backup document](crate::backup) of a whole group.
+///
+/// Only the group owner can back up the group.
+pub struct GetGroupBackupQuery {
+    pub group_id: GroupId,
+    pub current_user: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetGroupBackupError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("forbidden")]
+    Forbidden,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl GetGroupBackupQuery {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<BackupDocument, GetGroupBackupError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(GetGroupBackupError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user, Permission::Own).is_err() {
+            return Err(GetGroupBackupError::Forbidden);
+        }
+
+        let categories = database::queries::category::get_all_for_group(tx, &self.group_id).await?;
+        let expense_entries =
+            database::queries::expense_entry::get_all_for_group(tx, &self.group_id).await?;
+        let settlements =
+            database::queries::settlement::get_all_for_group_unpaginated(tx, &self.group_id)
+                .await?;
+        let users = database::queries::user::get_all_in_ids(
+            tx,
+            referenced_users(&group, &expense_entries, &settlements),
+        )
+        .await?;
+
+        Ok(BackupDocument::new(
+            &group,
+            &users,
+            &categories,
+            &expense_entries,
+            &settlements,
+        ))
+    }
+}
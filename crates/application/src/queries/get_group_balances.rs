@@ -0,0 +1,120 @@
+use domain::{
+    balances::GroupBalances,
+    entities::Group,
+    types::{
+        currency::Currency, group_id::GroupId, money::Money, user_id::UserId, username::Username,
+    },
+};
+
+use crate::permissions::{self, Permission};
+
+pub struct GetGroupBalancesQuery {
+    pub group_id: GroupId,
+    pub current_user: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetGroupBalancesError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("forbidden")]
+    Forbidden,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl GetGroupBalancesQuery {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<Output, GetGroupBalancesError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(GetGroupBalancesError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user, Permission::Read).is_err() {
+            return Err(GetGroupBalancesError::Forbidden);
+        }
+
+        let balances = compute_group_balances(tx, &group).await?.to_sorted_vec();
+
+        let user_ids = balances.iter().map(|(user_id, _)| *user_id).collect();
+        let users = database::queries::user::get_all_in_ids(tx, user_ids).await?;
+
+        let mut out = Vec::new();
+        for (user_id, balance) in balances {
+            let user = users
+                .get(&user_id)
+                .expect("corrupted data: user is not here");
+            out.push(MemberBalance {
+                user: UserSummary {
+                    id: user.id,
+                    name: user.name.clone(),
+                },
+                balance,
+            });
+        }
+
+        Ok(Output {
+            currency: group.currency,
+            balances: out,
+        })
+    }
+}
+
+/// Computes the balances of all users of the provided group from active
+/// expense entries and settlements, including group members that are not
+/// involved in any of them yet.
+pub(crate) async fn compute_group_balances(
+    tx: &mut database::Transaction<'_>,
+    group: &Group,
+) -> Result<GroupBalances, database::Error> {
+    let mut balances =
+        GroupBalances::new(std::iter::once(group.owner_id).chain(group.members.keys().copied()));
+
+    let expense_entries =
+        database::queries::expense_entry::get_all_active_for_group_unpaginated(tx, &group.id)
+            .await?;
+    for expense_entry in &expense_entries {
+        balances
+            .add_expense_entry(expense_entry)
+            .map_err(overflow_err)?;
+    }
+
+    let settlements =
+        database::queries::settlement::get_all_active_for_group_unpaginated(tx, &group.id).await?;
+    for settlement in &settlements {
+        balances.add_settlement(settlement).map_err(overflow_err)?;
+    }
+
+    Ok(balances)
+}
+
+/// Amounts are bounded when they are provided, so balances of a group
+/// can only overflow if stored amounts are corrupted.
+fn overflow_err(err: domain::balances::Error) -> database::Error {
+    database::Error::CorruptedData {
+        msg: format!("group balances cannot be computed: {err}"),
+    }
+}
+
+pub struct Output {
+    /// Currency of the group, in which balances are expressed.
+    pub currency: Currency,
+    /// Balances sorted by user id.
+    pub balances: Vec<MemberBalance>,
+}
+
+pub struct MemberBalance {
+    pub user: UserSummary,
+
+    /// Positive if the user is owed money, negative if the user owes money.
+    pub balance: Money,
+}
+
+pub struct UserSummary {
+    pub id: UserId,
+    pub name: Username,
+}
@@ -3,13 +3,18 @@ use std::collections::{HashMap, HashSet};
 use chrono::{DateTime, Utc};
 use domain::{
     entities::{Group, User},
-    types::{group_id::GroupId, groupname::Groupname, user_id::UserId, username::Username},
+    types::{
+        currency::Currency, group_id::GroupId, groupname::Groupname, user_id::UserId,
+        username::Username,
+    },
 };
 
 use crate::pagination::Pagination;
 
 pub struct GetGroupsForUserQuery {
     pub current_user: UserId,
+    /// Whether to return archived groups, or the ones in use.
+    pub archived: bool,
     pub pagination: Pagination,
 }
 
@@ -27,12 +32,14 @@ impl GetGroupsForUserQuery {
         let groups = database::queries::group::get_all_for_user(
             tx,
             &self.current_user,
+            self.archived,
             self.pagination.into(),
         )
         .await?;
 
         let total_groups =
-            database::queries::group::count_all_for_user(tx, &self.current_user).await?;
+            database::queries::group::count_all_for_user(tx, &self.current_user, self.archived)
+                .await?;
 
         if groups.is_empty() {
             return Ok(Output {
@@ -64,8 +71,10 @@ fn build_group_summaries(groups: Vec<Group>, users: HashMap<UserId, User>) -> Ve
         let gs = GroupSummary {
             id: group.id,
             name: group.name,
+            currency: group.currency,
             owner: owner_summary,
             created_at: group.created_at,
+            archived_at: group.archived_at,
         };
         out.push(gs);
     }
@@ -76,9 +85,7 @@ fn get_user_ids(groups: &[Group]) -> HashSet<UserId> {
     let mut ids = HashSet::new();
     for group in groups {
         ids.insert(group.owner_id);
-        for member in &group.members {
-            ids.insert(*member);
-        }
+        ids.extend(group.members.keys().copied());
     }
     ids
 }
@@ -91,8 +98,10 @@ pub struct Output {
 pub struct GroupSummary {
     pub id: GroupId,
     pub name: Groupname,
+    pub currency: Currency,
     pub owner: UserSummary,
     pub created_at: DateTime<Utc>,
+    pub archived_at: Option<DateTime<Utc>>,
 }
 
 pub struct UserSummary {
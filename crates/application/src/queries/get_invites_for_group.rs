@@ -0,0 +1,43 @@
+use domain::{
+    entities::Invite,
+    types::{group_id::GroupId, user_id::UserId},
+};
+
+use crate::permissions::{self, Permission};
+
+/// Returns all invites of a group, including revoked and expired ones, most
+/// recent first. Only the group owner and admins can see them, as their
+/// tokens let anyone join the group.
+pub struct GetInvitesForGroupQuery {
+    pub group_id: GroupId,
+    pub current_user: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetInvitesForGroupError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("forbidden")]
+    Forbidden,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl GetInvitesForGroupQuery {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<Vec<Invite>, GetInvitesForGroupError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(GetInvitesForGroupError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user, Permission::ManageMembers).is_err() {
+            return Err(GetInvitesForGroupError::Forbidden);
+        }
+
+        Ok(database::queries::invite::get_all_for_group(tx, &self.group_id).await?)
+    }
+}
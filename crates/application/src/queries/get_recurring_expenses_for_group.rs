@@ -0,0 +1,48 @@
+use domain::{
+    entities::RecurringExpense,
+    types::{group_id::GroupId, user_id::UserId},
+};
+
+use crate::permissions::{self, Permission};
+
+pub struct GetRecurringExpensesForGroupQuery {
+    pub group_id: GroupId,
+    pub current_user: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetRecurringExpensesForGroupError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("forbidden")]
+    Forbidden,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl GetRecurringExpensesForGroupQuery {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<Output, GetRecurringExpensesForGroupError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(GetRecurringExpensesForGroupError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user, Permission::Read).is_err() {
+            return Err(GetRecurringExpensesForGroupError::Forbidden);
+        }
+
+        let recurring_expenses =
+            database::queries::recurring_expense::get_all_for_group(tx, &self.group_id).await?;
+
+        Ok(Output { recurring_expenses })
+    }
+}
+
+pub struct Output {
+    /// Recurring expenses sorted by creation date.
+    pub recurring_expenses: Vec<RecurringExpense>,
+}
@@ -0,0 +1,86 @@
+use domain::types::{
+    currency::Currency, group_id::GroupId, money::Money, user_id::UserId, username::Username,
+};
+
+use crate::{
+    permissions::{self, Permission},
+    queries::get_group_balances::compute_group_balances,
+};
+
+pub struct GetSettlementPlanQuery {
+    pub group_id: GroupId,
+    pub current_user: UserId,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetSettlementPlanError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("forbidden")]
+    Forbidden,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl GetSettlementPlanQuery {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<Output, GetSettlementPlanError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(GetSettlementPlanError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user, Permission::Read).is_err() {
+            return Err(GetSettlementPlanError::Forbidden);
+        }
+
+        let plan = compute_group_balances(tx, &group).await?.settlement_plan();
+
+        let user_ids = plan.iter().flat_map(|t| [t.from, t.to]).collect();
+        let users = database::queries::user::get_all_in_ids(tx, user_ids).await?;
+        let user_summary = |user_id: &UserId| {
+            let user = users
+                .get(user_id)
+                .expect("corrupted data: user is not here");
+            UserSummary {
+                id: user.id,
+                name: user.name.clone(),
+            }
+        };
+
+        let transfers = plan
+            .iter()
+            .map(|transfer| PlannedTransfer {
+                from: user_summary(&transfer.from),
+                to: user_summary(&transfer.to),
+                amount: transfer.amount,
+            })
+            .collect();
+
+        Ok(Output {
+            currency: group.currency,
+            transfers,
+        })
+    }
+}
+
+pub struct Output {
+    /// Currency of the group, in which transfers are expressed.
+    pub currency: Currency,
+    /// Transfers sorted by amount (DESC).
+    pub transfers: Vec<PlannedTransfer>,
+}
+
+pub struct PlannedTransfer {
+    pub from: UserSummary,
+    pub to: UserSummary,
+    pub amount: Money,
+}
+
+pub struct UserSummary {
+    pub id: UserId,
+    pub name: Username,
+}
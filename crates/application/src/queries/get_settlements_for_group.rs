@@ -0,0 +1,124 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use domain::{
+    entities::{Settlement, User},
+    types::{
+        group_id::GroupId, money::Money, settlement_id::SettlementId,
+        settlement_status::SettlementStatus, user_id::UserId, username::Username,
+    },
+};
+
+use crate::{
+    pagination::Pagination,
+    permissions::{self, Permission},
+};
+
+pub struct GetSettlementsForGroupQuery {
+    pub group_id: GroupId,
+    pub current_user: UserId,
+    pub pagination: Pagination,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetSettlementsForGroupError {
+    #[error("group not found")]
+    GroupNotFound,
+
+    #[error("forbidden")]
+    Forbidden,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl GetSettlementsForGroupQuery {
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<Output, GetSettlementsForGroupError> {
+        let Some(group) = database::queries::group::get_by_id(tx, &self.group_id).await? else {
+            return Err(GetSettlementsForGroupError::GroupNotFound);
+        };
+
+        if permissions::check(&group, &self.current_user, Permission::Read).is_err() {
+            return Err(GetSettlementsForGroupError::Forbidden);
+        }
+
+        let settlements = database::queries::settlement::get_all_for_group(
+            tx,
+            &self.group_id,
+            self.pagination.into(),
+        )
+        .await?;
+
+        let total_settlements =
+            database::queries::settlement::count_all_for_group(tx, &self.group_id).await?;
+
+        let user_ids = get_user_ids(&settlements);
+        let users = database::queries::user::get_all_in_ids(tx, user_ids).await?;
+
+        Ok(Output {
+            settlements: build_group_settlements(settlements, &users),
+            total_items: total_settlements as usize,
+        })
+    }
+}
+
+fn get_user_ids(settlements: &[Settlement]) -> HashSet<UserId> {
+    let mut ids = HashSet::new();
+    for settlement in settlements {
+        ids.insert(settlement.from);
+        ids.insert(settlement.to);
+        ids.insert(settlement.author_id);
+    }
+    ids
+}
+
+fn build_group_settlements(
+    settlements: Vec<Settlement>,
+    users: &HashMap<UserId, User>,
+) -> Vec<GroupSettlement> {
+    let user_summary = |user_id: &UserId| {
+        let user = users
+            .get(user_id)
+            .expect("corrupted data: user is not here");
+        UserSummary {
+            id: user.id,
+            name: user.name.clone(),
+        }
+    };
+
+    settlements
+        .into_iter()
+        .map(|settlement| GroupSettlement {
+            id: settlement.id,
+            from: user_summary(&settlement.from),
+            to: user_summary(&settlement.to),
+            amount: settlement.amount,
+            author: user_summary(&settlement.author_id),
+            cancelled: matches!(settlement.status, SettlementStatus::Cancelled { .. }),
+            occurred_at: settlement.occurred_at,
+        })
+        .collect()
+}
+
+pub struct Output {
+    pub settlements: Vec<GroupSettlement>,
+    pub total_items: usize,
+}
+
+pub struct GroupSettlement {
+    pub id: SettlementId,
+    pub from: UserSummary,
+    pub to: UserSummary,
+    pub amount: Money,
+    pub author: UserSummary,
+    pub cancelled: bool,
+    pub occurred_at: DateTime<Utc>,
+}
+
+pub struct UserSummary {
+    pub id: UserId,
+    pub name: Username,
+}
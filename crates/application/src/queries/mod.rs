@@ -1,4 +1,19 @@
+pub mod export_expenses_for_group;
+pub mod get_attachment;
+pub mod get_attachments_for_expense;
+pub mod get_budget_alerts_for_group;
+pub mod get_budgets_for_group;
+pub mod get_categories_for_group;
+pub mod get_category_report;
+pub mod get_expense_history;
 pub mod get_expenses_for_group;
+pub mod get_group_backup;
+pub mod get_group_balances;
 pub mod get_groups_for_user;
+pub mod get_invites_for_group;
+pub mod get_recurring_expenses_for_group;
+pub mod get_settlement_plan;
+pub mod get_settlements_for_group;
 pub mod get_user_by_email;
 pub mod get_user_by_id;
+pub mod search_users;
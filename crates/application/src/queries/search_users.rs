@@ -0,0 +1,39 @@
+use domain::entities::User;
+
+/// Returns the users whose handle or name starts with the provided text,
+/// so that they can be found without knowing their id. Emails are not
+/// searched, so that searching does not reveal who is registered.
+pub struct SearchUsersQuery {
+    /// Start of a handle, with or without its leading `@`, or of a name.
+    pub text: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SearchUsersError {
+    #[error(
+        "search text must be at least {} characters long",
+        SearchUsersQuery::MIN_LENGTH
+    )]
+    TextTooShort,
+
+    #[error("database error: {0}")]
+    Database(#[from] database::Error),
+}
+
+impl SearchUsersQuery {
+    const MIN_LENGTH: usize = 2;
+    const MAX_RESULTS: u32 = 20;
+
+    pub async fn handle(
+        self,
+        tx: &mut database::Transaction<'_>,
+    ) -> Result<Vec<User>, SearchUsersError> {
+        let text = self.text.trim();
+        let text = text.strip_prefix('@').unwrap_or(text);
+        if text.chars().count() < Self::MIN_LENGTH {
+            return Err(SearchUsersError::TextTooShort);
+        }
+
+        Ok(database::queries::user::search(tx, text, Self::MAX_RESULTS).await?)
+    }
+}
@@ -0,0 +1,274 @@
+use application::commands::accept_invite::AcceptInviteError;
+use chrono::{Duration, Utc};
+
+use crate::infra::{ctx::TestContext, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    let invite = ctx
+        .invites()
+        .create_invite_with(
+            group_id,
+            alice_id,
+            "admin",
+            None,
+            Utc::now() + Duration::days(1),
+        )
+        .await?;
+
+    // When
+    let joined_group_id = ctx
+        .invites()
+        .accept_invite(invite.token.value(), bob_id)
+        .await?;
+
+    // Then
+    assert_eq!(group_id, joined_group_id);
+    ctx.groups()
+        .assert_group_contains_members(group_id, vec![bob_id])
+        .await?;
+    // the role of the invite is given to the new member
+    ctx.groups()
+        .add_member(group_id, bob_id, charlie_id)
+        .await?;
+    let invites = ctx.invites().get_invites(group_id, alice_id).await?;
+    assert_eq!(1, invites[0].uses);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn multi_use_invite_can_be_accepted_by_several_users() -> anyhow::Result<()> {
+    let db_pool =
+        build_test_database(FILE, "multi_use_invite_can_be_accepted_by_several_users").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    let invite = ctx
+        .invites()
+        .create_invite(group_id, alice_id, None)
+        .await?;
+
+    // When
+    ctx.invites()
+        .accept_invite(invite.token.value(), bob_id)
+        .await?;
+    ctx.invites()
+        .accept_invite(invite.token.value(), charlie_id)
+        .await?;
+
+    // Then
+    ctx.groups()
+        .assert_group_contains_members(group_id, vec![bob_id, charlie_id])
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn single_use_invite_cannot_be_reused() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "single_use_invite_cannot_be_reused").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    let invite = ctx
+        .invites()
+        .create_invite(group_id, alice_id, Some(1))
+        .await?;
+    ctx.invites()
+        .accept_invite(invite.token.value(), bob_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .invites()
+        .accept_invite(invite.token.value(), charlie_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        AcceptInviteError::InviteExhausted.to_string(),
+        err.to_string()
+    );
+    ctx.groups()
+        .assert_group_does_not_contain_member(group_id, charlie_id)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn expired_invite_cannot_be_accepted() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "expired_invite_cannot_be_accepted").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    let invite = ctx
+        .invites()
+        .create_expired_invite(group_id, alice_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .invites()
+        .accept_invite(invite.token.value(), bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        AcceptInviteError::InviteExpired.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn revoked_invite_cannot_be_accepted() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "revoked_invite_cannot_be_accepted").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    let invite = ctx
+        .invites()
+        .create_invite(group_id, alice_id, None)
+        .await?;
+    ctx.invites()
+        .revoke_invite(group_id, invite.id.value(), alice_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .invites()
+        .accept_invite(invite.token.value(), bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        AcceptInviteError::InviteRevoked.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn member_cannot_accept_invite_of_own_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "member_cannot_accept_invite_of_own_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+    let invite = ctx
+        .invites()
+        .create_invite(group_id, alice_id, Some(1))
+        .await?;
+
+    // When
+    let err = ctx
+        .invites()
+        .accept_invite(invite.token.value(), bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        AcceptInviteError::AlreadyMember.to_string(),
+        err.to_string()
+    );
+    // the failed attempt does not consume the invite
+    let invites = ctx.invites().get_invites(group_id, alice_id).await?;
+    assert_eq!(0, invites[0].uses);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn invite_stops_working_once_author_is_demoted() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "invite_stops_working_once_author_is_demoted").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    ctx.groups()
+        .add_member_with_role(group_id, alice_id, bob_id, "admin")
+        .await?;
+    let invite = ctx.invites().create_invite(group_id, bob_id, None).await?;
+    ctx.groups()
+        .change_member_role(group_id, alice_id, bob_id, "member")
+        .await?;
+
+    // When
+    let err = ctx
+        .invites()
+        .accept_invite(invite.token.value(), charlie_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        AcceptInviteError::AuthorNotAllowed.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unknown_token() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "unknown_token").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+
+    // When
+    let err = ctx
+        .invites()
+        .accept_invite(&"0".repeat(64), bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        AcceptInviteError::InviteNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
@@ -31,6 +31,36 @@ async fn happy_path() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn viewer_cannot_add_member() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "viewer_cannot_add_member").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups()
+        .add_member_with_role(group_id, alice_id, bob_id, "viewer")
+        .await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .add_member(group_id, bob_id, charlie_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(AddGroupMemberError::NotAdmin.to_string(), err.to_string());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn owner_cannot_self_add() -> anyhow::Result<()> {
     let db_pool = build_test_database(FILE, "owner_cannot_self_add").await?;
@@ -163,7 +193,129 @@ async fn owner_not_found() -> anyhow::Result<()> {
         .unwrap_err();
 
     // Then
-    assert_eq!(AddGroupMemberError::NotOwner.to_string(), err.to_string());
+    assert_eq!(AddGroupMemberError::NotAdmin.to_string(), err.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn add_member_by_handle() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "add_member_by_handle").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx
+        .users()
+        .create_user_with_handle("Bob", "builder")
+        .await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+
+    // When
+    ctx.groups()
+        .add_member_by_reference(group_id, alice_id, "@Builder")
+        .await?;
+
+    // Then
+    ctx.groups()
+        .assert_group_contains_members(group_id, vec![bob_id])
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn add_member_by_email() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "add_member_by_email").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx
+        .users()
+        .create_user_with_name_and_email("Bob", "bob.thegoat@gmail.com")
+        .await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+
+    // When
+    ctx.groups()
+        .add_member_by_reference(group_id, alice_id, "bob.thegoat@gmail.com")
+        .await?;
+
+    // Then
+    ctx.groups()
+        .assert_group_contains_members(group_id, vec![bob_id])
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unknown_email_or_handle() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "unknown_email_or_handle").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+
+    for reference in ["nobody@gmail.com", "@nobody"] {
+        // When
+        let err = ctx
+            .groups()
+            .add_member_by_reference(group_id, alice_id, reference)
+            .await
+            .unwrap_err();
+
+        // Then
+        assert_eq!(
+            AddGroupMemberError::UserNotFound.to_string(),
+            err.to_string()
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn registered_emails_are_not_revealed_to_non_admins() -> anyhow::Result<()> {
+    let db_pool =
+        build_test_database(FILE, "registered_emails_are_not_revealed_to_non_admins").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    ctx.users()
+        .create_user_with_name_and_email("Charlie", "charlie@gmail.com")
+        .await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+
+    for email in ["charlie@gmail.com", "nobody@gmail.com"] {
+        // When
+        let err = ctx
+            .groups()
+            .add_member_by_reference(group_id, bob_id, email)
+            .await
+            .unwrap_err();
+
+        // Then
+        // registered or not, the error is the same
+        assert_eq!(AddGroupMemberError::NotAdmin.to_string(), err.to_string());
+    }
 
     Ok(())
 }
@@ -0,0 +1,226 @@
+use application::commands::{
+    add_group_member::AddGroupMemberError, archive_group::ArchiveGroupError,
+    cancel_settlement::CancelSettlementError, create_category::CreateCategoryError,
+    create_expense::CreateExpenseError, delete_expense::DeleteExpenseError,
+    record_settlement::RecordSettlementError,
+};
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+
+    // When
+    ctx.groups().archive_group(group_id, alice_id).await?;
+
+    // Then
+    let group_ids = ctx.groups().get_group_ids_for_user(bob_id, false).await?;
+    assert!(group_ids.is_empty());
+    let group_ids = ctx.groups().get_group_ids_for_user(bob_id, true).await?;
+    assert_eq!(vec![group_id], group_ids);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_owner_can_archive_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "only_owner_can_archive_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .archive_group(group_id, bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(ArchiveGroupError::NotOwner.to_string(), err.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn group_cannot_be_archived_twice() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "group_cannot_be_archived_twice").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().archive_group(group_id, alice_id).await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .archive_group(group_id, alice_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        ArchiveGroupError::AlreadyArchived.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn archived_group_rejects_writes() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "archived_group_rejects_writes").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().archive_group(group_id, alice_id).await?;
+
+    // When
+    let expense_err = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(
+            group_id,
+            alice_id,
+            30,
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await
+        .unwrap_err();
+    let member_err = ctx
+        .groups()
+        .add_member(group_id, alice_id, bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateExpenseError::GroupArchived.to_string(),
+        expense_err.to_string()
+    );
+    assert_eq!(
+        AddGroupMemberError::GroupArchived.to_string(),
+        member_err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn archived_group_rejects_changes_to_existing_data() -> anyhow::Result<()> {
+    let db_pool =
+        build_test_database(FILE, "archived_group_rejects_changes_to_existing_data").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(
+            group_id,
+            alice_id,
+            30,
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+    let settlement_id = ctx
+        .settlements()
+        .record_settlement(
+            group_id,
+            bob_id,
+            alice_id,
+            1_000,
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+    ctx.groups().archive_group(group_id, alice_id).await?;
+
+    // When
+    let delete_err = ctx
+        .expense_entries()
+        .delete_expense(group_id, expense_id, alice_id)
+        .await
+        .unwrap_err();
+    let record_err = ctx
+        .settlements()
+        .record_settlement(
+            group_id,
+            bob_id,
+            alice_id,
+            500,
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await
+        .unwrap_err();
+    let cancel_err = ctx
+        .settlements()
+        .cancel_settlement(group_id, settlement_id, bob_id)
+        .await
+        .unwrap_err();
+    let category_err = ctx
+        .categories()
+        .create_category(group_id, alice_id, "Bars")
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        DeleteExpenseError::GroupArchived.to_string(),
+        delete_err.to_string()
+    );
+    assert_eq!(
+        RecordSettlementError::GroupArchived.to_string(),
+        record_err.to_string()
+    );
+    assert_eq!(
+        CancelSettlementError::GroupArchived.to_string(),
+        cancel_err.to_string()
+    );
+    assert_eq!(
+        CreateCategoryError::GroupArchived.to_string(),
+        category_err.to_string()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,277 @@
+use application::commands::cancel_settlement::CancelSettlementError;
+use domain::types::settlement_status::SettlementStatus;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    let settlement_id = ctx
+        .settlements()
+        .record_settlement(
+            group_id,
+            alice_id,
+            bob_id,
+            2_000,
+            alice_id,
+            dates::jan_10_2025(),
+        )
+        .await?;
+
+    // When
+    ctx.settlements()
+        .cancel_settlement(group_id, settlement_id, alice_id)
+        .await?;
+
+    // Then
+    let settlement = ctx
+        .settlements()
+        .assert_settlement_exists(settlement_id)
+        .await?;
+    assert!(matches!(
+        settlement.status,
+        SettlementStatus::Cancelled { cancelled_by, .. } if cancelled_by.value() == alice_id
+    ));
+    let balances = ctx.balances().get_group_balances(group_id, bob_id).await?;
+    assert_eq!(Some(&0), balances.get(&bob_id));
+    assert_eq!(Some(&0), balances.get(&alice_id));
+
+    // cancelled settlements are still listed
+    let settlements = ctx
+        .settlements()
+        .get_settlements_for_group(group_id, bob_id)
+        .await?;
+    assert_eq!(1, settlements.total_items);
+    assert!(settlements.settlements[0].cancelled);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn owner_can_cancel() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "owner_can_cancel").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    let settlement_id = ctx
+        .settlements()
+        .record_settlement(
+            group_id,
+            alice_id,
+            bob_id,
+            2_000,
+            alice_id,
+            dates::jan_10_2025(),
+        )
+        .await?;
+
+    // When
+    ctx.settlements()
+        .cancel_settlement(group_id, settlement_id, bob_id)
+        .await?;
+
+    // Then
+    let settlement = ctx
+        .settlements()
+        .assert_settlement_exists(settlement_id)
+        .await?;
+    assert!(!settlement.is_active());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn admin_can_cancel() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "admin_can_cancel").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    ctx.groups()
+        .add_member_with_role(group_id, bob_id, charlie_id, "admin")
+        .await?;
+    let settlement_id = ctx
+        .settlements()
+        .record_settlement(
+            group_id,
+            alice_id,
+            bob_id,
+            2_000,
+            alice_id,
+            dates::jan_10_2025(),
+        )
+        .await?;
+
+    // When
+    ctx.settlements()
+        .cancel_settlement(group_id, settlement_id, charlie_id)
+        .await?;
+
+    // Then
+    let settlement = ctx
+        .settlements()
+        .assert_settlement_exists(settlement_id)
+        .await?;
+    assert!(!settlement.is_active());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn other_member_cannot_cancel() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "other_member_cannot_cancel").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    ctx.groups()
+        .add_member(group_id, bob_id, charlie_id)
+        .await?;
+    let settlement_id = ctx
+        .settlements()
+        .record_settlement(
+            group_id,
+            alice_id,
+            bob_id,
+            2_000,
+            alice_id,
+            dates::jan_10_2025(),
+        )
+        .await?;
+
+    // When
+    let err = ctx
+        .settlements()
+        .cancel_settlement(group_id, settlement_id, charlie_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CancelSettlementError::Forbidden.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn already_cancelled() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "already_cancelled").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    let settlement_id = ctx
+        .settlements()
+        .record_settlement(
+            group_id,
+            alice_id,
+            bob_id,
+            2_000,
+            alice_id,
+            dates::jan_10_2025(),
+        )
+        .await?;
+    ctx.settlements()
+        .cancel_settlement(group_id, settlement_id, alice_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .settlements()
+        .cancel_settlement(group_id, settlement_id, alice_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CancelSettlementError::AlreadyCancelled.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn settlement_from_another_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "settlement_from_another_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let other_group_id = ctx.groups().create_empty_group("Rent", bob_id).await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    let settlement_id = ctx
+        .settlements()
+        .record_settlement(
+            group_id,
+            alice_id,
+            bob_id,
+            2_000,
+            alice_id,
+            dates::jan_10_2025(),
+        )
+        .await?;
+
+    // When
+    let err = ctx
+        .settlements()
+        .cancel_settlement(other_group_id, settlement_id, bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CancelSettlementError::SettlementNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
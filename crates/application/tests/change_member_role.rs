@@ -0,0 +1,121 @@
+use application::commands::{
+    add_group_member::AddGroupMemberError, change_member_role::ChangeMemberRoleError,
+};
+
+use crate::infra::{ctx::TestContext, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+
+    // When
+    ctx.groups()
+        .change_member_role(group_id, alice_id, bob_id, "admin")
+        .await?;
+
+    // Then
+    // admins manage members along with the owner
+    ctx.groups()
+        .add_member(group_id, bob_id, charlie_id)
+        .await?;
+    ctx.groups()
+        .assert_group_contains_members(group_id, vec![bob_id, charlie_id])
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn demoted_admin_cannot_manage_members() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "demoted_admin_cannot_manage_members").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    ctx.groups()
+        .add_member_with_role(group_id, alice_id, bob_id, "admin")
+        .await?;
+
+    // When
+    ctx.groups()
+        .change_member_role(group_id, alice_id, bob_id, "member")
+        .await?;
+
+    // Then
+    let err = ctx
+        .groups()
+        .add_member(group_id, bob_id, charlie_id)
+        .await
+        .unwrap_err();
+    assert_eq!(AddGroupMemberError::NotAdmin.to_string(), err.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_owner_and_admins_can_change_roles() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "only_owner_and_admins_can_change_roles").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .change_member_role(group_id, bob_id, bob_id, "admin")
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(ChangeMemberRoleError::NotAdmin.to_string(), err.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn owner_has_no_role() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "owner_has_no_role").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    ctx.groups()
+        .add_member_with_role(group_id, alice_id, bob_id, "admin")
+        .await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .change_member_role(group_id, bob_id, alice_id, "viewer")
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        ChangeMemberRoleError::NotMember.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
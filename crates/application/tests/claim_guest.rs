@@ -0,0 +1,308 @@
+use application::commands::claim_guest::ClaimGuestError;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    let guest_id = ctx.groups().create_guest(group_id, alice_id, "Bob").await?;
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(
+            group_id,
+            guest_id,
+            100,
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+    ctx.settlements()
+        .record_settlement(
+            group_id,
+            alice_id,
+            guest_id,
+            2_000,
+            alice_id,
+            dates::jan_10_2025(),
+        )
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+
+    // When
+    ctx.groups()
+        .claim_guest(group_id, alice_id, guest_id, bob_id)
+        .await?;
+
+    // Then
+    ctx.groups()
+        .assert_group_does_not_contain_member(group_id, guest_id)
+        .await?;
+    let balances = ctx.balances().get_group_balances(group_id, bob_id).await?;
+    assert_eq!(2, balances.len());
+    assert_eq!(3_000, balances[&bob_id]);
+    assert_eq!(-3_000, balances[&alice_id]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn user_already_takes_part() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "user_already_takes_part").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+    let guest_id = ctx.groups().create_guest(group_id, alice_id, "Bob").await?;
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(
+            group_id,
+            guest_id,
+            100,
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .claim_guest(group_id, alice_id, guest_id, bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        ClaimGuestError::AlreadyTakesPart.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn registered_member_cannot_be_claimed() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "registered_member_cannot_be_claimed").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+    ctx.groups()
+        .add_member(group_id, alice_id, charlie_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .claim_guest(group_id, alice_id, charlie_id, bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(ClaimGuestError::NotGuest.to_string(), err.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn guest_of_another_group_cannot_be_claimed() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "guest_of_another_group_cannot_be_claimed").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    let other_group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    let guest_id = ctx
+        .groups()
+        .create_guest(other_group_id, alice_id, "Gabe")
+        .await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .claim_guest(group_id, alice_id, guest_id, alice_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(ClaimGuestError::NotGuest.to_string(), err.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn member_cannot_claim_guest() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "member_cannot_claim_guest").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+    let guest_id = ctx.groups().create_guest(group_id, alice_id, "Bob").await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .claim_guest(group_id, bob_id, guest_id, bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(ClaimGuestError::Forbidden.to_string(), err.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn user_not_in_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "user_not_in_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    let guest_id = ctx.groups().create_guest(group_id, alice_id, "Bob").await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .claim_guest(group_id, bob_id, guest_id, bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(ClaimGuestError::GroupNotFound.to_string(), err.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn admin_can_hand_guest_over() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "admin_can_hand_guest_over").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups()
+        .add_member_with_role(group_id, alice_id, bob_id, "admin")
+        .await?;
+    ctx.groups()
+        .add_member(group_id, alice_id, charlie_id)
+        .await?;
+    let guest_id = ctx
+        .groups()
+        .create_guest(group_id, alice_id, "Charlie")
+        .await?;
+
+    // When
+    ctx.groups()
+        .claim_guest(group_id, bob_id, guest_id, charlie_id)
+        .await?;
+
+    // Then
+    ctx.groups()
+        .assert_group_does_not_contain_member(group_id, guest_id)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn member_not_in_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "member_not_in_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    let guest_id = ctx.groups().create_guest(group_id, alice_id, "Bob").await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .claim_guest(group_id, alice_id, guest_id, bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(ClaimGuestError::MemberNotFound.to_string(), err.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn guest_cannot_be_handed_over_to_another_guest() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "guest_cannot_be_handed_over_to_another_guest").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    let guest_id = ctx.groups().create_guest(group_id, alice_id, "Bob").await?;
+    let other_guest_id = ctx
+        .groups()
+        .create_guest(group_id, alice_id, "Charlie")
+        .await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .claim_guest(group_id, alice_id, guest_id, other_guest_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(ClaimGuestError::MemberNotFound.to_string(), err.to_string());
+
+    Ok(())
+}
@@ -0,0 +1,229 @@
+use application::commands::create_attachment::CreateAttachmentError;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+
+    // When
+    let attachment_id = ctx
+        .attachments()
+        .create_attachment(group_id, expense_id, alice_id, "receipt.jpg", 2_048)
+        .await?;
+
+    // Then
+    let attachment = ctx
+        .attachments()
+        .get_attachment(group_id, expense_id, attachment_id, bob_id)
+        .await?;
+    assert_eq!("receipt.jpg", attachment.file_name.value());
+    assert_eq!("image/jpeg", attachment.content_type.value());
+    assert_eq!(2_048, attachment.size_bytes);
+    assert_eq!(alice_id, attachment.uploaded_by.value());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn kept_when_expense_is_updated() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "kept_when_expense_is_updated").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+    let attachment_id = ctx
+        .attachments()
+        .create_attachment(group_id, expense_id, bob_id, "receipt.jpg", 2_048)
+        .await?;
+
+    // When
+    ctx.expense_entries()
+        .update_expense(
+            group_id,
+            expense_id,
+            bob_id,
+            120,
+            vec![bob_id],
+            bob_id,
+            dates::jan_10_2025(),
+        )
+        .await?;
+
+    // Then
+    let attachment_ids = ctx
+        .attachments()
+        .get_attachment_ids_for_expense(group_id, expense_id, bob_id)
+        .await?;
+    assert_eq!(vec![attachment_id], attachment_ids);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn not_group_member() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "not_group_member").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+
+    // When
+    let err = ctx
+        .attachments()
+        .create_attachment(group_id, expense_id, charlie_id, "receipt.jpg", 2_048)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateAttachmentError::Forbidden.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn expense_of_another_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "expense_of_another_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let other_group_id = ctx
+        .groups()
+        .create_empty_group("Bob's expenses", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(
+            other_group_id,
+            bob_id,
+            90,
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // When
+    let err = ctx
+        .attachments()
+        .create_attachment(group_id, expense_id, bob_id, "receipt.jpg", 2_048)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateAttachmentError::ExpenseNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn deleted_expense() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "deleted_expense").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+    ctx.expense_entries()
+        .delete_expense(group_id, expense_id, bob_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .attachments()
+        .create_attachment(group_id, expense_id, bob_id, "receipt.jpg", 2_048)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateAttachmentError::ExpenseNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn empty_file() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "empty_file").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+
+    // When
+    let err = ctx
+        .attachments()
+        .create_attachment(group_id, expense_id, bob_id, "receipt.jpg", 0)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateAttachmentError::EmptyFile.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
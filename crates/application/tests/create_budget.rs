@@ -0,0 +1,145 @@
+use application::commands::create_budget::CreateBudgetError;
+use domain::types::{budget_period::BudgetPeriod, money::Money};
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let category_id = ctx
+        .categories()
+        .create_category(group_id, bob_id, "Surf lessons")
+        .await?;
+
+    // When
+    let budget_id = ctx
+        .budgets()
+        .create_budget(
+            group_id,
+            bob_id,
+            Some(category_id),
+            300,
+            BudgetPeriod::Monthly,
+        )
+        .await?;
+
+    // Then
+    let budgets = ctx
+        .budgets()
+        .get_budgets_for_group(group_id, bob_id, dates::jan_10_2025())
+        .await?;
+    assert_eq!(1, budgets.len());
+    let budget = &budgets[0].budget;
+    assert_eq!(budget_id, budget.id.value());
+    assert_eq!(Some(category_id), budget.category_id.map(|id| id.value()));
+    assert_eq!(Money::from_euros(300), budget.amount);
+    assert_eq!(BudgetPeriod::Monthly, budget.period);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_owner_and_admins_can_create_budget() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "only_owner_and_admins_can_create_budget").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+
+    // When
+    let err = ctx
+        .budgets()
+        .create_budget(group_id, alice_id, None, 300, BudgetPeriod::Monthly)
+        .await
+        .expect_err("creation must fail");
+
+    // Then
+    assert_eq!(CreateBudgetError::NotAdmin.to_string(), err.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn category_must_belong_to_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "category_must_belong_to_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let other_group_id = ctx.groups().create_empty_group("Flatmates", bob_id).await?;
+    let category_id = ctx
+        .categories()
+        .create_category(other_group_id, bob_id, "Groceries")
+        .await?;
+
+    // When
+    let err = ctx
+        .budgets()
+        .create_budget(
+            group_id,
+            bob_id,
+            Some(category_id),
+            300,
+            BudgetPeriod::Monthly,
+        )
+        .await
+        .expect_err("creation must fail");
+
+    // Then
+    assert_eq!(
+        CreateBudgetError::CategoryNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn amount_must_be_positive() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "amount_must_be_positive").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .budgets()
+        .create_budget(group_id, bob_id, None, 0, BudgetPeriod::Monthly)
+        .await
+        .expect_err("creation must fail");
+
+    // Then
+    assert_eq!(
+        CreateBudgetError::InvalidAmount.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use application::commands::create_category::CreateCategoryError;
+use uuid::Uuid;
+
+use crate::infra::{ctx::TestContext, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+
+    // When
+    let category_id = ctx
+        .categories()
+        .create_category(group_id, bob_id, "Surf lessons")
+        .await?;
+
+    // Then
+    let category = ctx.categories().assert_category_exists(category_id).await?;
+    assert_eq!(group_id, category.group_id.value());
+    assert_eq!("Surf lessons", category.name.value());
+    assert_eq!("#FF8800", category.color.value());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_owner_and_admins_can_create_category() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "only_owner_and_admins_can_create_category").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+
+    // When
+    let err = ctx
+        .categories()
+        .create_category(group_id, alice_id, "Surf lessons")
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(CreateCategoryError::NotAdmin.to_string(), err.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn name_must_be_unique_in_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "name_must_be_unique_in_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.categories()
+        .create_category(group_id, bob_id, "Surf lessons")
+        .await?;
+
+    // When
+    let err = ctx
+        .categories()
+        .create_category(group_id, bob_id, "SURF LESSONS")
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateCategoryError::NameNotAvailable.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn same_name_in_another_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "same_name_in_another_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let bali_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let hawaii_id = ctx
+        .groups()
+        .create_empty_group("Hawaii Trip 2027", bob_id)
+        .await?;
+    ctx.categories()
+        .create_category(bali_id, bob_id, "Surf lessons")
+        .await?;
+
+    // When
+    let res = ctx
+        .categories()
+        .create_category(hawaii_id, bob_id, "Surf lessons")
+        .await;
+
+    // Then
+    assert!(res.is_ok());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn group_not_found() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "group_not_found").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+
+    // When
+    let err = ctx
+        .categories()
+        .create_category(Uuid::now_v7(), bob_id, "Surf lessons")
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateCategoryError::GroupNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
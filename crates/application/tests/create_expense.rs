@@ -1,7 +1,18 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use application::commands::create_expense::CreateExpenseError;
-use domain::types::user_id::UserId;
+use application::commands::create_expense::{
+    CreateExpenseError, CurrencyError, PayersError, SplitSpecification,
+};
+use chrono::Datelike;
+use domain::types::{
+    budget_period::BudgetPeriod,
+    budget_threshold::BudgetThreshold,
+    expense_category::ExpenseCategory,
+    expense_payers,
+    expense_split::{self, ExpenseSplit},
+    money::Money,
+    user_id::UserId,
+};
 use uuid::Uuid;
 
 use crate::infra::{ctx::TestContext, dates, db::build_test_database};
@@ -45,6 +56,137 @@ async fn happy_path() -> anyhow::Result<()> {
     assert_eq!(12_800, expense_entry.total.cents());
     assert_eq!(bob_id, expense_entry.author_id.value());
     assert_eq!(dates::jan_08_2025(), expense_entry.occurred_at);
+    assert_eq!("Expense", expense_entry.title.value());
+    assert_eq!(None, expense_entry.note);
+    assert_eq!(ExpenseCategory::Other, expense_entry.category);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn happy_path_with_details() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path_with_details").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+
+    // When
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_with_details(
+            group_id,
+            bob_id,
+            42,
+            "  Scuba diving  ",
+            Some("Equipment rental included"),
+            ExpenseCategory::Entertainment,
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // Then
+    let expense_entry = ctx
+        .expense_entries()
+        .assert_expense_has_a_single_entry(expense_id)
+        .await?;
+
+    assert_eq!("Scuba diving", expense_entry.title.value());
+    assert_eq!(
+        Some("Equipment rental included".to_string()),
+        expense_entry.note.map(|note| note.value())
+    );
+    assert_eq!(ExpenseCategory::Entertainment, expense_entry.category);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn happy_path_custom_category() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path_custom_category").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let category_id = ctx
+        .categories()
+        .create_category(group_id, bob_id, "Surf lessons")
+        .await?;
+
+    // When
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_in_category(
+            group_id,
+            bob_id,
+            42,
+            category_id,
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // Then
+    let expense_entry = ctx
+        .expense_entries()
+        .assert_expense_has_a_single_entry(expense_id)
+        .await?;
+    assert_eq!(
+        Some(category_id),
+        expense_entry.category_id.map(|id| id.value())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn custom_category_of_another_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "custom_category_of_another_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let bali_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let hawaii_id = ctx
+        .groups()
+        .create_empty_group("Hawaii Trip 2027", bob_id)
+        .await?;
+    let category_id = ctx
+        .categories()
+        .create_category(hawaii_id, bob_id, "Surf lessons")
+        .await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .create_expense_in_category(
+            bali_id,
+            bob_id,
+            42,
+            category_id,
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateExpenseError::CategoryNotFound.to_string(),
+        err.to_string()
+    );
 
     Ok(())
 }
@@ -194,6 +336,165 @@ async fn payer_not_in_group() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn happy_path_several_payers() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path_several_payers").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let carol_id = ctx.users().create_user("Carol").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    ctx.groups().add_member(group_id, bob_id, carol_id).await?;
+
+    // When
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_paid_by(
+            group_id,
+            bob_id,
+            vec![(bob_id, 6_000), (alice_id, 3_000)],
+            9_000,
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // Then
+    let balances = ctx.balances().get_group_balances(group_id, bob_id).await?;
+    assert_eq!(Some(&3_000), balances.get(&bob_id));
+    assert_eq!(Some(&0), balances.get(&alice_id));
+    assert_eq!(Some(&-3_000), balances.get(&carol_id));
+
+    let payers = ctx
+        .expense_entries()
+        .get_payers(group_id, expense_id, bob_id)
+        .await?;
+    let mut expected = vec![(bob_id, 6_000), (alice_id, 3_000)];
+    expected.sort();
+    assert_eq!(expected, payers);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn every_payer_must_be_group_member() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "every_payer_must_be_group_member").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bob's expenses", bob_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .create_expense_paid_by(
+            group_id,
+            bob_id,
+            vec![(bob_id, 1_000), (charlie_id, 1_000)],
+            2_000,
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateExpenseError::PayerIsNotGroupMember.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn amounts_paid_must_sum_up_to_total() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "amounts_paid_must_sum_up_to_total").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .create_expense_paid_by(
+            group_id,
+            bob_id,
+            vec![(bob_id, 1_000), (alice_id, 900)],
+            2_000,
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateExpenseError::InvalidPayers(PayersError::Invalid(
+            expense_payers::Error::AmountsMismatch
+        ))
+        .to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn main_payer_must_be_one_of_the_payers() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "main_payer_must_be_one_of_the_payers").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .create_expense_paid_by(
+            group_id,
+            bob_id,
+            vec![(alice_id, 2_000)],
+            2_000,
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateExpenseError::InvalidPayers(PayersError::MissingPayer).to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn author_not_in_group() -> anyhow::Result<()> {
     let db_pool = build_test_database(FILE, "author_not_in_group").await?;
@@ -233,6 +534,44 @@ async fn author_not_in_group() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn viewer_cannot_add_expense() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "viewer_cannot_add_expense").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Alice's expenses", alice_id)
+        .await?;
+    ctx.groups()
+        .add_member_with_role(group_id, alice_id, charlie_id, "viewer")
+        .await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(
+            group_id,
+            alice_id,
+            23,
+            charlie_id,
+            dates::jan_08_2025(),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateExpenseError::ReadOnlyMember.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn participant_not_found_in_group() -> anyhow::Result<()> {
     let db_pool = build_test_database(FILE, "participant_not_found_in_group").await?;
@@ -269,3 +608,477 @@ async fn participant_not_found_in_group() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn happy_path_shares_split() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path_shares_split").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    ctx.groups()
+        .add_member(group_id, bob_id, charlie_id)
+        .await?;
+    let shares = HashMap::from_iter(vec![(UserId::new(alice_id)?, 2), (UserId::new(bob_id)?, 1)]);
+
+    // When
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_with_split(
+            group_id,
+            bob_id,
+            9_000,
+            SplitSpecification::Shares {
+                shares: shares.clone(),
+            },
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // Then
+    let expense_entry = ctx
+        .expense_entries()
+        .assert_expense_has_a_single_entry(expense_id)
+        .await?;
+    assert_eq!(
+        HashSet::from_iter(vec![UserId::new(alice_id)?]),
+        expense_entry.participants
+    );
+    assert_eq!(ExpenseSplit::Shares { shares }, expense_entry.split);
+
+    let balances = ctx.balances().get_group_balances(group_id, bob_id).await?;
+    assert_eq!(Some(&6_000), balances.get(&bob_id));
+    assert_eq!(Some(&-6_000), balances.get(&alice_id));
+    assert_eq!(Some(&0), balances.get(&charlie_id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn happy_path_exact_split() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path_exact_split").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+
+    // When
+    ctx.expense_entries()
+        .create_expense_with_split(
+            group_id,
+            bob_id,
+            2_000,
+            SplitSpecification::Exact {
+                amounts: HashMap::from_iter(vec![
+                    (UserId::new(alice_id)?, Money::from_cents(1_250)),
+                    (UserId::new(bob_id)?, Money::from_cents(750)),
+                ]),
+            },
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // Then
+    let balances = ctx.balances().get_group_balances(group_id, bob_id).await?;
+    assert_eq!(Some(&1_250), balances.get(&bob_id));
+    assert_eq!(Some(&-1_250), balances.get(&alice_id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn exact_amounts_must_sum_up_to_total() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "exact_amounts_must_sum_up_to_total").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .create_expense_with_split(
+            group_id,
+            bob_id,
+            2_000,
+            SplitSpecification::Exact {
+                amounts: HashMap::from_iter(vec![
+                    (UserId::new(alice_id)?, Money::from_cents(1_250)),
+                    (UserId::new(bob_id)?, Money::from_cents(700)),
+                ]),
+            },
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateExpenseError::InvalidSplit(expense_split::Error::AmountsMismatch).to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn shares_must_be_bounded() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "shares_must_be_bounded").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .create_expense_with_split(
+            group_id,
+            bob_id,
+            2_000,
+            SplitSpecification::Shares {
+                shares: HashMap::from_iter(vec![
+                    (UserId::new(alice_id)?, u64::MAX),
+                    (UserId::new(bob_id)?, 1),
+                ]),
+            },
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateExpenseError::InvalidSplit(expense_split::Error::InvalidShares).to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn percentages_must_sum_up_to_100() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "percentages_must_sum_up_to_100").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .create_expense_with_split(
+            group_id,
+            bob_id,
+            2_000,
+            SplitSpecification::Percentages {
+                percentages: HashMap::from_iter(vec![
+                    (UserId::new(alice_id)?, 60),
+                    (UserId::new(bob_id)?, 30),
+                ]),
+            },
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateExpenseError::InvalidSplit(expense_split::Error::PercentagesMismatch).to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn split_user_not_found_in_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "split_user_not_found_in_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .create_expense_with_split(
+            group_id,
+            bob_id,
+            2_000,
+            SplitSpecification::Shares {
+                shares: HashMap::from_iter(vec![(UserId::new(alice_id)?, 1)]),
+            },
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateExpenseError::ParticipantNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn happy_path_foreign_currency() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path_foreign_currency").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+
+    // When
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_in_currency(
+            group_id,
+            bob_id,
+            1_500_000,
+            "idr",
+            Some("0.000058"),
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // Then
+    let expense_entry = ctx
+        .expense_entries()
+        .assert_expense_has_a_single_entry(expense_id)
+        .await?;
+
+    assert_eq!(1_500_000, expense_entry.total.cents());
+    assert_eq!("IDR", expense_entry.currency.value());
+    assert_eq!("0.000058", expense_entry.exchange_rate.to_string());
+    assert_eq!(87, expense_entry.total_in_group_currency().cents());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn group_currency_is_used_by_default() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "group_currency_is_used_by_default").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group_with_currency("Bali Trip 2026", bob_id, "USD")
+        .await?;
+
+    // When
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 10, bob_id, dates::jan_08_2025())
+        .await?;
+
+    // Then
+    let expense_entry = ctx
+        .expense_entries()
+        .assert_expense_has_a_single_entry(expense_id)
+        .await?;
+
+    assert_eq!("USD", expense_entry.currency.value());
+    assert!(expense_entry.exchange_rate.is_identity());
+
+    Ok(())
+}
+
+#[rstest::rstest]
+#[case::foreign_currency_without_rate("USD", None, CurrencyError::MissingExchangeRate)]
+#[case::group_currency_with_rate("EUR", Some("1.1"), CurrencyError::UnexpectedExchangeRate)]
+#[case::total_too_large_once_converted("JPY", Some("1000"), CurrencyError::TotalTooLarge)]
+#[tokio::test]
+async fn invalid_currency(
+    #[case] currency: &str,
+    #[case] exchange_rate: Option<&str>,
+    #[case] expected: CurrencyError,
+) -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, &format!("invalid_currency_{currency}")).await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .create_expense_in_currency(
+            group_id,
+            bob_id,
+            i64::MAX / 100,
+            currency,
+            exchange_rate,
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateExpenseError::InvalidCurrency(expected).to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn budget_alerts_are_raised_at_thresholds() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "budget_alerts_are_raised_at_thresholds").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let budget_id = ctx
+        .budgets()
+        .create_budget(group_id, bob_id, None, 100, BudgetPeriod::Monthly)
+        .await?;
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 50, bob_id, dates::jan_08_2025())
+        .await?;
+    let alerts = ctx
+        .budgets()
+        .get_budget_alerts_for_group(group_id, bob_id)
+        .await?;
+    assert!(alerts.is_empty());
+
+    // When
+    let warning_expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 30, bob_id, dates::jan_10_2025())
+        .await?;
+    let exceeded_expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 30, bob_id, dates::jan_31_2025())
+        .await?;
+
+    // Then
+    let alerts = ctx
+        .budgets()
+        .get_budget_alerts_for_group(group_id, bob_id)
+        .await?;
+    assert_eq!(2, alerts.len());
+    assert_eq!(budget_id, alerts[0].budget_id.value());
+    assert_eq!(BudgetThreshold::Exceeded, alerts[0].threshold);
+    assert_eq!(exceeded_expense_id, alerts[0].expense_id.value());
+    assert_eq!(Money::from_euros(110), alerts[0].consumed);
+    assert_eq!(BudgetThreshold::Warning, alerts[1].threshold);
+    assert_eq!(warning_expense_id, alerts[1].expense_id.value());
+    assert_eq!(Money::from_euros(80), alerts[1].consumed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn budget_alerts_are_raised_once_per_period() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "budget_alerts_are_raised_once_per_period").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let surf_id = ctx
+        .categories()
+        .create_category(group_id, bob_id, "Surf lessons")
+        .await?;
+    ctx.budgets()
+        .create_budget(group_id, bob_id, Some(surf_id), 100, BudgetPeriod::Monthly)
+        .await?;
+    ctx.expense_entries()
+        .create_expense_in_category(group_id, bob_id, 120, surf_id, bob_id, dates::jan_08_2025())
+        .await?;
+
+    // When
+    ctx.expense_entries()
+        .create_expense_in_category(group_id, bob_id, 10, surf_id, bob_id, dates::jan_10_2025())
+        .await?;
+    // expenses of other categories do not consume the budget
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::feb_28_2025())
+        .await?;
+    ctx.expense_entries()
+        .create_expense_in_category(group_id, bob_id, 85, surf_id, bob_id, dates::feb_28_2025())
+        .await?;
+
+    // Then
+    let alerts = ctx
+        .budgets()
+        .get_budget_alerts_for_group(group_id, bob_id)
+        .await?;
+    let periods = alerts
+        .iter()
+        .map(|alert| (alert.period_starts_at.month(), alert.threshold))
+        .collect::<Vec<_>>();
+    assert_eq!(3, periods.len());
+    assert!(periods.contains(&(1, BudgetThreshold::Warning)));
+    assert!(periods.contains(&(1, BudgetThreshold::Exceeded)));
+    assert!(periods.contains(&(2, BudgetThreshold::Warning)));
+
+    Ok(())
+}
@@ -0,0 +1,175 @@
+use application::commands::{
+    add_group_member::AddGroupMemberError, create_guest::CreateGuestError,
+};
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+
+    // When
+    let guest_id = ctx
+        .groups()
+        .create_guest(group_id, alice_id, "Gabe")
+        .await?;
+
+    // Then
+    ctx.groups()
+        .assert_group_contains_members(group_id, vec![guest_id])
+        .await?;
+    assert!(
+        ctx.users()
+            .get_handle(guest_id)
+            .await?
+            .starts_with("guest_")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn guest_pays_and_takes_part_in_expenses() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "guest_pays_and_takes_part_in_expenses").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    let guest_id = ctx
+        .groups()
+        .create_guest(group_id, alice_id, "Gabe")
+        .await?;
+
+    // When
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(
+            group_id,
+            guest_id,
+            100,
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+    ctx.expense_entries()
+        .create_expense(
+            group_id,
+            alice_id,
+            20,
+            vec![guest_id],
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // Then
+    let balances = ctx
+        .balances()
+        .get_group_balances(group_id, alice_id)
+        .await?;
+    assert_eq!(4_000, balances[&guest_id]);
+    assert_eq!(-4_000, balances[&alice_id]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn member_cannot_create_guest() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "member_cannot_create_guest").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .create_guest(group_id, bob_id, "Gabe")
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(CreateGuestError::NotAdmin.to_string(), err.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn archived_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "archived_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().archive_group(group_id, alice_id).await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .create_guest(group_id, alice_id, "Gabe")
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(CreateGuestError::GroupArchived.to_string(), err.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn guest_cannot_be_added_to_another_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "guest_cannot_be_added_to_another_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    let other_group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    let guest_id = ctx
+        .groups()
+        .create_guest(group_id, alice_id, "Gabe")
+        .await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .add_member(other_group_id, alice_id, guest_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        AddGroupMemberError::UserNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
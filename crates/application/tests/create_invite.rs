@@ -0,0 +1,146 @@
+use application::commands::create_invite::CreateInviteError;
+use chrono::{Duration, Utc};
+
+use crate::infra::{ctx::TestContext, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+
+    // When
+    let invite = ctx
+        .invites()
+        .create_invite(group_id, alice_id, Some(1))
+        .await?;
+
+    // Then
+    let invites = ctx.invites().get_invites(group_id, alice_id).await?;
+    assert_eq!(vec![invite.clone()], invites);
+    assert_eq!(Some(1), invite.max_uses);
+    assert_eq!(0, invite.uses);
+    assert_eq!(64, invite.token.value().len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_owner_and_admins_can_create_invite() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "only_owner_and_admins_can_create_invite").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+    ctx.groups()
+        .add_member_with_role(group_id, alice_id, charlie_id, "admin")
+        .await?;
+
+    // When
+    let err = ctx
+        .invites()
+        .create_invite(group_id, bob_id, None)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(CreateInviteError::NotAdmin.to_string(), err.to_string());
+    ctx.invites()
+        .create_invite(group_id, charlie_id, None)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn expiry_must_be_in_the_future() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "expiry_must_be_in_the_future").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+
+    // When
+    let err = ctx
+        .invites()
+        .create_invite_with(
+            group_id,
+            alice_id,
+            "member",
+            None,
+            Utc::now() - Duration::minutes(5),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateInviteError::InvalidExpiry.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn max_uses_must_be_positive() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "max_uses_must_be_positive").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+
+    // When
+    let err = ctx
+        .invites()
+        .create_invite(group_id, alice_id, Some(0))
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateInviteError::InvalidMaxUses.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn archived_group_rejects_invites() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "archived_group_rejects_invites").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    ctx.groups().archive_group(group_id, alice_id).await?;
+
+    // When
+    let err = ctx
+        .invites()
+        .create_invite(group_id, alice_id, None)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateInviteError::GroupArchived.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+
+use application::{
+    commands::create_recurring_expense::CreateRecurringExpenseError,
+    queries::get_recurring_expenses_for_group::GetRecurringExpensesForGroupError,
+};
+use domain::types::{expense_split::ExpenseSplit, money::Money, recurrence::Frequency};
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", bob_id).await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+
+    // When
+    let recurring_expense_id = ctx
+        .recurring_expenses()
+        .create_monthly_recurring_expense(
+            group_id,
+            bob_id,
+            1_200,
+            alice_id,
+            dates::jan_31_2025(),
+            None,
+        )
+        .await?;
+
+    // Then
+    let recurring_expense = ctx
+        .recurring_expenses()
+        .assert_recurring_expense_exists(recurring_expense_id)
+        .await?;
+    assert_eq!(group_id, recurring_expense.group_id.value());
+    assert_eq!(bob_id, recurring_expense.payer_id.value());
+    assert_eq!(alice_id, recurring_expense.author_id.value());
+    assert_eq!(
+        HashSet::from_iter(vec![alice_id]),
+        recurring_expense
+            .participants
+            .iter()
+            .map(|p| p.value())
+            .collect::<HashSet<_>>()
+    );
+    assert_eq!(ExpenseSplit::Equal, recurring_expense.split);
+    assert_eq!(Money::from_euros(1_200), recurring_expense.total);
+    assert_eq!(Frequency::Monthly, recurring_expense.recurrence.frequency());
+    assert_eq!(
+        Some(dates::jan_31_2025()),
+        recurring_expense.next_occurrence_at
+    );
+
+    let recurring_expenses = ctx
+        .recurring_expenses()
+        .get_recurring_expenses_for_group(group_id, alice_id)
+        .await?;
+    assert_eq!(vec![recurring_expense], recurring_expenses);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn payer_must_be_group_member() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "payer_must_be_group_member").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", bob_id).await?;
+
+    // When
+    let err = ctx
+        .recurring_expenses()
+        .create_monthly_recurring_expense(
+            group_id,
+            charlie_id,
+            1_200,
+            bob_id,
+            dates::jan_31_2025(),
+            None,
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateRecurringExpenseError::PayerIsNotGroupMember.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_members_can_list_recurring_expenses() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "only_members_can_list_recurring_expenses").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", bob_id).await?;
+
+    // When
+    let err = ctx
+        .recurring_expenses()
+        .get_recurring_expenses_for_group(group_id, charlie_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        GetRecurringExpensesForGroupError::Forbidden.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
@@ -81,3 +81,118 @@ async fn same_username_different_email() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn handle_derived_from_name() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "handle_derived_from_name").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let first_bob_id = ctx
+        .users()
+        .create_user_with_name_and_email("Bob", "bob@gmail.com")
+        .await?;
+
+    // When
+    let second_bob_id = ctx
+        .users()
+        .create_user_with_name_and_email("bob", "bob.thegoat@gmail.com")
+        .await?;
+    let accented_id = ctx
+        .users()
+        .create_user_with_name_and_email("Zoé", "zoe@gmail.com")
+        .await?;
+
+    // Then
+    assert_eq!("bob", ctx.users().get_handle(first_bob_id).await?);
+    assert_eq!("bob_2", ctx.users().get_handle(second_bob_id).await?);
+    let accented_handle = ctx.users().get_handle(accented_id).await?;
+    assert!(accented_handle.starts_with("user_"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn handle_already_taken() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "handle_already_taken").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    ctx.users()
+        .create_user_with_handle("Bob", "builder")
+        .await?;
+
+    // When
+    let err = ctx
+        .users()
+        .create_user_with_handle("Josh", "@Builder")
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateUserError::HandleAlreadyTaken.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+/// Simulates a user signing up with the handle `bob` between the moment it
+/// is checked and the moment the new user is inserted.
+async fn take_handle_bob_on_insert(db_pool: &database::SqlitePool) -> anyhow::Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TRIGGER take_handle_bob
+        BEFORE INSERT ON user
+        WHEN NEW.handle = 'bob' AND NEW.email <> 'concurrent@gmail.com'
+        BEGIN
+            INSERT INTO user (id, name, handle, email, guest, role, created_at)
+            VALUES (randomblob(16), 'Bob', 'bob', 'concurrent@gmail.com', 0, 0, NEW.created_at);
+        END
+        "#,
+    )
+    .execute(db_pool)
+    .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn handle_taken_concurrently_is_skipped() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "handle_taken_concurrently_is_skipped").await?;
+    take_handle_bob_on_insert(&db_pool).await?;
+    let ctx = TestContext::new(db_pool);
+
+    // When
+    let bob_id = ctx
+        .users()
+        .create_user_with_name_and_email("Bob", "bob@gmail.com")
+        .await?;
+
+    // Then
+    assert_eq!("bob_2", ctx.users().get_handle(bob_id).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn chosen_handle_taken_concurrently() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "chosen_handle_taken_concurrently").await?;
+    take_handle_bob_on_insert(&db_pool).await?;
+    let ctx = TestContext::new(db_pool);
+
+    // When
+    let err = ctx
+        .users()
+        .create_user_with_handle("Robert", "bob")
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        CreateUserError::HandleAlreadyTaken.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use application::commands::delete_attachment::DeleteAttachmentError;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+    let receipt_id = ctx
+        .attachments()
+        .create_attachment(group_id, expense_id, bob_id, "receipt.jpg", 2_048)
+        .await?;
+    let invoice_id = ctx
+        .attachments()
+        .create_attachment(group_id, expense_id, bob_id, "invoice.jpg", 1_024)
+        .await?;
+
+    // When
+    ctx.attachments()
+        .delete_attachment(group_id, expense_id, receipt_id, alice_id)
+        .await?;
+
+    // Then
+    let attachment_ids = ctx
+        .attachments()
+        .get_attachment_ids_for_expense(group_id, expense_id, bob_id)
+        .await?;
+    assert_eq!(vec![invoice_id], attachment_ids);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn not_group_member() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "not_group_member").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+    let attachment_id = ctx
+        .attachments()
+        .create_attachment(group_id, expense_id, bob_id, "receipt.jpg", 2_048)
+        .await?;
+
+    // When
+    let err = ctx
+        .attachments()
+        .delete_attachment(group_id, expense_id, attachment_id, charlie_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        DeleteAttachmentError::Forbidden.to_string(),
+        err.to_string()
+    );
+    assert!(ctx.attachments().exists(attachment_id).await?);
+
+    Ok(())
+}
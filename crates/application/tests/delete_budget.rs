@@ -0,0 +1,70 @@
+use application::commands::delete_budget::DeleteBudgetError;
+use domain::types::budget_period::BudgetPeriod;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let budget_id = ctx
+        .budgets()
+        .create_budget(group_id, bob_id, None, 300, BudgetPeriod::Monthly)
+        .await?;
+
+    // When
+    ctx.budgets()
+        .delete_budget(group_id, budget_id, bob_id)
+        .await?;
+
+    // Then
+    let budgets = ctx
+        .budgets()
+        .get_budgets_for_group(group_id, bob_id, dates::jan_10_2025())
+        .await?;
+    assert!(budgets.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_owner_and_admins_can_delete_budget() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "only_owner_and_admins_can_delete_budget").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    let budget_id = ctx
+        .budgets()
+        .create_budget(group_id, bob_id, None, 300, BudgetPeriod::Monthly)
+        .await?;
+
+    // When
+    let err = ctx
+        .budgets()
+        .delete_budget(group_id, budget_id, alice_id)
+        .await
+        .expect_err("deletion must fail");
+
+    // Then
+    assert_eq!(DeleteBudgetError::NotAdmin.to_string(), err.to_string());
+
+    Ok(())
+}
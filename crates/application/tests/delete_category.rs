@@ -0,0 +1,113 @@
+use application::commands::delete_category::DeleteCategoryError;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let category_id = ctx
+        .categories()
+        .create_category(group_id, bob_id, "Surf lessons")
+        .await?;
+
+    // When
+    ctx.categories()
+        .delete_category(group_id, category_id, bob_id)
+        .await?;
+
+    // Then
+    let categories = ctx
+        .categories()
+        .get_categories_for_group(group_id, bob_id)
+        .await?;
+    assert!(categories.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn expenses_become_uncategorized() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "expenses_become_uncategorized").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let category_id = ctx
+        .categories()
+        .create_category(group_id, bob_id, "Surf lessons")
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_in_category(
+            group_id,
+            bob_id,
+            40,
+            category_id,
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // When
+    ctx.categories()
+        .delete_category(group_id, category_id, bob_id)
+        .await?;
+
+    // Then
+    let entry = ctx
+        .expense_entries()
+        .assert_expense_has_a_single_entry(expense_id)
+        .await?;
+    assert!(entry.is_active());
+    assert_eq!(None, entry.category_id);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_owner_and_admins_can_delete_category() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "only_owner_and_admins_can_delete_category").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    let category_id = ctx
+        .categories()
+        .create_category(group_id, bob_id, "Surf lessons")
+        .await?;
+
+    // When
+    let err = ctx
+        .categories()
+        .delete_category(group_id, category_id, alice_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(DeleteCategoryError::NotAdmin.to_string(), err.to_string());
+    ctx.categories().assert_category_exists(category_id).await?;
+
+    Ok(())
+}
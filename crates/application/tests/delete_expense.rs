@@ -0,0 +1,233 @@
+use application::commands::delete_expense::DeleteExpenseError;
+use domain::types::expense_entry_status::ExpenseEntryStatus;
+use uuid::Uuid;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+
+    // When
+    ctx.expense_entries()
+        .delete_expense(group_id, expense_id, alice_id)
+        .await?;
+
+    // Then
+    let entries = ctx.expense_entries().get_all_entries(expense_id).await?;
+    assert_eq!(2, entries.len());
+    let (old_entry, tombstone) = (&entries[0], &entries[1]);
+    assert_eq!(
+        ExpenseEntryStatus::Inactive {
+            overwritten_by: tombstone.id
+        },
+        old_entry.status
+    );
+    assert!(matches!(
+        tombstone.status,
+        ExpenseEntryStatus::Deleted { deleted_by, .. } if deleted_by.value() == alice_id
+    ));
+    assert_eq!(old_entry.total, tombstone.total);
+    assert_eq!(old_entry.participants, tombstone.participants);
+
+    let expense_ids = ctx
+        .expense_entries()
+        .get_expense_ids_for_group(group_id, bob_id)
+        .await?;
+    assert!(expense_ids.is_empty());
+
+    let balances = ctx.balances().get_group_balances(group_id, bob_id).await?;
+    assert_eq!(Some(&0), balances.get(&bob_id));
+    assert_eq!(Some(&0), balances.get(&alice_id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attachments_are_kept_until_restore() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "attachments_are_kept_until_restore").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+    let attachment_id = ctx
+        .attachments()
+        .create_attachment(group_id, expense_id, bob_id, "receipt.jpg", 2_048)
+        .await?;
+
+    // When
+    ctx.expense_entries()
+        .delete_expense(group_id, expense_id, bob_id)
+        .await?;
+
+    // Then
+    assert!(ctx.attachments().exists(attachment_id).await?);
+    ctx.expense_entries()
+        .restore_expense(group_id, expense_id, bob_id)
+        .await?;
+    let attachment_ids = ctx
+        .attachments()
+        .get_attachment_ids_for_expense(group_id, expense_id, bob_id)
+        .await?;
+    assert_eq!(vec![attachment_id], attachment_ids);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn already_deleted() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "already_deleted").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+    ctx.expense_entries()
+        .delete_expense(group_id, expense_id, bob_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .delete_expense(group_id, expense_id, bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        DeleteExpenseError::ExpenseNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn expense_not_found() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "expense_not_found").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .delete_expense(group_id, Uuid::now_v7(), bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        DeleteExpenseError::ExpenseNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn user_not_in_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "user_not_in_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .delete_expense(group_id, expense_id, alice_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(DeleteExpenseError::Forbidden.to_string(), err.to_string());
+    ctx.expense_entries()
+        .assert_expense_has_a_single_entry(expense_id)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn viewer_cannot_delete_expense() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "viewer_cannot_delete_expense").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups()
+        .add_member_with_role(group_id, bob_id, alice_id, "viewer")
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .delete_expense(group_id, expense_id, alice_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(DeleteExpenseError::Forbidden.to_string(), err.to_string());
+    ctx.expense_entries()
+        .assert_expense_has_a_single_entry(expense_id)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn added_expenses_are_kept() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "added_expenses_are_kept").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", bob_id).await?;
+    let recurring_expense_id = ctx
+        .recurring_expenses()
+        .create_monthly_recurring_expense(group_id, bob_id, 40, bob_id, dates::jan_31_2025(), None)
+        .await?;
+    ctx.recurring_expenses()
+        .materialize(dates::feb_28_2025())
+        .await?;
+
+    // When
+    ctx.recurring_expenses()
+        .delete_recurring_expense(group_id, recurring_expense_id, bob_id)
+        .await?;
+
+    // Then
+    let recurring_expenses = ctx
+        .recurring_expenses()
+        .get_recurring_expenses_for_group(group_id, bob_id)
+        .await?;
+    assert!(recurring_expenses.is_empty());
+    let output = ctx
+        .recurring_expenses()
+        .materialize(dates::apr_15_2025())
+        .await?;
+    assert!(output.created.is_empty());
+    let expense_ids = ctx
+        .expense_entries()
+        .get_expense_ids_for_group(group_id, bob_id)
+        .await?;
+    assert_eq!(2, expense_ids.len());
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use application::queries::export_expenses_for_group::ExportExpensesForGroupError;
+use domain::types::user_id::UserId;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let carol_id = ctx.users().create_user("Carol").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    ctx.groups().add_member(group_id, bob_id, carol_id).await?;
+    let later_expense_id = ctx
+        .expense_entries()
+        .create_expense(
+            group_id,
+            alice_id,
+            40,
+            vec![bob_id],
+            carol_id,
+            dates::jan_10_2025(),
+        )
+        .await?;
+    let earlier_expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+
+    // When
+    let export = ctx
+        .expense_entries()
+        .export_expenses(group_id, carol_id)
+        .await?;
+
+    // Then
+    let member_names: Vec<String> = export.members.iter().map(|m| m.name.value()).collect();
+    assert_eq!(vec!["Alice", "Bob", "Carol"], member_names);
+
+    let expense_ids: Vec<_> = export
+        .expenses
+        .iter()
+        .map(|e| e.expense.id.value())
+        .collect();
+    assert_eq!(vec![earlier_expense_id, later_expense_id], expense_ids);
+
+    let earlier = &export.expenses[0];
+    assert_eq!(bob_id, earlier.author.id.value());
+    assert_eq!(
+        Some(3_000),
+        earlier
+            .shares
+            .get(&UserId::new(carol_id)?)
+            .map(|share| share.cents())
+    );
+
+    let later = &export.expenses[1];
+    assert_eq!(carol_id, later.author.id.value());
+    assert_eq!(None, later.shares.get(&UserId::new(carol_id)?));
+    assert_eq!(
+        Some(2_000),
+        later
+            .shares
+            .get(&UserId::new(bob_id)?)
+            .map(|share| share.cents())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn deleted_expenses_are_not_exported() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "deleted_expenses_are_not_exported").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+    ctx.expense_entries()
+        .delete_expense(group_id, expense_id, bob_id)
+        .await?;
+
+    // When
+    let export = ctx
+        .expense_entries()
+        .export_expenses(group_id, bob_id)
+        .await?;
+
+    // Then
+    assert!(export.expenses.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn user_not_in_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "user_not_in_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .export_expenses(group_id, charlie_id)
+        .await
+        .err()
+        .expect("export must fail");
+
+    // Then
+    assert_eq!(
+        ExportExpensesForGroupError::Forbidden.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use application::queries::get_attachment::GetAttachmentError;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn not_group_member() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "not_group_member").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+    let attachment_id = ctx
+        .attachments()
+        .create_attachment(group_id, expense_id, bob_id, "receipt.jpg", 2_048)
+        .await?;
+
+    // When
+    let err = ctx
+        .attachments()
+        .get_attachment(group_id, expense_id, attachment_id, charlie_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(GetAttachmentError::Forbidden.to_string(), err.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn attachment_of_another_expense() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "attachment_of_another_expense").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+    let other_expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 30, bob_id, dates::jan_10_2025())
+        .await?;
+    let attachment_id = ctx
+        .attachments()
+        .create_attachment(group_id, expense_id, bob_id, "receipt.jpg", 2_048)
+        .await?;
+
+    // When
+    let err = ctx
+        .attachments()
+        .get_attachment(group_id, other_expense_id, attachment_id, bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        GetAttachmentError::AttachmentNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use chrono::Datelike;
+use domain::types::{budget_period::BudgetPeriod, money::Money};
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn consumption_of_current_period() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "consumption_of_current_period").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let surf_id = ctx
+        .categories()
+        .create_category(group_id, bob_id, "Surf lessons")
+        .await?;
+    let group_budget_id = ctx
+        .budgets()
+        .create_budget(group_id, bob_id, None, 1_000, BudgetPeriod::Monthly)
+        .await?;
+    let surf_budget_id = ctx
+        .budgets()
+        .create_budget(group_id, bob_id, Some(surf_id), 100, BudgetPeriod::Monthly)
+        .await?;
+    ctx.expense_entries()
+        .create_expense_in_category(group_id, bob_id, 40, surf_id, bob_id, dates::jan_08_2025())
+        .await?;
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 25, bob_id, dates::jan_10_2025())
+        .await?;
+    // deleted, or in another month
+    let deleted_id = ctx
+        .expense_entries()
+        .create_expense_in_category(group_id, bob_id, 60, surf_id, bob_id, dates::jan_31_2025())
+        .await?;
+    ctx.expense_entries()
+        .delete_expense(group_id, deleted_id, bob_id)
+        .await?;
+    ctx.expense_entries()
+        .create_expense_in_category(group_id, bob_id, 80, surf_id, bob_id, dates::feb_28_2025())
+        .await?;
+
+    // When
+    let budgets = ctx
+        .budgets()
+        .get_budgets_for_group(group_id, bob_id, dates::jan_10_2025())
+        .await?;
+
+    // Then
+    assert_eq!(2, budgets.len());
+    assert_eq!(group_budget_id, budgets[0].budget.id.value());
+    assert_eq!(Money::from_euros(65), budgets[0].consumed);
+    assert_eq!(surf_budget_id, budgets[1].budget.id.value());
+    assert_eq!(Money::from_euros(40), budgets[1].consumed);
+    let window = budgets[1].window.clone().expect("january is a period");
+    assert_eq!(dates::jan_08_2025().with_day(1), Some(window.start));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn nothing_is_consumed_outside_of_range() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "nothing_is_consumed_outside_of_range").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let period = BudgetPeriod::range(dates::jan_08_2025(), dates::jan_31_2025())?;
+    ctx.budgets()
+        .create_budget(group_id, bob_id, None, 1_000, period)
+        .await?;
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 25, bob_id, dates::jan_10_2025())
+        .await?;
+
+    // When
+    let budgets = ctx
+        .budgets()
+        .get_budgets_for_group(group_id, bob_id, dates::feb_28_2025())
+        .await?;
+
+    // Then
+    assert_eq!(None, budgets[0].window);
+    assert_eq!(Money::zero(), budgets[0].consumed);
+
+    Ok(())
+}
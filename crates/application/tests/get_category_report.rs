@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+
+use application::queries::get_category_report::{GetCategoryReportError, MemberTotal};
+use chrono::Duration;
+use uuid::Uuid;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn totals_per_category_and_member() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "totals_per_category_and_member").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    let surf_id = ctx
+        .categories()
+        .create_category(group_id, bob_id, "Surf lessons")
+        .await?;
+    let scooter_id = ctx
+        .categories()
+        .create_category(group_id, bob_id, "Scooter")
+        .await?;
+    ctx.expense_entries()
+        .create_expense_in_category(group_id, bob_id, 60, surf_id, bob_id, dates::jan_08_2025())
+        .await?;
+    ctx.expense_entries()
+        .create_expense_in_category(
+            group_id,
+            alice_id,
+            20,
+            surf_id,
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+    ctx.expense_entries()
+        .create_expense(
+            group_id,
+            alice_id,
+            10,
+            vec![],
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // When
+    let report = ctx
+        .categories()
+        .get_category_report(
+            group_id,
+            alice_id,
+            dates::jan_08_2025(),
+            dates::jan_10_2025(),
+        )
+        .await?;
+
+    // Then
+    assert_eq!("EUR", report.currency.value());
+    assert_eq!(9_000, report.total.cents());
+
+    let member_totals = |members: &[MemberTotal]| -> HashMap<Uuid, i64> {
+        members
+            .iter()
+            .map(|m| (m.user.id.value(), m.total.cents()))
+            .collect()
+    };
+    assert_eq!(3, report.categories.len());
+
+    let scooter = &report.categories[0];
+    assert_eq!(
+        Some(scooter_id),
+        scooter.category.as_ref().map(|c| c.id.value())
+    );
+    assert_eq!(0, scooter.total.cents());
+    assert!(scooter.members.is_empty());
+
+    let surf = &report.categories[1];
+    assert_eq!(Some(surf_id), surf.category.as_ref().map(|c| c.id.value()));
+    assert_eq!(8_000, surf.total.cents());
+    assert_eq!(
+        HashMap::from([(bob_id, 4_000), (alice_id, 4_000)]),
+        member_totals(&surf.members)
+    );
+
+    let uncategorized = &report.categories[2];
+    assert!(uncategorized.category.is_none());
+    assert_eq!(1_000, uncategorized.total.cents());
+    assert_eq!(
+        HashMap::from([(alice_id, 1_000)]),
+        member_totals(&uncategorized.members)
+    );
+
+    let members: Vec<_> = report
+        .members
+        .iter()
+        .map(|m| (m.user.id.value(), m.total.cents()))
+        .collect();
+    assert_eq!(vec![(alice_id, 5_000), (bob_id, 4_000)], members);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn expenses_out_of_range_are_ignored() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "expenses_out_of_range_are_ignored").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let surf_id = ctx
+        .categories()
+        .create_category(group_id, bob_id, "Surf lessons")
+        .await?;
+    ctx.expense_entries()
+        .create_expense_in_category(group_id, bob_id, 60, surf_id, bob_id, dates::jan_08_2025())
+        .await?;
+    ctx.expense_entries()
+        .create_expense_in_category(group_id, bob_id, 20, surf_id, bob_id, dates::jan_10_2025())
+        .await?;
+
+    // When
+    let report = ctx
+        .categories()
+        .get_category_report(
+            group_id,
+            bob_id,
+            dates::jan_08_2025() + Duration::days(1),
+            dates::jan_10_2025() + Duration::days(1),
+        )
+        .await?;
+
+    // Then
+    assert_eq!(2_000, report.total.cents());
+    assert_eq!(1, report.categories.len());
+    assert_eq!(2_000, report.categories[0].total.cents());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn invalid_range() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "invalid_range").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .categories()
+        .get_category_report(group_id, bob_id, dates::jan_10_2025(), dates::jan_08_2025())
+        .await
+        .err()
+        .unwrap();
+
+    // Then
+    assert_eq!(
+        GetCategoryReportError::InvalidRange.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_members_can_see_report() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "only_members_can_see_report").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let eve_id = ctx.users().create_user("Eve").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .categories()
+        .get_category_report(group_id, eve_id, dates::jan_08_2025(), dates::jan_10_2025())
+        .await
+        .err()
+        .unwrap();
+
+    // Then
+    assert_eq!(
+        GetCategoryReportError::Forbidden.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
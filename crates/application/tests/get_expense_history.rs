@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+
+use application::{
+    commands::create_expense::SplitSpecification,
+    queries::get_expense_history::{GetExpenseHistoryError, VersionStatus},
+};
+use domain::types::{expense_split::ExpenseSplit, user_id::UserId};
+use uuid::Uuid;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    ctx.groups()
+        .add_member(group_id, bob_id, charlie_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense(
+            group_id,
+            bob_id,
+            90,
+            vec![alice_id],
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+    ctx.expense_entries()
+        .update_expense(
+            group_id,
+            expense_id,
+            alice_id,
+            60,
+            vec![charlie_id],
+            alice_id,
+            dates::jan_10_2025(),
+        )
+        .await?;
+    ctx.expense_entries()
+        .delete_expense(group_id, expense_id, charlie_id)
+        .await?;
+
+    // When
+    let history = ctx
+        .expense_entries()
+        .get_expense_history(group_id, expense_id, charlie_id)
+        .await?;
+
+    // Then
+    assert_eq!(3, history.versions.len());
+
+    let first = &history.versions[0];
+    assert!(matches!(first.status, VersionStatus::Overwritten));
+    assert_eq!(bob_id, first.author.id.value());
+    assert_eq!(9_000, first.total.cents());
+    assert!(first.changes.is_none());
+
+    let second = &history.versions[1];
+    assert!(matches!(second.status, VersionStatus::Overwritten));
+    assert_eq!(alice_id, second.author.id.value());
+    let changes = second.changes.as_ref().unwrap();
+    let total = changes.total.as_ref().unwrap();
+    assert_eq!((9_000, 6_000), (total.before.cents(), total.after.cents()));
+    let payer = changes.payer.as_ref().unwrap();
+    assert_eq!(
+        (bob_id, alice_id),
+        (payer.before.id.value(), payer.after.id.value())
+    );
+    assert_eq!(
+        vec![charlie_id],
+        changes
+            .participants_added
+            .iter()
+            .map(|u| u.id.value())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![alice_id],
+        changes
+            .participants_removed
+            .iter()
+            .map(|u| u.id.value())
+            .collect::<Vec<_>>()
+    );
+    let occurred_at = changes.occurred_at.as_ref().unwrap();
+    assert_eq!(
+        (dates::jan_08_2025(), dates::jan_10_2025()),
+        (occurred_at.before, occurred_at.after)
+    );
+
+    let third = &history.versions[2];
+    assert!(matches!(third.status, VersionStatus::Deleted));
+    assert_eq!(charlie_id, third.author.id.value());
+    let changes = third.changes.as_ref().unwrap();
+    assert!(changes.total.is_none());
+    assert!(changes.payer.is_none());
+    assert!(changes.participants_added.is_empty());
+    assert!(changes.participants_removed.is_empty());
+    assert!(changes.occurred_at.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn split_only_change() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "split_only_change").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense(
+            group_id,
+            bob_id,
+            90,
+            vec![alice_id],
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+    let shares = HashMap::from_iter(vec![(UserId::new(bob_id)?, 1), (UserId::new(alice_id)?, 2)]);
+    ctx.expense_entries()
+        .update_expense_with_split(
+            group_id,
+            expense_id,
+            bob_id,
+            9_000,
+            SplitSpecification::Shares {
+                shares: shares.clone(),
+            },
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // When
+    let history = ctx
+        .expense_entries()
+        .get_expense_history(group_id, expense_id, bob_id)
+        .await?;
+
+    // Then
+    assert_eq!(2, history.versions.len());
+    let changes = history.versions[1].changes.as_ref().unwrap();
+    let split = changes.split.as_ref().unwrap();
+    assert_eq!(ExpenseSplit::Equal, split.before);
+    assert_eq!(ExpenseSplit::Shares { shares }, split.after);
+    assert!(changes.total.is_none());
+    assert!(changes.payer.is_none());
+    assert!(changes.participants_added.is_empty());
+    assert!(changes.participants_removed.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn expense_not_found() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "expense_not_found").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .get_expense_history(group_id, Uuid::now_v7(), bob_id)
+        .await
+        .err()
+        .unwrap();
+
+    // Then
+    assert_eq!(
+        GetExpenseHistoryError::ExpenseNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn expense_from_another_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "expense_from_another_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let other_group_id = ctx.groups().create_empty_group("Rent", bob_id).await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .get_expense_history(other_group_id, expense_id, bob_id)
+        .await
+        .err()
+        .unwrap();
+
+    // Then
+    assert_eq!(
+        GetExpenseHistoryError::ExpenseNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn user_not_in_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "user_not_in_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .get_expense_history(group_id, expense_id, alice_id)
+        .await
+        .err()
+        .unwrap();
+
+    // Then
+    assert_eq!(
+        GetExpenseHistoryError::Forbidden.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use application::{
+    backup::{CURRENT_VERSION, ExpenseEntryStatusRecord, SettlementStatusRecord},
+    queries::get_group_backup::GetGroupBackupError,
+};
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+    let category_id = ctx
+        .categories()
+        .create_category(group_id, alice_id, "Fuel")
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_in_category(
+            group_id,
+            alice_id,
+            30,
+            category_id,
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+    ctx.expense_entries()
+        .update_expense(
+            group_id,
+            expense_id,
+            alice_id,
+            60,
+            vec![bob_id],
+            bob_id,
+            dates::jan_10_2025(),
+        )
+        .await?;
+    let settlement_id = ctx
+        .settlements()
+        .record_settlement(
+            group_id,
+            bob_id,
+            alice_id,
+            1_000,
+            bob_id,
+            dates::jan_10_2025(),
+        )
+        .await?;
+    ctx.settlements()
+        .cancel_settlement(group_id, settlement_id, alice_id)
+        .await?;
+
+    // When
+    let document = ctx.backups().get_group_backup(group_id, alice_id).await?;
+
+    // Then
+    assert_eq!(CURRENT_VERSION, document.version);
+    assert_eq!(group_id, document.group.id);
+    assert_eq!("Bali Trip 2026", document.group.name);
+    assert_eq!(alice_id, document.group.owner_id);
+    assert_eq!(vec![bob_id], document.group.member_ids);
+    assert_eq!(2, document.users.len());
+    assert_eq!(1, document.categories.len());
+    assert_eq!("Fuel", document.categories[0].name);
+
+    // every version is kept, the overwritten one first
+    assert_eq!(2, document.expense_entries.len());
+    assert!(matches!(
+        document.expense_entries[0].status,
+        ExpenseEntryStatusRecord::Inactive { overwritten_by } if overwritten_by == document.expense_entries[1].id
+    ));
+    assert_eq!(3_000, document.expense_entries[0].total_cents);
+    assert_eq!(Some(category_id), document.expense_entries[0].category_id);
+    assert!(matches!(
+        document.expense_entries[1].status,
+        ExpenseEntryStatusRecord::Active
+    ));
+    assert_eq!(6_000, document.expense_entries[1].total_cents);
+    assert_eq!(vec![bob_id], document.expense_entries[1].participant_ids);
+
+    assert_eq!(1, document.settlements.len());
+    assert!(matches!(
+        document.settlements[0].status,
+        SettlementStatusRecord::Cancelled { cancelled_by, .. } if cancelled_by == alice_id
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_owner_can_back_up_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "only_owner_can_back_up_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+
+    // When
+    let err = ctx
+        .backups()
+        .get_group_backup(group_id, bob_id)
+        .await
+        .expect_err("backup must fail");
+
+    // Then
+    assert_eq!(GetGroupBackupError::Forbidden.to_string(), err.to_string());
+
+    Ok(())
+}
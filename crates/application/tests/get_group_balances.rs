@@ -0,0 +1,244 @@
+use application::queries::get_group_balances::GetGroupBalancesError;
+use uuid::Uuid;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 100, bob_id, dates::jan_08_2025())
+        .await?;
+
+    // When
+    let balances = ctx
+        .balances()
+        .get_group_balances(group_id, alice_id)
+        .await?;
+
+    // Then
+    assert_eq!(2, balances.len());
+    assert_eq!(5_000, balances[&bob_id]);
+    assert_eq!(-5_000, balances[&alice_id]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn no_expenses() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "no_expenses").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+
+    // When
+    let balances = ctx.balances().get_group_balances(group_id, bob_id).await?;
+
+    // Then
+    assert_eq!(2, balances.len());
+    assert_eq!(0, balances[&bob_id]);
+    assert_eq!(0, balances[&alice_id]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn payer_excluded_from_participants_still_bears_a_share() -> anyhow::Result<()> {
+    let db_pool =
+        build_test_database(FILE, "payer_excluded_from_participants_still_bears_a_share").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    ctx.groups()
+        .add_member(group_id, bob_id, charlie_id)
+        .await?;
+    ctx.expense_entries()
+        .create_expense(
+            group_id,
+            alice_id,
+            90,
+            vec![alice_id, bob_id],
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // When
+    let balances = ctx.balances().get_group_balances(group_id, bob_id).await?;
+
+    // Then
+    assert_eq!(4_500, balances[&alice_id]);
+    assert_eq!(-4_500, balances[&bob_id]);
+    assert_eq!(0, balances[&charlie_id]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn balances_always_sum_to_zero() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "balances_always_sum_to_zero").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    ctx.groups()
+        .add_member(group_id, bob_id, charlie_id)
+        .await?;
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 100, bob_id, dates::jan_08_2025())
+        .await?;
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(
+            group_id,
+            charlie_id,
+            10,
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // When
+    let balances = ctx.balances().get_group_balances(group_id, bob_id).await?;
+
+    // Then
+    // remaining cents go to the lowest user ids (Bob, then Alice)
+    assert_eq!(6_332, balances[&bob_id]);
+    assert_eq!(-3_666, balances[&alice_id]);
+    assert_eq!(-2_666, balances[&charlie_id]);
+    assert_eq!(0, balances.values().sum::<i64>());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn group_not_found() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "group_not_found").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+
+    // When
+    let err = ctx
+        .balances()
+        .get_group_balances(Uuid::now_v7(), bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        GetGroupBalancesError::GroupNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn user_not_in_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "user_not_in_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .balances()
+        .get_group_balances(group_id, alice_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        GetGroupBalancesError::Forbidden.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn expenses_are_converted_to_group_currency() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "expenses_are_converted_to_group_currency").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group_with_currency("Bali Trip 2026", bob_id, "USD")
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    ctx.expense_entries()
+        .create_expense_in_currency(
+            group_id,
+            bob_id,
+            10_000,
+            "EUR",
+            Some("1.1"),
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(
+            group_id,
+            alice_id,
+            20,
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // When
+    let balances = ctx.balances().get_group_balances(group_id, bob_id).await?;
+
+    // Then
+    assert_eq!(4_500, balances[&bob_id]);
+    assert_eq!(-4_500, balances[&alice_id]);
+
+    Ok(())
+}
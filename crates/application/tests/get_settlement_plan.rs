@@ -0,0 +1,175 @@
+use application::queries::get_settlement_plan::GetSettlementPlanError;
+use uuid::Uuid;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    ctx.groups()
+        .add_member(group_id, bob_id, charlie_id)
+        .await?;
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+
+    // When
+    let plan = ctx
+        .balances()
+        .get_settlement_plan(group_id, charlie_id)
+        .await?;
+
+    // Then
+    assert_eq!(
+        vec![(alice_id, bob_id, 3_000), (charlie_id, bob_id, 3_000)],
+        plan
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn remainder_cents_sum_to_zero() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "remainder_cents_sum_to_zero").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    ctx.groups()
+        .add_member(group_id, bob_id, charlie_id)
+        .await?;
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(
+            group_id,
+            charlie_id,
+            100,
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // When
+    let plan = ctx
+        .balances()
+        .get_settlement_plan(group_id, charlie_id)
+        .await?;
+
+    // Then
+    // Bob gets the remaining cent as he has the lowest id
+    assert_eq!(
+        vec![(bob_id, charlie_id, 3_334), (alice_id, charlie_id, 3_333)],
+        plan
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn settled_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "settled_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 20, bob_id, dates::jan_08_2025())
+        .await?;
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(
+            group_id,
+            alice_id,
+            20,
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // When
+    let plan = ctx.balances().get_settlement_plan(group_id, bob_id).await?;
+
+    // Then
+    assert!(plan.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn group_not_found() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "group_not_found").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+
+    // When
+    let err = ctx
+        .balances()
+        .get_settlement_plan(Uuid::now_v7(), bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        GetSettlementPlanError::GroupNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn user_not_in_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "user_not_in_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .balances()
+        .get_settlement_plan(group_id, alice_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        GetSettlementPlanError::Forbidden.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
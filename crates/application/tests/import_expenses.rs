@@ -0,0 +1,334 @@
+use application::{
+    commands::import_expenses::{ImportExpensesError, RowOutcome},
+    import::{ImportFormat, RowError},
+};
+use uuid::Uuid;
+
+use crate::infra::{ctx::TestContext, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+const SPLITWISE_EXPORT: &str = "\
+Date,Description,Category,Cost,Currency,Alice,Bob,Carol
+2024-03-15,Dinner,Food and drink - Dining out,90.00,EUR,60.00,-30.00,-30.00
+2024-03-16,Taxi,Transportation - Taxi,20.00,EUR,-10.00,10.00,0.00
+2024-03-17,Bob paid Alice,Payment,30.00,EUR,-30.00,30.00,0.00
+
+,Total balance,,,EUR,20.00,10.00,-30.00
+";
+
+const TRICOUNT_EXPORT: &str = "\
+Title,Amount,Currency,Exchange rate,Date & time,Type of transaction,Paid by,Impacted to Alice,Impacted to Bob
+Hotel,-120.00,EUR,1,2024-03-15 18:34:00,Normal,Alice,-60.00,-60.00
+Refund,20.00,EUR,1,2024-03-16 09:00:00,Money transfer,Bob,-20.00,
+Lottery,10.00,EUR,1,2024-03-17 09:00:00,Income,Alice,5.00,5.00
+";
+
+/// Creates a group owned by Alice, with Bob and Carol as members.
+async fn create_group(ctx: &TestContext) -> anyhow::Result<(Uuid, Uuid, Uuid, Uuid)> {
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let carol_id = ctx.users().create_user("Carol").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Flatmates", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+    ctx.groups()
+        .add_member(group_id, alice_id, carol_id)
+        .await?;
+    Ok((group_id, alice_id, bob_id, carol_id))
+}
+
+#[tokio::test]
+async fn splitwise_happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "splitwise_happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let (group_id, alice_id, bob_id, carol_id) = create_group(&ctx).await?;
+
+    // When
+    let output = ctx
+        .imports()
+        .import_expenses(
+            group_id,
+            alice_id,
+            ImportFormat::Splitwise,
+            SPLITWISE_EXPORT,
+            &[("Alice", alice_id), ("Bob", bob_id), ("Carol", carol_id)],
+            false,
+        )
+        .await?;
+
+    // Then
+    assert!(output.committed);
+    assert_eq!(
+        vec![2, 3, 4],
+        output.rows.iter().map(|row| row.line).collect::<Vec<_>>()
+    );
+    assert!(matches!(output.rows[0].outcome, RowOutcome::Expense { .. }));
+    assert!(matches!(
+        output.rows[2].outcome,
+        RowOutcome::Settlement { .. }
+    ));
+
+    let expense_ids = ctx
+        .expense_entries()
+        .get_expense_ids_for_group(group_id, alice_id)
+        .await?;
+    assert_eq!(2, expense_ids.len());
+
+    let balances = ctx
+        .balances()
+        .get_group_balances(group_id, alice_id)
+        .await?;
+    assert_eq!(Some(&2_000), balances.get(&alice_id));
+    assert_eq!(Some(&1_000), balances.get(&bob_id));
+    assert_eq!(Some(&-3_000), balances.get(&carol_id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn splitwise_expense_with_several_payers() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "splitwise_expense_with_several_payers").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let (group_id, alice_id, bob_id, carol_id) = create_group(&ctx).await?;
+    // Carol owes 10 to Alice and 5 to Bob, who both paid for the wine
+    let content = "\
+Date,Description,Category,Cost,Currency,Alice,Bob,Carol
+2024-03-16,Wine,General,30.00,EUR,10.00,5.00,-15.00
+";
+
+    // When
+    let output = ctx
+        .imports()
+        .import_expenses(
+            group_id,
+            alice_id,
+            ImportFormat::Splitwise,
+            content,
+            &[("Alice", alice_id), ("Bob", bob_id), ("Carol", carol_id)],
+            false,
+        )
+        .await?;
+
+    // Then
+    assert!(output.committed);
+    let RowOutcome::Expense { expense_id } = &output.rows[0].outcome else {
+        panic!("expected an expense");
+    };
+    let mut payers = ctx
+        .expense_entries()
+        .get_payers(group_id, expense_id.value(), alice_id)
+        .await?;
+    payers.sort_by_key(|(_, cents)| *cents);
+    assert_eq!(vec![(bob_id, 1_000), (alice_id, 2_000)], payers);
+
+    let balances = ctx
+        .balances()
+        .get_group_balances(group_id, alice_id)
+        .await?;
+    assert_eq!(Some(&1_000), balances.get(&alice_id));
+    assert_eq!(Some(&500), balances.get(&bob_id));
+    assert_eq!(Some(&-1_500), balances.get(&carol_id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn tricount_happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "tricount_happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let (group_id, alice_id, bob_id, _) = create_group(&ctx).await?;
+
+    // When
+    let output = ctx
+        .imports()
+        .import_expenses(
+            group_id,
+            bob_id,
+            ImportFormat::Tricount,
+            TRICOUNT_EXPORT,
+            &[("Alice", alice_id), ("Bob", bob_id)],
+            false,
+        )
+        .await?;
+
+    // Then
+    assert!(output.committed);
+    assert!(matches!(output.rows[0].outcome, RowOutcome::Expense { .. }));
+    assert!(matches!(
+        output.rows[1].outcome,
+        RowOutcome::Settlement { .. }
+    ));
+    assert!(matches!(output.rows[2].outcome, RowOutcome::Ignored { .. }));
+
+    let balances = ctx
+        .balances()
+        .get_group_balances(group_id, alice_id)
+        .await?;
+    assert_eq!(Some(&4_000), balances.get(&alice_id));
+    assert_eq!(Some(&-4_000), balances.get(&bob_id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn dry_run_saves_nothing() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "dry_run_saves_nothing").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let (group_id, alice_id, bob_id, carol_id) = create_group(&ctx).await?;
+
+    // When
+    let output = ctx
+        .imports()
+        .import_expenses(
+            group_id,
+            alice_id,
+            ImportFormat::Splitwise,
+            SPLITWISE_EXPORT,
+            &[("Alice", alice_id), ("Bob", bob_id), ("Carol", carol_id)],
+            true,
+        )
+        .await?;
+
+    // Then
+    assert!(!output.committed);
+    assert!(
+        output
+            .rows
+            .iter()
+            .all(|row| !matches!(row.outcome, RowOutcome::Failed(_)))
+    );
+
+    let expense_ids = ctx
+        .expense_entries()
+        .get_expense_ids_for_group(group_id, alice_id)
+        .await?;
+    assert!(expense_ids.is_empty());
+    let settlements = ctx
+        .settlements()
+        .get_settlements_for_group(group_id, alice_id)
+        .await?;
+    assert!(settlements.settlements.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn invalid_row_cancels_the_whole_import() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "invalid_row_cancels_the_whole_import").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let (group_id, alice_id, bob_id, carol_id) = create_group(&ctx).await?;
+    // what Bob and Carol owe exceeds the cost of the taxi
+    let content = SPLITWISE_EXPORT.replace(
+        "20.00,EUR,-10.00,10.00,0.00",
+        "20.00,EUR,-10.00,30.00,-20.00",
+    );
+
+    // When
+    let output = ctx
+        .imports()
+        .import_expenses(
+            group_id,
+            alice_id,
+            ImportFormat::Splitwise,
+            &content,
+            &[("Alice", alice_id), ("Bob", bob_id), ("Carol", carol_id)],
+            false,
+        )
+        .await?;
+
+    // Then
+    assert!(!output.committed);
+    assert!(matches!(output.rows[0].outcome, RowOutcome::Expense { .. }));
+    assert!(matches!(
+        output.rows[1].outcome,
+        RowOutcome::Failed(RowError::Expense(_))
+    ));
+
+    let expense_ids = ctx
+        .expense_entries()
+        .get_expense_ids_for_group(group_id, alice_id)
+        .await?;
+    assert!(expense_ids.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn every_person_must_be_mapped() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "every_person_must_be_mapped").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let (group_id, alice_id, bob_id, _) = create_group(&ctx).await?;
+
+    // When
+    let err = ctx
+        .imports()
+        .import_expenses(
+            group_id,
+            alice_id,
+            ImportFormat::Splitwise,
+            SPLITWISE_EXPORT,
+            &[("Alice", alice_id), ("Bob", bob_id)],
+            false,
+        )
+        .await
+        .err()
+        .expect("import must fail");
+
+    // Then
+    assert_eq!(
+        ImportExpensesError::UnmappedPerson("Carol".to_string()).to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mapped_users_must_be_group_members() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "mapped_users_must_be_group_members").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let (group_id, alice_id, bob_id, _) = create_group(&ctx).await?;
+    let dave_id = ctx.users().create_user("Dave").await?;
+
+    // When
+    let err = ctx
+        .imports()
+        .import_expenses(
+            group_id,
+            alice_id,
+            ImportFormat::Splitwise,
+            SPLITWISE_EXPORT,
+            &[("Alice", alice_id), ("Bob", bob_id), ("Carol", dave_id)],
+            false,
+        )
+        .await
+        .err()
+        .expect("import must fail");
+
+    // Then
+    assert_eq!(
+        ImportExpensesError::PersonNotInGroup("Carol".to_string()).to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
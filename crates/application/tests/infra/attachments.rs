@@ -0,0 +1,137 @@
+use application::{
+    commands::{
+        create_attachment::CreateAttachmentCommand, delete_attachment::DeleteAttachmentCommand,
+        purge_deleted_expense_attachments::PurgeDeletedExpenseAttachmentsCommand,
+    },
+    queries::{
+        get_attachment::GetAttachmentQuery,
+        get_attachments_for_expense::GetAttachmentsForExpenseQuery,
+    },
+};
+use chrono::{DateTime, Utc};
+use domain::{
+    entities::Attachment,
+    types::{
+        attachment_id::AttachmentId, expense_id::ExpenseId, group_id::GroupId, user_id::UserId,
+    },
+};
+use uuid::Uuid;
+
+pub struct AttachmentsHelper<'a> {
+    pool: &'a database::SqlitePool,
+}
+
+impl<'a> AttachmentsHelper<'a> {
+    pub(super) fn new(pool: &'a database::SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create_attachment(
+        &mut self,
+        group_id: Uuid,
+        expense_id: Uuid,
+        current_user_id: Uuid,
+        file_name: &str,
+        size_bytes: u64,
+    ) -> anyhow::Result<Uuid> {
+        let mut tx = self.pool.begin().await?;
+        let id = CreateAttachmentCommand {
+            group_id: GroupId::new(group_id)?,
+            expense_id: ExpenseId::new(expense_id)?,
+            current_user_id: UserId::new(current_user_id)?,
+            file_name: file_name.parse()?,
+            content_type: "image/jpeg".parse()?,
+            size_bytes,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(id.value())
+    }
+
+    pub async fn delete_attachment(
+        &mut self,
+        group_id: Uuid,
+        expense_id: Uuid,
+        attachment_id: Uuid,
+        current_user_id: Uuid,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        DeleteAttachmentCommand {
+            group_id: GroupId::new(group_id)?,
+            expense_id: ExpenseId::new(expense_id)?,
+            attachment_id: AttachmentId::new(attachment_id)?,
+            current_user_id: UserId::new(current_user_id)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn get_attachment(
+        &mut self,
+        group_id: Uuid,
+        expense_id: Uuid,
+        attachment_id: Uuid,
+        current_user: Uuid,
+    ) -> anyhow::Result<Attachment> {
+        let mut tx = self.pool.begin().await?;
+        let attachment = GetAttachmentQuery {
+            group_id: GroupId::new(group_id)?,
+            expense_id: ExpenseId::new(expense_id)?,
+            attachment_id: AttachmentId::new(attachment_id)?,
+            current_user: UserId::new(current_user)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(attachment)
+    }
+
+    /// Returns the ids of the attachments of the provided expense.
+    pub async fn get_attachment_ids_for_expense(
+        &mut self,
+        group_id: Uuid,
+        expense_id: Uuid,
+        current_user: Uuid,
+    ) -> anyhow::Result<Vec<Uuid>> {
+        let mut tx = self.pool.begin().await?;
+        let output = GetAttachmentsForExpenseQuery {
+            group_id: GroupId::new(group_id)?,
+            expense_id: ExpenseId::new(expense_id)?,
+            current_user: UserId::new(current_user)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(output
+            .attachments
+            .into_iter()
+            .map(|attachment| attachment.id.value())
+            .collect())
+    }
+
+    /// Checks whether the attachment is still recorded, whatever the state
+    /// of its expense.
+    pub async fn exists(&mut self, attachment_id: Uuid) -> anyhow::Result<bool> {
+        let mut tx = self.pool.begin().await?;
+        let exists =
+            database::queries::attachment::exists(&mut tx, &AttachmentId::new(attachment_id)?)
+                .await?;
+        tx.commit().await?;
+        Ok(exists)
+    }
+
+    pub async fn purge_deleted_expense_attachments(
+        &mut self,
+        deleted_before: DateTime<Utc>,
+    ) -> anyhow::Result<u64> {
+        let mut tx = self.pool.begin().await?;
+        let purged = PurgeDeletedExpenseAttachmentsCommand { deleted_before }
+            .handle(&mut tx)
+            .await?;
+        tx.commit().await?;
+        Ok(purged)
+    }
+}
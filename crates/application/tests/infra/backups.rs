@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use application::{
+    backup::BackupDocument, commands::restore_group_backup::RestoreGroupBackupCommand,
+    queries::get_group_backup::GetGroupBackupQuery,
+};
+use domain::types::{group_id::GroupId, user_id::UserId};
+use uuid::Uuid;
+
+pub struct BackupsHelper<'a> {
+    pool: &'a database::SqlitePool,
+}
+
+impl<'a> BackupsHelper<'a> {
+    pub(super) fn new(pool: &'a database::SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn get_group_backup(
+        &mut self,
+        group_id: Uuid,
+        current_user: Uuid,
+    ) -> anyhow::Result<BackupDocument> {
+        let mut tx = self.pool.begin().await?;
+        let document = GetGroupBackupQuery {
+            group_id: GroupId::new(group_id)?,
+            current_user: UserId::new(current_user)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(document)
+    }
+
+    /// Restores a document, mapping each user of the document (first) to
+    /// a user of the database (second).
+    pub async fn restore_group_backup(
+        &mut self,
+        current_user_id: Uuid,
+        document: serde_json::Value,
+        user_mapping: &[(Uuid, Uuid)],
+        name: Option<&str>,
+    ) -> anyhow::Result<Uuid> {
+        let mut tx = self.pool.begin().await?;
+        let group_id = RestoreGroupBackupCommand {
+            current_user_id: UserId::new(current_user_id)?,
+            document,
+            user_mapping: user_mapping
+                .iter()
+                .map(|(from, to)| Ok((*from, UserId::new(*to)?)))
+                .collect::<anyhow::Result<HashMap<_, _>>>()?,
+            name: name.map(str::parse).transpose()?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(group_id.value())
+    }
+}
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use application::queries::{
+    get_group_balances::GetGroupBalancesQuery, get_settlement_plan::GetSettlementPlanQuery,
+};
+use domain::types::{group_id::GroupId, user_id::UserId};
+use uuid::Uuid;
+
+pub struct BalancesHelper<'a> {
+    pool: &'a database::SqlitePool,
+}
+
+impl<'a> BalancesHelper<'a> {
+    pub(super) fn new(pool: &'a database::SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns balances in cents, by user id.
+    pub async fn get_group_balances(
+        &mut self,
+        group_id: Uuid,
+        current_user: Uuid,
+    ) -> anyhow::Result<HashMap<Uuid, i64>> {
+        let mut tx = self.pool.begin().await?;
+        let output = GetGroupBalancesQuery {
+            group_id: GroupId::new(group_id)?,
+            current_user: UserId::new(current_user)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(output
+            .balances
+            .into_iter()
+            .map(|b| (b.user.id.value(), b.balance.cents()))
+            .collect())
+    }
+
+    /// Returns transfers as (from, to, cents).
+    pub async fn get_settlement_plan(
+        &mut self,
+        group_id: Uuid,
+        current_user: Uuid,
+    ) -> anyhow::Result<Vec<(Uuid, Uuid, i64)>> {
+        let mut tx = self.pool.begin().await?;
+        let output = GetSettlementPlanQuery {
+            group_id: GroupId::new(group_id)?,
+            current_user: UserId::new(current_user)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(output
+            .transfers
+            .into_iter()
+            .map(|t| (t.from.id.value(), t.to.id.value(), t.amount.cents()))
+            .collect())
+    }
+}
@@ -0,0 +1,126 @@
+use application::{
+    commands::{
+        create_budget::CreateBudgetCommand, delete_budget::DeleteBudgetCommand,
+        update_budget::UpdateBudgetCommand,
+    },
+    queries::{
+        get_budget_alerts_for_group::GetBudgetAlertsForGroupQuery,
+        get_budgets_for_group::{BudgetConsumption, GetBudgetsForGroupQuery},
+    },
+};
+use chrono::{DateTime, Utc};
+use domain::{
+    entities::BudgetAlert,
+    types::{
+        budget_id::BudgetId, budget_period::BudgetPeriod, category_id::CategoryId,
+        group_id::GroupId, money::Money, user_id::UserId,
+    },
+};
+use uuid::Uuid;
+
+pub struct BudgetsHelper<'a> {
+    pool: &'a database::SqlitePool,
+}
+
+impl<'a> BudgetsHelper<'a> {
+    pub(super) fn new(pool: &'a database::SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create_budget(
+        &mut self,
+        group_id: Uuid,
+        current_user_id: Uuid,
+        category_id: Option<Uuid>,
+        amount_euros: i64,
+        period: BudgetPeriod,
+    ) -> anyhow::Result<Uuid> {
+        let mut tx = self.pool.begin().await?;
+        let id = CreateBudgetCommand {
+            group_id: GroupId::new(group_id)?,
+            current_user_id: UserId::new(current_user_id)?,
+            category_id: category_id.map(CategoryId::new).transpose()?,
+            amount: Money::from_euros(amount_euros),
+            period,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(id.value())
+    }
+
+    pub async fn update_budget(
+        &mut self,
+        group_id: Uuid,
+        budget_id: Uuid,
+        current_user_id: Uuid,
+        amount_euros: i64,
+        period: BudgetPeriod,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        UpdateBudgetCommand {
+            group_id: GroupId::new(group_id)?,
+            budget_id: BudgetId::new(budget_id)?,
+            current_user_id: UserId::new(current_user_id)?,
+            category_id: None,
+            amount: Money::from_euros(amount_euros),
+            period,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn delete_budget(
+        &mut self,
+        group_id: Uuid,
+        budget_id: Uuid,
+        current_user_id: Uuid,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        DeleteBudgetCommand {
+            group_id: GroupId::new(group_id)?,
+            budget_id: BudgetId::new(budget_id)?,
+            current_user_id: UserId::new(current_user_id)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn get_budgets_for_group(
+        &mut self,
+        group_id: Uuid,
+        current_user: Uuid,
+        at: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<BudgetConsumption>> {
+        let mut tx = self.pool.begin().await?;
+        let output = GetBudgetsForGroupQuery {
+            group_id: GroupId::new(group_id)?,
+            current_user: UserId::new(current_user)?,
+            at,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(output.budgets)
+    }
+
+    pub async fn get_budget_alerts_for_group(
+        &mut self,
+        group_id: Uuid,
+        current_user: Uuid,
+    ) -> anyhow::Result<Vec<BudgetAlert>> {
+        let mut tx = self.pool.begin().await?;
+        let output = GetBudgetAlertsForGroupQuery {
+            group_id: GroupId::new(group_id)?,
+            current_user: UserId::new(current_user)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(output.alerts)
+    }
+}
@@ -0,0 +1,130 @@
+use application::{
+    commands::{
+        create_category::CreateCategoryCommand, delete_category::DeleteCategoryCommand,
+        update_category::UpdateCategoryCommand,
+    },
+    queries::{
+        get_categories_for_group::GetCategoriesForGroupQuery,
+        get_category_report::{GetCategoryReportQuery, Output as CategoryReport},
+    },
+};
+use chrono::{DateTime, Utc};
+use domain::{
+    entities::Category,
+    types::{category_id::CategoryId, group_id::GroupId, user_id::UserId},
+};
+use uuid::Uuid;
+
+pub struct CategoriesHelper<'a> {
+    pool: &'a database::SqlitePool,
+}
+
+impl<'a> CategoriesHelper<'a> {
+    pub(super) fn new(pool: &'a database::SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn create_category(
+        &mut self,
+        group_id: Uuid,
+        current_user_id: Uuid,
+        name: &str,
+    ) -> anyhow::Result<Uuid> {
+        let mut tx = self.pool.begin().await?;
+        let id = CreateCategoryCommand {
+            group_id: GroupId::new(group_id)?,
+            current_user_id: UserId::new(current_user_id)?,
+            name: name.parse()?,
+            color: "#FF8800".parse()?,
+            emoji: "🏷️".parse()?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(id.value())
+    }
+
+    pub async fn delete_category(
+        &mut self,
+        group_id: Uuid,
+        category_id: Uuid,
+        current_user_id: Uuid,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        DeleteCategoryCommand {
+            group_id: GroupId::new(group_id)?,
+            category_id: CategoryId::new(category_id)?,
+            current_user_id: UserId::new(current_user_id)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn rename_category(
+        &mut self,
+        group_id: Uuid,
+        category_id: Uuid,
+        current_user_id: Uuid,
+        name: &str,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        UpdateCategoryCommand {
+            group_id: GroupId::new(group_id)?,
+            category_id: CategoryId::new(category_id)?,
+            current_user_id: UserId::new(current_user_id)?,
+            name: name.parse()?,
+            color: "#FF8800".parse()?,
+            emoji: "🏷️".parse()?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn get_categories_for_group(
+        &mut self,
+        group_id: Uuid,
+        current_user: Uuid,
+    ) -> anyhow::Result<Vec<Category>> {
+        let mut tx = self.pool.begin().await?;
+        let output = GetCategoriesForGroupQuery {
+            group_id: GroupId::new(group_id)?,
+            current_user: UserId::new(current_user)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(output.categories)
+    }
+
+    pub async fn get_category_report(
+        &mut self,
+        group_id: Uuid,
+        current_user: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> anyhow::Result<CategoryReport> {
+        let mut tx = self.pool.begin().await?;
+        let output = GetCategoryReportQuery {
+            group_id: GroupId::new(group_id)?,
+            current_user: UserId::new(current_user)?,
+            from,
+            to,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(output)
+    }
+
+    pub async fn assert_category_exists(&mut self, category_id: Uuid) -> anyhow::Result<Category> {
+        let mut tx = self.pool.begin().await?;
+        let category =
+            database::queries::category::get_by_id(&mut tx, &CategoryId::new(category_id)?).await?;
+        tx.commit().await?;
+        Ok(category.expect("category should exist"))
+    }
+}
@@ -1,5 +1,9 @@
 use crate::infra::{
-    expense_entries::ExpenseEntriesHelper, groups::GroupsHelper, users::UsersHelper,
+    attachments::AttachmentsHelper, backups::BackupsHelper, balances::BalancesHelper,
+    budgets::BudgetsHelper, categories::CategoriesHelper, expense_entries::ExpenseEntriesHelper,
+    groups::GroupsHelper, imports::ImportsHelper, invites::InvitesHelper,
+    recurring_expenses::RecurringExpensesHelper, settlements::SettlementsHelper,
+    users::UsersHelper,
 };
 
 pub struct TestContext {
@@ -22,4 +26,40 @@ impl TestContext {
     pub fn expense_entries(&self) -> ExpenseEntriesHelper<'_> {
         ExpenseEntriesHelper::new(&self.pool)
     }
+
+    pub fn backups(&self) -> BackupsHelper<'_> {
+        BackupsHelper::new(&self.pool)
+    }
+
+    pub fn balances(&self) -> BalancesHelper<'_> {
+        BalancesHelper::new(&self.pool)
+    }
+
+    pub fn budgets(&self) -> BudgetsHelper<'_> {
+        BudgetsHelper::new(&self.pool)
+    }
+
+    pub fn categories(&self) -> CategoriesHelper<'_> {
+        CategoriesHelper::new(&self.pool)
+    }
+
+    pub fn recurring_expenses(&self) -> RecurringExpensesHelper<'_> {
+        RecurringExpensesHelper::new(&self.pool)
+    }
+
+    pub fn attachments(&self) -> AttachmentsHelper<'_> {
+        AttachmentsHelper::new(&self.pool)
+    }
+
+    pub fn imports(&self) -> ImportsHelper<'_> {
+        ImportsHelper::new(&self.pool)
+    }
+
+    pub fn invites(&self) -> InvitesHelper<'_> {
+        InvitesHelper::new(&self.pool)
+    }
+
+    pub fn settlements(&self) -> SettlementsHelper<'_> {
+        SettlementsHelper::new(&self.pool)
+    }
 }
@@ -5,3 +5,45 @@ pub fn jan_08_2025() -> DateTime<Utc> {
         .unwrap()
         .to_utc()
 }
+
+pub fn jan_10_2025() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2025-01-10T00:00:00Z")
+        .unwrap()
+        .to_utc()
+}
+
+pub fn jan_31_2025() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2025-01-31T00:00:00Z")
+        .unwrap()
+        .to_utc()
+}
+
+pub fn feb_28_2025() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2025-02-28T00:00:00Z")
+        .unwrap()
+        .to_utc()
+}
+
+pub fn mar_07_2025() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2025-03-07T00:00:00Z")
+        .unwrap()
+        .to_utc()
+}
+
+pub fn mar_31_2025() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2025-03-31T00:00:00Z")
+        .unwrap()
+        .to_utc()
+}
+
+pub fn apr_15_2025() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2025-04-15T00:00:00Z")
+        .unwrap()
+        .to_utc()
+}
+
+pub fn apr_30_2025() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2025-04-30T00:00:00Z")
+        .unwrap()
+        .to_utc()
+}
@@ -1,8 +1,24 @@
-use application::commands::create_expense::{CreateExpenseCommand, IncludeParticipants};
+use std::collections::HashMap;
+
+use application::{
+    commands::{
+        create_expense::{CreateExpenseCommand, IncludeParticipants, SplitSpecification},
+        delete_expense::DeleteExpenseCommand,
+        restore_expense::RestoreExpenseCommand,
+        update_expense::UpdateExpenseCommand,
+    },
+    pagination::Pagination,
+    queries::{
+        export_expenses_for_group::{ExportExpensesForGroupQuery, Output as ExpensesExport},
+        get_expense_history::{GetExpenseHistoryQuery, Output as ExpenseHistory},
+        get_expenses_for_group::GetExpensesForGroupQuery,
+    },
+};
 use chrono::{DateTime, Utc};
 use domain::{
     entities::ExpenseEntry,
     types::{
+        category_id::CategoryId, expense_category::ExpenseCategory,
         expense_entry_id::ExpenseEntryId, expense_id::ExpenseId, group_id::GroupId, money::Money,
         user_id::UserId,
     },
@@ -30,10 +46,19 @@ impl<'a> ExpenseEntriesHelper<'a> {
         let id = CreateExpenseCommand {
             group_id: GroupId::new(group_id)?,
             payer_id: UserId::new(payer_id)?,
-            participants: IncludeParticipants::All,
+            payers: None,
+            title: "Expense".parse()?,
+            note: None,
+            category: ExpenseCategory::default(),
+            category_id: None,
+            split: SplitSpecification::Equal {
+                participants: IncludeParticipants::All,
+            },
             total: Money::from_euros(total_euros),
             author_id: UserId::new(author_id)?,
             occured_at,
+            currency: None,
+            exchange_rate: None,
         }
         .handle(&mut tx)
         .await?;
@@ -54,15 +79,307 @@ impl<'a> ExpenseEntriesHelper<'a> {
         let id = CreateExpenseCommand {
             group_id: GroupId::new(group_id)?,
             payer_id: UserId::new(payer_id)?,
-            participants: IncludeParticipants::List {
-                participants: participants
-                    .into_iter()
-                    .map(|id| UserId::new(id))
-                    .collect::<Result<_, _>>()?,
+            payers: None,
+            title: "Expense".parse()?,
+            note: None,
+            category: ExpenseCategory::default(),
+            category_id: None,
+            split: SplitSpecification::Equal {
+                participants: IncludeParticipants::List {
+                    participants: participants
+                        .into_iter()
+                        .map(UserId::new)
+                        .collect::<Result<_, _>>()?,
+                },
+            },
+            total: Money::from_euros(total_euros),
+            author_id: UserId::new(author_id)?,
+            occured_at,
+            currency: None,
+            exchange_rate: None,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(id.value())
+    }
+
+    pub async fn create_expense_with_split(
+        &mut self,
+        group_id: Uuid,
+        payer_id: Uuid,
+        total_cents: i64,
+        split: SplitSpecification,
+        author_id: Uuid,
+        occured_at: DateTime<Utc>,
+    ) -> anyhow::Result<Uuid> {
+        let mut tx = self.pool.begin().await?;
+        let id = CreateExpenseCommand {
+            group_id: GroupId::new(group_id)?,
+            payer_id: UserId::new(payer_id)?,
+            payers: None,
+            title: "Expense".parse()?,
+            note: None,
+            category: ExpenseCategory::default(),
+            category_id: None,
+            split,
+            total: Money::from_cents(total_cents),
+            author_id: UserId::new(author_id)?,
+            occured_at,
+            currency: None,
+            exchange_rate: None,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(id.value())
+    }
+
+    pub async fn create_expense_paid_by(
+        &mut self,
+        group_id: Uuid,
+        payer_id: Uuid,
+        payers: Vec<(Uuid, i64)>,
+        total_cents: i64,
+        author_id: Uuid,
+        occured_at: DateTime<Utc>,
+    ) -> anyhow::Result<Uuid> {
+        let payers = payers
+            .into_iter()
+            .map(|(user_id, cents)| Ok((UserId::new(user_id)?, Money::from_cents(cents))))
+            .collect::<anyhow::Result<HashMap<_, _>>>()?;
+        let mut tx = self.pool.begin().await?;
+        let id = CreateExpenseCommand {
+            group_id: GroupId::new(group_id)?,
+            payer_id: UserId::new(payer_id)?,
+            payers: Some(payers),
+            title: "Expense".parse()?,
+            note: None,
+            category: ExpenseCategory::default(),
+            category_id: None,
+            split: SplitSpecification::Equal {
+                participants: IncludeParticipants::All,
+            },
+            total: Money::from_cents(total_cents),
+            author_id: UserId::new(author_id)?,
+            occured_at,
+            currency: None,
+            exchange_rate: None,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(id.value())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_expense_with_details(
+        &mut self,
+        group_id: Uuid,
+        payer_id: Uuid,
+        total_euros: i64,
+        title: &str,
+        note: Option<&str>,
+        category: ExpenseCategory,
+        author_id: Uuid,
+        occured_at: DateTime<Utc>,
+    ) -> anyhow::Result<Uuid> {
+        let mut tx = self.pool.begin().await?;
+        let id = CreateExpenseCommand {
+            group_id: GroupId::new(group_id)?,
+            payer_id: UserId::new(payer_id)?,
+            payers: None,
+            title: title.parse()?,
+            note: note.map(str::parse).transpose()?,
+            category,
+            category_id: None,
+            split: SplitSpecification::Equal {
+                participants: IncludeParticipants::All,
+            },
+            total: Money::from_euros(total_euros),
+            author_id: UserId::new(author_id)?,
+            occured_at,
+            currency: None,
+            exchange_rate: None,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(id.value())
+    }
+
+    pub async fn create_expense_in_category(
+        &mut self,
+        group_id: Uuid,
+        payer_id: Uuid,
+        total_euros: i64,
+        category_id: Uuid,
+        author_id: Uuid,
+        occured_at: DateTime<Utc>,
+    ) -> anyhow::Result<Uuid> {
+        let mut tx = self.pool.begin().await?;
+        let id = CreateExpenseCommand {
+            group_id: GroupId::new(group_id)?,
+            payer_id: UserId::new(payer_id)?,
+            payers: None,
+            title: "Expense".parse()?,
+            note: None,
+            category: ExpenseCategory::default(),
+            category_id: Some(CategoryId::new(category_id)?),
+            split: SplitSpecification::Equal {
+                participants: IncludeParticipants::All,
+            },
+            total: Money::from_euros(total_euros),
+            author_id: UserId::new(author_id)?,
+            occured_at,
+            currency: None,
+            exchange_rate: None,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(id.value())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_expense_in_currency(
+        &mut self,
+        group_id: Uuid,
+        payer_id: Uuid,
+        total_cents: i64,
+        currency: &str,
+        exchange_rate: Option<&str>,
+        author_id: Uuid,
+        occured_at: DateTime<Utc>,
+    ) -> anyhow::Result<Uuid> {
+        let mut tx = self.pool.begin().await?;
+        let id = CreateExpenseCommand {
+            group_id: GroupId::new(group_id)?,
+            payer_id: UserId::new(payer_id)?,
+            payers: None,
+            title: "Expense".parse()?,
+            note: None,
+            category: ExpenseCategory::default(),
+            category_id: None,
+            split: SplitSpecification::Equal {
+                participants: IncludeParticipants::All,
+            },
+            total: Money::from_cents(total_cents),
+            author_id: UserId::new(author_id)?,
+            occured_at,
+            currency: Some(currency.parse()?),
+            exchange_rate: exchange_rate.map(str::parse).transpose()?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(id.value())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_expense(
+        &mut self,
+        group_id: Uuid,
+        expense_id: Uuid,
+        payer_id: Uuid,
+        total_euros: i64,
+        participants: Vec<Uuid>,
+        author_id: Uuid,
+        occured_at: DateTime<Utc>,
+    ) -> anyhow::Result<Uuid> {
+        let mut tx = self.pool.begin().await?;
+        let id = UpdateExpenseCommand {
+            group_id: GroupId::new(group_id)?,
+            expense_id: ExpenseId::new(expense_id)?,
+            payer_id: UserId::new(payer_id)?,
+            payers: None,
+            title: "Expense".parse()?,
+            note: None,
+            category: ExpenseCategory::default(),
+            category_id: None,
+            split: SplitSpecification::Equal {
+                participants: IncludeParticipants::List {
+                    participants: participants
+                        .into_iter()
+                        .map(UserId::new)
+                        .collect::<Result<_, _>>()?,
+                },
             },
             total: Money::from_euros(total_euros),
             author_id: UserId::new(author_id)?,
             occured_at,
+            currency: None,
+            exchange_rate: None,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(id.value())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_expense_with_split(
+        &mut self,
+        group_id: Uuid,
+        expense_id: Uuid,
+        payer_id: Uuid,
+        total_cents: i64,
+        split: SplitSpecification,
+        author_id: Uuid,
+        occured_at: DateTime<Utc>,
+    ) -> anyhow::Result<Uuid> {
+        let mut tx = self.pool.begin().await?;
+        let id = UpdateExpenseCommand {
+            group_id: GroupId::new(group_id)?,
+            expense_id: ExpenseId::new(expense_id)?,
+            payer_id: UserId::new(payer_id)?,
+            payers: None,
+            title: "Expense".parse()?,
+            note: None,
+            category: ExpenseCategory::default(),
+            category_id: None,
+            split,
+            total: Money::from_cents(total_cents),
+            author_id: UserId::new(author_id)?,
+            occured_at,
+            currency: None,
+            exchange_rate: None,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(id.value())
+    }
+
+    pub async fn delete_expense(
+        &mut self,
+        group_id: Uuid,
+        expense_id: Uuid,
+        current_user_id: Uuid,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        DeleteExpenseCommand {
+            group_id: GroupId::new(group_id)?,
+            expense_id: ExpenseId::new(expense_id)?,
+            current_user_id: UserId::new(current_user_id)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn restore_expense(
+        &mut self,
+        group_id: Uuid,
+        expense_id: Uuid,
+        current_user_id: Uuid,
+    ) -> anyhow::Result<Uuid> {
+        let mut tx = self.pool.begin().await?;
+        let id = RestoreExpenseCommand {
+            group_id: GroupId::new(group_id)?,
+            expense_id: ExpenseId::new(expense_id)?,
+            current_user_id: UserId::new(current_user_id)?,
         }
         .handle(&mut tx)
         .await?;
@@ -70,6 +387,103 @@ impl<'a> ExpenseEntriesHelper<'a> {
         Ok(id.value())
     }
 
+    /// Returns the amounts paid by each payer of a group expense, sorted by user id.
+    pub async fn get_payers(
+        &mut self,
+        group_id: Uuid,
+        expense_id: Uuid,
+        current_user: Uuid,
+    ) -> anyhow::Result<Vec<(Uuid, i64)>> {
+        let mut tx = self.pool.begin().await?;
+        let output = GetExpensesForGroupQuery {
+            group_id: GroupId::new(group_id)?,
+            current_user: UserId::new(current_user)?,
+            pagination: Pagination::new_from_optional(None, None)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        let expense = output
+            .expenses
+            .into_iter()
+            .find(|expense| expense.id.value() == expense_id)
+            .ok_or_else(|| anyhow::anyhow!("expense not found"))?;
+        Ok(expense
+            .payers
+            .into_iter()
+            .map(|paid| (paid.user.id.value(), paid.amount.cents()))
+            .collect())
+    }
+
+    /// Returns the ids of the group expenses listed to the provided user.
+    pub async fn get_expense_ids_for_group(
+        &mut self,
+        group_id: Uuid,
+        current_user: Uuid,
+    ) -> anyhow::Result<Vec<Uuid>> {
+        let mut tx = self.pool.begin().await?;
+        let output = GetExpensesForGroupQuery {
+            group_id: GroupId::new(group_id)?,
+            current_user: UserId::new(current_user)?,
+            pagination: Pagination::new_from_optional(None, None)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(output
+            .expenses
+            .into_iter()
+            .map(|expense| expense.id.value())
+            .collect())
+    }
+
+    pub async fn get_expense_history(
+        &mut self,
+        group_id: Uuid,
+        expense_id: Uuid,
+        current_user: Uuid,
+    ) -> anyhow::Result<ExpenseHistory> {
+        let mut tx = self.pool.begin().await?;
+        let output = GetExpenseHistoryQuery {
+            group_id: GroupId::new(group_id)?,
+            expense_id: ExpenseId::new(expense_id)?,
+            current_user: UserId::new(current_user)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(output)
+    }
+
+    pub async fn export_expenses(
+        &mut self,
+        group_id: Uuid,
+        current_user: Uuid,
+    ) -> anyhow::Result<ExpensesExport> {
+        let mut tx = self.pool.begin().await?;
+        let output = ExportExpensesForGroupQuery {
+            group_id: GroupId::new(group_id)?,
+            current_user: UserId::new(current_user)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(output)
+    }
+
+    /// Returns all entries of the provided expense, sorted by creation date.
+    pub async fn get_all_entries(&mut self, expense_id: Uuid) -> anyhow::Result<Vec<ExpenseEntry>> {
+        let mut tx = self.pool.begin().await?;
+        let mut expense_entries = database::queries::expense_entry::get_all_by_expense_id(
+            &mut tx,
+            &ExpenseId::new(expense_id)?,
+        )
+        .await?;
+        tx.commit().await?;
+        expense_entries.sort_by_key(|entry| entry.created_at);
+        Ok(expense_entries)
+    }
+
     pub async fn assert_expense_has_a_single_entry(
         &mut self,
         expense_id: Uuid,
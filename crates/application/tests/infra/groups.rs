@@ -1,5 +1,18 @@
-use application::commands::{
-    add_group_member::AddGroupMemberCommand, create_empty_group::CreateEmptyGroupCommand,
+use application::{
+    commands::{
+        add_group_member::{AddGroupMemberCommand, UserReference},
+        archive_group::ArchiveGroupCommand,
+        change_member_role::ChangeMemberRoleCommand,
+        claim_guest::ClaimGuestCommand,
+        create_empty_group::CreateEmptyGroupCommand,
+        create_guest::CreateGuestCommand,
+        leave_group::LeaveGroupCommand,
+        remove_group_member::RemoveGroupMemberCommand,
+        transfer_group_ownership::TransferGroupOwnershipCommand,
+        unarchive_group::UnarchiveGroupCommand,
+    },
+    pagination::Pagination,
+    queries::get_groups_for_user::GetGroupsForUserQuery,
 };
 use domain::types::{group_id::GroupId, user_id::UserId};
 use uuid::Uuid;
@@ -17,11 +30,22 @@ impl<'a> GroupsHelper<'a> {
         &mut self,
         groupname: &str,
         user_id: Uuid,
+    ) -> anyhow::Result<Uuid> {
+        self.create_empty_group_with_currency(groupname, user_id, "EUR")
+            .await
+    }
+
+    pub async fn create_empty_group_with_currency(
+        &mut self,
+        groupname: &str,
+        user_id: Uuid,
+        currency: &str,
     ) -> anyhow::Result<Uuid> {
         let mut tx = self.pool.begin().await?;
         let group_id = CreateEmptyGroupCommand {
             groupname: groupname.parse()?,
             owner_id: UserId::new(user_id)?,
+            currency: currency.parse()?,
         }
         .handle(&mut tx)
         .await?;
@@ -34,11 +58,23 @@ impl<'a> GroupsHelper<'a> {
         group_id: Uuid,
         owner_id: Uuid,
         user_to_add: Uuid,
+    ) -> anyhow::Result<()> {
+        self.add_member_with_role(group_id, owner_id, user_to_add, "member")
+            .await
+    }
+
+    pub async fn add_member_with_role(
+        &mut self,
+        group_id: Uuid,
+        owner_id: Uuid,
+        user_to_add: Uuid,
+        role: &str,
     ) -> anyhow::Result<()> {
         let mut tx = self.pool.begin().await?;
         AddGroupMemberCommand {
             group_id: GroupId::new(group_id)?,
-            user_id_to_add: UserId::new(user_to_add)?,
+            user_to_add: UserId::new(user_to_add)?.into(),
+            role: role.parse()?,
             current_user_id: UserId::new(owner_id)?,
         }
         .handle(&mut tx)
@@ -47,6 +83,198 @@ impl<'a> GroupsHelper<'a> {
         Ok(())
     }
 
+    /// Adds a member designated by their handle, or by their email if
+    /// `reference` contains a `@` after its first character.
+    pub async fn add_member_by_reference(
+        &mut self,
+        group_id: Uuid,
+        owner_id: Uuid,
+        reference: &str,
+    ) -> anyhow::Result<()> {
+        let user_to_add = if reference.get(1..).is_some_and(|rest| rest.contains('@')) {
+            UserReference::Email(reference.parse()?)
+        } else {
+            UserReference::Handle(reference.parse()?)
+        };
+        let mut tx = self.pool.begin().await?;
+        AddGroupMemberCommand {
+            group_id: GroupId::new(group_id)?,
+            user_to_add,
+            role: "member".parse()?,
+            current_user_id: UserId::new(owner_id)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn create_guest(
+        &mut self,
+        group_id: Uuid,
+        current_user_id: Uuid,
+        name: &str,
+    ) -> anyhow::Result<Uuid> {
+        let mut tx = self.pool.begin().await?;
+        let guest_id = CreateGuestCommand {
+            group_id: GroupId::new(group_id)?,
+            name: name.parse()?,
+            current_user_id: UserId::new(current_user_id)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(guest_id.value())
+    }
+
+    pub async fn claim_guest(
+        &mut self,
+        group_id: Uuid,
+        current_user_id: Uuid,
+        guest_id: Uuid,
+        member_id: Uuid,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        ClaimGuestCommand {
+            group_id: GroupId::new(group_id)?,
+            guest_id: UserId::new(guest_id)?,
+            member_id: UserId::new(member_id)?,
+            current_user_id: UserId::new(current_user_id)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn change_member_role(
+        &mut self,
+        group_id: Uuid,
+        current_user_id: Uuid,
+        member_id: Uuid,
+        role: &str,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        ChangeMemberRoleCommand {
+            group_id: GroupId::new(group_id)?,
+            member_id: UserId::new(member_id)?,
+            role: role.parse()?,
+            current_user_id: UserId::new(current_user_id)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn remove_member(
+        &mut self,
+        group_id: Uuid,
+        owner_id: Uuid,
+        user_to_remove: Uuid,
+        force: bool,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        RemoveGroupMemberCommand {
+            group_id: GroupId::new(group_id)?,
+            user_id_to_remove: UserId::new(user_to_remove)?,
+            current_user_id: UserId::new(owner_id)?,
+            force,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn leave_group(
+        &mut self,
+        group_id: Uuid,
+        current_user_id: Uuid,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        LeaveGroupCommand {
+            group_id: GroupId::new(group_id)?,
+            current_user_id: UserId::new(current_user_id)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn transfer_ownership(
+        &mut self,
+        group_id: Uuid,
+        owner_id: Uuid,
+        new_owner_id: Uuid,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        TransferGroupOwnershipCommand {
+            group_id: GroupId::new(group_id)?,
+            new_owner_id: UserId::new(new_owner_id)?,
+            current_user_id: UserId::new(owner_id)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn archive_group(
+        &mut self,
+        group_id: Uuid,
+        current_user_id: Uuid,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        ArchiveGroupCommand {
+            group_id: GroupId::new(group_id)?,
+            current_user_id: UserId::new(current_user_id)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn unarchive_group(
+        &mut self,
+        group_id: Uuid,
+        current_user_id: Uuid,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        UnarchiveGroupCommand {
+            group_id: GroupId::new(group_id)?,
+            current_user_id: UserId::new(current_user_id)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Returns the ids of the groups of a user, archived or in use.
+    pub async fn get_group_ids_for_user(
+        &mut self,
+        current_user: Uuid,
+        archived: bool,
+    ) -> anyhow::Result<Vec<Uuid>> {
+        let mut tx = self.pool.begin().await?;
+        let output = GetGroupsForUserQuery {
+            current_user: UserId::new(current_user)?,
+            archived,
+            pagination: Pagination::new_from_optional(None, None)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(output
+            .groups
+            .into_iter()
+            .map(|group| group.id.value())
+            .collect())
+    }
+
     pub async fn assert_group_exists(
         &mut self,
         groupname: &str,
@@ -82,4 +310,18 @@ impl<'a> GroupsHelper<'a> {
         }
         Ok(())
     }
+
+    pub async fn assert_group_does_not_contain_member(
+        &mut self,
+        group_id: Uuid,
+        user_id: Uuid,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        let group = database::queries::group::get_by_id(&mut tx, &GroupId::new(group_id)?).await?;
+        tx.commit().await?;
+
+        assert!(group.is_some(), "group not found");
+        assert!(!group.unwrap().contains_user(&UserId::new(user_id)?));
+        Ok(())
+    }
 }
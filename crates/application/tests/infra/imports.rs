@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+use application::{
+    commands::import_expenses::{ImportExpensesCommand, Output},
+    import::ImportFormat,
+};
+use domain::types::{group_id::GroupId, user_id::UserId};
+use uuid::Uuid;
+
+pub struct ImportsHelper<'a> {
+    pool: &'a database::SqlitePool,
+}
+
+impl<'a> ImportsHelper<'a> {
+    pub(super) fn new(pool: &'a database::SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Imports a file, mapping each person name to a user id.
+    pub async fn import_expenses(
+        &mut self,
+        group_id: Uuid,
+        current_user_id: Uuid,
+        format: ImportFormat,
+        content: &str,
+        mapping: &[(&str, Uuid)],
+        dry_run: bool,
+    ) -> anyhow::Result<Output> {
+        let mut tx = self.pool.begin().await?;
+        let output = ImportExpensesCommand {
+            group_id: GroupId::new(group_id)?,
+            current_user_id: UserId::new(current_user_id)?,
+            format,
+            content: content.to_string(),
+            mapping: mapping
+                .iter()
+                .map(|(name, user_id)| Ok((name.to_string(), UserId::new(*user_id)?)))
+                .collect::<anyhow::Result<HashMap<_, _>>>()?,
+            exchange_rates: HashMap::new(),
+            dry_run,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(output)
+    }
+}
@@ -0,0 +1,136 @@
+use application::{
+    commands::{
+        accept_invite::AcceptInviteCommand, create_invite::CreateInviteCommand,
+        revoke_invite::RevokeInviteCommand,
+    },
+    queries::get_invites_for_group::GetInvitesForGroupQuery,
+};
+use chrono::{DateTime, Duration, Utc};
+use domain::{
+    entities::Invite,
+    types::{
+        group_id::GroupId, invite_id::InviteId, invite_token::InviteToken, member_role::MemberRole,
+        user_id::UserId,
+    },
+};
+use uuid::Uuid;
+
+pub struct InvitesHelper<'a> {
+    pool: &'a database::SqlitePool,
+}
+
+impl<'a> InvitesHelper<'a> {
+    pub(super) fn new(pool: &'a database::SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Creates an invite giving the member role, expiring in a week.
+    pub async fn create_invite(
+        &mut self,
+        group_id: Uuid,
+        current_user_id: Uuid,
+        max_uses: Option<u32>,
+    ) -> anyhow::Result<Invite> {
+        self.create_invite_with(
+            group_id,
+            current_user_id,
+            "member",
+            max_uses,
+            Utc::now() + Duration::days(7),
+        )
+        .await
+    }
+
+    pub async fn create_invite_with(
+        &mut self,
+        group_id: Uuid,
+        current_user_id: Uuid,
+        role: &str,
+        max_uses: Option<u32>,
+        expires_at: DateTime<Utc>,
+    ) -> anyhow::Result<Invite> {
+        let mut tx = self.pool.begin().await?;
+        let invite = CreateInviteCommand {
+            group_id: GroupId::new(group_id)?,
+            current_user_id: UserId::new(current_user_id)?,
+            role: role.parse()?,
+            max_uses,
+            expires_at,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(invite)
+    }
+
+    /// Inserts an invite which expired a second ago, as commands do not
+    /// allow creating one.
+    pub async fn create_expired_invite(
+        &mut self,
+        group_id: Uuid,
+        author_id: Uuid,
+    ) -> anyhow::Result<Invite> {
+        let now = Utc::now();
+        let invite = Invite::new(
+            InviteId::new_random(),
+            GroupId::new(group_id)?,
+            InviteToken::new_random(),
+            MemberRole::Member,
+            None,
+            0,
+            UserId::new(author_id)?,
+            now - Duration::seconds(1),
+            now - Duration::days(7),
+        );
+        let mut tx = self.pool.begin().await?;
+        database::queries::invite::create(&mut tx, &invite).await?;
+        tx.commit().await?;
+        Ok(invite)
+    }
+
+    pub async fn accept_invite(&mut self, token: &str, user_id: Uuid) -> anyhow::Result<Uuid> {
+        let mut tx = self.pool.begin().await?;
+        let group_id = AcceptInviteCommand {
+            token: token.parse()?,
+            current_user_id: UserId::new(user_id)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(group_id.value())
+    }
+
+    pub async fn revoke_invite(
+        &mut self,
+        group_id: Uuid,
+        invite_id: Uuid,
+        current_user_id: Uuid,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        RevokeInviteCommand {
+            group_id: GroupId::new(group_id)?,
+            invite_id: InviteId::new(invite_id)?,
+            current_user_id: UserId::new(current_user_id)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn get_invites(
+        &mut self,
+        group_id: Uuid,
+        current_user_id: Uuid,
+    ) -> anyhow::Result<Vec<Invite>> {
+        let mut tx = self.pool.begin().await?;
+        let invites = GetInvitesForGroupQuery {
+            group_id: GroupId::new(group_id)?,
+            current_user: UserId::new(current_user_id)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(invites)
+    }
+}
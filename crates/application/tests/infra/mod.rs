@@ -1,8 +1,17 @@
 #![allow(unused)]
 
+pub mod attachments;
+pub mod backups;
+pub mod balances;
+pub mod budgets;
+pub mod categories;
 pub mod ctx;
 pub mod dates;
 pub mod db;
 pub mod expense_entries;
 pub mod groups;
+pub mod imports;
+pub mod invites;
+pub mod recurring_expenses;
+pub mod settlements;
 pub mod users;
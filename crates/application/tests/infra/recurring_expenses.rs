@@ -0,0 +1,161 @@
+use application::{
+    commands::{
+        create_expense::{IncludeParticipants, SplitSpecification},
+        create_recurring_expense::CreateRecurringExpenseCommand,
+        delete_recurring_expense::DeleteRecurringExpenseCommand,
+        materialize_recurring_expenses::{
+            MaterializeRecurringExpensesCommand, Output as MaterializeOutput,
+        },
+        update_recurring_expense::UpdateRecurringExpenseCommand,
+    },
+    queries::get_recurring_expenses_for_group::GetRecurringExpensesForGroupQuery,
+};
+use chrono::{DateTime, Utc};
+use domain::{
+    entities::RecurringExpense,
+    types::{
+        expense_category::ExpenseCategory,
+        group_id::GroupId,
+        money::Money,
+        recurrence::{Frequency, Recurrence},
+        recurring_expense_id::RecurringExpenseId,
+        user_id::UserId,
+    },
+};
+use uuid::Uuid;
+
+pub struct RecurringExpensesHelper<'a> {
+    pool: &'a database::SqlitePool,
+}
+
+impl<'a> RecurringExpensesHelper<'a> {
+    pub(super) fn new(pool: &'a database::SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Creates a monthly recurring expense split equally between all group
+    /// members.
+    pub async fn create_monthly_recurring_expense(
+        &mut self,
+        group_id: Uuid,
+        payer_id: Uuid,
+        total_euros: i64,
+        author_id: Uuid,
+        starts_at: DateTime<Utc>,
+        ends_at: Option<DateTime<Utc>>,
+    ) -> anyhow::Result<Uuid> {
+        let mut tx = self.pool.begin().await?;
+        let id = CreateRecurringExpenseCommand {
+            group_id: GroupId::new(group_id)?,
+            payer_id: UserId::new(payer_id)?,
+            author_id: UserId::new(author_id)?,
+            title: "Rent".parse()?,
+            note: None,
+            category: ExpenseCategory::Lodging,
+            category_id: None,
+            split: SplitSpecification::Equal {
+                participants: IncludeParticipants::All,
+            },
+            total: Money::from_euros(total_euros),
+            currency: None,
+            exchange_rate: None,
+            recurrence: Recurrence::new(Frequency::Monthly, 1, starts_at, ends_at)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(id.value())
+    }
+
+    /// Replaces the amount and the schedule of a recurring expense, keeping
+    /// it split equally between all group members.
+    pub async fn update_recurring_expense(
+        &mut self,
+        group_id: Uuid,
+        recurring_expense_id: Uuid,
+        current_user_id: Uuid,
+        payer_id: Uuid,
+        total_euros: i64,
+        recurrence: Recurrence,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        UpdateRecurringExpenseCommand {
+            group_id: GroupId::new(group_id)?,
+            recurring_expense_id: RecurringExpenseId::new(recurring_expense_id)?,
+            current_user_id: UserId::new(current_user_id)?,
+            payer_id: UserId::new(payer_id)?,
+            title: "Rent".parse()?,
+            note: None,
+            category: ExpenseCategory::Lodging,
+            category_id: None,
+            split: SplitSpecification::Equal {
+                participants: IncludeParticipants::All,
+            },
+            total: Money::from_euros(total_euros),
+            currency: None,
+            exchange_rate: None,
+            recurrence,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn delete_recurring_expense(
+        &mut self,
+        group_id: Uuid,
+        recurring_expense_id: Uuid,
+        current_user_id: Uuid,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        DeleteRecurringExpenseCommand {
+            group_id: GroupId::new(group_id)?,
+            recurring_expense_id: RecurringExpenseId::new(recurring_expense_id)?,
+            current_user_id: UserId::new(current_user_id)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn get_recurring_expenses_for_group(
+        &mut self,
+        group_id: Uuid,
+        current_user: Uuid,
+    ) -> anyhow::Result<Vec<RecurringExpense>> {
+        let mut tx = self.pool.begin().await?;
+        let output = GetRecurringExpensesForGroupQuery {
+            group_id: GroupId::new(group_id)?,
+            current_user: UserId::new(current_user)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(output.recurring_expenses)
+    }
+
+    pub async fn materialize(&mut self, now: DateTime<Utc>) -> anyhow::Result<MaterializeOutput> {
+        let mut tx = self.pool.begin().await?;
+        let output = MaterializeRecurringExpensesCommand { now }
+            .handle(&mut tx)
+            .await?;
+        tx.commit().await?;
+        Ok(output)
+    }
+
+    pub async fn assert_recurring_expense_exists(
+        &mut self,
+        recurring_expense_id: Uuid,
+    ) -> anyhow::Result<RecurringExpense> {
+        let mut tx = self.pool.begin().await?;
+        let recurring_expense = database::queries::recurring_expense::get_by_id(
+            &mut tx,
+            &RecurringExpenseId::new(recurring_expense_id)?,
+        )
+        .await?;
+        tx.commit().await?;
+        Ok(recurring_expense.expect("recurring expense should exist"))
+    }
+}
@@ -0,0 +1,94 @@
+use application::{
+    commands::{
+        cancel_settlement::CancelSettlementCommand, record_settlement::RecordSettlementCommand,
+    },
+    pagination::Pagination,
+    queries::get_settlements_for_group::{GetSettlementsForGroupQuery, Output},
+};
+use chrono::{DateTime, Utc};
+use domain::{
+    entities::Settlement,
+    types::{group_id::GroupId, money::Money, settlement_id::SettlementId, user_id::UserId},
+};
+use uuid::Uuid;
+
+pub struct SettlementsHelper<'a> {
+    pool: &'a database::SqlitePool,
+}
+
+impl<'a> SettlementsHelper<'a> {
+    pub(super) fn new(pool: &'a database::SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn record_settlement(
+        &mut self,
+        group_id: Uuid,
+        from: Uuid,
+        to: Uuid,
+        amount_cents: i64,
+        author_id: Uuid,
+        occurred_at: DateTime<Utc>,
+    ) -> anyhow::Result<Uuid> {
+        let mut tx = self.pool.begin().await?;
+        let id = RecordSettlementCommand {
+            group_id: GroupId::new(group_id)?,
+            from: UserId::new(from)?,
+            to: UserId::new(to)?,
+            amount: Money::from_cents(amount_cents),
+            author_id: UserId::new(author_id)?,
+            occurred_at,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(id.value())
+    }
+
+    pub async fn cancel_settlement(
+        &mut self,
+        group_id: Uuid,
+        settlement_id: Uuid,
+        current_user_id: Uuid,
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        CancelSettlementCommand {
+            group_id: GroupId::new(group_id)?,
+            settlement_id: SettlementId::new(settlement_id)?,
+            current_user_id: UserId::new(current_user_id)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    pub async fn get_settlements_for_group(
+        &mut self,
+        group_id: Uuid,
+        current_user: Uuid,
+    ) -> anyhow::Result<Output> {
+        let mut tx = self.pool.begin().await?;
+        let output = GetSettlementsForGroupQuery {
+            group_id: GroupId::new(group_id)?,
+            current_user: UserId::new(current_user)?,
+            pagination: Pagination::new_from_optional(None, None)?,
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(output)
+    }
+
+    pub async fn assert_settlement_exists(
+        &mut self,
+        settlement_id: Uuid,
+    ) -> anyhow::Result<Settlement> {
+        let mut tx = self.pool.begin().await?;
+        let settlement =
+            database::queries::settlement::get_by_id(&mut tx, &SettlementId::new(settlement_id)?)
+                .await?;
+        tx.commit().await?;
+        Ok(settlement.expect("settlement should exist"))
+    }
+}
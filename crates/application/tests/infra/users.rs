@@ -1,6 +1,8 @@
 use std::str::FromStr;
 
-use application::commands::create_user::CreateUserCommand;
+use application::{
+    commands::create_user::CreateUserCommand, queries::search_users::SearchUsersQuery,
+};
 use domain::types::{role::Role, user_id::UserId};
 use email_address::EmailAddress;
 use uuid::Uuid;
@@ -28,11 +30,54 @@ impl<'a> UsersHelper<'a> {
         let mut tx = self.pool.begin().await?;
         let name = username.parse()?;
         let email = EmailAddress::from_str(email)?;
-        let user_id = CreateUserCommand { email, name }.handle(&mut tx).await?;
+        let user_id = CreateUserCommand {
+            email,
+            name,
+            handle: None,
+        }
+        .handle(&mut tx)
+        .await?;
         tx.commit().await?;
         Ok(user_id.value())
     }
 
+    pub async fn create_user_with_handle(
+        &mut self,
+        username: &str,
+        handle: &str,
+    ) -> anyhow::Result<Uuid> {
+        let mut tx = self.pool.begin().await?;
+        let user_id = CreateUserCommand {
+            email: EmailAddress::from_str(&format!("{}@gmail.com", username))?,
+            name: username.parse()?,
+            handle: Some(handle.parse()?),
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(user_id.value())
+    }
+
+    /// Returns the handle of a user, without its leading `@`.
+    pub async fn get_handle(&mut self, user_id: Uuid) -> anyhow::Result<String> {
+        let mut tx = self.pool.begin().await?;
+        let user = database::queries::user::get_by_id(&mut tx, &UserId::new(user_id)?).await?;
+        tx.commit().await?;
+        Ok(user.expect("user exists").handle.value())
+    }
+
+    /// Returns the ids of the users found.
+    pub async fn search(&mut self, text: &str) -> anyhow::Result<Vec<Uuid>> {
+        let mut tx = self.pool.begin().await?;
+        let users = SearchUsersQuery {
+            text: text.to_string(),
+        }
+        .handle(&mut tx)
+        .await?;
+        tx.commit().await?;
+        Ok(users.into_iter().map(|user| user.id.value()).collect())
+    }
+
     pub async fn assert_user_exists(
         &mut self,
         user_id: Uuid,
@@ -45,7 +90,10 @@ impl<'a> UsersHelper<'a> {
         assert!(user.is_some());
         let user = user.unwrap();
         assert_eq!(username, user.name.value());
-        assert_eq!(email, user.email.email());
+        assert_eq!(
+            Some(email),
+            user.email.map(|email| email.email()).as_deref()
+        );
         assert_eq!(role, user.role);
         tx.commit().await?;
         Ok(())
@@ -0,0 +1,128 @@
+use application::commands::leave_group::LeaveGroupError;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+
+    // When
+    ctx.groups().leave_group(group_id, bob_id).await?;
+
+    // Then
+    ctx.groups()
+        .assert_group_does_not_contain_member(group_id, bob_id)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn owner_cannot_leave_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "owner_cannot_leave_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .leave_group(group_id, alice_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        LeaveGroupError::OwnerCannotLeave.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn user_not_in_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "user_not_in_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .leave_group(group_id, bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(LeaveGroupError::GroupNotFound.to_string(), err.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn balance_must_be_settled() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "balance_must_be_settled").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(
+            group_id,
+            alice_id,
+            100,
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .leave_group(group_id, bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        LeaveGroupError::BalanceNotSettled.to_string(),
+        err.to_string()
+    );
+    ctx.groups()
+        .assert_group_contains_members(group_id, vec![bob_id])
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,284 @@
+use application::commands::create_expense::CreateExpenseError;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn catches_up_on_missed_occurrences() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "catches_up_on_missed_occurrences").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", bob_id).await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    let recurring_expense_id = ctx
+        .recurring_expenses()
+        .create_monthly_recurring_expense(
+            group_id,
+            bob_id,
+            1_200,
+            alice_id,
+            dates::jan_31_2025(),
+            None,
+        )
+        .await?;
+
+    // When
+    let output = ctx
+        .recurring_expenses()
+        .materialize(dates::apr_15_2025())
+        .await?;
+
+    // Then
+    assert!(output.skipped.is_empty());
+    assert_eq!(3, output.created.len());
+    let mut occurrences = vec![];
+    for expense_id in &output.created {
+        let entry = ctx
+            .expense_entries()
+            .assert_expense_has_a_single_entry(expense_id.value())
+            .await?;
+        assert_eq!(bob_id, entry.payer_id.value());
+        assert_eq!(alice_id, entry.author_id.value());
+        assert_eq!("Rent", entry.title.value());
+        assert_eq!(1_200, entry.total.euros());
+        occurrences.push(entry.occurred_at);
+    }
+    assert_eq!(
+        vec![
+            dates::jan_31_2025(),
+            dates::feb_28_2025(),
+            dates::mar_31_2025()
+        ],
+        occurrences
+    );
+
+    let recurring_expense = ctx
+        .recurring_expenses()
+        .assert_recurring_expense_exists(recurring_expense_id)
+        .await?;
+    assert_eq!(
+        Some(dates::apr_30_2025()),
+        recurring_expense.next_occurrence_at
+    );
+
+    let balances = ctx.balances().get_group_balances(group_id, bob_id).await?;
+    assert_eq!(Some(&180_000), balances.get(&bob_id));
+    assert_eq!(Some(&-180_000), balances.get(&alice_id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn running_twice_does_not_add_occurrences_twice() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "running_twice_does_not_add_occurrences_twice").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", bob_id).await?;
+    ctx.recurring_expenses()
+        .create_monthly_recurring_expense(group_id, bob_id, 40, bob_id, dates::jan_31_2025(), None)
+        .await?;
+    ctx.recurring_expenses()
+        .materialize(dates::apr_15_2025())
+        .await?;
+
+    // When
+    let output = ctx
+        .recurring_expenses()
+        .materialize(dates::apr_15_2025())
+        .await?;
+
+    // Then
+    assert!(output.created.is_empty());
+    assert!(output.skipped.is_empty());
+    let expense_ids = ctx
+        .expense_entries()
+        .get_expense_ids_for_group(group_id, bob_id)
+        .await?;
+    assert_eq!(3, expense_ids.len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn stops_at_end_of_schedule() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "stops_at_end_of_schedule").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", bob_id).await?;
+    let recurring_expense_id = ctx
+        .recurring_expenses()
+        .create_monthly_recurring_expense(
+            group_id,
+            bob_id,
+            40,
+            bob_id,
+            dates::jan_31_2025(),
+            Some(dates::feb_28_2025()),
+        )
+        .await?;
+
+    // When
+    let output = ctx
+        .recurring_expenses()
+        .materialize(dates::apr_15_2025())
+        .await?;
+
+    // Then
+    assert_eq!(2, output.created.len());
+    let recurring_expense = ctx
+        .recurring_expenses()
+        .assert_recurring_expense_exists(recurring_expense_id)
+        .await?;
+    assert_eq!(None, recurring_expense.next_occurrence_at);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn nothing_added_before_first_occurrence() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "nothing_added_before_first_occurrence").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", bob_id).await?;
+    ctx.recurring_expenses()
+        .create_monthly_recurring_expense(group_id, bob_id, 40, bob_id, dates::apr_30_2025(), None)
+        .await?;
+
+    // When
+    let output = ctx
+        .recurring_expenses()
+        .materialize(dates::apr_15_2025())
+        .await?;
+
+    // Then
+    assert!(output.created.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn failing_recurring_expense_does_not_block_others() -> anyhow::Result<()> {
+    let db_pool =
+        build_test_database(FILE, "failing_recurring_expense_does_not_block_others").await?;
+    // expenses of 666 euros fail once their entry has been inserted
+    sqlx::query(
+        r#"
+        CREATE TRIGGER fail_expense_of_666_euros
+        BEFORE INSERT ON expense_entry_payer
+        WHEN (SELECT total FROM expense_entry WHERE id = NEW.expense_entry_id) = 66600
+        BEGIN
+            SELECT RAISE(ABORT, 'boom');
+        END
+        "#,
+    )
+    .execute(&db_pool)
+    .await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let failing_group_id = ctx.groups().create_empty_group("Flat", bob_id).await?;
+    let group_id = ctx.groups().create_empty_group("Gym", alice_id).await?;
+    let failing_id = ctx
+        .recurring_expenses()
+        .create_monthly_recurring_expense(
+            failing_group_id,
+            bob_id,
+            666,
+            bob_id,
+            dates::jan_31_2025(),
+            None,
+        )
+        .await?;
+    ctx.recurring_expenses()
+        .create_monthly_recurring_expense(
+            group_id,
+            alice_id,
+            40,
+            alice_id,
+            dates::jan_31_2025(),
+            None,
+        )
+        .await?;
+
+    // When
+    let output = ctx
+        .recurring_expenses()
+        .materialize(dates::apr_15_2025())
+        .await?;
+
+    // Then
+    assert_eq!(3, output.created.len());
+    assert_eq!(3, output.skipped.len());
+    for skipped in &output.skipped {
+        assert_eq!(failing_id, skipped.recurring_expense_id.value());
+        assert!(matches!(skipped.reason, CreateExpenseError::Database(_)));
+    }
+    let expense_ids = ctx
+        .expense_entries()
+        .get_expense_ids_for_group(failing_group_id, bob_id)
+        .await?;
+    assert!(expense_ids.is_empty());
+    let expense_ids = ctx
+        .expense_entries()
+        .get_expense_ids_for_group(group_id, alice_id)
+        .await?;
+    assert_eq!(3, expense_ids.len());
+
+    let recurring_expense = ctx
+        .recurring_expenses()
+        .assert_recurring_expense_exists(failing_id)
+        .await?;
+    assert_eq!(
+        Some(dates::apr_30_2025()),
+        recurring_expense.next_occurrence_at
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn archived_group_is_caught_up_once_unarchived() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "archived_group_is_caught_up_once_unarchived").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", bob_id).await?;
+    ctx.recurring_expenses()
+        .create_monthly_recurring_expense(group_id, bob_id, 40, bob_id, dates::jan_31_2025(), None)
+        .await?;
+    ctx.groups().archive_group(group_id, bob_id).await?;
+    let output = ctx
+        .recurring_expenses()
+        .materialize(dates::apr_15_2025())
+        .await?;
+    assert!(output.created.is_empty());
+    assert!(output.skipped.is_empty());
+
+    // When
+    ctx.groups().unarchive_group(group_id, bob_id).await?;
+    let output = ctx
+        .recurring_expenses()
+        .materialize(dates::apr_15_2025())
+        .await?;
+
+    // Then
+    assert_eq!(3, output.created.len());
+    assert!(output.skipped.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use chrono::{Duration, Utc};
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let deleted_expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 30, bob_id, dates::jan_08_2025())
+        .await?;
+    let purged_attachment_id = ctx
+        .attachments()
+        .create_attachment(group_id, deleted_expense_id, bob_id, "receipt.jpg", 2_048)
+        .await?;
+    let attachment_id = ctx
+        .attachments()
+        .create_attachment(group_id, expense_id, bob_id, "ticket.jpg", 1_024)
+        .await?;
+    ctx.expense_entries()
+        .delete_expense(group_id, deleted_expense_id, bob_id)
+        .await?;
+
+    // When
+    let purged = ctx
+        .attachments()
+        .purge_deleted_expense_attachments(Utc::now() + Duration::minutes(1))
+        .await?;
+
+    // Then
+    assert_eq!(1, purged);
+    assert!(!ctx.attachments().exists(purged_attachment_id).await?);
+    assert!(ctx.attachments().exists(attachment_id).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn recently_deleted_expenses_keep_their_attachments() -> anyhow::Result<()> {
+    let db_pool =
+        build_test_database(FILE, "recently_deleted_expenses_keep_their_attachments").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+    let attachment_id = ctx
+        .attachments()
+        .create_attachment(group_id, expense_id, bob_id, "receipt.jpg", 2_048)
+        .await?;
+    ctx.expense_entries()
+        .delete_expense(group_id, expense_id, bob_id)
+        .await?;
+
+    // When
+    let purged = ctx
+        .attachments()
+        .purge_deleted_expense_attachments(Utc::now() - Duration::days(30))
+        .await?;
+
+    // Then
+    assert_eq!(0, purged);
+    ctx.expense_entries()
+        .restore_expense(group_id, expense_id, bob_id)
+        .await?;
+    let attachment_ids = ctx
+        .attachments()
+        .get_attachment_ids_for_expense(group_id, expense_id, bob_id)
+        .await?;
+    assert_eq!(vec![attachment_id], attachment_ids);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn restored_expenses_keep_their_attachments() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "restored_expenses_keep_their_attachments").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+    let attachment_id = ctx
+        .attachments()
+        .create_attachment(group_id, expense_id, bob_id, "receipt.jpg", 2_048)
+        .await?;
+    ctx.expense_entries()
+        .delete_expense(group_id, expense_id, bob_id)
+        .await?;
+    ctx.expense_entries()
+        .restore_expense(group_id, expense_id, bob_id)
+        .await?;
+
+    // When
+    let purged = ctx
+        .attachments()
+        .purge_deleted_expense_attachments(Utc::now() + Duration::minutes(1))
+        .await?;
+
+    // Then
+    assert_eq!(0, purged);
+    assert!(ctx.attachments().exists(attachment_id).await?);
+
+    Ok(())
+}
@@ -0,0 +1,267 @@
+use application::commands::record_settlement::RecordSettlementError;
+use domain::types::settlement_status::SettlementStatus;
+use uuid::Uuid;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+
+    // When
+    let settlement_id = ctx
+        .settlements()
+        .record_settlement(
+            group_id,
+            alice_id,
+            bob_id,
+            2_000,
+            alice_id,
+            dates::jan_10_2025(),
+        )
+        .await?;
+
+    // Then
+    let settlement = ctx
+        .settlements()
+        .assert_settlement_exists(settlement_id)
+        .await?;
+    assert_eq!(group_id, settlement.group_id.value());
+    assert_eq!(alice_id, settlement.from.value());
+    assert_eq!(bob_id, settlement.to.value());
+    assert_eq!(2_000, settlement.amount.cents());
+    assert_eq!(alice_id, settlement.author_id.value());
+    assert_eq!(dates::jan_10_2025(), settlement.occurred_at);
+    assert_eq!(SettlementStatus::Active, settlement.status);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn settlement_is_included_in_balances() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "settlement_is_included_in_balances").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 60, bob_id, dates::jan_08_2025())
+        .await?;
+
+    // When
+    ctx.settlements()
+        .record_settlement(
+            group_id,
+            alice_id,
+            bob_id,
+            2_000,
+            alice_id,
+            dates::jan_10_2025(),
+        )
+        .await?;
+
+    // Then
+    let balances = ctx.balances().get_group_balances(group_id, bob_id).await?;
+    assert_eq!(Some(&1_000), balances.get(&bob_id));
+    assert_eq!(Some(&-1_000), balances.get(&alice_id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn group_not_found() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "group_not_found").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+
+    // When
+    let err = ctx
+        .settlements()
+        .record_settlement(
+            Uuid::now_v7(),
+            alice_id,
+            bob_id,
+            2_000,
+            alice_id,
+            dates::jan_10_2025(),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        RecordSettlementError::GroupNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[rstest::rstest]
+#[case(0)]
+#[case(-100)]
+#[tokio::test]
+async fn invalid_amount(#[case] amount_cents: i64) -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, &format!("invalid_amount_{amount_cents}")).await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+
+    // When
+    let err = ctx
+        .settlements()
+        .record_settlement(
+            group_id,
+            alice_id,
+            bob_id,
+            amount_cents,
+            alice_id,
+            dates::jan_10_2025(),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        RecordSettlementError::InvalidAmount.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn same_user() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "same_user").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .settlements()
+        .record_settlement(
+            group_id,
+            bob_id,
+            bob_id,
+            2_000,
+            bob_id,
+            dates::jan_10_2025(),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(RecordSettlementError::SameUser.to_string(), err.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn receiver_not_in_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "receiver_not_in_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .settlements()
+        .record_settlement(
+            group_id,
+            bob_id,
+            alice_id,
+            2_000,
+            bob_id,
+            dates::jan_10_2025(),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        RecordSettlementError::ReceiverNotInGroup.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn author_not_in_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "author_not_in_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+
+    // When
+    let err = ctx
+        .settlements()
+        .record_settlement(
+            group_id,
+            alice_id,
+            bob_id,
+            2_000,
+            charlie_id,
+            dates::jan_10_2025(),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        RecordSettlementError::AuthorNotInGroup.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,285 @@
+use application::commands::remove_group_member::RemoveGroupMemberError;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(
+            group_id,
+            alice_id,
+            100,
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+    ctx.settlements()
+        .record_settlement(
+            group_id,
+            bob_id,
+            alice_id,
+            5_000,
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // When
+    ctx.groups()
+        .remove_member(group_id, alice_id, bob_id, false)
+        .await?;
+
+    // Then
+    ctx.groups()
+        .assert_group_does_not_contain_member(group_id, bob_id)
+        .await?;
+    let expense_ids = ctx
+        .expense_entries()
+        .get_expense_ids_for_group(group_id, alice_id)
+        .await?;
+    assert_eq!(1, expense_ids.len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_owner_and_admins_can_remove_member() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "only_owner_and_admins_can_remove_member").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+    ctx.groups()
+        .add_member(group_id, alice_id, charlie_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .remove_member(group_id, bob_id, charlie_id, false)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        RemoveGroupMemberError::NotAdmin.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn user_must_be_member() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "user_must_be_member").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .remove_member(group_id, alice_id, bob_id, false)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        RemoveGroupMemberError::NotMember.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn balance_must_be_settled() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "balance_must_be_settled").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(
+            group_id,
+            alice_id,
+            100,
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .remove_member(group_id, alice_id, bob_id, false)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        RemoveGroupMemberError::BalanceNotSettled.to_string(),
+        err.to_string()
+    );
+    ctx.groups()
+        .assert_group_contains_members(group_id, vec![bob_id])
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn forced_removal_keeps_balance() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "forced_removal_keeps_balance").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(
+            group_id,
+            alice_id,
+            100,
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // When
+    ctx.groups()
+        .remove_member(group_id, alice_id, bob_id, true)
+        .await?;
+
+    // Then
+    ctx.groups()
+        .assert_group_does_not_contain_member(group_id, bob_id)
+        .await?;
+    let balances = ctx
+        .balances()
+        .get_group_balances(group_id, alice_id)
+        .await?;
+    assert_eq!(5_000, balances[&alice_id]);
+    assert_eq!(-5_000, balances[&bob_id]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_owner_can_force_removal() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "only_owner_can_force_removal").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+    ctx.groups()
+        .add_member(group_id, alice_id, charlie_id)
+        .await?;
+    ctx.groups()
+        .change_member_role(group_id, alice_id, charlie_id, "admin")
+        .await?;
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(
+            group_id,
+            alice_id,
+            90,
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .remove_member(group_id, charlie_id, bob_id, true)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        RemoveGroupMemberError::ForceNotOwner.to_string(),
+        err.to_string()
+    );
+    ctx.groups()
+        .assert_group_contains_members(group_id, vec![bob_id, charlie_id])
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn archived_group_rejects_removal() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "archived_group_rejects_removal").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+    ctx.groups().archive_group(group_id, alice_id).await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .remove_member(group_id, alice_id, bob_id, true)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        RemoveGroupMemberError::GroupArchived.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use application::commands::restore_expense::RestoreExpenseError;
+use domain::types::expense_entry_status::ExpenseEntryStatus;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+    ctx.expense_entries()
+        .delete_expense(group_id, expense_id, alice_id)
+        .await?;
+
+    // When
+    let restored_entry_id = ctx
+        .expense_entries()
+        .restore_expense(group_id, expense_id, bob_id)
+        .await?;
+
+    // Then
+    let entries = ctx.expense_entries().get_all_entries(expense_id).await?;
+    assert_eq!(3, entries.len());
+    let (original, tombstone, restored) = (&entries[0], &entries[1], &entries[2]);
+    assert!(matches!(
+        tombstone.status,
+        ExpenseEntryStatus::Deleted { .. }
+    ));
+    assert_eq!(restored_entry_id, restored.id.value());
+    assert_eq!(ExpenseEntryStatus::Active, restored.status);
+    assert_eq!(bob_id, restored.author_id.value());
+    assert_eq!(original.total, restored.total);
+    assert_eq!(original.payer_id, restored.payer_id);
+    assert_eq!(original.participants, restored.participants);
+    assert_eq!(original.occurred_at, restored.occurred_at);
+
+    let expense_ids = ctx
+        .expense_entries()
+        .get_expense_ids_for_group(group_id, bob_id)
+        .await?;
+    assert_eq!(vec![expense_id], expense_ids);
+
+    let balances = ctx.balances().get_group_balances(group_id, bob_id).await?;
+    assert_eq!(Some(&4_500), balances.get(&bob_id));
+    assert_eq!(Some(&-4_500), balances.get(&alice_id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn delete_again_after_restore() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "delete_again_after_restore").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+    ctx.expense_entries()
+        .delete_expense(group_id, expense_id, bob_id)
+        .await?;
+    ctx.expense_entries()
+        .restore_expense(group_id, expense_id, bob_id)
+        .await?;
+
+    // When
+    ctx.expense_entries()
+        .delete_expense(group_id, expense_id, bob_id)
+        .await?;
+    ctx.expense_entries()
+        .restore_expense(group_id, expense_id, bob_id)
+        .await?;
+
+    // Then
+    let entries = ctx.expense_entries().get_all_entries(expense_id).await?;
+    assert_eq!(5, entries.len());
+    assert_eq!(
+        1,
+        entries
+            .iter()
+            .filter(|entry| entry.status == ExpenseEntryStatus::Active)
+            .count()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn not_deleted() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "not_deleted").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .restore_expense(group_id, expense_id, bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(RestoreExpenseError::NotDeleted.to_string(), err.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn user_not_in_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "user_not_in_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+    ctx.expense_entries()
+        .delete_expense(group_id, expense_id, bob_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .restore_expense(group_id, expense_id, alice_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(RestoreExpenseError::Forbidden.to_string(), err.to_string());
+
+    Ok(())
+}
@@ -0,0 +1,266 @@
+use application::{
+    backup::Error as BackupError, commands::restore_group_backup::RestoreGroupBackupError,
+};
+use domain::types::user_id::UserId;
+use uuid::Uuid;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+/// Creates a group owned by Alice, with Bob as member, an updated expense
+/// and a settlement, and returns its backup.
+async fn create_backed_up_group(
+    ctx: &TestContext,
+) -> anyhow::Result<(Uuid, Uuid, Uuid, serde_json::Value)> {
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(
+            group_id,
+            alice_id,
+            30,
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+    ctx.expense_entries()
+        .update_expense(
+            group_id,
+            expense_id,
+            alice_id,
+            60,
+            vec![alice_id, bob_id],
+            bob_id,
+            dates::jan_10_2025(),
+        )
+        .await?;
+    ctx.settlements()
+        .record_settlement(
+            group_id,
+            bob_id,
+            alice_id,
+            1_000,
+            bob_id,
+            dates::jan_10_2025(),
+        )
+        .await?;
+
+    let document = ctx.backups().get_group_backup(group_id, alice_id).await?;
+    Ok((group_id, alice_id, bob_id, serde_json::to_value(&document)?))
+}
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let (group_id, alice_id, bob_id, document) = create_backed_up_group(&ctx).await?;
+    let carol_id = ctx.users().create_user("Carol").await?;
+    let dave_id = ctx.users().create_user("Dave").await?;
+    let other_group_id = ctx
+        .groups()
+        .create_empty_group("Ski Trip 2026", carol_id)
+        .await?;
+    ctx.groups()
+        .add_member(other_group_id, carol_id, dave_id)
+        .await?;
+
+    // When
+    let restored_id = ctx
+        .backups()
+        .restore_group_backup(
+            carol_id,
+            document,
+            &[(alice_id, carol_id), (bob_id, dave_id)],
+            None,
+        )
+        .await?;
+
+    // Then
+    assert_ne!(group_id, restored_id);
+    ctx.groups()
+        .assert_group_exists("Bali Trip 2026", carol_id)
+        .await?;
+    ctx.groups()
+        .assert_group_contains_members(restored_id, vec![dave_id])
+        .await?;
+
+    let expense_ids = ctx
+        .expense_entries()
+        .get_expense_ids_for_group(restored_id, carol_id)
+        .await?;
+    assert_eq!(1, expense_ids.len());
+    let entries = ctx
+        .expense_entries()
+        .get_all_entries(expense_ids[0])
+        .await?;
+    assert_eq!(2, entries.len());
+
+    let balances = ctx
+        .balances()
+        .get_group_balances(restored_id, carol_id)
+        .await?;
+    assert_eq!(Some(&2_000), balances.get(&carol_id));
+    assert_eq!(Some(&-2_000), balances.get(&dave_id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unsupported_version_is_rejected() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "unsupported_version_is_rejected").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let (_, alice_id, bob_id, mut document) = create_backed_up_group(&ctx).await?;
+    document["version"] = 2.into();
+
+    // When
+    let err = ctx
+        .backups()
+        .restore_group_backup(
+            alice_id,
+            document,
+            &[(alice_id, alice_id), (bob_id, bob_id)],
+            Some("Bali Trip 2026 (restored)"),
+        )
+        .await
+        .expect_err("restore must fail");
+
+    // Then
+    assert_eq!(
+        RestoreGroupBackupError::InvalidDocument(BackupError::UnsupportedVersion(2)).to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn every_user_must_be_mapped() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "every_user_must_be_mapped").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let (_, alice_id, bob_id, document) = create_backed_up_group(&ctx).await?;
+
+    // When
+    let err = ctx
+        .backups()
+        .restore_group_backup(
+            alice_id,
+            document,
+            &[(alice_id, alice_id)],
+            Some("Bali Trip 2026 (restored)"),
+        )
+        .await
+        .expect_err("restore must fail");
+
+    // Then
+    assert_eq!(
+        RestoreGroupBackupError::UnmappedUser(bob_id).to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn name_must_be_available() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "name_must_be_available").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let (_, alice_id, bob_id, document) = create_backed_up_group(&ctx).await?;
+
+    // When
+    let err = ctx
+        .backups()
+        .restore_group_backup(
+            alice_id,
+            document,
+            &[(alice_id, alice_id), (bob_id, bob_id)],
+            None,
+        )
+        .await
+        .expect_err("restore must fail");
+
+    // Then
+    assert_eq!(
+        RestoreGroupBackupError::NameNotAvailable.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn guests_cannot_be_restored() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "guests_cannot_be_restored").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let (group_id, alice_id, bob_id, document) = create_backed_up_group(&ctx).await?;
+    let guest_id = ctx.groups().create_guest(group_id, alice_id, "Gus").await?;
+
+    // When
+    let err = ctx
+        .backups()
+        .restore_group_backup(
+            alice_id,
+            document,
+            &[(alice_id, alice_id), (bob_id, guest_id)],
+            Some("Bali Trip 2026 (restored)"),
+        )
+        .await
+        .expect_err("restore must fail");
+
+    // Then
+    assert_eq!(
+        RestoreGroupBackupError::GuestUser(UserId::new(guest_id)?).to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn users_must_share_a_group_with_current_user() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "users_must_share_a_group_with_current_user").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let (_, alice_id, bob_id, document) = create_backed_up_group(&ctx).await?;
+    let carol_id = ctx.users().create_user("Carol").await?;
+    let dave_id = ctx.users().create_user("Dave").await?;
+
+    // When
+    let err = ctx
+        .backups()
+        .restore_group_backup(
+            carol_id,
+            document,
+            &[(alice_id, carol_id), (bob_id, dave_id)],
+            None,
+        )
+        .await
+        .expect_err("restore must fail");
+
+    // Then
+    assert_eq!(
+        RestoreGroupBackupError::UserNotCoMember(UserId::new(dave_id)?).to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
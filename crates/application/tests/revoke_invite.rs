@@ -0,0 +1,122 @@
+use application::commands::revoke_invite::RevokeInviteError;
+
+use crate::infra::{ctx::TestContext, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    let invite = ctx
+        .invites()
+        .create_invite(group_id, alice_id, None)
+        .await?;
+
+    // When
+    ctx.invites()
+        .revoke_invite(group_id, invite.id.value(), alice_id)
+        .await?;
+
+    // Then
+    let invites = ctx.invites().get_invites(group_id, alice_id).await?;
+    assert!(invites[0].revoked_at.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_owner_and_admins_can_revoke_invite() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "only_owner_and_admins_can_revoke_invite").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+    let invite = ctx
+        .invites()
+        .create_invite(group_id, alice_id, None)
+        .await?;
+
+    // When
+    let err = ctx
+        .invites()
+        .revoke_invite(group_id, invite.id.value(), bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(RevokeInviteError::NotAdmin.to_string(), err.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn invite_must_belong_to_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "invite_must_belong_to_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    let other_group_id = ctx.groups().create_empty_group("Trip", alice_id).await?;
+    let invite = ctx
+        .invites()
+        .create_invite(other_group_id, alice_id, None)
+        .await?;
+
+    // When
+    let err = ctx
+        .invites()
+        .revoke_invite(group_id, invite.id.value(), alice_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        RevokeInviteError::InviteNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn invite_cannot_be_revoked_twice() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "invite_cannot_be_revoked_twice").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    let invite = ctx
+        .invites()
+        .create_invite(group_id, alice_id, None)
+        .await?;
+    ctx.invites()
+        .revoke_invite(group_id, invite.id.value(), alice_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .invites()
+        .revoke_invite(group_id, invite.id.value(), alice_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        RevokeInviteError::AlreadyRevoked.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
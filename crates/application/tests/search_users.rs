@@ -0,0 +1,67 @@
+use application::queries::search_users::SearchUsersError;
+
+use crate::infra::{ctx::TestContext, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let alicia_id = ctx
+        .users()
+        .create_user_with_handle("Alicia", "lissa")
+        .await?;
+    let _bob_id = ctx.users().create_user("Bob").await?;
+
+    // When
+    let by_name = ctx.users().search("ali").await?;
+    let by_handle = ctx.users().search("@LIS").await?;
+
+    // Then
+    assert_eq!(vec![alice_id, alicia_id], by_name);
+    assert_eq!(vec![alicia_id], by_handle);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn emails_are_not_searched() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "emails_are_not_searched").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    ctx.users()
+        .create_user_with_name_and_email("Bob", "charlie@gmail.com")
+        .await?;
+
+    // When
+    let found = ctx.users().search("charlie").await?;
+
+    // Then
+    assert!(found.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn text_too_short() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "text_too_short").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    ctx.users().create_user("Alice").await?;
+
+    // When
+    let err = ctx.users().search(" @a ").await.unwrap_err();
+
+    // Then
+    assert_eq!(SearchUsersError::TextTooShort.to_string(), err.to_string());
+
+    Ok(())
+}
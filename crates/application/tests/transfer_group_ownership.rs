@@ -0,0 +1,154 @@
+use application::commands::{
+    archive_group::ArchiveGroupError, transfer_group_ownership::TransferGroupOwnershipError,
+};
+
+use crate::infra::{ctx::TestContext, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+
+    // When
+    ctx.groups()
+        .transfer_ownership(group_id, alice_id, bob_id)
+        .await?;
+
+    // Then
+    ctx.groups().assert_group_exists("Flat", bob_id).await?;
+    ctx.groups()
+        .assert_group_contains_members(group_id, vec![alice_id])
+        .await?;
+    // the former owner becomes an admin, without owner rights
+    let err = ctx
+        .groups()
+        .archive_group(group_id, alice_id)
+        .await
+        .unwrap_err();
+    assert_eq!(ArchiveGroupError::NotOwner.to_string(), err.to_string());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_owner_can_transfer_ownership() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "only_owner_can_transfer_ownership").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .transfer_ownership(group_id, bob_id, bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        TransferGroupOwnershipError::NotOwner.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn new_owner_must_be_member() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "new_owner_must_be_member").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .transfer_ownership(group_id, alice_id, bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        TransferGroupOwnershipError::NotMember.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn new_owner_must_not_be_guest() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "new_owner_must_not_be_guest").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    let guest_id = ctx
+        .groups()
+        .create_guest(group_id, alice_id, "Gabe")
+        .await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .transfer_ownership(group_id, alice_id, guest_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        TransferGroupOwnershipError::NewOwnerIsGuest.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn new_owner_must_not_own_group_with_same_name() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "new_owner_must_not_own_group_with_same_name").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", alice_id).await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+    ctx.groups().create_empty_group("Flat", bob_id).await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .transfer_ownership(group_id, alice_id, bob_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        TransferGroupOwnershipError::NameNotAvailable.to_string(),
+        err.to_string()
+    );
+    ctx.groups()
+        .assert_group_contains_members(group_id, vec![bob_id])
+        .await?;
+
+    Ok(())
+}
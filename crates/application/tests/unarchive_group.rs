@@ -0,0 +1,69 @@
+use application::commands::unarchive_group::UnarchiveGroupError;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+    ctx.groups().add_member(group_id, alice_id, bob_id).await?;
+    ctx.groups().archive_group(group_id, alice_id).await?;
+
+    // When
+    ctx.groups().unarchive_group(group_id, alice_id).await?;
+
+    // Then
+    let group_ids = ctx.groups().get_group_ids_for_user(bob_id, false).await?;
+    assert_eq!(vec![group_id], group_ids);
+    ctx.expense_entries()
+        .create_expense_for_all_group_members(
+            group_id,
+            alice_id,
+            30,
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn group_must_be_archived() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "group_must_be_archived").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Trip Summer 2026", alice_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .groups()
+        .unarchive_group(group_id, alice_id)
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        UnarchiveGroupError::NotArchived.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use application::commands::update_budget::UpdateBudgetError;
+use domain::types::{budget_period::BudgetPeriod, money::Money};
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let budget_id = ctx
+        .budgets()
+        .create_budget(group_id, bob_id, None, 300, BudgetPeriod::Monthly)
+        .await?;
+    let period = BudgetPeriod::range(dates::jan_08_2025(), dates::jan_31_2025())?;
+
+    // When
+    ctx.budgets()
+        .update_budget(group_id, budget_id, bob_id, 1_000, period)
+        .await?;
+
+    // Then
+    let budgets = ctx
+        .budgets()
+        .get_budgets_for_group(group_id, bob_id, dates::jan_10_2025())
+        .await?;
+    assert_eq!(Money::from_euros(1_000), budgets[0].budget.amount);
+    assert_eq!(period, budgets[0].budget.period);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn budget_of_another_group_is_not_found() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "budget_of_another_group_is_not_found").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let other_group_id = ctx.groups().create_empty_group("Flatmates", bob_id).await?;
+    let budget_id = ctx
+        .budgets()
+        .create_budget(other_group_id, bob_id, None, 300, BudgetPeriod::Monthly)
+        .await?;
+
+    // When
+    let err = ctx
+        .budgets()
+        .update_budget(group_id, budget_id, bob_id, 1_000, BudgetPeriod::Yearly)
+        .await
+        .expect_err("update must fail");
+
+    // Then
+    assert_eq!(
+        UpdateBudgetError::BudgetNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
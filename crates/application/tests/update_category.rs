@@ -0,0 +1,165 @@
+use application::commands::update_category::UpdateCategoryError;
+
+use crate::infra::{ctx::TestContext, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let category_id = ctx
+        .categories()
+        .create_category(group_id, bob_id, "Surf lessons")
+        .await?;
+
+    // When
+    ctx.categories()
+        .rename_category(group_id, category_id, bob_id, "Surf")
+        .await?;
+
+    // Then
+    let category = ctx.categories().assert_category_exists(category_id).await?;
+    assert_eq!("Surf", category.name.value());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn change_case_of_own_name() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "change_case_of_own_name").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let category_id = ctx
+        .categories()
+        .create_category(group_id, bob_id, "surf lessons")
+        .await?;
+
+    // When
+    ctx.categories()
+        .rename_category(group_id, category_id, bob_id, "Surf Lessons")
+        .await?;
+
+    // Then
+    let category = ctx.categories().assert_category_exists(category_id).await?;
+    assert_eq!("Surf Lessons", category.name.value());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn name_already_used_by_another_category() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "name_already_used_by_another_category").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.categories()
+        .create_category(group_id, bob_id, "Surf lessons")
+        .await?;
+    let category_id = ctx
+        .categories()
+        .create_category(group_id, bob_id, "Scooter")
+        .await?;
+
+    // When
+    let err = ctx
+        .categories()
+        .rename_category(group_id, category_id, bob_id, "surf lessons")
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        UpdateCategoryError::NameNotAvailable.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn category_of_another_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "category_of_another_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let bali_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let hawaii_id = ctx
+        .groups()
+        .create_empty_group("Hawaii Trip 2027", bob_id)
+        .await?;
+    let category_id = ctx
+        .categories()
+        .create_category(bali_id, bob_id, "Surf lessons")
+        .await?;
+
+    // When
+    let err = ctx
+        .categories()
+        .rename_category(hawaii_id, category_id, bob_id, "Surf")
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        UpdateCategoryError::CategoryNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_owner_and_admins_can_update_category() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "only_owner_and_admins_can_update_category").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    let category_id = ctx
+        .categories()
+        .create_category(group_id, bob_id, "Surf lessons")
+        .await?;
+
+    // When
+    let err = ctx
+        .categories()
+        .rename_category(group_id, category_id, alice_id, "Surf")
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(UpdateCategoryError::NotAdmin.to_string(), err.to_string());
+
+    Ok(())
+}
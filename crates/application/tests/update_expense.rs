@@ -0,0 +1,299 @@
+use std::collections::HashSet;
+
+use application::commands::update_expense::UpdateExpenseError;
+use domain::types::{expense_entry_status::ExpenseEntryStatus, user_id::UserId};
+use uuid::Uuid;
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn happy_path() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "happy_path").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    ctx.groups()
+        .add_member(group_id, bob_id, charlie_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+
+    // When
+    let new_entry_id = ctx
+        .expense_entries()
+        .update_expense(
+            group_id,
+            expense_id,
+            alice_id,
+            60,
+            vec![bob_id],
+            alice_id,
+            dates::jan_10_2025(),
+        )
+        .await?;
+
+    // Then
+    let entries = ctx.expense_entries().get_all_entries(expense_id).await?;
+    assert_eq!(2, entries.len());
+    let (old_entry, new_entry) = (&entries[0], &entries[1]);
+
+    assert_eq!(
+        ExpenseEntryStatus::Inactive {
+            overwritten_by: new_entry.id
+        },
+        old_entry.status
+    );
+    assert_eq!(9_000, old_entry.total.cents());
+
+    assert_eq!(new_entry_id, new_entry.id.value());
+    assert_eq!(ExpenseEntryStatus::Active, new_entry.status);
+    assert_eq!(alice_id, new_entry.payer_id.value());
+    assert_eq!(
+        HashSet::from_iter(vec![UserId::new(bob_id)?]),
+        new_entry.participants
+    );
+    assert_eq!(6_000, new_entry.total.cents());
+    assert_eq!(alice_id, new_entry.author_id.value());
+    assert_eq!(dates::jan_10_2025(), new_entry.occurred_at);
+
+    let balances = ctx.balances().get_group_balances(group_id, bob_id).await?;
+    assert_eq!(Some(&-3_000), balances.get(&bob_id));
+    assert_eq!(Some(&3_000), balances.get(&alice_id));
+    assert_eq!(Some(&0), balances.get(&charlie_id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn update_twice() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "update_twice").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+    ctx.expense_entries()
+        .update_expense(
+            group_id,
+            expense_id,
+            bob_id,
+            60,
+            vec![alice_id],
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // When
+    ctx.expense_entries()
+        .update_expense(
+            group_id,
+            expense_id,
+            bob_id,
+            30,
+            vec![alice_id],
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await?;
+
+    // Then
+    let entries = ctx.expense_entries().get_all_entries(expense_id).await?;
+    assert_eq!(3, entries.len());
+    let active: Vec<_> = entries
+        .iter()
+        .filter(|entry| entry.status == ExpenseEntryStatus::Active)
+        .collect();
+    assert_eq!(1, active.len());
+    assert_eq!(3_000, active[0].total.cents());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn author_not_in_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "author_not_in_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .update_expense(
+            group_id,
+            expense_id,
+            bob_id,
+            10,
+            vec![],
+            alice_id,
+            dates::jan_08_2025(),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        UpdateExpenseError::AuthorNotInGroup.to_string(),
+        err.to_string()
+    );
+    ctx.expense_entries()
+        .assert_expense_has_a_single_entry(expense_id)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn expense_not_found() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "expense_not_found").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .update_expense(
+            group_id,
+            Uuid::now_v7(),
+            bob_id,
+            10,
+            vec![],
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        UpdateExpenseError::ExpenseNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn expense_from_another_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "expense_from_another_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let other_group_id = ctx.groups().create_empty_group("Rent", bob_id).await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .update_expense(
+            other_group_id,
+            expense_id,
+            bob_id,
+            10,
+            vec![],
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        UpdateExpenseError::ExpenseNotFound.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn participant_not_in_group() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "participant_not_in_group").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx
+        .groups()
+        .create_empty_group("Bali Trip 2026", bob_id)
+        .await?;
+    let expense_id = ctx
+        .expense_entries()
+        .create_expense_for_all_group_members(group_id, bob_id, 90, bob_id, dates::jan_08_2025())
+        .await?;
+
+    // When
+    let err = ctx
+        .expense_entries()
+        .update_expense(
+            group_id,
+            expense_id,
+            bob_id,
+            10,
+            vec![alice_id],
+            bob_id,
+            dates::jan_08_2025(),
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        UpdateExpenseError::ParticipantNotFound.to_string(),
+        err.to_string()
+    );
+    ctx.expense_entries()
+        .assert_expense_has_a_single_entry(expense_id)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use application::commands::update_recurring_expense::UpdateRecurringExpenseError;
+use domain::types::recurrence::{Frequency, Recurrence};
+
+use crate::infra::{ctx::TestContext, dates, db::build_test_database};
+
+mod infra;
+
+const FILE: &str = file!();
+
+#[tokio::test]
+async fn new_schedule_resumes_after_last_added_occurrence() -> anyhow::Result<()> {
+    let db_pool =
+        build_test_database(FILE, "new_schedule_resumes_after_last_added_occurrence").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let alice_id = ctx.users().create_user("Alice").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", bob_id).await?;
+    ctx.groups().add_member(group_id, bob_id, alice_id).await?;
+    let recurring_expense_id = ctx
+        .recurring_expenses()
+        .create_monthly_recurring_expense(
+            group_id,
+            bob_id,
+            1_200,
+            bob_id,
+            dates::jan_31_2025(),
+            None,
+        )
+        .await?;
+    let output = ctx
+        .recurring_expenses()
+        .materialize(dates::feb_28_2025())
+        .await?;
+    assert_eq!(2, output.created.len());
+
+    // When
+    ctx.recurring_expenses()
+        .update_recurring_expense(
+            group_id,
+            recurring_expense_id,
+            alice_id,
+            alice_id,
+            300,
+            Recurrence::new(Frequency::Weekly, 1, dates::jan_31_2025(), None)?,
+        )
+        .await?;
+
+    // Then
+    let recurring_expense = ctx
+        .recurring_expenses()
+        .assert_recurring_expense_exists(recurring_expense_id)
+        .await?;
+    assert_eq!(alice_id, recurring_expense.payer_id.value());
+    assert_eq!(300, recurring_expense.total.euros());
+    assert_eq!(
+        Some(dates::mar_07_2025()),
+        recurring_expense.next_occurrence_at
+    );
+
+    // already added expenses are left untouched
+    for expense_id in output.created {
+        let entry = ctx
+            .expense_entries()
+            .assert_expense_has_a_single_entry(expense_id.value())
+            .await?;
+        assert_eq!(bob_id, entry.payer_id.value());
+        assert_eq!(1_200, entry.total.euros());
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn only_members_can_update() -> anyhow::Result<()> {
+    let db_pool = build_test_database(FILE, "only_members_can_update").await?;
+    let ctx = TestContext::new(db_pool);
+
+    // Given
+    let bob_id = ctx.users().create_user("Bob").await?;
+    let charlie_id = ctx.users().create_user("Charlie").await?;
+    let group_id = ctx.groups().create_empty_group("Flat", bob_id).await?;
+    let recurring_expense_id = ctx
+        .recurring_expenses()
+        .create_monthly_recurring_expense(
+            group_id,
+            bob_id,
+            1_200,
+            bob_id,
+            dates::jan_31_2025(),
+            None,
+        )
+        .await?;
+
+    // When
+    let err = ctx
+        .recurring_expenses()
+        .update_recurring_expense(
+            group_id,
+            recurring_expense_id,
+            charlie_id,
+            bob_id,
+            300,
+            Recurrence::new(Frequency::Weekly, 1, dates::jan_31_2025(), None)?,
+        )
+        .await
+        .unwrap_err();
+
+    // Then
+    assert_eq!(
+        UpdateRecurringExpenseError::Forbidden.to_string(),
+        err.to_string()
+    );
+
+    Ok(())
+}
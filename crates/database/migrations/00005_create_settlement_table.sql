@@ -0,0 +1,17 @@
+CREATE TABLE settlement (
+    id BLOB(16) PRIMARY KEY,
+    coin_group_id BLOB(16) NOT NULL,
+    from_user_id BLOB(16) NOT NULL,
+    to_user_id BLOB(16) NOT NULL,
+    amount INTEGER NOT NULL,
+    author_id BLOB(16) NOT NULL,
+    occurred_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    cancelled_by BLOB(16),
+    cancelled_at TIMESTAMP,
+    FOREIGN KEY (coin_group_id) REFERENCES coin_group(id) ON DELETE CASCADE,
+    FOREIGN KEY (from_user_id) REFERENCES user(id) ON DELETE CASCADE,
+    FOREIGN KEY (to_user_id) REFERENCES user(id) ON DELETE CASCADE,
+    FOREIGN KEY (author_id) REFERENCES user(id) ON DELETE CASCADE,
+    FOREIGN KEY (cancelled_by) REFERENCES user(id) ON DELETE CASCADE
+);
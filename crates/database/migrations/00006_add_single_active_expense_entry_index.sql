@@ -0,0 +1,4 @@
+-- At any time, exactly one expense entry per expense_id must be active.
+CREATE UNIQUE INDEX expense_entry_single_active_per_expense
+ON expense_entry (expense_id)
+WHERE status IS NULL;
@@ -0,0 +1,9 @@
+-- A deleted expense is represented by a tombstone entry: status is NULL and
+-- deleted_by/deleted_at are set. Tombstones are never active.
+ALTER TABLE expense_entry ADD COLUMN deleted_by BLOB(16) REFERENCES user(id) ON DELETE CASCADE;
+ALTER TABLE expense_entry ADD COLUMN deleted_at TIMESTAMP;
+
+DROP INDEX expense_entry_single_active_per_expense;
+CREATE UNIQUE INDEX expense_entry_single_active_per_expense
+ON expense_entry (expense_id)
+WHERE status IS NULL AND deleted_at IS NULL;
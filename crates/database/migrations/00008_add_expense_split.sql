@@ -0,0 +1,7 @@
+-- 10 = equal, 20 = shares, 30 = exact amounts, 40 = percentages
+ALTER TABLE expense_entry ADD COLUMN split_mode INTEGER NOT NULL DEFAULT 10;
+
+-- Number of shares, amount in cents or percentage, depending on the split mode.
+-- NULL for equal splits. For non-equal splits, the payer has a row if they
+-- bear a part of the expense.
+ALTER TABLE expense_entry_participant ADD COLUMN weight INTEGER;
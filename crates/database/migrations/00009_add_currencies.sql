@@ -0,0 +1,7 @@
+-- ISO 4217 code of the currency in which balances of the group are expressed.
+ALTER TABLE coin_group ADD COLUMN currency TEXT NOT NULL DEFAULT 'EUR';
+
+-- Currency in which the expense was paid, and rate to the group currency
+-- in millionths (1000000 = 1). Existing expenses are in the group currency.
+ALTER TABLE expense_entry ADD COLUMN currency TEXT NOT NULL DEFAULT 'EUR';
+ALTER TABLE expense_entry ADD COLUMN exchange_rate INTEGER NOT NULL DEFAULT 1000000;
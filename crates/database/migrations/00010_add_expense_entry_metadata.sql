@@ -0,0 +1,6 @@
+ALTER TABLE expense_entry ADD COLUMN title TEXT NOT NULL DEFAULT 'Untitled expense';
+ALTER TABLE expense_entry ADD COLUMN note TEXT;
+
+-- 10 = food, 20 = groceries, 30 = transport, 40 = lodging, 50 = entertainment,
+-- 60 = shopping, 70 = utilities, 80 = health, 90 = other
+ALTER TABLE expense_entry ADD COLUMN category INTEGER NOT NULL DEFAULT 90;
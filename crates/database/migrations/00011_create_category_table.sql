@@ -0,0 +1,13 @@
+CREATE TABLE category (
+    id BLOB(16) PRIMARY KEY,
+    coin_group_id BLOB(16) NOT NULL,
+    name TEXT NOT NULL,
+    color TEXT NOT NULL,
+    emoji TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    UNIQUE (coin_group_id, name COLLATE NOCASE),
+    FOREIGN KEY (coin_group_id) REFERENCES coin_group(id) ON DELETE CASCADE
+);
+
+-- deleting a category leaves its expenses (and their history) uncategorized
+ALTER TABLE expense_entry ADD COLUMN category_id BLOB(16) REFERENCES category(id) ON DELETE SET NULL;
@@ -0,0 +1,47 @@
+CREATE TABLE recurring_expense (
+    id BLOB(16) PRIMARY KEY,
+    coin_group_id BLOB(16) NOT NULL,
+    title TEXT NOT NULL,
+    note TEXT,
+    category INTEGER NOT NULL,
+    category_id BLOB(16),
+    payer_id BLOB(16) NOT NULL,
+    split_mode INTEGER NOT NULL,
+    total INTEGER NOT NULL,
+    currency TEXT NOT NULL,
+    exchange_rate INTEGER NOT NULL,
+    author_id BLOB(16) NOT NULL,
+    -- 10 = daily, 20 = weekly, 30 = monthly, 40 = yearly
+    frequency INTEGER NOT NULL,
+    frequency_interval INTEGER NOT NULL,
+    starts_at TIMESTAMP NOT NULL,
+    ends_at TIMESTAMP,
+    -- NULL once the schedule has ended
+    next_occurrence_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL,
+    FOREIGN KEY (coin_group_id) REFERENCES coin_group(id) ON DELETE CASCADE,
+    FOREIGN KEY (category_id) REFERENCES category(id) ON DELETE SET NULL,
+    FOREIGN KEY (payer_id) REFERENCES user(id) ON DELETE CASCADE,
+    FOREIGN KEY (author_id) REFERENCES user(id) ON DELETE CASCADE
+);
+
+CREATE INDEX recurring_expense_next_occurrence_at_idx ON recurring_expense (next_occurrence_at);
+
+CREATE TABLE recurring_expense_participant (
+    recurring_expense_id BLOB(16) NOT NULL,
+    participant_id BLOB(16) NOT NULL,
+    weight INTEGER,
+    PRIMARY KEY (recurring_expense_id, participant_id),
+    FOREIGN KEY (recurring_expense_id) REFERENCES recurring_expense(id) ON DELETE CASCADE,
+    FOREIGN KEY (participant_id) REFERENCES user(id) ON DELETE CASCADE
+);
+
+-- one row per materialized occurrence, so that an occurrence is never
+-- added twice; expense_id is NULL if the occurrence could not be added
+CREATE TABLE recurring_expense_occurrence (
+    recurring_expense_id BLOB(16) NOT NULL,
+    occurred_at TIMESTAMP NOT NULL,
+    expense_id BLOB(16),
+    PRIMARY KEY (recurring_expense_id, occurred_at),
+    FOREIGN KEY (recurring_expense_id) REFERENCES recurring_expense(id) ON DELETE CASCADE
+);
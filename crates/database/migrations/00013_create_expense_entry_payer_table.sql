@@ -0,0 +1,14 @@
+-- Amount paid by each payer of an expense entry, in cents of the expense
+-- currency. expense_entry.payer_id remains the main payer and always has a row.
+CREATE TABLE expense_entry_payer (
+    expense_entry_id BLOB(16) NOT NULL,
+    payer_id BLOB(16) NOT NULL,
+    amount INTEGER NOT NULL,
+    PRIMARY KEY (expense_entry_id, payer_id),
+    FOREIGN KEY (expense_entry_id) REFERENCES expense_entry(id) ON DELETE CASCADE,
+    FOREIGN KEY (payer_id) REFERENCES user(id) ON DELETE CASCADE
+);
+
+-- existing expense entries were paid entirely by their payer
+INSERT INTO expense_entry_payer (expense_entry_id, payer_id, amount)
+SELECT id, payer_id, total FROM expense_entry;
@@ -0,0 +1,17 @@
+-- attachments belong to an expense, not to one of its entries,
+-- so that they are kept across versions of the expense
+CREATE TABLE attachment (
+    id BLOB(16) PRIMARY KEY,
+    expense_id BLOB(16) NOT NULL,
+    coin_group_id BLOB(16) NOT NULL,
+    file_name TEXT NOT NULL,
+    -- 10 = jpeg, 20 = png, 30 = webp, 40 = heic, 50 = pdf
+    content_type INTEGER NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    uploaded_by BLOB(16) NOT NULL,
+    uploaded_at TIMESTAMP NOT NULL,
+    FOREIGN KEY (coin_group_id) REFERENCES coin_group(id) ON DELETE CASCADE,
+    FOREIGN KEY (uploaded_by) REFERENCES user(id) ON DELETE CASCADE
+);
+
+CREATE INDEX attachment_expense_id_idx ON attachment (expense_id);
@@ -0,0 +1,30 @@
+CREATE TABLE budget (
+    id BLOB(16) PRIMARY KEY,
+    coin_group_id BLOB(16) NOT NULL,
+    -- NULL for a budget on every expense of the group
+    category_id BLOB(16),
+    amount INTEGER NOT NULL,
+    -- 20 = weekly, 30 = monthly, 40 = yearly, 50 = date range
+    period INTEGER NOT NULL,
+    -- only set for a date range
+    starts_at TIMESTAMP,
+    ends_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL,
+    FOREIGN KEY (coin_group_id) REFERENCES coin_group(id) ON DELETE CASCADE,
+    -- a budget on a category is meaningless once the category is deleted
+    FOREIGN KEY (category_id) REFERENCES category(id) ON DELETE CASCADE
+);
+
+-- one row per threshold reached during a period of a budget, so that an
+-- alert is never raised twice
+CREATE TABLE budget_alert (
+    budget_id BLOB(16) NOT NULL,
+    -- in percent of the budget amount
+    threshold INTEGER NOT NULL,
+    period_starts_at TIMESTAMP NOT NULL,
+    expense_id BLOB(16) NOT NULL,
+    consumed INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    PRIMARY KEY (budget_id, threshold, period_starts_at),
+    FOREIGN KEY (budget_id) REFERENCES budget(id) ON DELETE CASCADE
+);
@@ -0,0 +1,2 @@
+-- NULL unless the group is archived
+ALTER TABLE coin_group ADD COLUMN archived_at TIMESTAMP;
@@ -0,0 +1,3 @@
+-- 10 = admin, 20 = member, 30 = viewer
+-- existing members keep their current rights
+ALTER TABLE coin_group_member ADD COLUMN role INTEGER NOT NULL DEFAULT 20;
@@ -0,0 +1,17 @@
+CREATE TABLE invite (
+    id BLOB(16) PRIMARY KEY,
+    coin_group_id BLOB(16) NOT NULL,
+    token TEXT NOT NULL UNIQUE,
+    -- same codes as coin_group_member.role
+    role INTEGER NOT NULL,
+    -- NULL for an invite that can be accepted any number of times
+    max_uses INTEGER,
+    uses INTEGER NOT NULL DEFAULT 0,
+    author_id BLOB(16) NOT NULL,
+    expires_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL,
+    -- NULL unless the invite was revoked
+    revoked_at TIMESTAMP,
+    FOREIGN KEY (coin_group_id) REFERENCES coin_group(id) ON DELETE CASCADE,
+    FOREIGN KEY (author_id) REFERENCES user(id) ON DELETE CASCADE
+);
@@ -0,0 +1,33 @@
+ALTER TABLE user ADD COLUMN handle TEXT NOT NULL DEFAULT '';
+
+-- backfills handles from names, which are not unique: the oldest user
+-- keeps the lowercase name, the next ones get a suffix built from the end
+-- of their id (a numeric suffix could be the name of another user, such as
+-- "bob_2"); names are shortened so that handles fit in 32 characters.
+-- Names that are not valid handles, such as non-ASCII ones, get a handle
+-- built from the end of the user id.
+UPDATE user
+SET handle = CASE
+    WHEN ranked.n = 1 THEN ranked.base
+    ELSE substr(ranked.base, 1, 23) || '_' || substr(lower(hex(ranked.id)), 25, 8)
+END
+FROM (
+    SELECT
+        id,
+        base,
+        ROW_NUMBER() OVER (PARTITION BY base ORDER BY created_at, id) AS n
+    FROM (
+        SELECT
+            id,
+            created_at,
+            CASE
+                WHEN name GLOB '[A-Za-z]*' AND name NOT GLOB '*[^A-Za-z0-9_-]*'
+                    THEN lower(name)
+                ELSE 'user_' || substr(lower(hex(id)), 25, 8)
+            END AS base
+        FROM user
+    )
+) AS ranked
+WHERE ranked.id = user.id;
+
+CREATE UNIQUE INDEX user_unique_handle ON user(handle);
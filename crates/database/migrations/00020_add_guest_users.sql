@@ -0,0 +1,6 @@
+-- guests are people without an account, added to a single group by name
+-- so that they can pay and take part in its expenses. They have no email:
+-- making the column nullable would require rebuilding the table, which
+-- would cascade to everything referencing users, so guests store a value
+-- that is never a valid email instead.
+ALTER TABLE user ADD COLUMN guest INTEGER NOT NULL DEFAULT 0;
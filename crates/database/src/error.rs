@@ -6,3 +6,15 @@ pub enum Error {
     #[error("database corrupted data: {msg}")]
     CorruptedData { msg: String },
 }
+
+impl Error {
+    /// Checks whether this error comes from the unique constraint on
+    /// `column`, given as `table.column` (e.g. `user.handle`).
+    pub fn is_unique_violation(&self, column: &str) -> bool {
+        let Error::SqlxError(sqlx::Error::Database(err)) = self else {
+            return false;
+        };
+        err.kind() == sqlx::error::ErrorKind::UniqueViolation
+            && err.message() == format!("UNIQUE constraint failed: {}", column)
+    }
+}
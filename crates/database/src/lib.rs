@@ -12,3 +12,9 @@ pub use sqlx::Error as SqlxError;
 pub use sqlx::SqlitePool;
 
 pub type Transaction<'a> = sqlx::Transaction<'a, sqlx::Sqlite>;
+
+/// Starts a transaction nested in `tx`, backed by a savepoint: its changes
+/// are rolled back when dropped without being committed, leaving `tx` usable.
+pub async fn begin_nested<'a>(tx: &'a mut Transaction<'_>) -> Result<Transaction<'a>, Error> {
+    Ok(sqlx::Acquire::begin(&mut **tx).await?)
+}
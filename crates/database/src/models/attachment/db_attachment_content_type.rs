@@ -0,0 +1,61 @@
+use domain::types::attachment_content_type::AttachmentContentType;
+
+pub struct DbAttachmentContentType(pub u8);
+
+impl From<&AttachmentContentType> for DbAttachmentContentType {
+    fn from(content_type: &AttachmentContentType) -> Self {
+        Self(match content_type {
+            AttachmentContentType::Jpeg => 10,
+            AttachmentContentType::Png => 20,
+            AttachmentContentType::Webp => 30,
+            AttachmentContentType::Heic => 40,
+            AttachmentContentType::Pdf => 50,
+        })
+    }
+}
+
+impl TryInto<AttachmentContentType> for DbAttachmentContentType {
+    type Error = crate::Error;
+
+    fn try_into(self) -> Result<AttachmentContentType, Self::Error> {
+        match self.0 {
+            10 => Ok(AttachmentContentType::Jpeg),
+            20 => Ok(AttachmentContentType::Png),
+            30 => Ok(AttachmentContentType::Webp),
+            40 => Ok(AttachmentContentType::Heic),
+            50 => Ok(AttachmentContentType::Pdf),
+            other => Err(crate::Error::CorruptedData {
+                msg: format!("unknown attachment content type: '{}'", other),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DbAttachmentContentType;
+    use domain::types::attachment_content_type::AttachmentContentType;
+
+    #[rstest::rstest]
+    #[case(AttachmentContentType::Jpeg, 10)]
+    #[case(AttachmentContentType::Png, 20)]
+    #[case(AttachmentContentType::Webp, 30)]
+    #[case(AttachmentContentType::Heic, 40)]
+    #[case(AttachmentContentType::Pdf, 50)]
+    fn round_trip(#[case] content_type: AttachmentContentType, #[case] expected_db_value: u8) {
+        let db_content_type = DbAttachmentContentType::from(&content_type);
+        assert_eq!(expected_db_value, db_content_type.0);
+        let actual: AttachmentContentType = db_content_type.try_into().unwrap();
+        assert_eq!(content_type, actual);
+    }
+
+    #[test]
+    fn unknown_content_type() {
+        let err =
+            TryInto::<AttachmentContentType>::try_into(DbAttachmentContentType(12)).unwrap_err();
+        assert_eq!(
+            "database corrupted data: unknown attachment content type: '12'",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use domain::{
+    entities::Attachment,
+    types::{
+        attachment_id::AttachmentId, expense_id::ExpenseId, group_id::GroupId, user_id::UserId,
+    },
+};
+use sqlx::types::chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::models::attachment::db_attachment_content_type::DbAttachmentContentType;
+
+pub mod db_attachment_content_type;
+
+#[derive(sqlx::FromRow)]
+pub struct DbAttachment {
+    pub id: Uuid,
+    pub expense_id: Uuid,
+    #[sqlx(rename = "coin_group_id")]
+    pub group_id: Uuid,
+    pub file_name: String,
+    pub content_type: u8,
+    pub size_bytes: i64,
+    pub uploaded_by: Uuid,
+    pub uploaded_at: DateTime<Utc>,
+}
+
+impl TryInto<Attachment> for DbAttachment {
+    type Error = crate::Error;
+
+    fn try_into(self) -> Result<Attachment, Self::Error> {
+        let id = AttachmentId::new(self.id).map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted id: {}", err),
+        })?;
+        let expense_id =
+            ExpenseId::new(self.expense_id).map_err(|err| crate::Error::CorruptedData {
+                msg: format!("corrupted expense_id: {}", err),
+            })?;
+        let group_id = GroupId::new(self.group_id).map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted group_id: {}", err),
+        })?;
+        let file_name = self
+            .file_name
+            .parse()
+            .map_err(|err| crate::Error::CorruptedData {
+                msg: format!("corrupted file_name: {}", err),
+            })?;
+        let content_type = DbAttachmentContentType(self.content_type).try_into()?;
+        let size_bytes =
+            u64::try_from(self.size_bytes).map_err(|err| crate::Error::CorruptedData {
+                msg: format!("corrupted size_bytes: {}", err),
+            })?;
+        let uploaded_by =
+            UserId::new(self.uploaded_by).map_err(|err| crate::Error::CorruptedData {
+                msg: format!("corrupted uploaded_by: {}", err),
+            })?;
+
+        Ok(Attachment::new(
+            id,
+            expense_id,
+            group_id,
+            file_name,
+            content_type,
+            size_bytes,
+            uploaded_by,
+            self.uploaded_at,
+        ))
+    }
+}
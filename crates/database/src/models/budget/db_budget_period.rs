@@ -0,0 +1,83 @@
+use domain::types::budget_period::BudgetPeriod;
+use sqlx::types::chrono::{DateTime, Utc};
+
+/// Kind of period, along with the bounds of a date range.
+pub struct DbBudgetPeriod(pub u8, pub Option<DateTime<Utc>>, pub Option<DateTime<Utc>>);
+
+impl From<&BudgetPeriod> for DbBudgetPeriod {
+    fn from(period: &BudgetPeriod) -> Self {
+        match period {
+            BudgetPeriod::Weekly => Self(20, None, None),
+            BudgetPeriod::Monthly => Self(30, None, None),
+            BudgetPeriod::Yearly => Self(40, None, None),
+            BudgetPeriod::Range { starts_at, ends_at } => {
+                Self(50, Some(*starts_at), Some(*ends_at))
+            }
+        }
+    }
+}
+
+impl TryInto<BudgetPeriod> for DbBudgetPeriod {
+    type Error = crate::Error;
+
+    fn try_into(self) -> Result<BudgetPeriod, Self::Error> {
+        match self {
+            Self(20, _, _) => Ok(BudgetPeriod::Weekly),
+            Self(30, _, _) => Ok(BudgetPeriod::Monthly),
+            Self(40, _, _) => Ok(BudgetPeriod::Yearly),
+            Self(50, Some(starts_at), Some(ends_at)) => BudgetPeriod::range(starts_at, ends_at)
+                .map_err(|err| crate::Error::CorruptedData {
+                    msg: format!("corrupted period: {}", err),
+                }),
+            Self(50, _, _) => Err(crate::Error::CorruptedData {
+                msg: "date range without bounds".to_string(),
+            }),
+            Self(other, _, _) => Err(crate::Error::CorruptedData {
+                msg: format!("unknown budget period: '{}'", other),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DbBudgetPeriod;
+    use domain::types::budget_period::BudgetPeriod;
+    use sqlx::types::chrono::DateTime;
+
+    #[rstest::rstest]
+    #[case(BudgetPeriod::Weekly, 20)]
+    #[case(BudgetPeriod::Monthly, 30)]
+    #[case(BudgetPeriod::Yearly, 40)]
+    #[case(
+        BudgetPeriod::range(
+            DateTime::parse_from_rfc3339("2025-03-01T00:00:00Z").unwrap().to_utc(),
+            DateTime::parse_from_rfc3339("2025-03-10T00:00:00Z").unwrap().to_utc(),
+        ).unwrap(),
+        50
+    )]
+    fn round_trip(#[case] period: BudgetPeriod, #[case] expected_db_value: u8) {
+        let db_period = DbBudgetPeriod::from(&period);
+        assert_eq!(expected_db_value, db_period.0);
+        let actual: BudgetPeriod = db_period.try_into().unwrap();
+        assert_eq!(period, actual);
+    }
+
+    #[test]
+    fn unknown_period() {
+        let err = TryInto::<BudgetPeriod>::try_into(DbBudgetPeriod(12, None, None)).unwrap_err();
+        assert_eq!(
+            "database corrupted data: unknown budget period: '12'",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn range_without_bounds() {
+        let err = TryInto::<BudgetPeriod>::try_into(DbBudgetPeriod(50, None, None)).unwrap_err();
+        assert_eq!(
+            "database corrupted data: date range without bounds",
+            err.to_string()
+        );
+    }
+}
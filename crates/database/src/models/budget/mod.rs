@@ -0,0 +1,90 @@
+use domain::{
+    entities::{Budget, BudgetAlert},
+    types::{
+        budget_id::BudgetId, budget_threshold::BudgetThreshold, expense_id::ExpenseId,
+        group_id::GroupId, money::Money,
+    },
+};
+use sqlx::types::chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::models::{budget::db_budget_period::DbBudgetPeriod, expense_entry::build_category_id};
+
+pub mod db_budget_period;
+
+#[derive(sqlx::FromRow)]
+pub struct DbBudget {
+    pub id: Uuid,
+    #[sqlx(rename = "coin_group_id")]
+    pub group_id: Uuid,
+    pub category_id: Option<Uuid>,
+    pub amount: i64,
+    pub period: u8,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TryInto<Budget> for DbBudget {
+    type Error = crate::Error;
+
+    fn try_into(self) -> Result<Budget, Self::Error> {
+        let id = BudgetId::new(self.id).map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted id: {}", err),
+        })?;
+        let group_id = GroupId::new(self.group_id).map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted group_id: {}", err),
+        })?;
+        let category_id = build_category_id(self.category_id)?;
+        let period = DbBudgetPeriod(self.period, self.starts_at, self.ends_at).try_into()?;
+
+        Ok(Budget::new(
+            id,
+            group_id,
+            category_id,
+            Money::from_cents(self.amount),
+            period,
+            self.created_at,
+        ))
+    }
+}
+
+#[derive(sqlx::FromRow)]
+pub struct DbBudgetAlert {
+    pub budget_id: Uuid,
+    pub threshold: u8,
+    pub period_starts_at: DateTime<Utc>,
+    pub expense_id: Uuid,
+    pub consumed: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TryInto<BudgetAlert> for DbBudgetAlert {
+    type Error = crate::Error;
+
+    fn try_into(self) -> Result<BudgetAlert, Self::Error> {
+        let budget_id =
+            BudgetId::new(self.budget_id).map_err(|err| crate::Error::CorruptedData {
+                msg: format!("corrupted budget_id: {}", err),
+            })?;
+        let threshold = BudgetThreshold::ALL
+            .into_iter()
+            .find(|threshold| threshold.percent() == self.threshold)
+            .ok_or_else(|| crate::Error::CorruptedData {
+                msg: format!("unknown threshold: '{}'", self.threshold),
+            })?;
+        let expense_id =
+            ExpenseId::new(self.expense_id).map_err(|err| crate::Error::CorruptedData {
+                msg: format!("corrupted expense_id: {}", err),
+            })?;
+
+        Ok(BudgetAlert::new(
+            budget_id,
+            threshold,
+            self.period_starts_at,
+            expense_id,
+            Money::from_cents(self.consumed),
+            self.created_at,
+        ))
+    }
+}
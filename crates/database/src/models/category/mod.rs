@@ -0,0 +1,57 @@
+use domain::{
+    entities::Category,
+    types::{category_id::CategoryId, group_id::GroupId},
+};
+use sqlx::types::chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(sqlx::FromRow)]
+pub struct DbCategory {
+    pub id: Uuid,
+    #[sqlx(rename = "coin_group_id")]
+    pub group_id: Uuid,
+    pub name: String,
+    pub color: String,
+    pub emoji: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TryInto<Category> for DbCategory {
+    type Error = crate::Error;
+
+    fn try_into(self) -> Result<Category, Self::Error> {
+        let id = CategoryId::new(self.id).map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted id: {}", err),
+        })?;
+        let group_id = GroupId::new(self.group_id).map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted group_id: {}", err),
+        })?;
+        let name = self
+            .name
+            .parse()
+            .map_err(|err| crate::Error::CorruptedData {
+                msg: format!("corrupted name: {}", err),
+            })?;
+        let color = self
+            .color
+            .parse()
+            .map_err(|err| crate::Error::CorruptedData {
+                msg: format!("corrupted color: {}", err),
+            })?;
+        let emoji = self
+            .emoji
+            .parse()
+            .map_err(|err| crate::Error::CorruptedData {
+                msg: format!("corrupted emoji: {}", err),
+            })?;
+
+        Ok(Category::new(
+            id,
+            group_id,
+            name,
+            color,
+            emoji,
+            self.created_at,
+        ))
+    }
+}
@@ -0,0 +1,73 @@
+use domain::types::expense_category::ExpenseCategory;
+
+pub struct DbExpenseCategory(pub u8);
+
+impl From<&ExpenseCategory> for DbExpenseCategory {
+    fn from(c: &ExpenseCategory) -> Self {
+        Self(match c {
+            ExpenseCategory::Food => 10,
+            ExpenseCategory::Groceries => 20,
+            ExpenseCategory::Transport => 30,
+            ExpenseCategory::Lodging => 40,
+            ExpenseCategory::Entertainment => 50,
+            ExpenseCategory::Shopping => 60,
+            ExpenseCategory::Utilities => 70,
+            ExpenseCategory::Health => 80,
+            ExpenseCategory::Other => 90,
+        })
+    }
+}
+
+impl TryInto<ExpenseCategory> for DbExpenseCategory {
+    type Error = crate::Error;
+
+    fn try_into(self) -> Result<ExpenseCategory, Self::Error> {
+        match self.0 {
+            10 => Ok(ExpenseCategory::Food),
+            20 => Ok(ExpenseCategory::Groceries),
+            30 => Ok(ExpenseCategory::Transport),
+            40 => Ok(ExpenseCategory::Lodging),
+            50 => Ok(ExpenseCategory::Entertainment),
+            60 => Ok(ExpenseCategory::Shopping),
+            70 => Ok(ExpenseCategory::Utilities),
+            80 => Ok(ExpenseCategory::Health),
+            90 => Ok(ExpenseCategory::Other),
+            other => Err(crate::Error::CorruptedData {
+                msg: format!("unknown expense category: '{}'", other),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DbExpenseCategory;
+    use domain::types::expense_category::ExpenseCategory;
+
+    #[rstest::rstest]
+    #[case(ExpenseCategory::Food, 10)]
+    #[case(ExpenseCategory::Groceries, 20)]
+    #[case(ExpenseCategory::Transport, 30)]
+    #[case(ExpenseCategory::Lodging, 40)]
+    #[case(ExpenseCategory::Entertainment, 50)]
+    #[case(ExpenseCategory::Shopping, 60)]
+    #[case(ExpenseCategory::Utilities, 70)]
+    #[case(ExpenseCategory::Health, 80)]
+    #[case(ExpenseCategory::Other, 90)]
+    fn roundtrip(#[case] category: ExpenseCategory, #[case] expected_db_value: u8) {
+        let db_category = DbExpenseCategory::from(&category);
+        assert_eq!(expected_db_value, db_category.0);
+
+        let back: ExpenseCategory = db_category.try_into().unwrap();
+        assert_eq!(category, back);
+    }
+
+    #[test]
+    fn from_db_to_domain_invalid() {
+        let err = TryInto::<ExpenseCategory>::try_into(DbExpenseCategory(15)).unwrap_err();
+        assert_eq!(
+            "database corrupted data: unknown expense category: '15'",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::{HashMap, HashSet};
+
+use domain::types::{expense_split::ExpenseSplit, money::Money, user_id::UserId};
+
+pub struct DbSplitMode(pub u8);
+
+impl From<&ExpenseSplit> for DbSplitMode {
+    fn from(split: &ExpenseSplit) -> Self {
+        Self(match split {
+            ExpenseSplit::Equal => 10,
+            ExpenseSplit::Shares { .. } => 20,
+            ExpenseSplit::Exact { .. } => 30,
+            ExpenseSplit::Percentages { .. } => 40,
+        })
+    }
+}
+
+/// Returns the weight persisted for `user_id` in `expense_entry_participant`:
+/// a number of shares, an amount in cents or a percentage, depending on the
+/// split mode. Returns `None` for an equal split.
+pub fn weight_of(split: &ExpenseSplit, user_id: &UserId) -> Result<Option<i64>, crate::Error> {
+    let weight = match split {
+        ExpenseSplit::Equal => return Ok(None),
+        ExpenseSplit::Shares { shares } => shares.get(user_id).copied(),
+        ExpenseSplit::Exact { amounts } => return Ok(amounts.get(user_id).map(Money::cents)),
+        ExpenseSplit::Percentages { percentages } => percentages.get(user_id).copied(),
+    };
+    weight
+        .map(|w| {
+            i64::try_from(w).map_err(|_| crate::Error::CorruptedData {
+                msg: format!("split weight '{}' does not fit in database", w),
+            })
+        })
+        .transpose()
+}
+
+impl DbSplitMode {
+    /// Rebuilds the participants and the split of an expense entry from the
+    /// users (and their weight) stored in `expense_entry_participant`.
+    pub fn into_participants_and_split(
+        self,
+        payer_id: &UserId,
+        users: Vec<(UserId, Option<i64>)>,
+    ) -> Result<(HashSet<UserId>, ExpenseSplit), crate::Error> {
+        let participants = users
+            .iter()
+            .map(|(user_id, _)| *user_id)
+            .filter(|user_id| user_id != payer_id)
+            .collect();
+
+        let split = match self.0 {
+            10 => ExpenseSplit::Equal,
+            20 => ExpenseSplit::Shares {
+                shares: weights(users, |w| u64::try_from(w).ok())?,
+            },
+            30 => ExpenseSplit::Exact {
+                amounts: weights(users, |w| Some(Money::from_cents(w)))?,
+            },
+            40 => ExpenseSplit::Percentages {
+                percentages: weights(users, |w| u64::try_from(w).ok())?,
+            },
+            other => {
+                return Err(crate::Error::CorruptedData {
+                    msg: format!("unknown split mode: '{}'", other),
+                });
+            }
+        };
+
+        Ok((participants, split))
+    }
+}
+
+fn weights<T>(
+    users: Vec<(UserId, Option<i64>)>,
+    convert: impl Fn(i64) -> Option<T>,
+) -> Result<HashMap<UserId, T>, crate::Error> {
+    users
+        .into_iter()
+        .map(|(user_id, weight)| {
+            weight
+                .and_then(&convert)
+                .map(|weight| (user_id, weight))
+                .ok_or_else(|| crate::Error::CorruptedData {
+                    msg: format!("invalid split weight for user '{}'", user_id.value()),
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use domain::types::{expense_split::ExpenseSplit, money::Money, user_id::UserId};
+
+    use super::{DbSplitMode, weight_of};
+
+    #[rstest::rstest]
+    #[case(ExpenseSplit::Equal, 10)]
+    #[case(ExpenseSplit::Shares { shares: HashMap::new() }, 20)]
+    #[case(ExpenseSplit::Exact { amounts: HashMap::new() }, 30)]
+    #[case(ExpenseSplit::Percentages { percentages: HashMap::new() }, 40)]
+    fn from_domain_to_db(#[case] split: ExpenseSplit, #[case] expected_db_value: u8) {
+        assert_eq!(expected_db_value, DbSplitMode::from(&split).0);
+    }
+
+    #[test]
+    fn round_trip_exact() {
+        let payer = UserId::new_random();
+        let participant = UserId::new_random();
+        let split = ExpenseSplit::Exact {
+            amounts: HashMap::from_iter(vec![
+                (payer, Money::from_cents(1_250)),
+                (participant, Money::from_cents(750)),
+            ]),
+        };
+        let users = vec![
+            (payer, weight_of(&split, &payer).unwrap()),
+            (participant, weight_of(&split, &participant).unwrap()),
+        ];
+
+        let (participants, actual) = DbSplitMode::from(&split)
+            .into_participants_and_split(&payer, users)
+            .unwrap();
+
+        assert_eq!(HashSet::from_iter(vec![participant]), participants);
+        assert_eq!(split, actual);
+    }
+
+    #[test]
+    fn weight_too_large() {
+        let user = UserId::new_random();
+        let split = ExpenseSplit::Shares {
+            shares: HashMap::from_iter(vec![(user, u64::MAX)]),
+        };
+        let err = weight_of(&split, &user).unwrap_err();
+        assert!(err.to_string().contains("does not fit in database"));
+    }
+
+    #[test]
+    fn missing_weight() {
+        let payer = UserId::new_random();
+        let err = DbSplitMode(20)
+            .into_participants_and_split(&payer, vec![(UserId::new_random(), None)])
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid split weight"));
+    }
+
+    #[test]
+    fn unknown_mode() {
+        let err = DbSplitMode(27)
+            .into_participants_and_split(&UserId::new_random(), vec![])
+            .unwrap_err();
+        assert_eq!(
+            "database corrupted data: unknown split mode: '27'",
+            err.to_string()
+        );
+    }
+}
@@ -1,33 +1,57 @@
-use std::collections::HashSet;
+use std::collections::HashMap;
 
 use domain::{
     entities::ExpenseEntry,
     types::{
-        expense_entry_id::ExpenseEntryId, expense_entry_status::ExpenseEntryStatus,
-        expense_id::ExpenseId, group_id::GroupId, money::Money, user_id::UserId,
+        category_id::CategoryId, currency::Currency, exchange_rate::ExchangeRate,
+        expense_category::ExpenseCategory, expense_entry_id::ExpenseEntryId,
+        expense_entry_status::ExpenseEntryStatus, expense_id::ExpenseId, expense_note::ExpenseNote,
+        expense_payers::ExpensePayers, expense_title::ExpenseTitle, group_id::GroupId,
+        money::Money, user_id::UserId,
     },
 };
 use sqlx::types::chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+use crate::models::expense_entry::{
+    db_expense_category::DbExpenseCategory, db_split_mode::DbSplitMode,
+};
+
+pub mod db_expense_category;
+pub mod db_split_mode;
+
 #[derive(sqlx::FromRow)]
 pub struct DbExpenseEntry {
     pub id: Uuid,
     pub expense_id: Uuid,
     #[sqlx(rename = "coin_group_id")]
     pub group_id: Uuid,
+    pub title: String,
+    pub note: Option<String>,
+    pub category: u8,
+    pub category_id: Option<Uuid>,
     pub payer_id: Uuid,
-    /// empty = active, Some(expense_entry_id) = inactive
+    /// empty = active or deleted, Some(expense_entry_id) = inactive
     pub status: Option<Uuid>,
     pub total: i64,
     pub author_id: Uuid,
     pub occurred_at: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
+    /// set only for deleted expense entries (tombstones)
+    pub deleted_by: Option<Uuid>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub split_mode: u8,
+    pub currency: String,
+    /// in millionths
+    pub exchange_rate: i64,
 }
 
 pub struct DbExpenseEntryWithParticipants {
     pub entry: DbExpenseEntry,
-    pub participants: Vec<Uuid>,
+    /// Every user listed in `expense_entry_participant`, with their weight.
+    pub participants: Vec<(Uuid, Option<i64>)>,
+    /// Every user listed in `expense_entry_payer`, with the amount they paid.
+    pub payers: Vec<(Uuid, i64)>,
 }
 
 impl TryInto<ExpenseEntry> for DbExpenseEntryWithParticipants {
@@ -49,25 +73,31 @@ impl TryInto<ExpenseEntry> for DbExpenseEntryWithParticipants {
             UserId::new(self.entry.payer_id).map_err(|err| crate::Error::CorruptedData {
                 msg: format!("corrupted payer_id: {}", err),
             })?;
+        let (title, note, category) = build_metadata(
+            &self.entry.title,
+            self.entry.note.as_deref(),
+            self.entry.category,
+        )?;
+        let category_id = build_category_id(self.entry.category_id)?;
         let total = Money::from_cents(self.entry.total);
-        let participants = self
+        let (currency, exchange_rate) =
+            build_currency(&self.entry.currency, self.entry.exchange_rate)?;
+        let users = self
             .participants
             .into_iter()
-            .map(UserId::new)
+            .map(|(user_id, weight)| UserId::new(user_id).map(|user_id| (user_id, weight)))
             .collect::<Result<_, _>>()
             .map_err(|err| crate::Error::CorruptedData {
                 msg: format!("corrupted participant(s): {}", err),
             })?;
-        let status = match self.entry.status {
-            Some(overwritten_by) => ExpenseEntryStatus::Inactive {
-                overwritten_by: ExpenseEntryId::new(overwritten_by).map_err(|err| {
-                    crate::Error::CorruptedData {
-                        msg: format!("corrupted status: {}", err),
-                    }
-                })?,
-            },
-            None => ExpenseEntryStatus::Active,
-        };
+        let (participants, split) =
+            DbSplitMode(self.entry.split_mode).into_participants_and_split(&payer_id, users)?;
+        let payers = build_payers(self.payers)?;
+        let status = build_status(
+            self.entry.status,
+            self.entry.deleted_by,
+            self.entry.deleted_at,
+        )?;
         let author_id =
             UserId::new(self.entry.author_id).map_err(|err| crate::Error::CorruptedData {
                 msg: format!("corrupted author_id: {}", err),
@@ -77,10 +107,18 @@ impl TryInto<ExpenseEntry> for DbExpenseEntryWithParticipants {
             id,
             expense_id,
             group_id,
+            title,
+            note,
+            category,
+            category_id,
             payer_id,
+            payers,
             participants,
+            split,
             status,
             total,
+            currency,
+            exchange_rate,
             author_id,
             self.entry.occurred_at,
             self.entry.created_at,
@@ -97,45 +135,69 @@ pub struct DbExpenseEntryWithOptionalParticipant {
     pub expense_id: Uuid,
     #[sqlx(rename = "coin_group_id")]
     pub group_id: Uuid,
+    pub title: String,
+    pub note: Option<String>,
+    pub category: u8,
+    pub category_id: Option<Uuid>,
     pub payer_id: Uuid,
-    /// empty = active, Some(expense_entry_id) = inactive
+    /// empty = active or deleted, Some(expense_entry_id) = inactive
     pub status: Option<Uuid>,
     pub total: i64,
     pub author_id: Uuid,
     pub occurred_at: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
+    /// set only for deleted expense entries (tombstones)
+    pub deleted_by: Option<Uuid>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub split_mode: u8,
+    pub currency: String,
+    /// in millionths
+    pub exchange_rate: i64,
     pub participant_id: Option<Uuid>,
+    pub weight: Option<i64>,
 }
 
+/// A participant of an expense entry, along with its weight in the split.
+type WeightedUser = (UserId, Option<i64>);
+
+/// Builds expense entries from rows joined with their participants.
+///
+/// # Arguments
+/// - `rows` rows of the same expense entry must be consecutive
+/// - `payers` payers (and the amount they paid) by expense entry id
 pub fn flatten_expense_entries_with_participants(
     rows: Vec<DbExpenseEntryWithOptionalParticipant>,
+    mut payers: HashMap<Uuid, Vec<(Uuid, i64)>>,
 ) -> Result<Vec<ExpenseEntry>, crate::Error> {
     let mut out = Vec::new();
-    let mut current: Option<(DbExpenseEntryWithOptionalParticipant, HashSet<UserId>)> = None;
+    let mut current: Option<(DbExpenseEntryWithOptionalParticipant, Vec<WeightedUser>)> = None;
 
     for row in rows {
         match &mut current {
             Some((first, participants)) if first.id == row.id => {
                 if let Some(participant_id) = row.participant_id {
-                    participants.insert(UserId::new(participant_id).map_err(|err| {
-                        crate::Error::CorruptedData {
+                    participants.push((
+                        UserId::new(participant_id).map_err(|err| crate::Error::CorruptedData {
                             msg: format!("corrupted participant: {}", err),
-                        }
-                    })?);
+                        })?,
+                        row.weight,
+                    ));
                 }
             }
             _ => {
                 if let Some((first, participants)) = current.take() {
-                    out.push(build_expense_entry(first, participants)?);
+                    let entry_payers = payers.remove(&first.id).unwrap_or_default();
+                    out.push(build_expense_entry(first, participants, entry_payers)?);
                 }
 
-                let mut participants = HashSet::new();
+                let mut participants = Vec::new();
                 if let Some(participant_id) = row.participant_id {
-                    participants.insert(UserId::new(participant_id).map_err(|err| {
-                        crate::Error::CorruptedData {
+                    participants.push((
+                        UserId::new(participant_id).map_err(|err| crate::Error::CorruptedData {
                             msg: format!("corrupted participant: {}", err),
-                        }
-                    })?);
+                        })?,
+                        row.weight,
+                    ));
                 }
 
                 current = Some((row, participants));
@@ -144,7 +206,8 @@ pub fn flatten_expense_entries_with_participants(
     }
 
     if let Some((first, participants)) = current {
-        out.push(build_expense_entry(first, participants)?);
+        let entry_payers = payers.remove(&first.id).unwrap_or_default();
+        out.push(build_expense_entry(first, participants, entry_payers)?);
     }
 
     Ok(out)
@@ -152,7 +215,8 @@ pub fn flatten_expense_entries_with_participants(
 
 fn build_expense_entry(
     first: DbExpenseEntryWithOptionalParticipant,
-    participants: HashSet<UserId>,
+    users: Vec<WeightedUser>,
+    payers: Vec<(Uuid, i64)>,
 ) -> Result<ExpenseEntry, crate::Error> {
     let id = ExpenseEntryId::new(first.id).map_err(|err| crate::Error::CorruptedData {
         msg: format!("corrupted id: {}", err),
@@ -175,27 +239,34 @@ fn build_expense_entry(
         msg: format!("corrupted author_id: {}", err),
     })?;
 
-    let status = match first.status {
-        Some(overwritten_by) => ExpenseEntryStatus::Inactive {
-            overwritten_by: ExpenseEntryId::new(overwritten_by).map_err(|err| {
-                crate::Error::CorruptedData {
-                    msg: format!("corrupted status: {}", err),
-                }
-            })?,
-        },
-        None => ExpenseEntryStatus::Active,
-    };
+    let status = build_status(first.status, first.deleted_by, first.deleted_at)?;
+
+    let (participants, split) =
+        DbSplitMode(first.split_mode).into_participants_and_split(&payer_id, users)?;
+    let payers = build_payers(payers)?;
 
+    let (title, note, category) =
+        build_metadata(&first.title, first.note.as_deref(), first.category)?;
+    let category_id = build_category_id(first.category_id)?;
     let total = Money::from_cents(first.total);
+    let (currency, exchange_rate) = build_currency(&first.currency, first.exchange_rate)?;
 
     ExpenseEntry::new(
         id,
         expense_id,
         group_id,
+        title,
+        note,
+        category,
+        category_id,
         payer_id,
+        payers,
         participants,
+        split,
         status,
         total,
+        currency,
+        exchange_rate,
         author_id,
         first.occurred_at,
         first.created_at,
@@ -204,3 +275,89 @@ fn build_expense_entry(
         msg: format!("corrupted expense entry: {}", err),
     })
 }
+
+pub(crate) fn build_metadata(
+    title: &str,
+    note: Option<&str>,
+    category: u8,
+) -> Result<(ExpenseTitle, Option<ExpenseNote>, ExpenseCategory), crate::Error> {
+    let title = title.parse().map_err(|err| crate::Error::CorruptedData {
+        msg: format!("corrupted title: {}", err),
+    })?;
+    let note = note
+        .map(str::parse)
+        .transpose()
+        .map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted note: {}", err),
+        })?;
+    let category = DbExpenseCategory(category).try_into()?;
+    Ok((title, note, category))
+}
+
+pub(crate) fn build_category_id(
+    category_id: Option<Uuid>,
+) -> Result<Option<CategoryId>, crate::Error> {
+    category_id
+        .map(CategoryId::new)
+        .transpose()
+        .map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted category_id: {}", err),
+        })
+}
+
+fn build_payers(payers: Vec<(Uuid, i64)>) -> Result<ExpensePayers, crate::Error> {
+    let amounts = payers
+        .into_iter()
+        .map(|(payer_id, amount)| {
+            UserId::new(payer_id).map(|payer_id| (payer_id, Money::from_cents(amount)))
+        })
+        .collect::<Result<_, _>>()
+        .map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted payer(s): {}", err),
+        })?;
+    ExpensePayers::new(amounts).map_err(|err| crate::Error::CorruptedData {
+        msg: format!("corrupted payers: {}", err),
+    })
+}
+
+pub(crate) fn build_currency(
+    currency: &str,
+    exchange_rate: i64,
+) -> Result<(Currency, ExchangeRate), crate::Error> {
+    let currency = currency
+        .parse()
+        .map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted currency: {}", err),
+        })?;
+    let exchange_rate =
+        ExchangeRate::from_micros(exchange_rate).map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted exchange_rate: {}", err),
+        })?;
+    Ok((currency, exchange_rate))
+}
+
+fn build_status(
+    status: Option<Uuid>,
+    deleted_by: Option<Uuid>,
+    deleted_at: Option<DateTime<Utc>>,
+) -> Result<ExpenseEntryStatus, crate::Error> {
+    match (status, deleted_by, deleted_at) {
+        (Some(overwritten_by), None, None) => Ok(ExpenseEntryStatus::Inactive {
+            overwritten_by: ExpenseEntryId::new(overwritten_by).map_err(|err| {
+                crate::Error::CorruptedData {
+                    msg: format!("corrupted status: {}", err),
+                }
+            })?,
+        }),
+        (None, Some(deleted_by), Some(deleted_at)) => Ok(ExpenseEntryStatus::Deleted {
+            deleted_by: UserId::new(deleted_by).map_err(|err| crate::Error::CorruptedData {
+                msg: format!("corrupted deleted_by: {}", err),
+            })?,
+            deleted_at,
+        }),
+        (None, None, None) => Ok(ExpenseEntryStatus::Active),
+        _ => Err(crate::Error::CorruptedData {
+            msg: "corrupted status: inconsistent status and deletion columns".to_string(),
+        }),
+    }
+}
@@ -0,0 +1,61 @@
+use domain::types::member_role::MemberRole;
+
+pub struct DbMemberRole(pub u8);
+
+impl From<&MemberRole> for DbMemberRole {
+    fn from(r: &MemberRole) -> Self {
+        Self(match r {
+            MemberRole::Admin => 10,
+            MemberRole::Member => 20,
+            MemberRole::Viewer => 30,
+        })
+    }
+}
+
+impl TryInto<MemberRole> for DbMemberRole {
+    type Error = crate::Error;
+
+    fn try_into(self) -> Result<MemberRole, Self::Error> {
+        match self.0 {
+            10 => Ok(MemberRole::Admin),
+            20 => Ok(MemberRole::Member),
+            30 => Ok(MemberRole::Viewer),
+            other => Err(crate::Error::CorruptedData {
+                msg: format!("unknown member role: '{}'", other),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DbMemberRole;
+    use domain::types::member_role::MemberRole;
+
+    #[rstest::rstest]
+    #[case(MemberRole::Admin, 10)]
+    #[case(MemberRole::Member, 20)]
+    #[case(MemberRole::Viewer, 30)]
+    fn from_domain_to_db(#[case] role: MemberRole, #[case] expected_db_value: u8) {
+        let db_role = DbMemberRole::from(&role);
+        assert_eq!(expected_db_value, db_role.0);
+    }
+
+    #[rstest::rstest]
+    #[case(10, MemberRole::Admin)]
+    #[case(20, MemberRole::Member)]
+    #[case(30, MemberRole::Viewer)]
+    fn from_db_to_domain_ok(#[case] db_role: u8, #[case] expected_role: MemberRole) {
+        let role: MemberRole = DbMemberRole(db_role).try_into().unwrap();
+        assert_eq!(expected_role, role);
+    }
+
+    #[test]
+    fn from_db_to_domain_invalid() {
+        let err = TryInto::<MemberRole>::try_into(DbMemberRole(27)).unwrap_err();
+        assert_eq!(
+            "database corrupted data: unknown member role: '27'",
+            err.to_string()
+        );
+    }
+}
@@ -1,18 +1,24 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 
 use domain::{
     entities::Group,
-    types::{group_id::GroupId, user_id::UserId},
+    types::{group_id::GroupId, member_role::MemberRole, user_id::UserId},
 };
 use sqlx::types::chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+use crate::models::group::db_member_role::DbMemberRole;
+
+pub mod db_member_role;
+
 #[derive(sqlx::FromRow)]
 pub struct DbGroup {
     pub id: Uuid,
     pub name: String,
+    pub currency: String,
     pub owner_id: Uuid,
     pub created_at: DateTime<Utc>,
+    pub archived_at: Option<DateTime<Utc>>,
 }
 
 #[derive(sqlx::FromRow)]
@@ -21,6 +27,7 @@ pub struct DbGroupMember {
     #[sqlx(rename = "coin_group_id")]
     pub group_id: Uuid,
     pub member_id: Uuid,
+    pub role: u8,
 }
 
 pub struct DbGroupWithMembers {
@@ -44,6 +51,15 @@ impl TryInto<Group> for DbGroupWithMembers {
                         msg: err.to_string(),
                     },
                 )?;
+        let currency =
+            self.group
+                .currency
+                .parse()
+                .map_err(
+                    |err: domain::types::currency::Error| crate::Error::CorruptedData {
+                        msg: err.to_string(),
+                    },
+                )?;
         let owner_id =
             UserId::new(self.group.owner_id).map_err(|err| crate::Error::CorruptedData {
                 msg: err.to_string(),
@@ -52,20 +68,21 @@ impl TryInto<Group> for DbGroupWithMembers {
         let mut group = Group::new(
             id,
             groupname,
+            currency,
             owner_id,
-            HashSet::new(),
+            HashMap::new(),
             self.group.created_at,
         );
+        group.archived_at = self.group.archived_at;
 
-        let members: Vec<UserId> = self
-            .members
-            .into_iter()
-            .map(|member| UserId::new(member.member_id))
-            .collect::<Result<_, _>>()
-            .map_err(|err| crate::Error::CorruptedData {
-                msg: err.to_string(),
-            })?;
-        group.members.extend(members);
+        for member in self.members {
+            let member_id =
+                UserId::new(member.member_id).map_err(|err| crate::Error::CorruptedData {
+                    msg: err.to_string(),
+                })?;
+            let role: MemberRole = DbMemberRole(member.role).try_into()?;
+            group.members.insert(member_id, role);
+        }
 
         Ok(group)
     }
@@ -75,9 +92,13 @@ impl TryInto<Group> for DbGroupWithMembers {
 pub struct DbGroupWithMember {
     pub id: Uuid,
     pub name: String,
+    pub currency: String,
     pub owner_id: Uuid,
-    pub member_id: Uuid,
+    /// `None` for groups without members.
+    pub member_id: Option<Uuid>,
+    pub role: Option<u8>,
     pub created_at: DateTime<Utc>,
+    pub archived_at: Option<DateTime<Utc>>,
 }
 pub fn flatten_group_with_member(rows: Vec<DbGroupWithMember>) -> Result<Vec<Group>, crate::Error> {
     let mut groups = Vec::new();
@@ -98,27 +119,34 @@ pub fn flatten_group_with_member(rows: Vec<DbGroupWithMember>) -> Result<Vec<Gro
             .map_err(|err| crate::Error::CorruptedData {
                 msg: format!("corrupted group_name: {}", err),
             })?;
+        let currency = first
+            .currency
+            .parse()
+            .map_err(|err| crate::Error::CorruptedData {
+                msg: format!("corrupted currency: {}", err),
+            })?;
         let owner_id = UserId::new(first.owner_id).map_err(|err| crate::Error::CorruptedData {
             msg: format!("corrupted owner_id: {}", err),
         })?;
-        let member_id =
-            UserId::new(first.member_id).map_err(|err| crate::Error::CorruptedData {
-                msg: format!("corrupted member_id: {}", err),
-            })?;
         let mut group = Group::new(
             id,
             name,
+            currency,
             owner_id,
-            HashSet::from_iter(vec![member_id]),
+            HashMap::new(),
             first.created_at,
         );
+        group.archived_at = first.archived_at;
 
-        for remaining in gvs.iter().skip(1) {
-            let member_id =
-                UserId::new(remaining.member_id).map_err(|err| crate::Error::CorruptedData {
-                    msg: format!("corrupted member_id: {}", err),
-                })?;
-            group.members.insert(member_id);
+        for row in gvs {
+            let (Some(member_id), Some(role)) = (row.member_id, row.role) else {
+                continue;
+            };
+            let member_id = UserId::new(member_id).map_err(|err| crate::Error::CorruptedData {
+                msg: format!("corrupted member_id: {}", err),
+            })?;
+            let role: MemberRole = DbMemberRole(role).try_into()?;
+            group.members.insert(member_id, role);
         }
 
         groups.push(group);
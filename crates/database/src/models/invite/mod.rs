@@ -0,0 +1,62 @@
+use domain::{
+    entities::Invite,
+    types::{group_id::GroupId, invite_id::InviteId, user_id::UserId},
+};
+use sqlx::types::chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::models::group::db_member_role::DbMemberRole;
+
+#[derive(sqlx::FromRow)]
+pub struct DbInvite {
+    pub id: Uuid,
+    #[sqlx(rename = "coin_group_id")]
+    pub group_id: Uuid,
+    pub token: String,
+    pub role: u8,
+    pub max_uses: Option<u32>,
+    pub uses: u32,
+    pub author_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl TryInto<Invite> for DbInvite {
+    type Error = crate::Error;
+
+    fn try_into(self) -> Result<Invite, Self::Error> {
+        let id = InviteId::new(self.id).map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted id: {}", err),
+        })?;
+        let group_id = GroupId::new(self.group_id).map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted group_id: {}", err),
+        })?;
+        let token = self
+            .token
+            .parse()
+            .map_err(
+                |err: domain::types::invite_token::Error| crate::Error::CorruptedData {
+                    msg: format!("corrupted token: {}", err),
+                },
+            )?;
+        let role = DbMemberRole(self.role).try_into()?;
+        let author_id = UserId::new(self.author_id).map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted author_id: {}", err),
+        })?;
+
+        let mut invite = Invite::new(
+            id,
+            group_id,
+            token,
+            role,
+            self.max_uses,
+            self.uses,
+            author_id,
+            self.expires_at,
+            self.created_at,
+        );
+        invite.revoked_at = self.revoked_at;
+        Ok(invite)
+    }
+}
@@ -0,0 +1,57 @@
+use domain::types::recurrence::Frequency;
+
+pub struct DbFrequency(pub u8);
+
+impl From<&Frequency> for DbFrequency {
+    fn from(frequency: &Frequency) -> Self {
+        Self(match frequency {
+            Frequency::Daily => 10,
+            Frequency::Weekly => 20,
+            Frequency::Monthly => 30,
+            Frequency::Yearly => 40,
+        })
+    }
+}
+
+impl TryInto<Frequency> for DbFrequency {
+    type Error = crate::Error;
+
+    fn try_into(self) -> Result<Frequency, Self::Error> {
+        match self.0 {
+            10 => Ok(Frequency::Daily),
+            20 => Ok(Frequency::Weekly),
+            30 => Ok(Frequency::Monthly),
+            40 => Ok(Frequency::Yearly),
+            other => Err(crate::Error::CorruptedData {
+                msg: format!("unknown frequency: '{}'", other),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DbFrequency;
+    use domain::types::recurrence::Frequency;
+
+    #[rstest::rstest]
+    #[case(Frequency::Daily, 10)]
+    #[case(Frequency::Weekly, 20)]
+    #[case(Frequency::Monthly, 30)]
+    #[case(Frequency::Yearly, 40)]
+    fn round_trip(#[case] frequency: Frequency, #[case] expected_db_value: u8) {
+        let db_frequency = DbFrequency::from(&frequency);
+        assert_eq!(expected_db_value, db_frequency.0);
+        let actual: Frequency = db_frequency.try_into().unwrap();
+        assert_eq!(frequency, actual);
+    }
+
+    #[test]
+    fn unknown_frequency() {
+        let err = TryInto::<Frequency>::try_into(DbFrequency(12)).unwrap_err();
+        assert_eq!(
+            "database corrupted data: unknown frequency: '12'",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use domain::{
+    entities::RecurringExpense,
+    types::{
+        group_id::GroupId, money::Money, recurrence::Recurrence,
+        recurring_expense_id::RecurringExpenseId, user_id::UserId,
+    },
+};
+use sqlx::types::chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::models::{
+    expense_entry::{
+        build_category_id, build_currency, build_metadata, db_split_mode::DbSplitMode,
+    },
+    recurring_expense::db_frequency::DbFrequency,
+};
+
+pub mod db_frequency;
+
+#[derive(sqlx::FromRow)]
+pub struct DbRecurringExpense {
+    pub id: Uuid,
+    #[sqlx(rename = "coin_group_id")]
+    pub group_id: Uuid,
+    pub title: String,
+    pub note: Option<String>,
+    pub category: u8,
+    pub category_id: Option<Uuid>,
+    pub payer_id: Uuid,
+    pub split_mode: u8,
+    pub total: i64,
+    pub currency: String,
+    /// in millionths
+    pub exchange_rate: i64,
+    pub author_id: Uuid,
+    pub frequency: u8,
+    pub frequency_interval: u32,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub next_occurrence_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+pub struct DbRecurringExpenseWithParticipants {
+    pub recurring_expense: DbRecurringExpense,
+    /// Every user listed in `recurring_expense_participant`, with their weight.
+    pub participants: Vec<(Uuid, Option<i64>)>,
+}
+
+impl TryInto<RecurringExpense> for DbRecurringExpenseWithParticipants {
+    type Error = crate::Error;
+
+    fn try_into(self) -> Result<RecurringExpense, Self::Error> {
+        let row = self.recurring_expense;
+        let id = RecurringExpenseId::new(row.id).map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted id: {}", err),
+        })?;
+        let group_id = GroupId::new(row.group_id).map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted group_id: {}", err),
+        })?;
+        let payer_id = UserId::new(row.payer_id).map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted payer_id: {}", err),
+        })?;
+        let author_id = UserId::new(row.author_id).map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted author_id: {}", err),
+        })?;
+        let (title, note, category) =
+            build_metadata(&row.title, row.note.as_deref(), row.category)?;
+        let category_id = build_category_id(row.category_id)?;
+        let (currency, exchange_rate) = build_currency(&row.currency, row.exchange_rate)?;
+        let users = self
+            .participants
+            .into_iter()
+            .map(|(user_id, weight)| UserId::new(user_id).map(|user_id| (user_id, weight)))
+            .collect::<Result<_, _>>()
+            .map_err(|err| crate::Error::CorruptedData {
+                msg: format!("corrupted participant(s): {}", err),
+            })?;
+        let (participants, split) =
+            DbSplitMode(row.split_mode).into_participants_and_split(&payer_id, users)?;
+        let recurrence = Recurrence::new(
+            DbFrequency(row.frequency).try_into()?,
+            row.frequency_interval,
+            row.starts_at,
+            row.ends_at,
+        )
+        .map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted recurrence: {}", err),
+        })?;
+
+        Ok(RecurringExpense::new(
+            id,
+            group_id,
+            title,
+            note,
+            category,
+            category_id,
+            payer_id,
+            participants,
+            split,
+            Money::from_cents(row.total),
+            currency,
+            exchange_rate,
+            author_id,
+            recurrence,
+            row.next_occurrence_at,
+            row.created_at,
+        ))
+    }
+}
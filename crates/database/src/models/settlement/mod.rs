@@ -0,0 +1,78 @@
+use domain::{
+    entities::Settlement,
+    types::{
+        group_id::GroupId, money::Money, settlement_id::SettlementId,
+        settlement_status::SettlementStatus, user_id::UserId,
+    },
+};
+use sqlx::types::chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[derive(sqlx::FromRow)]
+pub struct DbSettlement {
+    pub id: Uuid,
+    #[sqlx(rename = "coin_group_id")]
+    pub group_id: Uuid,
+    pub from_user_id: Uuid,
+    pub to_user_id: Uuid,
+    pub amount: i64,
+    pub author_id: Uuid,
+    pub occurred_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    /// empty = active, Some(user_id) = cancelled
+    pub cancelled_by: Option<Uuid>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+}
+
+impl TryInto<Settlement> for DbSettlement {
+    type Error = crate::Error;
+
+    fn try_into(self) -> Result<Settlement, Self::Error> {
+        let id = SettlementId::new(self.id).map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted id: {}", err),
+        })?;
+        let group_id = GroupId::new(self.group_id).map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted group_id: {}", err),
+        })?;
+        let from = UserId::new(self.from_user_id).map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted from_user_id: {}", err),
+        })?;
+        let to = UserId::new(self.to_user_id).map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted to_user_id: {}", err),
+        })?;
+        let author_id = UserId::new(self.author_id).map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted author_id: {}", err),
+        })?;
+        let status = match (self.cancelled_by, self.cancelled_at) {
+            (None, None) => SettlementStatus::Active,
+            (Some(cancelled_by), Some(cancelled_at)) => SettlementStatus::Cancelled {
+                cancelled_by: UserId::new(cancelled_by).map_err(|err| {
+                    crate::Error::CorruptedData {
+                        msg: format!("corrupted cancelled_by: {}", err),
+                    }
+                })?,
+                cancelled_at,
+            },
+            _ => {
+                return Err(crate::Error::CorruptedData {
+                    msg: "cancelled_by and cancelled_at must be both set or both empty".to_string(),
+                });
+            }
+        };
+
+        Settlement::new(
+            id,
+            group_id,
+            from,
+            to,
+            Money::from_cents(self.amount),
+            status,
+            author_id,
+            self.occurred_at,
+            self.created_at,
+        )
+        .map_err(|err| crate::Error::CorruptedData {
+            msg: format!("corrupted settlement: {}", err),
+        })
+    }
+}
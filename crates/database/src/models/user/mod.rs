@@ -13,7 +13,10 @@ pub mod db_role;
 pub struct DbUser {
     pub id: Uuid,
     pub name: String,
+    pub handle: String,
+    /// Placeholder for guests, see `guest_email_placeholder`.
     pub email: String,
+    pub guest: bool,
     pub role: u8,
     pub created_at: DateTime<Utc>,
 }
@@ -24,11 +27,16 @@ impl TryInto<User> for DbUser {
     fn try_into(self) -> Result<User, Self::Error> {
         let id = UserId::new(self.id).map_err(to_corrupted_data_err)?;
         let name = self.name.parse().map_err(to_corrupted_data_err)?;
-        let email = self.email.parse().map_err(to_corrupted_data_err)?;
+        let handle = self.handle.parse().map_err(to_corrupted_data_err)?;
+        let email = match self.guest {
+            true => None,
+            false => Some(self.email.parse().map_err(to_corrupted_data_err)?),
+        };
         let role = DbRole(self.role).try_into()?;
         Ok(User {
             id,
             name,
+            handle,
             email,
             role,
             created_at: self.created_at,
@@ -44,3 +52,9 @@ where
         msg: format!("corrupted data: {}", err),
     }
 }
+
+/// Value stored as email of guests, which have none. It is not a valid
+/// email, so that it never matches the one of a registered user.
+pub fn guest_email_placeholder(id: &UserId) -> String {
+    format!("guest:{}", id.value().simple())
+}
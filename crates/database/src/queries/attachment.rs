@@ -0,0 +1,143 @@
+use domain::{
+    entities::Attachment,
+    types::{attachment_id::AttachmentId, expense_id::ExpenseId},
+};
+use sqlx::types::chrono::{DateTime, Utc};
+
+use crate::models::attachment::{
+    DbAttachment, db_attachment_content_type::DbAttachmentContentType,
+};
+
+pub async fn create(
+    tx: &mut crate::Transaction<'_>,
+    attachment: &Attachment,
+) -> Result<(), crate::Error> {
+    sqlx::query(
+        r#"
+    INSERT INTO attachment
+    (id, expense_id, coin_group_id, file_name, content_type, size_bytes, uploaded_by, uploaded_at)
+    VALUES
+    (?, ?, ?, ?, ?, ?, ?, ?)
+    "#,
+    )
+    .bind(attachment.id.value())
+    .bind(attachment.expense_id.value())
+    .bind(attachment.group_id.value())
+    .bind(attachment.file_name.value())
+    .bind(DbAttachmentContentType::from(&attachment.content_type).0)
+    .bind(attachment.size_bytes as i64)
+    .bind(attachment.uploaded_by.value())
+    .bind(attachment.uploaded_at)
+    .execute(tx.as_mut())
+    .await?;
+    Ok(())
+}
+
+pub async fn get_by_id(
+    tx: &mut crate::Transaction<'_>,
+    id: &AttachmentId,
+) -> Result<Option<Attachment>, crate::Error> {
+    let row: Option<DbAttachment> = sqlx::query_as(
+        r#"
+    SELECT *
+    FROM attachment
+    WHERE id = ?
+    "#,
+    )
+    .bind(id.value())
+    .fetch_optional(tx.as_mut())
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(None);
+    };
+    Ok(Some(row.try_into()?))
+}
+
+/// Returns all attachments of the provided expense, sorted by upload date and id.
+pub async fn get_all_for_expense(
+    tx: &mut crate::Transaction<'_>,
+    expense_id: &ExpenseId,
+) -> Result<Vec<Attachment>, crate::Error> {
+    let rows: Vec<DbAttachment> = sqlx::query_as(
+        r#"
+    SELECT *
+    FROM attachment
+    WHERE expense_id = ?
+    ORDER BY uploaded_at, id
+    "#,
+    )
+    .bind(expense_id.value())
+    .fetch_all(tx.as_mut())
+    .await?;
+
+    rows.into_iter().map(TryInto::try_into).collect()
+}
+
+/// Checks whether the provided attachment exists.
+pub async fn exists(
+    tx: &mut crate::Transaction<'_>,
+    id: &AttachmentId,
+) -> Result<bool, crate::Error> {
+    Ok(sqlx::query_as::<_, (i64,)>(
+        r#"
+    SELECT 1
+    FROM attachment
+    WHERE id = ?
+    "#,
+    )
+    .bind(id.value())
+    .fetch_optional(tx.as_mut())
+    .await?
+    .is_some())
+}
+
+/// Deletes the provided attachment.
+/// Its content, stored outside of the database, must be removed by the caller.
+///
+/// # Return
+/// - `true` if the attachment has been deleted
+/// - `false` if it does not exist
+pub async fn delete(
+    tx: &mut crate::Transaction<'_>,
+    id: &AttachmentId,
+) -> Result<bool, crate::Error> {
+    let res = sqlx::query(
+        r#"
+    DELETE FROM attachment
+    WHERE id = ?
+    "#,
+    )
+    .bind(id.value())
+    .execute(tx.as_mut())
+    .await?;
+    Ok(res.rows_affected() == 1)
+}
+
+/// Deletes the attachments of the expenses deleted before `deleted_before`
+/// and not restored since.
+/// Their content, stored outside of the database, must be removed by the caller.
+///
+/// # Return
+/// - the number of deleted attachments
+pub async fn delete_all_of_expenses_deleted_before(
+    tx: &mut crate::Transaction<'_>,
+    deleted_before: DateTime<Utc>,
+) -> Result<u64, crate::Error> {
+    let res = sqlx::query(
+        r#"
+    DELETE FROM attachment
+    WHERE expense_id IN (
+        SELECT expense_id
+        FROM expense_entry
+        GROUP BY expense_id
+        HAVING SUM(status IS NULL AND deleted_at IS NULL) = 0
+        AND MAX(deleted_at) < ?
+    )
+    "#,
+    )
+    .bind(deleted_before)
+    .execute(tx.as_mut())
+    .await?;
+    Ok(res.rows_affected())
+}
@@ -0,0 +1,168 @@
+use domain::{
+    entities::{Budget, BudgetAlert},
+    types::{budget_id::BudgetId, group_id::GroupId},
+};
+
+use crate::models::budget::{DbBudget, DbBudgetAlert, db_budget_period::DbBudgetPeriod};
+
+pub async fn create(tx: &mut crate::Transaction<'_>, budget: &Budget) -> Result<(), crate::Error> {
+    let period = DbBudgetPeriod::from(&budget.period);
+    sqlx::query(
+        r#"
+    INSERT INTO budget
+    (id, coin_group_id, category_id, amount, period, starts_at, ends_at, created_at)
+    VALUES
+    (?, ?, ?, ?, ?, ?, ?, ?)
+    "#,
+    )
+    .bind(budget.id.value())
+    .bind(budget.group_id.value())
+    .bind(budget.category_id.map(|id| id.value()))
+    .bind(budget.amount.cents())
+    .bind(period.0)
+    .bind(period.1)
+    .bind(period.2)
+    .bind(budget.created_at)
+    .execute(tx.as_mut())
+    .await?;
+    Ok(())
+}
+
+pub async fn get_by_id(
+    tx: &mut crate::Transaction<'_>,
+    id: &BudgetId,
+) -> Result<Option<Budget>, crate::Error> {
+    let row: Option<DbBudget> = sqlx::query_as(
+        r#"
+    SELECT *
+    FROM budget
+    WHERE id = ?
+    "#,
+    )
+    .bind(id.value())
+    .fetch_optional(tx.as_mut())
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(None);
+    };
+    Ok(Some(row.try_into()?))
+}
+
+/// Returns all budgets of the provided group, sorted by creation date.
+pub async fn get_all_for_group(
+    tx: &mut crate::Transaction<'_>,
+    group_id: &GroupId,
+) -> Result<Vec<Budget>, crate::Error> {
+    let rows: Vec<DbBudget> = sqlx::query_as(
+        r#"
+    SELECT *
+    FROM budget
+    WHERE coin_group_id = ?
+    ORDER BY created_at, id
+    "#,
+    )
+    .bind(group_id.value())
+    .fetch_all(tx.as_mut())
+    .await?;
+
+    rows.into_iter().map(TryInto::try_into).collect()
+}
+
+/// Updates the category, amount and period of the provided budget.
+///
+/// # Return
+/// - `true` if the budget has been updated
+/// - `false` if it does not exist
+pub async fn update(
+    tx: &mut crate::Transaction<'_>,
+    budget: &Budget,
+) -> Result<bool, crate::Error> {
+    let period = DbBudgetPeriod::from(&budget.period);
+    let res = sqlx::query(
+        r#"
+    UPDATE budget
+    SET category_id = ?, amount = ?, period = ?, starts_at = ?, ends_at = ?
+    WHERE id = ?
+    "#,
+    )
+    .bind(budget.category_id.map(|id| id.value()))
+    .bind(budget.amount.cents())
+    .bind(period.0)
+    .bind(period.1)
+    .bind(period.2)
+    .bind(budget.id.value())
+    .execute(tx.as_mut())
+    .await?;
+    Ok(res.rows_affected() == 1)
+}
+
+/// Deletes the provided budget, along with its alerts.
+///
+/// # Return
+/// - `true` if the budget has been deleted
+/// - `false` if it does not exist
+pub async fn delete(tx: &mut crate::Transaction<'_>, id: &BudgetId) -> Result<bool, crate::Error> {
+    let res = sqlx::query(
+        r#"
+    DELETE FROM budget
+    WHERE id = ?
+    "#,
+    )
+    .bind(id.value())
+    .execute(tx.as_mut())
+    .await?;
+    Ok(res.rows_affected() == 1)
+}
+
+/// Records an alert, unless one was already raised for the same threshold
+/// and period of the budget.
+///
+/// # Return
+/// - `true` if the alert has been recorded
+/// - `false` if it had already been raised
+pub async fn create_alert(
+    tx: &mut crate::Transaction<'_>,
+    alert: &BudgetAlert,
+) -> Result<bool, crate::Error> {
+    let res = sqlx::query(
+        r#"
+    INSERT INTO budget_alert
+    (budget_id, threshold, period_starts_at, expense_id, consumed, created_at)
+    VALUES
+    (?, ?, ?, ?, ?, ?)
+    ON CONFLICT DO NOTHING
+    "#,
+    )
+    .bind(alert.budget_id.value())
+    .bind(alert.threshold.percent())
+    .bind(alert.period_starts_at)
+    .bind(alert.expense_id.value())
+    .bind(alert.consumed.cents())
+    .bind(alert.created_at)
+    .execute(tx.as_mut())
+    .await?;
+    Ok(res.rows_affected() == 1)
+}
+
+/// Returns the alerts raised for the budgets of the provided group,
+/// most recent first.
+pub async fn get_alerts_for_group(
+    tx: &mut crate::Transaction<'_>,
+    group_id: &GroupId,
+) -> Result<Vec<BudgetAlert>, crate::Error> {
+    let rows: Vec<DbBudgetAlert> = sqlx::query_as(
+        r#"
+    SELECT budget_alert.*
+    FROM budget_alert
+    JOIN budget ON budget.id = budget_alert.budget_id
+    WHERE budget.coin_group_id = ?
+    ORDER BY budget_alert.created_at DESC, budget_alert.threshold DESC
+    "#,
+    )
+    .bind(group_id.value())
+    .fetch_all(tx.as_mut())
+    .await?;
+
+    rows.into_iter().map(TryInto::try_into).collect()
+}
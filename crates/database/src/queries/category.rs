@@ -0,0 +1,149 @@
+use domain::{
+    entities::Category,
+    types::{category_id::CategoryId, category_name::CategoryName, group_id::GroupId},
+};
+use uuid::Uuid;
+
+use crate::models::category::DbCategory;
+
+/// Checks whether a category with the provided name already exists in the group.
+/// The comparison is case-insensitive.
+///
+/// # Arguments
+/// - `tx`
+/// - `group_id`
+/// - `name`
+/// - `excluded_id` category to ignore, such as the one being renamed
+pub async fn exists_by_name_for_group(
+    tx: &mut crate::Transaction<'_>,
+    group_id: &GroupId,
+    name: &CategoryName,
+    excluded_id: Option<&CategoryId>,
+) -> Result<bool, crate::Error> {
+    Ok(sqlx::query_as::<_, (Uuid,)>(
+        r#"
+        SELECT id
+        FROM category
+        WHERE coin_group_id = ?
+        AND name = ? COLLATE NOCASE
+        AND id IS NOT ?
+        "#,
+    )
+    .bind(group_id.value())
+    .bind(name.value())
+    .bind(excluded_id.map(|id| id.value()))
+    .fetch_optional(tx.as_mut())
+    .await?
+    .is_some())
+}
+
+pub async fn create(
+    tx: &mut crate::Transaction<'_>,
+    category: &Category,
+) -> Result<(), crate::Error> {
+    sqlx::query(
+        r#"
+    INSERT INTO category
+    (id, coin_group_id, name, color, emoji, created_at)
+    VALUES
+    (?, ?, ?, ?, ?, ?)
+    "#,
+    )
+    .bind(category.id.value())
+    .bind(category.group_id.value())
+    .bind(category.name.value())
+    .bind(category.color.value())
+    .bind(category.emoji.value())
+    .bind(category.created_at)
+    .execute(tx.as_mut())
+    .await?;
+    Ok(())
+}
+
+pub async fn get_by_id(
+    tx: &mut crate::Transaction<'_>,
+    id: &CategoryId,
+) -> Result<Option<Category>, crate::Error> {
+    let row: Option<DbCategory> = sqlx::query_as(
+        r#"
+    SELECT *
+    FROM category
+    WHERE id = ?
+    "#,
+    )
+    .bind(id.value())
+    .fetch_optional(tx.as_mut())
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(None);
+    };
+    Ok(Some(row.try_into()?))
+}
+
+/// Returns all categories of the provided group, sorted by name (case-insensitive).
+pub async fn get_all_for_group(
+    tx: &mut crate::Transaction<'_>,
+    group_id: &GroupId,
+) -> Result<Vec<Category>, crate::Error> {
+    let rows: Vec<DbCategory> = sqlx::query_as(
+        r#"
+    SELECT *
+    FROM category
+    WHERE coin_group_id = ?
+    ORDER BY name COLLATE NOCASE, id
+    "#,
+    )
+    .bind(group_id.value())
+    .fetch_all(tx.as_mut())
+    .await?;
+
+    rows.into_iter().map(TryInto::try_into).collect()
+}
+
+/// Updates the name, color and emoji of the provided category.
+///
+/// # Return
+/// - `true` if the category has been updated
+/// - `false` if it does not exist
+pub async fn update(
+    tx: &mut crate::Transaction<'_>,
+    category: &Category,
+) -> Result<bool, crate::Error> {
+    let res = sqlx::query(
+        r#"
+    UPDATE category
+    SET name = ?, color = ?, emoji = ?
+    WHERE id = ?
+    "#,
+    )
+    .bind(category.name.value())
+    .bind(category.color.value())
+    .bind(category.emoji.value())
+    .bind(category.id.value())
+    .execute(tx.as_mut())
+    .await?;
+    Ok(res.rows_affected() == 1)
+}
+
+/// Deletes the provided category.
+/// Expenses referencing it become uncategorized.
+///
+/// # Return
+/// - `true` if the category has been deleted
+/// - `false` if it does not exist
+pub async fn delete(
+    tx: &mut crate::Transaction<'_>,
+    id: &CategoryId,
+) -> Result<bool, crate::Error> {
+    let res = sqlx::query(
+        r#"
+    DELETE FROM category
+    WHERE id = ?
+    "#,
+    )
+    .bind(id.value())
+    .execute(tx.as_mut())
+    .await?;
+    Ok(res.rows_affected() == 1)
+}
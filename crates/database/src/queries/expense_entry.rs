@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use domain::{
     entities::ExpenseEntry,
     types::{
@@ -5,13 +7,18 @@ use domain::{
         expense_id::ExpenseId, group_id::GroupId,
     },
 };
-use sqlx::QueryBuilder;
+use sqlx::{
+    QueryBuilder,
+    types::chrono::{DateTime, Utc},
+};
 use uuid::Uuid;
 
 use crate::{
     DbPagination,
     models::expense_entry::{
         DbExpenseEntry, DbExpenseEntryWithOptionalParticipant, DbExpenseEntryWithParticipants,
+        db_expense_category::DbExpenseCategory,
+        db_split_mode::{DbSplitMode, weight_of},
         flatten_expense_entries_with_participants,
     },
 };
@@ -24,9 +31,10 @@ pub async fn create(
     sqlx::query(
         r#"
     INSERT INTO expense_entry 
-    (id, expense_id, coin_group_id, payer_id, status, total, author_id, occurred_at, created_at)
+    (id, expense_id, coin_group_id, payer_id, status, total, author_id, occurred_at, created_at,
+    deleted_by, deleted_at, split_mode, currency, exchange_rate, title, note, category, category_id)
     VALUES
-    (?, ?, ?, ?, ?, ?, ?, ?, ?)
+    (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
     "#,
     )
     .bind(expense_entry_id)
@@ -34,28 +42,78 @@ pub async fn create(
     .bind(expense_entry.group_id.value())
     .bind(expense_entry.payer_id.value())
     .bind(match expense_entry.status {
-        ExpenseEntryStatus::Active => None,
         ExpenseEntryStatus::Inactive { overwritten_by } => Some(overwritten_by.value()),
+        ExpenseEntryStatus::Active | ExpenseEntryStatus::Deleted { .. } => None,
     })
     .bind(expense_entry.total.cents())
     .bind(expense_entry.author_id.value())
     .bind(expense_entry.occurred_at)
     .bind(expense_entry.created_at)
+    .bind(match expense_entry.status {
+        ExpenseEntryStatus::Deleted { deleted_by, .. } => Some(deleted_by.value()),
+        _ => None,
+    })
+    .bind(match expense_entry.status {
+        ExpenseEntryStatus::Deleted { deleted_at, .. } => Some(deleted_at),
+        _ => None,
+    })
+    .bind(DbSplitMode::from(&expense_entry.split).0)
+    .bind(expense_entry.currency.value())
+    .bind(expense_entry.exchange_rate.micros())
+    .bind(expense_entry.title.value())
+    .bind(expense_entry.note.as_ref().map(|note| note.value()))
+    .bind(DbExpenseCategory::from(&expense_entry.category).0)
+    .bind(
+        expense_entry
+            .category_id
+            .map(|category_id| category_id.value()),
+    )
     .execute(tx.as_mut())
     .await?;
 
-    if expense_entry.participants.is_empty() {
+    let mut qb = QueryBuilder::new(
+        r#"
+    INSERT INTO expense_entry_payer (expense_entry_id, payer_id, amount)
+    "#,
+    );
+    qb.push_values(
+        expense_entry.payers.amounts(),
+        |mut b, (payer_id, amount)| {
+            b.push_bind(expense_entry_id)
+                .push_bind(payer_id.value())
+                .push_bind(amount.cents());
+        },
+    );
+    qb.build().execute(tx.as_mut()).await?;
+
+    // non-equal splits also list the payer if they bear a part of the expense
+    let users = expense_entry
+        .split
+        .users()
+        .unwrap_or_else(|| expense_entry.participants.clone());
+    if users.is_empty() {
         return Ok(());
     }
 
     let mut qb = QueryBuilder::new(
         r#"
-    INSERT INTO expense_entry_participant (expense_entry_id, participant_id)
+    INSERT INTO expense_entry_participant (expense_entry_id, participant_id, weight)
     "#,
     );
-    qb.push_values(&expense_entry.participants, |mut b, participant_id| {
+    let weights = users
+        .iter()
+        .map(|participant_id| {
+            Ok((
+                participant_id,
+                weight_of(&expense_entry.split, participant_id)?,
+            ))
+        })
+        .collect::<Result<Vec<_>, crate::Error>>()?;
+
+    qb.push_values(weights, |mut b, (participant_id, weight)| {
         b.push_bind(expense_entry_id)
-            .push_bind(participant_id.value());
+            .push_bind(participant_id.value())
+            .push_bind(weight);
     });
     qb.build().execute(tx.as_mut()).await?;
 
@@ -77,7 +135,16 @@ pub async fn get_by_id(
         total,
         author_id,
         occurred_at,
-        created_at
+        created_at,
+        deleted_by,
+        deleted_at,
+        split_mode,
+        currency,
+        exchange_rate,
+        title,
+        note,
+        category,
+        category_id
     FROM expense_entry
     WHERE id = ?
     "#,
@@ -90,9 +157,9 @@ pub async fn get_by_id(
         return Ok(None);
     };
 
-    let participants: Vec<(Uuid,)> = sqlx::query_as(
+    let participants: Vec<(Uuid, Option<i64>)> = sqlx::query_as(
         r#"
-    SELECT participant_id
+    SELECT participant_id, weight
     FROM expense_entry_participant
     WHERE expense_entry_id = ?
     "#,
@@ -101,15 +168,25 @@ pub async fn get_by_id(
     .fetch_all(tx.as_mut())
     .await?;
 
+    let payers = get_payers(tx, &[expense_entry.id])
+        .await?
+        .remove(&expense_entry.id)
+        .unwrap_or_default();
+
     Ok(Some(
         DbExpenseEntryWithParticipants {
             entry: expense_entry,
-            participants: participants.into_iter().map(|p| p.0).collect(),
+            participants,
+            payers,
         }
         .try_into()?,
     ))
 }
 
+/// Returns every version of the provided `expense_id`, whatever their status.
+///
+/// # Return
+/// - a list of expense entries, sorted by (system) creation date and entry id
 pub async fn get_all_by_expense_id(
     tx: &mut crate::Transaction<'_>,
     expense_id: &ExpenseId,
@@ -126,17 +203,153 @@ pub async fn get_all_by_expense_id(
         ee.author_id,
         ee.occurred_at,
         ee.created_at,
-        eep.participant_id
+        ee.deleted_by,
+        ee.deleted_at,
+        ee.split_mode,
+        ee.currency,
+        ee.exchange_rate,
+        ee.title,
+        ee.note,
+        ee.category,
+        ee.category_id,
+        eep.participant_id,
+        eep.weight
     FROM expense_entry ee
     LEFT JOIN expense_entry_participant eep ON eep.expense_entry_id = ee.id
     WHERE ee.expense_id = ?
+    ORDER BY ee.created_at, ee.id
     "#,
     )
     .bind(expense_id.value())
     .fetch_all(tx.as_mut())
     .await?;
 
-    flatten_expense_entries_with_participants(rows)
+    let payers = get_payers(tx, &entry_ids(&rows)).await?;
+    flatten_expense_entries_with_participants(rows, payers)
+}
+
+/// Returns every expense entry associated to the provided `group_id`,
+/// whatever their status.
+///
+/// # Return
+/// - a list of expense entries, sorted by (system) creation date and entry id
+pub async fn get_all_for_group(
+    tx: &mut crate::Transaction<'_>,
+    group_id: &GroupId,
+) -> Result<Vec<ExpenseEntry>, crate::Error> {
+    let rows: Vec<DbExpenseEntryWithOptionalParticipant> = sqlx::query_as(
+        r#"
+    SELECT
+        ee.id,
+        ee.expense_id,
+        ee.coin_group_id,
+        ee.payer_id,
+        ee.status,
+        ee.total,
+        ee.author_id,
+        ee.occurred_at,
+        ee.created_at,
+        ee.deleted_by,
+        ee.deleted_at,
+        ee.split_mode,
+        ee.currency,
+        ee.exchange_rate,
+        ee.title,
+        ee.note,
+        ee.category,
+        ee.category_id,
+        eep.participant_id,
+        eep.weight
+    FROM expense_entry ee
+    LEFT JOIN expense_entry_participant eep ON eep.expense_entry_id = ee.id
+    WHERE ee.coin_group_id = ?
+    ORDER BY ee.created_at, ee.id
+    "#,
+    )
+    .bind(group_id.value())
+    .fetch_all(tx.as_mut())
+    .await?;
+
+    let payers = get_payers(tx, &entry_ids(&rows)).await?;
+    flatten_expense_entries_with_participants(rows, payers)
+}
+
+/// Returns the active expense entry of the provided `expense_id`, if any.
+pub async fn get_active_by_expense_id(
+    tx: &mut crate::Transaction<'_>,
+    expense_id: &ExpenseId,
+) -> Result<Option<ExpenseEntry>, crate::Error> {
+    let rows: Vec<DbExpenseEntryWithOptionalParticipant> = sqlx::query_as(
+        r#"
+    SELECT
+        ee.id,
+        ee.expense_id,
+        ee.coin_group_id,
+        ee.payer_id,
+        ee.status,
+        ee.total,
+        ee.author_id,
+        ee.occurred_at,
+        ee.created_at,
+        ee.deleted_by,
+        ee.deleted_at,
+        ee.split_mode,
+        ee.currency,
+        ee.exchange_rate,
+        ee.title,
+        ee.note,
+        ee.category,
+        ee.category_id,
+        eep.participant_id,
+        eep.weight
+    FROM expense_entry ee
+    LEFT JOIN expense_entry_participant eep ON eep.expense_entry_id = ee.id
+    WHERE ee.expense_id = ?
+    AND ee.status IS NULL
+    AND ee.deleted_at IS NULL
+    "#,
+    )
+    .bind(expense_id.value())
+    .fetch_all(tx.as_mut())
+    .await?;
+
+    let payers = get_payers(tx, &entry_ids(&rows)).await?;
+    let mut expense_entries = flatten_expense_entries_with_participants(rows, payers)?;
+    if expense_entries.len() > 1 {
+        return Err(crate::Error::CorruptedData {
+            msg: format!(
+                "expense {} has more than one active entry",
+                expense_id.value()
+            ),
+        });
+    }
+    Ok(expense_entries.pop())
+}
+
+/// Marks the provided active expense entry as overwritten by `overwritten_by`.
+///
+/// # Return
+/// - `true` if the entry was active and has been updated
+/// - `false` if the entry does not exist or is not active
+pub async fn mark_as_overwritten(
+    tx: &mut crate::Transaction<'_>,
+    expense_entry_id: &ExpenseEntryId,
+    overwritten_by: &ExpenseEntryId,
+) -> Result<bool, crate::Error> {
+    let res = sqlx::query(
+        r#"
+    UPDATE expense_entry
+    SET status = ?
+    WHERE id = ?
+    AND status IS NULL
+    AND deleted_at IS NULL
+    "#,
+    )
+    .bind(overwritten_by.value())
+    .bind(expense_entry_id.value())
+    .execute(tx.as_mut())
+    .await?;
+    Ok(res.rows_affected() == 1)
 }
 
 /// Returns all active expense entries associated to the provided `group_id`.
@@ -160,6 +373,7 @@ pub async fn get_all_active_for_group(
             FROM expense_entry
             WHERE coin_group_id = ?
             AND status IS NULL
+            AND deleted_at IS NULL
             ORDER BY created_at DESC, id
             LIMIT ? OFFSET ?
         )
@@ -173,7 +387,17 @@ pub async fn get_all_active_for_group(
             ee.author_id,
             ee.occurred_at,
             ee.created_at,
-            eep.participant_id
+            ee.deleted_by,
+            ee.deleted_at,
+            ee.split_mode,
+            ee.currency,
+            ee.exchange_rate,
+            ee.title,
+            ee.note,
+            ee.category,
+            ee.category_id,
+            eep.participant_id,
+            eep.weight
         FROM expense_entry ee
         JOIN paged_expenses pe ON pe.id = ee.id
         LEFT JOIN expense_entry_participant eep ON eep.expense_entry_id = ee.id
@@ -186,7 +410,110 @@ pub async fn get_all_active_for_group(
     .fetch_all(tx.as_mut())
     .await?;
 
-    flatten_expense_entries_with_participants(rows)
+    let payers = get_payers(tx, &entry_ids(&rows)).await?;
+    flatten_expense_entries_with_participants(rows, payers)
+}
+
+/// Returns every active expense entry associated to the provided `group_id`,
+/// without any pagination.
+///
+/// # Return
+/// - a list of expense entires, sorted by (system) creation date and entry id
+pub async fn get_all_active_for_group_unpaginated(
+    tx: &mut crate::Transaction<'_>,
+    group_id: &GroupId,
+) -> Result<Vec<ExpenseEntry>, crate::Error> {
+    let rows: Vec<DbExpenseEntryWithOptionalParticipant> = sqlx::query_as(
+        r#"
+        SELECT
+            ee.id,
+            ee.expense_id,
+            ee.coin_group_id,
+            ee.payer_id,
+            ee.status,
+            ee.total,
+            ee.author_id,
+            ee.occurred_at,
+            ee.created_at,
+            ee.deleted_by,
+            ee.deleted_at,
+            ee.split_mode,
+            ee.currency,
+            ee.exchange_rate,
+            ee.title,
+            ee.note,
+            ee.category,
+            ee.category_id,
+            eep.participant_id,
+            eep.weight
+        FROM expense_entry ee
+        LEFT JOIN expense_entry_participant eep ON eep.expense_entry_id = ee.id
+        WHERE ee.coin_group_id = ?
+        AND ee.status IS NULL
+        AND ee.deleted_at IS NULL
+        ORDER BY ee.created_at DESC, ee.id
+        "#,
+    )
+    .bind(group_id.value())
+    .fetch_all(tx.as_mut())
+    .await?;
+
+    let payers = get_payers(tx, &entry_ids(&rows)).await?;
+    flatten_expense_entries_with_participants(rows, payers)
+}
+
+/// Returns every active expense entry associated to the provided `group_id`
+/// that occurred within `[from, to)`, without any pagination.
+///
+/// # Return
+/// - a list of expense entires, sorted by occurrence date and entry id
+pub async fn get_all_active_for_group_between(
+    tx: &mut crate::Transaction<'_>,
+    group_id: &GroupId,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<ExpenseEntry>, crate::Error> {
+    let rows: Vec<DbExpenseEntryWithOptionalParticipant> = sqlx::query_as(
+        r#"
+        SELECT
+            ee.id,
+            ee.expense_id,
+            ee.coin_group_id,
+            ee.payer_id,
+            ee.status,
+            ee.total,
+            ee.author_id,
+            ee.occurred_at,
+            ee.created_at,
+            ee.deleted_by,
+            ee.deleted_at,
+            ee.split_mode,
+            ee.currency,
+            ee.exchange_rate,
+            ee.title,
+            ee.note,
+            ee.category,
+            ee.category_id,
+            eep.participant_id,
+            eep.weight
+        FROM expense_entry ee
+        LEFT JOIN expense_entry_participant eep ON eep.expense_entry_id = ee.id
+        WHERE ee.coin_group_id = ?
+        AND ee.status IS NULL
+        AND ee.deleted_at IS NULL
+        AND ee.occurred_at >= ?
+        AND ee.occurred_at < ?
+        ORDER BY ee.occurred_at, ee.id
+        "#,
+    )
+    .bind(group_id.value())
+    .bind(from)
+    .bind(to)
+    .fetch_all(tx.as_mut())
+    .await?;
+
+    let payers = get_payers(tx, &entry_ids(&rows)).await?;
+    flatten_expense_entries_with_participants(rows, payers)
 }
 
 pub async fn count_all_active_for_group(
@@ -199,6 +526,7 @@ pub async fn count_all_active_for_group(
         FROM expense_entry ee
         WHERE ee.coin_group_id = ?
           AND ee.status IS NULL
+          AND ee.deleted_at IS NULL
         "#,
     )
     .bind(group_id.value())
@@ -206,3 +534,41 @@ pub async fn count_all_active_for_group(
     .await?;
     Ok(count as u64)
 }
+
+fn entry_ids(rows: &[DbExpenseEntryWithOptionalParticipant]) -> Vec<Uuid> {
+    let mut ids: Vec<Uuid> = rows.iter().map(|row| row.id).collect();
+    ids.dedup();
+    ids
+}
+
+/// Returns the payers of the provided expense entries, with the amount they
+/// paid, by expense entry id.
+async fn get_payers(
+    tx: &mut crate::Transaction<'_>,
+    expense_entry_ids: &[Uuid],
+) -> Result<HashMap<Uuid, Vec<(Uuid, i64)>>, crate::Error> {
+    let mut payers: HashMap<Uuid, Vec<(Uuid, i64)>> = HashMap::new();
+    if expense_entry_ids.is_empty() {
+        return Ok(payers);
+    }
+
+    let mut qb = QueryBuilder::new(
+        r#"
+    SELECT expense_entry_id, payer_id, amount
+    FROM expense_entry_payer
+    WHERE expense_entry_id IN
+    "#,
+    );
+    qb.push_tuples(expense_entry_ids, |mut b, id| {
+        b.push_bind(*id);
+    });
+    let rows: Vec<(Uuid, Uuid, i64)> = qb.build_query_as().fetch_all(tx.as_mut()).await?;
+
+    for (expense_entry_id, payer_id, amount) in rows {
+        payers
+            .entry(expense_entry_id)
+            .or_default()
+            .push((payer_id, amount));
+    }
+    Ok(payers)
+}
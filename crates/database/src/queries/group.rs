@@ -1,14 +1,18 @@
 use domain::{
     entities::Group,
-    types::{group_id::GroupId, groupname::Groupname, user_id::UserId},
+    types::{group_id::GroupId, groupname::Groupname, member_role::MemberRole, user_id::UserId},
+};
+use sqlx::{
+    QueryBuilder,
+    types::chrono::{DateTime, Utc},
 };
-use sqlx::QueryBuilder;
 use uuid::Uuid;
 
 use crate::{
     DbPagination,
     models::group::{
-        DbGroup, DbGroupMember, DbGroupWithMember, DbGroupWithMembers, flatten_group_with_member,
+        DbGroup, DbGroupMember, DbGroupWithMember, DbGroupWithMembers,
+        db_member_role::DbMemberRole, flatten_group_with_member,
     },
 };
 
@@ -39,12 +43,13 @@ pub async fn create(
     let group_id = group.id.value();
     sqlx::query(
         r#"
-    INSERT INTO coin_group (id, name, owner_id, created_at) VALUES
-    (?, ?, ?, ?)
+    INSERT INTO coin_group (id, name, currency, owner_id, created_at) VALUES
+    (?, ?, ?, ?, ?)
     "#,
     )
     .bind(group_id)
     .bind(group.name.value())
+    .bind(group.currency.value())
     .bind(group.owner_id.value())
     .bind(group.created_at)
     .execute(tx.as_mut())
@@ -56,11 +61,13 @@ pub async fn create(
 
     let mut qb = QueryBuilder::new(
         r#"
-    INSERT INTO coin_group_member (coin_group_id, member_id)
+    INSERT INTO coin_group_member (coin_group_id, member_id, role)
     "#,
     );
-    qb.push_values(&group.members, |mut b, member_id| {
-        b.push_bind(group_id).push_bind(member_id.value());
+    qb.push_values(&group.members, |mut b, (member_id, role)| {
+        b.push_bind(group_id)
+            .push_bind(member_id.value())
+            .push_bind(DbMemberRole::from(role).0);
     });
     qb.build().execute(tx.as_mut()).await?;
 
@@ -76,8 +83,10 @@ pub async fn get_by_id(
     SELECT
         id,
         name,
+        currency,
         owner_id,
-        created_at
+        created_at,
+        archived_at
     FROM coin_group cg
     WHERE cg.id = ?
     "#,
@@ -92,7 +101,7 @@ pub async fn get_by_id(
 
     let members: Vec<DbGroupMember> = sqlx::query_as(
         r#"
-    SELECT coin_group_id, member_id
+    SELECT coin_group_id, member_id, role
     FROM coin_group_member
     WHERE coin_group_id = ?
     "#,
@@ -108,25 +117,140 @@ pub async fn add_member(
     tx: &mut crate::Transaction<'_>,
     id: &GroupId,
     user_id: &UserId,
+    role: &MemberRole,
 ) -> Result<(), crate::Error> {
     sqlx::query(
         r#"
-    INSERT INTO coin_group_member (coin_group_id, member_id)
-    VALUES (?, ?)
+    INSERT INTO coin_group_member (coin_group_id, member_id, role)
+    VALUES (?, ?, ?)
     "#,
     )
     .bind(id.value())
     .bind(user_id.value())
+    .bind(DbMemberRole::from(role).0)
     .execute(tx.as_mut())
     .await?;
     Ok(())
 }
 
+/// Changes the role of the provided member of the group.
+///
+/// # Return
+/// - `true` if the role has been changed
+/// - `false` if the user is not a member of the group
+pub async fn set_member_role(
+    tx: &mut crate::Transaction<'_>,
+    id: &GroupId,
+    user_id: &UserId,
+    role: &MemberRole,
+) -> Result<bool, crate::Error> {
+    let res = sqlx::query(
+        r#"
+    UPDATE coin_group_member
+    SET role = ?
+    WHERE coin_group_id = ? AND member_id = ?
+    "#,
+    )
+    .bind(DbMemberRole::from(role).0)
+    .bind(id.value())
+    .bind(user_id.value())
+    .execute(tx.as_mut())
+    .await?;
+    Ok(res.rows_affected() == 1)
+}
+
+/// Removes the provided user from the members of the group.
+///
+/// Expense entries and settlements involving the user are left untouched:
+/// they reference the user, not the membership, so they still count in the
+/// group history and balances.
+///
+/// # Return
+/// - `true` if the user has been removed
+/// - `false` if the user was not a member of the group
+pub async fn remove_member(
+    tx: &mut crate::Transaction<'_>,
+    id: &GroupId,
+    user_id: &UserId,
+) -> Result<bool, crate::Error> {
+    let res = sqlx::query(
+        r#"
+    DELETE FROM coin_group_member
+    WHERE coin_group_id = ? AND member_id = ?
+    "#,
+    )
+    .bind(id.value())
+    .bind(user_id.value())
+    .execute(tx.as_mut())
+    .await?;
+    Ok(res.rows_affected() == 1)
+}
+
+/// Makes the provided member the owner of the group, and its current owner
+/// an admin of it.
+///
+/// # Return
+/// - `true` if ownership has been transferred
+/// - `false` if the group does not exist or is not owned by `from_owner_id`
+pub async fn transfer_ownership(
+    tx: &mut crate::Transaction<'_>,
+    id: &GroupId,
+    from_owner_id: &UserId,
+    to_owner_id: &UserId,
+) -> Result<bool, crate::Error> {
+    let res = sqlx::query(
+        r#"
+    UPDATE coin_group
+    SET owner_id = ?
+    WHERE id = ? AND owner_id = ?
+    "#,
+    )
+    .bind(to_owner_id.value())
+    .bind(id.value())
+    .bind(from_owner_id.value())
+    .execute(tx.as_mut())
+    .await?;
+    if res.rows_affected() != 1 {
+        return Ok(false);
+    }
+
+    remove_member(tx, id, to_owner_id).await?;
+    add_member(tx, id, from_owner_id, &MemberRole::Admin).await?;
+
+    Ok(true)
+}
+
+/// Sets the time at which the provided group was archived, `None` to
+/// unarchive it.
+///
+/// # Return
+/// - `true` if the group has been updated
+/// - `false` if it does not exist
+pub async fn set_archived_at(
+    tx: &mut crate::Transaction<'_>,
+    id: &GroupId,
+    archived_at: Option<DateTime<Utc>>,
+) -> Result<bool, crate::Error> {
+    let res = sqlx::query(
+        r#"
+    UPDATE coin_group
+    SET archived_at = ?
+    WHERE id = ?
+    "#,
+    )
+    .bind(archived_at)
+    .bind(id.value())
+    .execute(tx.as_mut())
+    .await?;
+    Ok(res.rows_affected() == 1)
+}
+
 /// Returns all group that contains the provided `user_id` as owner or member.
 ///
 /// # Arguments
 /// - `tx`
 /// - `user_id`
+/// - `archived` whether to return archived groups, or the ones in use
 /// - `page` pagination to apply to groups
 ///
 /// # Return
@@ -134,6 +258,7 @@ pub async fn add_member(
 pub async fn get_all_for_user(
     tx: &mut crate::Transaction<'_>,
     user_id: &UserId,
+    archived: bool,
     pagination: DbPagination,
 ) -> Result<Vec<Group>, crate::Error> {
     let group_ids: Vec<(Uuid,)> = sqlx::query_as(
@@ -141,14 +266,15 @@ pub async fn get_all_for_user(
     SELECT DISTINCT cg.id
     FROM coin_group cg
     LEFT JOIN coin_group_member cgm ON cgm.coin_group_id = cg.id
-    WHERE cg.owner_id = ?
-    OR cgm.member_id = ?
+    WHERE (cg.owner_id = ? OR cgm.member_id = ?)
+    AND (cg.archived_at IS NOT NULL) = ?
     ORDER BY cg.created_at DESC, cg.id
     LIMIT ? OFFSET ?
     "#,
     )
     .bind(user_id.value())
     .bind(user_id.value())
+    .bind(archived)
     .bind(pagination.limit as i64)
     .bind(pagination.offset as i64)
     .fetch_all(tx.as_mut())
@@ -168,9 +294,12 @@ pub async fn get_all_for_user(
     SELECT
         cg.id,
         cg.name,
+        cg.currency,
         cg.owner_id,
         cg.created_at,
-        cgm.member_id
+        cg.archived_at,
+        cgm.member_id,
+        cgm.role
     FROM coin_group cg
     LEFT JOIN coin_group_member cgm ON cgm.coin_group_id = cg.id
     WHERE cg.id IN ({})
@@ -189,18 +318,20 @@ pub async fn get_all_for_user(
 pub async fn count_all_for_user(
     tx: &mut crate::Transaction<'_>,
     user_id: &UserId,
+    archived: bool,
 ) -> Result<u64, crate::Error> {
     let count: i64 = sqlx::query_scalar(
         r#"
     SELECT COUNT(DISTINCT g.id)
     FROM coin_group g
     LEFT JOIN coin_group_member gm ON gm.coin_group_id = g.id
-    WHERE g.owner_id = ?
-    OR gm.member_id = ?
+    WHERE (g.owner_id = ? OR gm.member_id = ?)
+    AND (g.archived_at IS NOT NULL) = ?
     "#,
     )
     .bind(user_id.value())
     .bind(user_id.value())
+    .bind(archived)
     .fetch_one(tx.as_mut())
     .await?;
     Ok(count as u64)
@@ -0,0 +1,141 @@
+use domain::{
+    entities::Invite,
+    types::{group_id::GroupId, invite_id::InviteId, invite_token::InviteToken},
+};
+use sqlx::types::chrono::{DateTime, Utc};
+
+use crate::models::{group::db_member_role::DbMemberRole, invite::DbInvite};
+
+pub async fn create(tx: &mut crate::Transaction<'_>, invite: &Invite) -> Result<(), crate::Error> {
+    sqlx::query(
+        r#"
+    INSERT INTO invite
+    (id, coin_group_id, token, role, max_uses, uses, author_id, expires_at, created_at, revoked_at)
+    VALUES
+    (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+    "#,
+    )
+    .bind(invite.id.value())
+    .bind(invite.group_id.value())
+    .bind(invite.token.value())
+    .bind(DbMemberRole::from(&invite.role).0)
+    .bind(invite.max_uses)
+    .bind(invite.uses)
+    .bind(invite.author_id.value())
+    .bind(invite.expires_at)
+    .bind(invite.created_at)
+    .bind(invite.revoked_at)
+    .execute(tx.as_mut())
+    .await?;
+    Ok(())
+}
+
+pub async fn get_by_id(
+    tx: &mut crate::Transaction<'_>,
+    id: &InviteId,
+) -> Result<Option<Invite>, crate::Error> {
+    let row: Option<DbInvite> = sqlx::query_as(
+        r#"
+    SELECT *
+    FROM invite
+    WHERE id = ?
+    "#,
+    )
+    .bind(id.value())
+    .fetch_optional(tx.as_mut())
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(None);
+    };
+    Ok(Some(row.try_into()?))
+}
+
+pub async fn get_by_token(
+    tx: &mut crate::Transaction<'_>,
+    token: &InviteToken,
+) -> Result<Option<Invite>, crate::Error> {
+    let row: Option<DbInvite> = sqlx::query_as(
+        r#"
+    SELECT *
+    FROM invite
+    WHERE token = ?
+    "#,
+    )
+    .bind(token.value())
+    .fetch_optional(tx.as_mut())
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(None);
+    };
+    Ok(Some(row.try_into()?))
+}
+
+/// Returns all invites of the provided group, including revoked and
+/// expired ones, most recent first.
+pub async fn get_all_for_group(
+    tx: &mut crate::Transaction<'_>,
+    group_id: &GroupId,
+) -> Result<Vec<Invite>, crate::Error> {
+    let rows: Vec<DbInvite> = sqlx::query_as(
+        r#"
+    SELECT *
+    FROM invite
+    WHERE coin_group_id = ?
+    ORDER BY created_at DESC, id
+    "#,
+    )
+    .bind(group_id.value())
+    .fetch_all(tx.as_mut())
+    .await?;
+
+    rows.into_iter().map(TryInto::try_into).collect()
+}
+
+/// Records one more use of the provided invite, unless it reached its
+/// maximum number of uses in the meantime.
+///
+/// # Return
+/// - `true` if the use has been recorded
+/// - `false` if the invite does not exist or cannot be used anymore
+pub async fn increment_uses(
+    tx: &mut crate::Transaction<'_>,
+    id: &InviteId,
+) -> Result<bool, crate::Error> {
+    let res = sqlx::query(
+        r#"
+    UPDATE invite
+    SET uses = uses + 1
+    WHERE id = ? AND (max_uses IS NULL OR uses < max_uses)
+    "#,
+    )
+    .bind(id.value())
+    .execute(tx.as_mut())
+    .await?;
+    Ok(res.rows_affected() == 1)
+}
+
+/// Revokes the provided invite, so that it cannot be accepted anymore.
+///
+/// # Return
+/// - `true` if the invite has been revoked
+/// - `false` if it does not exist or was already revoked
+pub async fn revoke(
+    tx: &mut crate::Transaction<'_>,
+    id: &InviteId,
+    revoked_at: DateTime<Utc>,
+) -> Result<bool, crate::Error> {
+    let res = sqlx::query(
+        r#"
+    UPDATE invite
+    SET revoked_at = ?
+    WHERE id = ? AND revoked_at IS NULL
+    "#,
+    )
+    .bind(revoked_at)
+    .bind(id.value())
+    .execute(tx.as_mut())
+    .await?;
+    Ok(res.rows_affected() == 1)
+}
@@ -1,4 +1,10 @@
+pub mod attachment;
 pub mod auth;
+pub mod budget;
+pub mod category;
 pub mod expense_entry;
 pub mod group;
+pub mod invite;
+pub mod recurring_expense;
+pub mod settlement;
 pub mod user;
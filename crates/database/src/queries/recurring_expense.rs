@@ -0,0 +1,370 @@
+use std::collections::HashMap;
+
+use domain::{
+    entities::RecurringExpense,
+    types::{expense_id::ExpenseId, group_id::GroupId, recurring_expense_id::RecurringExpenseId},
+};
+use sqlx::{
+    QueryBuilder,
+    types::chrono::{DateTime, Utc},
+};
+use uuid::Uuid;
+
+use crate::models::{
+    expense_entry::{
+        db_expense_category::DbExpenseCategory,
+        db_split_mode::{DbSplitMode, weight_of},
+    },
+    recurring_expense::{
+        DbRecurringExpense, DbRecurringExpenseWithParticipants, db_frequency::DbFrequency,
+    },
+};
+
+pub async fn create(
+    tx: &mut crate::Transaction<'_>,
+    recurring_expense: &RecurringExpense,
+) -> Result<(), crate::Error> {
+    sqlx::query(
+        r#"
+    INSERT INTO recurring_expense
+    (id, coin_group_id, title, note, category, category_id, payer_id, split_mode, total,
+    currency, exchange_rate, author_id, frequency, frequency_interval, starts_at, ends_at,
+    next_occurrence_at, created_at)
+    VALUES
+    (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+    "#,
+    )
+    .bind(recurring_expense.id.value())
+    .bind(recurring_expense.group_id.value())
+    .bind(recurring_expense.title.value())
+    .bind(recurring_expense.note.as_ref().map(|note| note.value()))
+    .bind(DbExpenseCategory::from(&recurring_expense.category).0)
+    .bind(recurring_expense.category_id.map(|id| id.value()))
+    .bind(recurring_expense.payer_id.value())
+    .bind(DbSplitMode::from(&recurring_expense.split).0)
+    .bind(recurring_expense.total.cents())
+    .bind(recurring_expense.currency.value())
+    .bind(recurring_expense.exchange_rate.micros())
+    .bind(recurring_expense.author_id.value())
+    .bind(DbFrequency::from(&recurring_expense.recurrence.frequency()).0)
+    .bind(recurring_expense.recurrence.interval())
+    .bind(recurring_expense.recurrence.starts_at())
+    .bind(recurring_expense.recurrence.ends_at())
+    .bind(recurring_expense.next_occurrence_at)
+    .bind(recurring_expense.created_at)
+    .execute(tx.as_mut())
+    .await?;
+
+    insert_participants(tx, recurring_expense).await
+}
+
+pub async fn get_by_id(
+    tx: &mut crate::Transaction<'_>,
+    id: &RecurringExpenseId,
+) -> Result<Option<RecurringExpense>, crate::Error> {
+    let row: Option<DbRecurringExpense> = sqlx::query_as(
+        r#"
+    SELECT *
+    FROM recurring_expense
+    WHERE id = ?
+    "#,
+    )
+    .bind(id.value())
+    .fetch_optional(tx.as_mut())
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(None);
+    };
+
+    Ok(with_participants(tx, vec![row]).await?.pop())
+}
+
+/// Returns every recurring expense of the provided group.
+///
+/// # Return
+/// - a list of recurring expenses, sorted by (system) creation date and id
+pub async fn get_all_for_group(
+    tx: &mut crate::Transaction<'_>,
+    group_id: &GroupId,
+) -> Result<Vec<RecurringExpense>, crate::Error> {
+    let rows: Vec<DbRecurringExpense> = sqlx::query_as(
+        r#"
+    SELECT *
+    FROM recurring_expense
+    WHERE coin_group_id = ?
+    ORDER BY created_at, id
+    "#,
+    )
+    .bind(group_id.value())
+    .fetch_all(tx.as_mut())
+    .await?;
+
+    with_participants(tx, rows).await
+}
+
+/// Returns every recurring expense, of any group, having an occurrence due
+/// at `now`. Recurring expenses of archived groups are left out, so that
+/// their occurrences are only added once the group is unarchived.
+///
+/// # Return
+/// - a list of recurring expenses, sorted by next occurrence and id
+pub async fn get_all_due(
+    tx: &mut crate::Transaction<'_>,
+    now: DateTime<Utc>,
+) -> Result<Vec<RecurringExpense>, crate::Error> {
+    let rows: Vec<DbRecurringExpense> = sqlx::query_as(
+        r#"
+    SELECT re.*
+    FROM recurring_expense re
+    JOIN coin_group cg ON cg.id = re.coin_group_id
+    WHERE re.next_occurrence_at IS NOT NULL
+    AND re.next_occurrence_at <= ?
+    AND cg.archived_at IS NULL
+    ORDER BY re.next_occurrence_at, re.id
+    "#,
+    )
+    .bind(now)
+    .fetch_all(tx.as_mut())
+    .await?;
+
+    with_participants(tx, rows).await
+}
+
+/// Updates the template and the schedule of a recurring expense.
+/// Its participants are replaced by the provided ones.
+///
+/// # Return
+/// - true if the recurring expense has been updated
+/// - false if it does not exist
+pub async fn update(
+    tx: &mut crate::Transaction<'_>,
+    recurring_expense: &RecurringExpense,
+) -> Result<bool, crate::Error> {
+    let res = sqlx::query(
+        r#"
+    UPDATE recurring_expense
+    SET title = ?, note = ?, category = ?, category_id = ?, payer_id = ?, split_mode = ?,
+    total = ?, currency = ?, exchange_rate = ?, frequency = ?, frequency_interval = ?,
+    starts_at = ?, ends_at = ?, next_occurrence_at = ?
+    WHERE id = ?
+    "#,
+    )
+    .bind(recurring_expense.title.value())
+    .bind(recurring_expense.note.as_ref().map(|note| note.value()))
+    .bind(DbExpenseCategory::from(&recurring_expense.category).0)
+    .bind(recurring_expense.category_id.map(|id| id.value()))
+    .bind(recurring_expense.payer_id.value())
+    .bind(DbSplitMode::from(&recurring_expense.split).0)
+    .bind(recurring_expense.total.cents())
+    .bind(recurring_expense.currency.value())
+    .bind(recurring_expense.exchange_rate.micros())
+    .bind(DbFrequency::from(&recurring_expense.recurrence.frequency()).0)
+    .bind(recurring_expense.recurrence.interval())
+    .bind(recurring_expense.recurrence.starts_at())
+    .bind(recurring_expense.recurrence.ends_at())
+    .bind(recurring_expense.next_occurrence_at)
+    .bind(recurring_expense.id.value())
+    .execute(tx.as_mut())
+    .await?;
+
+    if res.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    sqlx::query(
+        r#"
+    DELETE FROM recurring_expense_participant
+    WHERE recurring_expense_id = ?
+    "#,
+    )
+    .bind(recurring_expense.id.value())
+    .execute(tx.as_mut())
+    .await?;
+
+    insert_participants(tx, recurring_expense).await?;
+    Ok(true)
+}
+
+/// Deletes a recurring expense. Expenses already added by it are kept.
+///
+/// # Return
+/// - true if the recurring expense has been deleted
+/// - false if it does not exist
+pub async fn delete(
+    tx: &mut crate::Transaction<'_>,
+    id: &RecurringExpenseId,
+) -> Result<bool, crate::Error> {
+    let res = sqlx::query(
+        r#"
+    DELETE FROM recurring_expense
+    WHERE id = ?
+    "#,
+    )
+    .bind(id.value())
+    .execute(tx.as_mut())
+    .await?;
+    Ok(res.rows_affected() > 0)
+}
+
+/// Moves the next occurrence of a recurring expense from `expected` to `next`.
+///
+/// The update only happens if the next occurrence is still `expected`, so
+/// that concurrent schedulers never claim the same occurrence twice.
+///
+/// # Return
+/// - true if the next occurrence has been updated
+/// - false otherwise
+pub async fn update_next_occurrence(
+    tx: &mut crate::Transaction<'_>,
+    id: &RecurringExpenseId,
+    expected: Option<DateTime<Utc>>,
+    next: Option<DateTime<Utc>>,
+) -> Result<bool, crate::Error> {
+    let res = sqlx::query(
+        r#"
+    UPDATE recurring_expense
+    SET next_occurrence_at = ?
+    WHERE id = ?
+    AND next_occurrence_at IS ?
+    "#,
+    )
+    .bind(next)
+    .bind(id.value())
+    .bind(expected)
+    .execute(tx.as_mut())
+    .await?;
+    Ok(res.rows_affected() > 0)
+}
+
+/// Records an occurrence of a recurring expense.
+///
+/// # Arguments
+/// - `tx`
+/// - `id`
+/// - `occurred_at` scheduled time of the occurrence
+/// - `expense_id` expense added for this occurrence, `None` if it was skipped
+///
+/// # Errors
+/// - unique violation if the occurrence has already been recorded
+pub async fn record_occurrence(
+    tx: &mut crate::Transaction<'_>,
+    id: &RecurringExpenseId,
+    occurred_at: DateTime<Utc>,
+    expense_id: Option<&ExpenseId>,
+) -> Result<(), crate::Error> {
+    sqlx::query(
+        r#"
+    INSERT INTO recurring_expense_occurrence
+    (recurring_expense_id, occurred_at, expense_id)
+    VALUES
+    (?, ?, ?)
+    "#,
+    )
+    .bind(id.value())
+    .bind(occurred_at)
+    .bind(expense_id.map(|id| id.value()))
+    .execute(tx.as_mut())
+    .await?;
+    Ok(())
+}
+
+/// Returns the scheduled time of the latest recorded occurrence of a
+/// recurring expense, if any.
+pub async fn get_last_occurrence_at(
+    tx: &mut crate::Transaction<'_>,
+    id: &RecurringExpenseId,
+) -> Result<Option<DateTime<Utc>>, crate::Error> {
+    Ok(sqlx::query_as::<_, (DateTime<Utc>,)>(
+        r#"
+    SELECT occurred_at
+    FROM recurring_expense_occurrence
+    WHERE recurring_expense_id = ?
+    ORDER BY occurred_at DESC
+    LIMIT 1
+    "#,
+    )
+    .bind(id.value())
+    .fetch_optional(tx.as_mut())
+    .await?
+    .map(|(occurred_at,)| occurred_at))
+}
+
+async fn insert_participants(
+    tx: &mut crate::Transaction<'_>,
+    recurring_expense: &RecurringExpense,
+) -> Result<(), crate::Error> {
+    // non-equal splits also list the payer if they bear a part of the expense
+    let users = recurring_expense
+        .split
+        .users()
+        .unwrap_or_else(|| recurring_expense.participants.clone());
+    if users.is_empty() {
+        return Ok(());
+    }
+
+    let mut qb = QueryBuilder::new(
+        r#"
+    INSERT INTO recurring_expense_participant (recurring_expense_id, participant_id, weight)
+    "#,
+    );
+    let weights = users
+        .iter()
+        .map(|participant_id| {
+            Ok((
+                participant_id,
+                weight_of(&recurring_expense.split, participant_id)?,
+            ))
+        })
+        .collect::<Result<Vec<_>, crate::Error>>()?;
+
+    qb.push_values(weights, |mut b, (participant_id, weight)| {
+        b.push_bind(recurring_expense.id.value())
+            .push_bind(participant_id.value())
+            .push_bind(weight);
+    });
+    qb.build().execute(tx.as_mut()).await?;
+
+    Ok(())
+}
+
+async fn with_participants(
+    tx: &mut crate::Transaction<'_>,
+    rows: Vec<DbRecurringExpense>,
+) -> Result<Vec<RecurringExpense>, crate::Error> {
+    if rows.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut qb = QueryBuilder::new(
+        r#"
+    SELECT recurring_expense_id, participant_id, weight
+    FROM recurring_expense_participant
+    WHERE recurring_expense_id IN
+    "#,
+    );
+    qb.push_tuples(&rows, |mut b, row| {
+        b.push_bind(row.id);
+    });
+    let participants: Vec<(Uuid, Uuid, Option<i64>)> =
+        qb.build_query_as().fetch_all(tx.as_mut()).await?;
+
+    let mut participants_by_id: HashMap<Uuid, Vec<(Uuid, Option<i64>)>> = HashMap::new();
+    for (recurring_expense_id, participant_id, weight) in participants {
+        participants_by_id
+            .entry(recurring_expense_id)
+            .or_default()
+            .push((participant_id, weight));
+    }
+
+    rows.into_iter()
+        .map(|row| {
+            let participants = participants_by_id.remove(&row.id).unwrap_or_default();
+            DbRecurringExpenseWithParticipants {
+                recurring_expense: row,
+                participants,
+            }
+            .try_into()
+        })
+        .collect()
+}
@@ -0,0 +1,185 @@
+use domain::{
+    entities::Settlement,
+    types::{
+        group_id::GroupId, settlement_id::SettlementId, settlement_status::SettlementStatus,
+        user_id::UserId,
+    },
+};
+use sqlx::types::chrono::{DateTime, Utc};
+
+use crate::{DbPagination, models::settlement::DbSettlement};
+
+pub async fn create(
+    tx: &mut crate::Transaction<'_>,
+    settlement: &Settlement,
+) -> Result<(), crate::Error> {
+    let (cancelled_by, cancelled_at) = match settlement.status {
+        SettlementStatus::Active => (None, None),
+        SettlementStatus::Cancelled {
+            cancelled_by,
+            cancelled_at,
+        } => (Some(cancelled_by.value()), Some(cancelled_at)),
+    };
+
+    sqlx::query(
+        r#"
+    INSERT INTO settlement
+    (id, coin_group_id, from_user_id, to_user_id, amount, author_id, occurred_at, created_at, cancelled_by, cancelled_at)
+    VALUES
+    (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+    "#,
+    )
+    .bind(settlement.id.value())
+    .bind(settlement.group_id.value())
+    .bind(settlement.from.value())
+    .bind(settlement.to.value())
+    .bind(settlement.amount.cents())
+    .bind(settlement.author_id.value())
+    .bind(settlement.occurred_at)
+    .bind(settlement.created_at)
+    .bind(cancelled_by)
+    .bind(cancelled_at)
+    .execute(tx.as_mut())
+    .await?;
+    Ok(())
+}
+
+pub async fn get_by_id(
+    tx: &mut crate::Transaction<'_>,
+    id: &SettlementId,
+) -> Result<Option<Settlement>, crate::Error> {
+    let row: Option<DbSettlement> = sqlx::query_as(
+        r#"
+    SELECT *
+    FROM settlement
+    WHERE id = ?
+    "#,
+    )
+    .bind(id.value())
+    .fetch_optional(tx.as_mut())
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(None);
+    };
+    Ok(Some(row.try_into()?))
+}
+
+/// Marks the provided settlement as cancelled.
+///
+/// # Return
+/// - `true` if the settlement has been cancelled
+/// - `false` if it does not exist or is already cancelled
+pub async fn cancel(
+    tx: &mut crate::Transaction<'_>,
+    id: &SettlementId,
+    cancelled_by: &UserId,
+    cancelled_at: DateTime<Utc>,
+) -> Result<bool, crate::Error> {
+    let res = sqlx::query(
+        r#"
+    UPDATE settlement
+    SET cancelled_by = ?, cancelled_at = ?
+    WHERE id = ?
+    AND cancelled_at IS NULL
+    "#,
+    )
+    .bind(cancelled_by.value())
+    .bind(cancelled_at)
+    .bind(id.value())
+    .execute(tx.as_mut())
+    .await?;
+    Ok(res.rows_affected() == 1)
+}
+
+/// Returns all active settlements associated to the provided `group_id`,
+/// without any pagination.
+pub async fn get_all_active_for_group_unpaginated(
+    tx: &mut crate::Transaction<'_>,
+    group_id: &GroupId,
+) -> Result<Vec<Settlement>, crate::Error> {
+    let rows: Vec<DbSettlement> = sqlx::query_as(
+        r#"
+    SELECT *
+    FROM settlement
+    WHERE coin_group_id = ?
+    AND cancelled_at IS NULL
+    ORDER BY created_at DESC, id
+    "#,
+    )
+    .bind(group_id.value())
+    .fetch_all(tx.as_mut())
+    .await?;
+
+    rows.into_iter().map(TryInto::try_into).collect()
+}
+
+/// Returns all settlements (active and cancelled) associated to the provided
+/// `group_id`, without any pagination, sorted by creation date and id.
+pub async fn get_all_for_group_unpaginated(
+    tx: &mut crate::Transaction<'_>,
+    group_id: &GroupId,
+) -> Result<Vec<Settlement>, crate::Error> {
+    let rows: Vec<DbSettlement> = sqlx::query_as(
+        r#"
+    SELECT *
+    FROM settlement
+    WHERE coin_group_id = ?
+    ORDER BY created_at, id
+    "#,
+    )
+    .bind(group_id.value())
+    .fetch_all(tx.as_mut())
+    .await?;
+
+    rows.into_iter().map(TryInto::try_into).collect()
+}
+
+/// Returns all settlements (active and cancelled) associated to the provided `group_id`.
+///
+/// # Arguments
+/// - `tx`
+/// - `group_id`
+/// - `page` pagination to apply to settlements
+///
+/// # Return
+/// - a list of settlements, sorted by occurrence date (DESC) and id
+pub async fn get_all_for_group(
+    tx: &mut crate::Transaction<'_>,
+    group_id: &GroupId,
+    page: DbPagination,
+) -> Result<Vec<Settlement>, crate::Error> {
+    let rows: Vec<DbSettlement> = sqlx::query_as(
+        r#"
+    SELECT *
+    FROM settlement
+    WHERE coin_group_id = ?
+    ORDER BY occurred_at DESC, id
+    LIMIT ? OFFSET ?
+    "#,
+    )
+    .bind(group_id.value())
+    .bind(page.limit as i64)
+    .bind(page.offset as i64)
+    .fetch_all(tx.as_mut())
+    .await?;
+
+    rows.into_iter().map(TryInto::try_into).collect()
+}
+
+pub async fn count_all_for_group(
+    tx: &mut crate::Transaction<'_>,
+    group_id: &GroupId,
+) -> Result<u64, crate::Error> {
+    let count: i64 = sqlx::query_scalar(
+        r#"
+    SELECT COUNT(*)
+    FROM settlement
+    WHERE coin_group_id = ?
+    "#,
+    )
+    .bind(group_id.value())
+    .fetch_one(tx.as_mut())
+    .await?;
+    Ok(count as u64)
+}
@@ -1,10 +1,13 @@
 use std::collections::{HashMap, HashSet};
 
-use domain::{entities::User, types::user_id::UserId};
+use domain::{
+    entities::User,
+    types::{group_id::GroupId, handle::Handle, user_id::UserId},
+};
 use email_address::EmailAddress;
 use uuid::Uuid;
 
-use crate::models::user::{DbUser, db_role::DbRole};
+use crate::models::user::{DbUser, db_role::DbRole, guest_email_placeholder};
 
 pub async fn email_exists(
     tx: &mut crate::Transaction<'_>,
@@ -23,19 +26,41 @@ pub async fn email_exists(
     .is_some())
 }
 
+pub async fn handle_exists(
+    tx: &mut crate::Transaction<'_>,
+    handle: &Handle,
+) -> Result<bool, crate::Error> {
+    Ok(sqlx::query_as::<_, (Uuid,)>(
+        r#"
+        SELECT id
+        FROM user
+        WHERE handle = ?
+        "#,
+    )
+    .bind(handle.value())
+    .fetch_optional(tx.as_mut())
+    .await?
+    .is_some())
+}
+
 pub async fn create(
     tx: &mut crate::Transaction<'_>,
     user: &domain::entities::User,
 ) -> Result<(), crate::Error> {
     sqlx::query(
         r#"
-    INSERT INTO user (id, name, email, role, created_at)
-    VALUES (?, ?, ? ,?, ?)
+    INSERT INTO user (id, name, handle, email, guest, role, created_at)
+    VALUES (?, ?, ?, ?, ?, ?, ?)
     "#,
     )
     .bind(user.id.value())
     .bind(user.name.value())
-    .bind(user.email.email())
+    .bind(user.handle.value())
+    .bind(match &user.email {
+        Some(email) => email.email(),
+        None => guest_email_placeholder(&user.id),
+    })
+    .bind(user.is_guest())
     .bind(DbRole::from(&user.role).0)
     .bind(user.created_at)
     .execute(tx.as_mut())
@@ -64,6 +89,29 @@ pub async fn get_by_email(
     Ok(Some(row.try_into()?))
 }
 
+/// Returns the registered user with the provided handle. Guests are left
+/// out, as they only belong to the group they were added to.
+pub async fn get_by_handle(
+    tx: &mut crate::Transaction<'_>,
+    handle: &Handle,
+) -> Result<Option<User>, crate::Error> {
+    let row: Option<DbUser> = sqlx::query_as(
+        r#"
+    SELECT *
+    FROM user
+    WHERE handle = ? AND guest = FALSE
+    "#,
+    )
+    .bind(handle.value())
+    .fetch_optional(tx.as_mut())
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(None);
+    };
+    Ok(Some(row.try_into()?))
+}
+
 pub async fn get_by_id(
     tx: &mut crate::Transaction<'_>,
     id: &UserId,
@@ -140,3 +188,204 @@ pub async fn get_all_in_ids(
 
     Ok(users)
 }
+
+/// Returns the registered users whose handle or name starts with the
+/// provided text, ignoring the case of ASCII letters, sorted by handle.
+pub async fn search(
+    tx: &mut crate::Transaction<'_>,
+    text: &str,
+    limit: u32,
+) -> Result<Vec<User>, crate::Error> {
+    let pattern = format!(
+        "{}%",
+        text.replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_")
+    );
+    let rows: Vec<DbUser> = sqlx::query_as(
+        r#"
+    SELECT *
+    FROM user
+    WHERE (handle LIKE ?1 ESCAPE '\' OR name LIKE ?1 ESCAPE '\') AND guest = FALSE
+    ORDER BY handle
+    LIMIT ?2
+    "#,
+    )
+    .bind(pattern)
+    .bind(limit)
+    .fetch_all(tx.as_mut())
+    .await?;
+
+    rows.into_iter().map(TryInto::try_into).collect()
+}
+
+/// Returns the users sharing at least one group with the provided user,
+/// as owner or member, the user excluded.
+pub async fn get_co_member_ids(
+    tx: &mut crate::Transaction<'_>,
+    user_id: &UserId,
+) -> Result<HashSet<UserId>, crate::Error> {
+    let rows: Vec<(Uuid,)> = sqlx::query_as(
+        r#"
+        WITH membership (coin_group_id, user_id) AS (
+            SELECT id, owner_id FROM coin_group
+            UNION
+            SELECT coin_group_id, member_id FROM coin_group_member
+        )
+        SELECT DISTINCT other.user_id
+        FROM membership mine
+        JOIN membership other ON other.coin_group_id = mine.coin_group_id
+        WHERE mine.user_id = ?1 AND other.user_id <> ?1
+        "#,
+    )
+    .bind(user_id.value())
+    .fetch_all(tx.as_mut())
+    .await?;
+
+    rows.into_iter()
+        .map(|(id,)| {
+            UserId::new(id).map_err(|err| crate::Error::CorruptedData {
+                msg: format!("corrupted user id: {}", err),
+            })
+        })
+        .collect()
+}
+
+/// Returns whether the provided user pays or takes part in expenses,
+/// recurring expenses or settlements of the provided group.
+pub async fn takes_part_in_group(
+    tx: &mut crate::Transaction<'_>,
+    group_id: &GroupId,
+    user_id: &UserId,
+) -> Result<bool, crate::Error> {
+    Ok(sqlx::query_as::<_, (i64,)>(
+        r#"
+        SELECT 1
+        FROM expense_entry
+        LEFT JOIN expense_entry_participant
+            ON expense_entry_participant.expense_entry_id = expense_entry.id
+        LEFT JOIN expense_entry_payer
+            ON expense_entry_payer.expense_entry_id = expense_entry.id
+        WHERE expense_entry.coin_group_id = ?1
+            AND (
+                expense_entry.payer_id = ?2
+                OR expense_entry_participant.participant_id = ?2
+                OR expense_entry_payer.payer_id = ?2
+            )
+        UNION ALL
+        SELECT 1
+        FROM recurring_expense
+        LEFT JOIN recurring_expense_participant
+            ON recurring_expense_participant.recurring_expense_id = recurring_expense.id
+        WHERE recurring_expense.coin_group_id = ?1
+            AND (
+                recurring_expense.payer_id = ?2
+                OR recurring_expense_participant.participant_id = ?2
+            )
+        UNION ALL
+        SELECT 1
+        FROM settlement
+        WHERE coin_group_id = ?1 AND (from_user_id = ?2 OR to_user_id = ?2)
+        LIMIT 1
+        "#,
+    )
+    .bind(group_id.value())
+    .bind(user_id.value())
+    .fetch_optional(tx.as_mut())
+    .await?
+    .is_some())
+}
+
+/// Hands over everything of a guest to a registered user within a group:
+/// the payers, participants and authors of its expenses, recurring expenses
+/// and settlements are rewritten, then the guest is deleted along with their
+/// membership. Guests cannot log in, so they never own groups nor author
+/// invites.
+///
+/// Every rewrite is scoped to the group, and the guest must belong to it
+/// only, so that deleting them cannot drop anything outside of it. The user
+/// must not take part in the group yet, otherwise an expense could end up
+/// with the same participant twice.
+///
+/// # Return
+/// - `true` if the guest has been claimed
+/// - `false` if the provided guest does not exist, is not a guest or does not
+///   belong to this group only
+pub async fn claim_guest(
+    tx: &mut crate::Transaction<'_>,
+    group_id: &GroupId,
+    guest_id: &UserId,
+    user_id: &UserId,
+) -> Result<bool, crate::Error> {
+    const OF_GROUP: &str = "coin_group_id = ?";
+    const OF_GROUP_EXPENSE: &str =
+        "expense_entry_id IN (SELECT id FROM expense_entry WHERE coin_group_id = ?)";
+    const OF_GROUP_RECURRING_EXPENSE: &str =
+        "recurring_expense_id IN (SELECT id FROM recurring_expense WHERE coin_group_id = ?)";
+    const REFERENCES: [(&str, &str, &str); 13] = [
+        ("expense_entry", "payer_id", OF_GROUP),
+        ("expense_entry", "author_id", OF_GROUP),
+        ("expense_entry", "deleted_by", OF_GROUP),
+        (
+            "expense_entry_participant",
+            "participant_id",
+            OF_GROUP_EXPENSE,
+        ),
+        ("expense_entry_payer", "payer_id", OF_GROUP_EXPENSE),
+        ("recurring_expense", "payer_id", OF_GROUP),
+        ("recurring_expense", "author_id", OF_GROUP),
+        (
+            "recurring_expense_participant",
+            "participant_id",
+            OF_GROUP_RECURRING_EXPENSE,
+        ),
+        ("settlement", "from_user_id", OF_GROUP),
+        ("settlement", "to_user_id", OF_GROUP),
+        ("settlement", "author_id", OF_GROUP),
+        ("settlement", "cancelled_by", OF_GROUP),
+        ("attachment", "uploaded_by", OF_GROUP),
+    ];
+
+    let is_guest_of_group_only = sqlx::query_as::<_, (Uuid,)>(
+        r#"
+        SELECT user.id
+        FROM user
+        JOIN coin_group_member ON coin_group_member.member_id = user.id
+        WHERE user.id = ? AND user.guest = TRUE
+        GROUP BY user.id
+        HAVING COUNT(*) = 1 AND MAX(coin_group_member.coin_group_id = ?)
+        "#,
+    )
+    .bind(guest_id.value())
+    .bind(group_id.value())
+    .fetch_optional(tx.as_mut())
+    .await?
+    .is_some();
+    if !is_guest_of_group_only {
+        return Ok(false);
+    }
+
+    for (table, column, of_group) in REFERENCES {
+        sqlx::query(&format!(
+            "UPDATE {table} SET {column} = ? WHERE {column} = ? AND {of_group}"
+        ))
+        .bind(user_id.value())
+        .bind(guest_id.value())
+        .bind(group_id.value())
+        .execute(tx.as_mut())
+        .await?;
+    }
+
+    // the membership of the guest goes along with them
+    sqlx::query(
+        r#"
+    DELETE FROM user
+    WHERE id = ?
+    "#,
+    )
+    .bind(guest_id.value())
+    .execute(tx.as_mut())
+    .await?;
+
+    Ok(true)
+}
@@ -0,0 +1,228 @@
+use domain::{
+    entities::Attachment,
+    types::{
+        attachment_content_type::AttachmentContentType, attachment_id::AttachmentId,
+        expense_id::ExpenseId, group_id::GroupId,
+    },
+};
+use sqlx::{SqlitePool, types::chrono::Utc};
+
+mod fixtures;
+
+// -- create
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries", "attachments"))]
+async fn create_ok(pool: SqlitePool) {
+    let attachment = Attachment::new(
+        AttachmentId::new_random(),
+        fixtures::expense_entries::john_and_bill_shared_shared_expenses_active_expense_entry()
+            .expense_id,
+        fixtures::groups::john_and_bill_shared_expenses().id,
+        "ticket.png".parse().unwrap(),
+        AttachmentContentType::Png,
+        4_096,
+        fixtures::users::johndoe().id,
+        Utc::now(),
+    );
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::attachment::create(&mut tx, &attachment)
+        .await
+        .unwrap();
+
+    let saved = database::queries::attachment::get_by_id(&mut tx, &attachment.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(attachment), saved);
+}
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries", "attachments"))]
+async fn create_err_foreign_key_group_not_existing(pool: SqlitePool) {
+    let attachment = Attachment::new(
+        AttachmentId::new_random(),
+        ExpenseId::new_random(),
+        GroupId::new_random(),
+        "ticket.png".parse().unwrap(),
+        AttachmentContentType::Png,
+        4_096,
+        fixtures::users::johndoe().id,
+        Utc::now(),
+    );
+
+    let mut tx = pool.begin().await.unwrap();
+    let err = database::queries::attachment::create(&mut tx, &attachment)
+        .await
+        .unwrap_err();
+    match err {
+        database::Error::SqlxError(error) => {
+            assert_eq!(
+                sqlx::error::ErrorKind::ForeignKeyViolation,
+                error.as_database_error().unwrap().kind()
+            );
+        }
+        unexpected => panic!(
+            "{}",
+            format!(
+                "expected database::error::SqlxError but received {}",
+                unexpected
+            )
+        ),
+    };
+}
+
+// -- get_by_id
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries", "attachments"))]
+async fn get_by_id_not_existing(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let attachment = database::queries::attachment::get_by_id(&mut tx, &AttachmentId::new_random())
+        .await
+        .unwrap();
+    assert_eq!(None, attachment);
+}
+
+// -- get_all_for_expense
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries", "attachments"))]
+async fn get_all_for_expense_sorted_by_upload_date(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let attachments = database::queries::attachment::get_all_for_expense(
+        &mut tx,
+        &fixtures::expense_entries::john_and_bill_shared_shared_expenses_active_expense_entry()
+            .expense_id,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        vec![
+            fixtures::attachments::receipt(),
+            fixtures::attachments::invoice()
+        ],
+        attachments
+    );
+}
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries", "attachments"))]
+async fn get_all_for_expense_empty(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let attachments = database::queries::attachment::get_all_for_expense(
+        &mut tx,
+        &fixtures::expense_entries::john_and_bill_shared_shared_expenses_deleted_expense_entry()
+            .expense_id,
+    )
+    .await
+    .unwrap();
+    assert!(attachments.is_empty());
+}
+
+// -- exists
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries", "attachments"))]
+async fn exists_ok(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    assert!(
+        database::queries::attachment::exists(&mut tx, &fixtures::attachments::receipt().id)
+            .await
+            .unwrap()
+    );
+    assert!(
+        !database::queries::attachment::exists(&mut tx, &AttachmentId::new_random())
+            .await
+            .unwrap()
+    );
+}
+
+// -- delete
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries", "attachments"))]
+async fn delete_ok(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let deleted =
+        database::queries::attachment::delete(&mut tx, &fixtures::attachments::receipt().id)
+            .await
+            .unwrap();
+    assert!(deleted);
+
+    let attachments = database::queries::attachment::get_all_for_expense(
+        &mut tx,
+        &fixtures::attachments::receipt().expense_id,
+    )
+    .await
+    .unwrap();
+    assert_eq!(vec![fixtures::attachments::invoice()], attachments);
+}
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries", "attachments"))]
+async fn delete_not_existing(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let deleted = database::queries::attachment::delete(&mut tx, &AttachmentId::new_random())
+        .await
+        .unwrap();
+    assert!(!deleted);
+}
+
+// -- delete_all_of_expenses_deleted_before
+
+async fn attach_to_deleted_expense(tx: &mut database::Transaction<'_>) -> Attachment {
+    let attachment = Attachment::new(
+        AttachmentId::new_random(),
+        fixtures::expense_entries::john_and_bill_shared_shared_expenses_deleted_expense_entry()
+            .expense_id,
+        fixtures::groups::john_and_bill_shared_expenses().id,
+        "ticket.png".parse().unwrap(),
+        AttachmentContentType::Png,
+        4_096,
+        fixtures::users::johndoe().id,
+        "2025-12-02T11:00:00Z".parse().unwrap(),
+    );
+    database::queries::attachment::create(tx, &attachment)
+        .await
+        .unwrap();
+    attachment
+}
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries", "attachments"))]
+async fn delete_all_of_expenses_deleted_before_ok(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let attachment = attach_to_deleted_expense(&mut tx).await;
+
+    let deleted = database::queries::attachment::delete_all_of_expenses_deleted_before(
+        &mut tx,
+        "2025-12-04T00:00:00Z".parse().unwrap(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(1, deleted);
+    assert!(
+        !database::queries::attachment::exists(&mut tx, &attachment.id)
+            .await
+            .unwrap()
+    );
+    // attachments of active expenses are kept
+    assert!(
+        database::queries::attachment::exists(&mut tx, &fixtures::attachments::receipt().id)
+            .await
+            .unwrap()
+    );
+}
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries", "attachments"))]
+async fn delete_all_of_expenses_deleted_before_keeps_recently_deleted(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let attachment = attach_to_deleted_expense(&mut tx).await;
+
+    let deleted = database::queries::attachment::delete_all_of_expenses_deleted_before(
+        &mut tx,
+        "2025-12-03T08:00:00Z".parse().unwrap(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(0, deleted);
+    assert!(
+        database::queries::attachment::exists(&mut tx, &attachment.id)
+            .await
+            .unwrap()
+    );
+}
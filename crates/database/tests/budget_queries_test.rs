@@ -0,0 +1,236 @@
+use domain::{
+    entities::{Budget, BudgetAlert},
+    types::{
+        budget_id::BudgetId, budget_period::BudgetPeriod, budget_threshold::BudgetThreshold,
+        expense_id::ExpenseId, money::Money,
+    },
+};
+use sqlx::{
+    SqlitePool,
+    types::chrono::{DateTime, Utc},
+};
+
+mod fixtures;
+
+fn at(rfc3339: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(rfc3339).unwrap().to_utc()
+}
+
+fn bars_budget(period: BudgetPeriod, created_at: DateTime<Utc>) -> Budget {
+    Budget::new(
+        BudgetId::new_random(),
+        fixtures::groups::john_and_bill_shared_expenses().id,
+        Some(fixtures::categories::bars().id),
+        Money::from_euros(200),
+        period,
+        created_at,
+    )
+}
+
+fn alert(budget: &Budget, threshold: BudgetThreshold, created_at: DateTime<Utc>) -> BudgetAlert {
+    BudgetAlert::new(
+        budget.id,
+        threshold,
+        at("2026-01-01T00:00:00Z"),
+        ExpenseId::new_random(),
+        Money::from_euros(180),
+        created_at,
+    )
+}
+
+// -- create
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn create_ok(pool: SqlitePool) {
+    let budget = bars_budget(BudgetPeriod::Monthly, Utc::now());
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::budget::create(&mut tx, &budget)
+        .await
+        .unwrap();
+
+    let saved = database::queries::budget::get_by_id(&mut tx, &budget.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(budget), saved);
+}
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn create_ok_range(pool: SqlitePool) {
+    let period =
+        BudgetPeriod::range(at("2026-02-01T00:00:00Z"), at("2026-02-15T00:00:00Z")).unwrap();
+    let mut budget = bars_budget(period, Utc::now());
+    budget.category_id = None;
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::budget::create(&mut tx, &budget)
+        .await
+        .unwrap();
+
+    let saved = database::queries::budget::get_by_id(&mut tx, &budget.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(budget), saved);
+}
+
+// -- get_by_id
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn get_by_id_not_found(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let budget = database::queries::budget::get_by_id(&mut tx, &BudgetId::new_random())
+        .await
+        .unwrap();
+    assert_eq!(None, budget);
+}
+
+// -- get_all_for_group
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn get_all_for_group_sorted_by_creation(pool: SqlitePool) {
+    let yearly = bars_budget(BudgetPeriod::Yearly, at("2026-01-02T00:00:00Z"));
+    let weekly = bars_budget(BudgetPeriod::Weekly, at("2026-01-01T00:00:00Z"));
+
+    let mut tx = pool.begin().await.unwrap();
+    for budget in [&yearly, &weekly] {
+        database::queries::budget::create(&mut tx, budget)
+            .await
+            .unwrap();
+    }
+
+    let budgets = database::queries::budget::get_all_for_group(
+        &mut tx,
+        &fixtures::groups::john_and_bill_shared_expenses().id,
+    )
+    .await
+    .unwrap();
+    assert_eq!(vec![weekly, yearly], budgets);
+
+    let budgets = database::queries::budget::get_all_for_group(
+        &mut tx,
+        &fixtures::groups::trip_to_europe_2025().id,
+    )
+    .await
+    .unwrap();
+    assert!(budgets.is_empty());
+}
+
+// -- update
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn update_ok(pool: SqlitePool) {
+    let mut budget = bars_budget(BudgetPeriod::Monthly, Utc::now());
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::budget::create(&mut tx, &budget)
+        .await
+        .unwrap();
+
+    budget.category_id = Some(fixtures::categories::ski_passes().id);
+    budget.amount = Money::from_euros(500);
+    budget.period =
+        BudgetPeriod::range(at("2026-02-01T00:00:00Z"), at("2026-02-15T00:00:00Z")).unwrap();
+    let updated = database::queries::budget::update(&mut tx, &budget)
+        .await
+        .unwrap();
+    assert!(updated);
+
+    let saved = database::queries::budget::get_by_id(&mut tx, &budget.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(budget), saved);
+}
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn update_not_found(pool: SqlitePool) {
+    let budget = bars_budget(BudgetPeriod::Monthly, Utc::now());
+
+    let mut tx = pool.begin().await.unwrap();
+    let updated = database::queries::budget::update(&mut tx, &budget)
+        .await
+        .unwrap();
+    assert!(!updated);
+}
+
+// -- delete
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn delete_ok_with_alerts(pool: SqlitePool) {
+    let budget = bars_budget(BudgetPeriod::Monthly, Utc::now());
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::budget::create(&mut tx, &budget)
+        .await
+        .unwrap();
+    database::queries::budget::create_alert(
+        &mut tx,
+        &alert(&budget, BudgetThreshold::Warning, Utc::now()),
+    )
+    .await
+    .unwrap();
+
+    let deleted = database::queries::budget::delete(&mut tx, &budget.id)
+        .await
+        .unwrap();
+    assert!(deleted);
+
+    let alerts = database::queries::budget::get_alerts_for_group(&mut tx, &budget.group_id)
+        .await
+        .unwrap();
+    assert!(alerts.is_empty());
+    let deleted = database::queries::budget::delete(&mut tx, &budget.id)
+        .await
+        .unwrap();
+    assert!(!deleted);
+}
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn deleting_category_deletes_its_budgets(pool: SqlitePool) {
+    let budget = bars_budget(BudgetPeriod::Monthly, Utc::now());
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::budget::create(&mut tx, &budget)
+        .await
+        .unwrap();
+    database::queries::category::delete(&mut tx, &fixtures::categories::bars().id)
+        .await
+        .unwrap();
+
+    let saved = database::queries::budget::get_by_id(&mut tx, &budget.id)
+        .await
+        .unwrap();
+    assert_eq!(None, saved);
+}
+
+// -- create_alert
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn create_alert_only_once_per_threshold_and_period(pool: SqlitePool) {
+    let budget = bars_budget(BudgetPeriod::Monthly, Utc::now());
+    let warning = alert(
+        &budget,
+        BudgetThreshold::Warning,
+        at("2026-01-10T00:00:00Z"),
+    );
+    let exceeded = alert(
+        &budget,
+        BudgetThreshold::Exceeded,
+        at("2026-01-12T00:00:00Z"),
+    );
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::budget::create(&mut tx, &budget)
+        .await
+        .unwrap();
+    for (alert, expected) in [(&warning, true), (&exceeded, true), (&warning, false)] {
+        let created = database::queries::budget::create_alert(&mut tx, alert)
+            .await
+            .unwrap();
+        assert_eq!(expected, created);
+    }
+
+    let alerts = database::queries::budget::get_alerts_for_group(&mut tx, &budget.group_id)
+        .await
+        .unwrap();
+    assert_eq!(vec![exceeded, warning], alerts);
+}
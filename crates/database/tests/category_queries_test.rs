@@ -0,0 +1,253 @@
+use domain::{
+    entities::Category,
+    types::{category_id::CategoryId, group_id::GroupId},
+};
+use sqlx::{SqlitePool, types::chrono::Utc};
+
+mod fixtures;
+
+// -- exists_by_name_for_group
+
+macro_rules! exists_by_name_for_group_test {
+    ($scenario:ident, $group_id:expr, $name:expr, $excluded_id:expr, $expected:expr) => {
+        #[sqlx::test(fixtures("users", "groups", "categories"))]
+        async fn $scenario(pool: SqlitePool) {
+            let group_id = $group_id;
+            let name = $name.parse().unwrap();
+            let excluded_id: Option<CategoryId> = $excluded_id;
+
+            let mut tx = pool.begin().await.unwrap();
+            let exists = database::queries::category::exists_by_name_for_group(
+                &mut tx,
+                &group_id,
+                &name,
+                excluded_id.as_ref(),
+            )
+            .await
+            .unwrap();
+
+            let expected: bool = $expected;
+            assert_eq!(expected, exists);
+        }
+    };
+}
+
+exists_by_name_for_group_test!(
+    exists_by_name_for_group_found,
+    fixtures::groups::john_and_bill_shared_expenses().id,
+    "Bars",
+    None,
+    true
+);
+
+exists_by_name_for_group_test!(
+    exists_by_name_for_group_found_different_case,
+    fixtures::groups::john_and_bill_shared_expenses().id,
+    "bARS",
+    None,
+    true
+);
+
+exists_by_name_for_group_test!(
+    exists_by_name_for_group_not_existing,
+    fixtures::groups::john_and_bill_shared_expenses().id,
+    "Restaurants",
+    None,
+    false
+);
+
+exists_by_name_for_group_test!(
+    exists_by_name_for_group_different_group,
+    fixtures::groups::trip_to_europe_2025().id,
+    "Bars",
+    None,
+    false
+);
+
+exists_by_name_for_group_test!(
+    exists_by_name_for_group_excluded,
+    fixtures::groups::john_and_bill_shared_expenses().id,
+    "Bars",
+    Some(fixtures::categories::bars().id),
+    false
+);
+
+// -- create
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn create_ok(pool: SqlitePool) {
+    let category = Category::new(
+        CategoryId::new_random(),
+        fixtures::groups::trip_to_europe_2025().id,
+        "Bars".parse().unwrap(),
+        "#112233".parse().unwrap(),
+        "🍷".parse().unwrap(),
+        Utc::now(),
+    );
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::category::create(&mut tx, &category)
+        .await
+        .unwrap();
+
+    let saved = database::queries::category::get_by_id(&mut tx, &category.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(category), saved);
+}
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn create_err_unique_violation_same_name_different_case(pool: SqlitePool) {
+    let category = Category::new(
+        CategoryId::new_random(),
+        fixtures::groups::john_and_bill_shared_expenses().id,
+        "bars".parse().unwrap(),
+        "#112233".parse().unwrap(),
+        "🍷".parse().unwrap(),
+        Utc::now(),
+    );
+
+    let mut tx = pool.begin().await.unwrap();
+    let err = database::queries::category::create(&mut tx, &category)
+        .await
+        .unwrap_err();
+    match err {
+        database::Error::SqlxError(error) => {
+            assert_eq!(
+                sqlx::error::ErrorKind::UniqueViolation,
+                error.as_database_error().unwrap().kind()
+            );
+        }
+        unexpected => panic!(
+            "{}",
+            format!(
+                "expected database::error::SqlxError but received {}",
+                unexpected
+            )
+        ),
+    };
+}
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn create_err_foreign_key_group_not_existing(pool: SqlitePool) {
+    let category = Category::new(
+        CategoryId::new_random(),
+        GroupId::new_random(),
+        "Bars".parse().unwrap(),
+        "#112233".parse().unwrap(),
+        "🍷".parse().unwrap(),
+        Utc::now(),
+    );
+
+    let mut tx = pool.begin().await.unwrap();
+    let err = database::queries::category::create(&mut tx, &category)
+        .await
+        .unwrap_err();
+    match err {
+        database::Error::SqlxError(error) => {
+            assert_eq!(
+                sqlx::error::ErrorKind::ForeignKeyViolation,
+                error.as_database_error().unwrap().kind()
+            );
+        }
+        unexpected => panic!(
+            "{}",
+            format!(
+                "expected database::error::SqlxError but received {}",
+                unexpected
+            )
+        ),
+    };
+}
+
+// -- get_all_for_group
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn get_all_for_group_sorted_by_name(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let categories = database::queries::category::get_all_for_group(
+        &mut tx,
+        &fixtures::groups::john_and_bill_shared_expenses().id,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        vec![
+            fixtures::categories::bars(),
+            fixtures::categories::ski_passes()
+        ],
+        categories
+    );
+}
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn get_all_for_group_empty(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let categories = database::queries::category::get_all_for_group(
+        &mut tx,
+        &fixtures::groups::trip_to_europe_2025().id,
+    )
+    .await
+    .unwrap();
+    assert!(categories.is_empty());
+}
+
+// -- update
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn update_ok(pool: SqlitePool) {
+    let mut category = fixtures::categories::bars();
+    category.name = "Pubs".parse().unwrap();
+    category.color = "#000000".parse().unwrap();
+    category.emoji = "🍻".parse().unwrap();
+
+    let mut tx = pool.begin().await.unwrap();
+    let updated = database::queries::category::update(&mut tx, &category)
+        .await
+        .unwrap();
+    assert!(updated);
+
+    let saved = database::queries::category::get_by_id(&mut tx, &category.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(category), saved);
+}
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn update_not_found(pool: SqlitePool) {
+    let mut category = fixtures::categories::bars();
+    category.id = CategoryId::new_random();
+
+    let mut tx = pool.begin().await.unwrap();
+    let updated = database::queries::category::update(&mut tx, &category)
+        .await
+        .unwrap();
+    assert!(!updated);
+}
+
+// -- delete
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn delete_ok(pool: SqlitePool) {
+    let category = fixtures::categories::bars();
+
+    let mut tx = pool.begin().await.unwrap();
+    let deleted = database::queries::category::delete(&mut tx, &category.id)
+        .await
+        .unwrap();
+    assert!(deleted);
+
+    let saved = database::queries::category::get_by_id(&mut tx, &category.id)
+        .await
+        .unwrap();
+    assert!(saved.is_none());
+}
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn delete_not_found(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let deleted = database::queries::category::delete(&mut tx, &CategoryId::new_random())
+        .await
+        .unwrap();
+    assert!(!deleted);
+}
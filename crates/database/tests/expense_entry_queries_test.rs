@@ -1,10 +1,18 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
+use database::DbPagination;
 use domain::{
     testutils::expense_entry::TestExpenseEntry,
-    types::{expense_entry_id::ExpenseEntryId, expense_entry_status::ExpenseEntryStatus},
+    types::{
+        expense_category::ExpenseCategory, expense_entry_id::ExpenseEntryId,
+        expense_entry_status::ExpenseEntryStatus, expense_id::ExpenseId,
+        expense_payers::ExpensePayers, expense_split::ExpenseSplit, money::Money,
+    },
+};
+use sqlx::{
+    SqlitePool,
+    types::chrono::{DateTime, Utc},
 };
-use sqlx::{SqlitePool, types::chrono::Utc};
 use uuid::Uuid;
 
 mod fixtures;
@@ -82,6 +90,241 @@ async fn create_err_pk_violation(pool: SqlitePool) {
     };
 }
 
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn create_with_split_ok(pool: SqlitePool) {
+    let john = fixtures::users::johndoe().id;
+    let bill = fixtures::users::bill().id;
+    let marc = fixtures::users::marc().id;
+    let expense_entry = TestExpenseEntry::new_valid_with_split(
+        Uuid::now_v7(),
+        Uuid::now_v7(),
+        fixtures::groups::trip_to_europe_2025().id.value(),
+        john.value(),
+        HashSet::from_iter(vec![bill.value(), marc.value()]),
+        ExpenseSplit::Shares {
+            shares: HashMap::from_iter(vec![(john, 2), (bill, 1), (marc, 3)]),
+        },
+        ExpenseEntryStatus::Active,
+        120,
+        john.value(),
+        Utc::now(),
+        Utc::now(),
+    );
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::expense_entry::create(&mut tx, &expense_entry)
+        .await
+        .unwrap();
+
+    let by_id = database::queries::expense_entry::get_by_id(&mut tx, &expense_entry.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(&expense_entry), by_id.as_ref());
+
+    let by_expense_id =
+        database::queries::expense_entry::get_all_by_expense_id(&mut tx, &expense_entry.expense_id)
+            .await
+            .unwrap();
+    assert_eq!(vec![expense_entry], by_expense_id);
+}
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn create_with_metadata_ok(pool: SqlitePool) {
+    let john = fixtures::users::johndoe().id;
+    let bill = fixtures::users::bill().id;
+    let mut expense_entry = TestExpenseEntry::new_valid(
+        Uuid::now_v7(),
+        Uuid::now_v7(),
+        fixtures::groups::trip_to_europe_2025().id.value(),
+        john.value(),
+        HashSet::from_iter(vec![bill.value()]),
+        ExpenseEntryStatus::Active,
+        50,
+        john.value(),
+        Utc::now(),
+        Utc::now(),
+    );
+    expense_entry.title = "Train to Lyon".parse().unwrap();
+    expense_entry.note = Some("Booked 2 weeks ahead".parse().unwrap());
+    expense_entry.category = ExpenseCategory::Transport;
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::expense_entry::create(&mut tx, &expense_entry)
+        .await
+        .unwrap();
+
+    let by_id = database::queries::expense_entry::get_by_id(&mut tx, &expense_entry.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(expense_entry), by_id);
+}
+
+#[sqlx::test(fixtures("users", "groups", "categories", "expense_entries"))]
+async fn create_with_custom_category_ok(pool: SqlitePool) {
+    let john = fixtures::users::johndoe().id;
+    let bill = fixtures::users::bill().id;
+    let mut expense_entry = TestExpenseEntry::new_valid(
+        Uuid::now_v7(),
+        Uuid::now_v7(),
+        fixtures::groups::john_and_bill_shared_expenses().id.value(),
+        john.value(),
+        HashSet::from_iter(vec![bill.value()]),
+        ExpenseEntryStatus::Active,
+        50,
+        john.value(),
+        Utc::now(),
+        Utc::now(),
+    );
+    expense_entry.category_id = Some(fixtures::categories::bars().id);
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::expense_entry::create(&mut tx, &expense_entry)
+        .await
+        .unwrap();
+
+    let by_id = database::queries::expense_entry::get_by_id(&mut tx, &expense_entry.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(expense_entry), by_id);
+}
+
+#[sqlx::test(fixtures("users", "groups", "categories", "expense_entries"))]
+async fn get_by_id_custom_category_deleted(pool: SqlitePool) {
+    let john = fixtures::users::johndoe().id;
+    let mut expense_entry = TestExpenseEntry::new_valid(
+        Uuid::now_v7(),
+        Uuid::now_v7(),
+        fixtures::groups::john_and_bill_shared_expenses().id.value(),
+        john.value(),
+        HashSet::<Uuid>::new(),
+        ExpenseEntryStatus::Active,
+        50,
+        john.value(),
+        Utc::now(),
+        Utc::now(),
+    );
+    expense_entry.category_id = Some(fixtures::categories::bars().id);
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::expense_entry::create(&mut tx, &expense_entry)
+        .await
+        .unwrap();
+    database::queries::category::delete(&mut tx, &fixtures::categories::bars().id)
+        .await
+        .unwrap();
+
+    let by_id = database::queries::expense_entry::get_by_id(&mut tx, &expense_entry.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(None, by_id.category_id);
+}
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn create_in_foreign_currency_ok(pool: SqlitePool) {
+    let john = fixtures::users::johndoe().id;
+    let bill = fixtures::users::bill().id;
+    let mut expense_entry = TestExpenseEntry::new_valid(
+        Uuid::now_v7(),
+        Uuid::now_v7(),
+        fixtures::groups::trip_to_europe_2025().id.value(),
+        john.value(),
+        HashSet::from_iter(vec![bill.value()]),
+        ExpenseEntryStatus::Active,
+        50,
+        john.value(),
+        Utc::now(),
+        Utc::now(),
+    );
+    expense_entry.currency = "USD".parse().unwrap();
+    expense_entry.exchange_rate = "0.9215".parse().unwrap();
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::expense_entry::create(&mut tx, &expense_entry)
+        .await
+        .unwrap();
+
+    let by_id = database::queries::expense_entry::get_by_id(&mut tx, &expense_entry.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(expense_entry), by_id);
+}
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn create_with_exact_split_payer_not_involved_ok(pool: SqlitePool) {
+    let john = fixtures::users::johndoe().id;
+    let bill = fixtures::users::bill().id;
+    let expense_entry = TestExpenseEntry::new_valid_with_split(
+        Uuid::now_v7(),
+        Uuid::now_v7(),
+        fixtures::groups::trip_to_europe_2025().id.value(),
+        john.value(),
+        HashSet::from_iter(vec![bill.value()]),
+        ExpenseSplit::Exact {
+            amounts: HashMap::from_iter(vec![(bill, Money::from_euros(120))]),
+        },
+        ExpenseEntryStatus::Active,
+        120,
+        john.value(),
+        Utc::now(),
+        Utc::now(),
+    );
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::expense_entry::create(&mut tx, &expense_entry)
+        .await
+        .unwrap();
+
+    let actual = database::queries::expense_entry::get_active_by_expense_id(
+        &mut tx,
+        &expense_entry.expense_id,
+    )
+    .await
+    .unwrap();
+    assert_eq!(Some(expense_entry), actual);
+}
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn create_with_several_payers_ok(pool: SqlitePool) {
+    let john = fixtures::users::johndoe().id;
+    let bill = fixtures::users::bill().id;
+    let mut expense_entry = TestExpenseEntry::new_valid(
+        Uuid::now_v7(),
+        Uuid::now_v7(),
+        fixtures::groups::john_and_bill_shared_expenses().id.value(),
+        john.value(),
+        HashSet::from_iter(vec![bill.value()]),
+        ExpenseEntryStatus::Active,
+        80,
+        john.value(),
+        Utc::now(),
+        Utc::now(),
+    );
+    expense_entry.payers = ExpensePayers::new(HashMap::from_iter(vec![
+        (john, Money::from_cents(5_050)),
+        (bill, Money::from_cents(2_950)),
+    ]))
+    .unwrap();
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::expense_entry::create(&mut tx, &expense_entry)
+        .await
+        .unwrap();
+
+    let by_id = database::queries::expense_entry::get_by_id(&mut tx, &expense_entry.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(&expense_entry), by_id.as_ref());
+
+    let active = database::queries::expense_entry::get_active_by_expense_id(
+        &mut tx,
+        &expense_entry.expense_id,
+    )
+    .await
+    .unwrap();
+    assert_eq!(Some(expense_entry), active);
+}
+
 // -- get_by_id
 
 #[sqlx::test(fixtures("users", "groups", "expense_entries"))]
@@ -114,3 +357,337 @@ async fn get_by_id_found_status_inactive(pool: SqlitePool) {
         .unwrap();
     assert_eq!(Some(expected), actual);
 }
+
+// -- get_all_by_expense_id
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn get_all_by_expense_id_sorted_by_creation_date(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let active =
+        fixtures::expense_entries::john_and_bill_shared_shared_expenses_active_expense_entry();
+    let actual =
+        database::queries::expense_entry::get_all_by_expense_id(&mut tx, &active.expense_id)
+            .await
+            .unwrap();
+    assert_eq!(
+        vec![
+            fixtures::expense_entries::john_and_bill_shared_shared_expenses_overwritten_expense_entry(),
+            active,
+        ],
+        actual
+    );
+}
+
+// -- get_all_for_group
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn get_all_for_group_includes_every_status(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let group = fixtures::groups::john_and_bill_shared_expenses();
+    let actual = database::queries::expense_entry::get_all_for_group(&mut tx, &group.id)
+        .await
+        .unwrap();
+    assert_eq!(
+        vec![
+            fixtures::expense_entries::john_and_bill_shared_shared_expenses_overwritten_expense_entry(),
+            fixtures::expense_entries::john_and_bill_shared_shared_expenses_active_expense_entry(),
+            fixtures::expense_entries::john_and_bill_shared_shared_expenses_deleted_expense_entry(),
+        ],
+        actual
+    );
+}
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn get_all_for_group_empty(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let group = fixtures::groups::trip_to_europe_2025();
+    let actual = database::queries::expense_entry::get_all_for_group(&mut tx, &group.id)
+        .await
+        .unwrap();
+    assert!(actual.is_empty());
+}
+
+// -- get_all_active_for_group_unpaginated
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn get_all_active_for_group_unpaginated_only_active(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let group = fixtures::groups::john_and_bill_shared_expenses();
+    let actual =
+        database::queries::expense_entry::get_all_active_for_group_unpaginated(&mut tx, &group.id)
+            .await
+            .unwrap();
+    assert_eq!(
+        vec![
+            fixtures::expense_entries::john_and_bill_shared_shared_expenses_active_expense_entry()
+        ],
+        actual
+    );
+}
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn get_all_active_for_group_unpaginated_empty(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let group = fixtures::groups::trip_to_europe_2025();
+    let actual =
+        database::queries::expense_entry::get_all_active_for_group_unpaginated(&mut tx, &group.id)
+            .await
+            .unwrap();
+    assert!(actual.is_empty());
+}
+
+// -- get_all_active_for_group_between
+
+macro_rules! get_all_active_for_group_between_test {
+    ($scenario:ident, $from:expr, $to:expr, $expected_count:expr) => {
+        #[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+        async fn $scenario(pool: SqlitePool) {
+            let mut tx = pool.begin().await.unwrap();
+            let group = fixtures::groups::john_and_bill_shared_expenses();
+            let actual = database::queries::expense_entry::get_all_active_for_group_between(
+                &mut tx,
+                &group.id,
+                DateTime::parse_from_rfc3339($from).unwrap().to_utc(),
+                DateTime::parse_from_rfc3339($to).unwrap().to_utc(),
+            )
+            .await
+            .unwrap();
+
+            let expected_count: usize = $expected_count;
+            assert_eq!(expected_count, actual.len());
+            assert!(actual.iter().all(|entry| entry.is_active()));
+        }
+    };
+}
+
+get_all_active_for_group_between_test!(
+    get_all_active_for_group_between_in_range,
+    "2025-12-01T00:00:00Z",
+    "2025-12-02T00:00:00Z",
+    1
+);
+
+get_all_active_for_group_between_test!(
+    get_all_active_for_group_between_from_is_inclusive,
+    "2025-12-01T08:00:50Z",
+    "2025-12-02T00:00:00Z",
+    1
+);
+
+get_all_active_for_group_between_test!(
+    get_all_active_for_group_between_to_is_exclusive,
+    "2025-11-01T00:00:00Z",
+    "2025-12-01T08:00:50Z",
+    0
+);
+
+get_all_active_for_group_between_test!(
+    get_all_active_for_group_between_out_of_range,
+    "2025-12-02T00:00:00Z",
+    "2026-01-01T00:00:00Z",
+    0
+);
+
+// -- get_active_by_expense_id
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn get_active_by_expense_id_found(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let expected =
+        fixtures::expense_entries::john_and_bill_shared_shared_expenses_active_expense_entry();
+    let actual =
+        database::queries::expense_entry::get_active_by_expense_id(&mut tx, &expected.expense_id)
+            .await
+            .unwrap();
+    assert_eq!(Some(expected), actual);
+}
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn get_active_by_expense_id_not_found(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let actual = database::queries::expense_entry::get_active_by_expense_id(
+        &mut tx,
+        &ExpenseId::new_random(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(None, actual);
+}
+
+// -- mark_as_overwritten
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn mark_as_overwritten_ok(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let active =
+        fixtures::expense_entries::john_and_bill_shared_shared_expenses_active_expense_entry();
+    let overwritten_by = ExpenseEntryId::new_random();
+
+    let updated =
+        database::queries::expense_entry::mark_as_overwritten(&mut tx, &active.id, &overwritten_by)
+            .await
+            .unwrap();
+
+    assert!(updated);
+    let actual = database::queries::expense_entry::get_by_id(&mut tx, &active.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        ExpenseEntryStatus::Inactive { overwritten_by },
+        actual.status
+    );
+}
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn mark_as_overwritten_already_inactive(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let inactive =
+        fixtures::expense_entries::john_and_bill_shared_shared_expenses_overwritten_expense_entry();
+
+    let updated = database::queries::expense_entry::mark_as_overwritten(
+        &mut tx,
+        &inactive.id,
+        &ExpenseEntryId::new_random(),
+    )
+    .await
+    .unwrap();
+
+    assert!(!updated);
+}
+
+// -- single active entry per expense
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn create_err_second_active_entry_for_expense(pool: SqlitePool) {
+    let active =
+        fixtures::expense_entries::john_and_bill_shared_shared_expenses_active_expense_entry();
+    let expense_entry = TestExpenseEntry::new_valid(
+        Uuid::now_v7(),
+        active.expense_id.value(),
+        active.group_id.value(),
+        active.payer_id.value(),
+        HashSet::<Uuid>::new(),
+        ExpenseEntryStatus::Active,
+        20,
+        active.author_id.value(),
+        Utc::now(),
+        Utc::now(),
+    );
+
+    let mut tx = pool.begin().await.unwrap();
+    let err = database::queries::expense_entry::create(&mut tx, &expense_entry)
+        .await
+        .unwrap_err();
+    match err {
+        database::Error::SqlxError(error) => {
+            assert_eq!(
+                sqlx::error::ErrorKind::UniqueViolation,
+                error.as_database_error().unwrap().kind()
+            );
+        }
+        unexpected => panic!("expected database::error::SqlxError but received {unexpected}"),
+    };
+}
+
+// -- deleted expense entries
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn get_by_id_found_status_deleted(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let expected =
+        fixtures::expense_entries::john_and_bill_shared_shared_expenses_deleted_expense_entry();
+    let actual = database::queries::expense_entry::get_by_id(&mut tx, &expected.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(expected), actual);
+}
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn get_active_by_expense_id_deleted(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let deleted =
+        fixtures::expense_entries::john_and_bill_shared_shared_expenses_deleted_expense_entry();
+    let actual =
+        database::queries::expense_entry::get_active_by_expense_id(&mut tx, &deleted.expense_id)
+            .await
+            .unwrap();
+    assert_eq!(None, actual);
+}
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn get_all_active_for_group_excludes_deleted(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let group = fixtures::groups::john_and_bill_shared_expenses();
+    let actual = database::queries::expense_entry::get_all_active_for_group(
+        &mut tx,
+        &group.id,
+        DbPagination {
+            limit: 10,
+            offset: 0,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        vec![
+            fixtures::expense_entries::john_and_bill_shared_shared_expenses_active_expense_entry()
+        ],
+        actual
+    );
+}
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn count_all_active_for_group_excludes_deleted(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let group = fixtures::groups::john_and_bill_shared_expenses();
+    let count = database::queries::expense_entry::count_all_active_for_group(&mut tx, &group.id)
+        .await
+        .unwrap();
+    assert_eq!(1, count);
+}
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn mark_as_overwritten_deleted(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let deleted =
+        fixtures::expense_entries::john_and_bill_shared_shared_expenses_deleted_expense_entry();
+
+    let updated = database::queries::expense_entry::mark_as_overwritten(
+        &mut tx,
+        &deleted.id,
+        &ExpenseEntryId::new_random(),
+    )
+    .await
+    .unwrap();
+
+    assert!(!updated);
+}
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn create_active_entry_for_deleted_expense_ok(pool: SqlitePool) {
+    let deleted =
+        fixtures::expense_entries::john_and_bill_shared_shared_expenses_deleted_expense_entry();
+    let expense_entry = TestExpenseEntry::new_valid(
+        Uuid::now_v7(),
+        deleted.expense_id.value(),
+        deleted.group_id.value(),
+        deleted.payer_id.value(),
+        HashSet::<Uuid>::new(),
+        ExpenseEntryStatus::Active,
+        10,
+        deleted.author_id.value(),
+        Utc::now(),
+        Utc::now(),
+    );
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::expense_entry::create(&mut tx, &expense_entry)
+        .await
+        .unwrap();
+    let actual =
+        database::queries::expense_entry::get_active_by_expense_id(&mut tx, &deleted.expense_id)
+            .await
+            .unwrap();
+    assert_eq!(Some(expense_entry), actual);
+}
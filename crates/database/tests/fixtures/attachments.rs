@@ -0,0 +1,34 @@
+use domain::{entities::Attachment, types::attachment_content_type::AttachmentContentType};
+use sqlx::types::chrono::DateTime;
+
+pub fn receipt() -> Attachment {
+    Attachment::new(
+        "019b7a1c-4d2e-7f3a-9b8c-1d2e3f4a5b6c".parse().unwrap(),
+        super::expense_entries::john_and_bill_shared_shared_expenses_active_expense_entry()
+            .expense_id,
+        super::groups::john_and_bill_shared_expenses().id,
+        "receipt.jpg".parse().unwrap(),
+        AttachmentContentType::Jpeg,
+        245_760,
+        super::users::bill().id,
+        DateTime::parse_from_rfc3339("2025-12-02T11:00:00Z")
+            .unwrap()
+            .to_utc(),
+    )
+}
+
+pub fn invoice() -> Attachment {
+    Attachment::new(
+        "019b7a1d-5e3f-7a4b-8c9d-2e3f4a5b6c7d".parse().unwrap(),
+        super::expense_entries::john_and_bill_shared_shared_expenses_active_expense_entry()
+            .expense_id,
+        super::groups::john_and_bill_shared_expenses().id,
+        "invoice.pdf".parse().unwrap(),
+        AttachmentContentType::Pdf,
+        102_400,
+        super::users::johndoe().id,
+        DateTime::parse_from_rfc3339("2025-12-02T12:00:00Z")
+            .unwrap()
+            .to_utc(),
+    )
+}
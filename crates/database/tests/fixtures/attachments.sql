@@ -0,0 +1,23 @@
+INSERT INTO attachment
+(id, expense_id, coin_group_id, file_name, content_type, size_bytes, uploaded_by, uploaded_at)
+VALUES
+( -- John and Bill shared expenses
+    X'019b7a1c4d2e7f3a9b8c1d2e3f4a5b6c', -- Attachment Id
+    X'019b5648dcf47d7a8fbb2a414de4bcc6', -- Expense Id
+    X'019b37f428ff7b9dbe10b91f7a0dec74', -- Group Id
+    'receipt.jpg',
+    10, -- jpeg
+    245760,
+    X'019b3752b7d87a208bb28d0a44a1f661', -- Uploaded by bill
+    '2025-12-02T11:00:00Z' -- Uploaded at
+),
+( -- John and Bill shared expenses
+    X'019b7a1d5e3f7a4b8c9d2e3f4a5b6c7d', -- Attachment Id
+    X'019b5648dcf47d7a8fbb2a414de4bcc6', -- Expense Id
+    X'019b37f428ff7b9dbe10b91f7a0dec74', -- Group Id
+    'invoice.pdf',
+    50, -- pdf
+    102400,
+    X'019b14ef290a70d9a2452a4723d9d44a', -- Uploaded by johndoe
+    '2025-12-02T12:00:00Z' -- Uploaded at
+);
@@ -0,0 +1,28 @@
+use domain::entities::Category;
+use sqlx::types::chrono::DateTime;
+
+pub fn bars() -> Category {
+    Category::new(
+        "019b6c1a-2e4f-7a3b-8c5d-9e0f1a2b3c4d".parse().unwrap(),
+        super::groups::john_and_bill_shared_expenses().id,
+        "Bars".parse().unwrap(),
+        "#FF8800".parse().unwrap(),
+        "🍺".parse().unwrap(),
+        DateTime::parse_from_rfc3339("2025-12-01T09:00:00Z")
+            .unwrap()
+            .to_utc(),
+    )
+}
+
+pub fn ski_passes() -> Category {
+    Category::new(
+        "019b6c1b-3f5a-7b4c-9d6e-0f1a2b3c4d5e".parse().unwrap(),
+        super::groups::john_and_bill_shared_expenses().id,
+        "Ski passes".parse().unwrap(),
+        "#0055FF".parse().unwrap(),
+        "🎿".parse().unwrap(),
+        DateTime::parse_from_rfc3339("2025-12-01T10:00:00Z")
+            .unwrap()
+            .to_utc(),
+    )
+}
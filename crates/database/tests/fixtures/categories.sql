@@ -0,0 +1,19 @@
+INSERT INTO category
+(id, coin_group_id, name, color, emoji, created_at)
+VALUES
+( -- John and Bill shared expenses
+    X'019b6c1a2e4f7a3b8c5d9e0f1a2b3c4d', -- Category Id
+    X'019b37f428ff7b9dbe10b91f7a0dec74', -- Group Id
+    'Bars',
+    '#FF8800',
+    '🍺',
+    '2025-12-01T09:00:00Z' -- Created at
+),
+( -- John and Bill shared expenses
+    X'019b6c1b3f5a7b4c9d6e0f1a2b3c4d5e', -- Category Id
+    X'019b37f428ff7b9dbe10b91f7a0dec74', -- Group Id
+    'Ski passes',
+    '#0055FF',
+    '🎿',
+    '2025-12-01T10:00:00Z' -- Created at
+);
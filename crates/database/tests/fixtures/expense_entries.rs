@@ -47,3 +47,28 @@ pub fn john_and_bill_shared_shared_expenses_overwritten_expense_entry()
             .to_utc(),
     )
 }
+
+pub fn john_and_bill_shared_shared_expenses_deleted_expense_entry() -> domain::entities::ExpenseEntry
+{
+    TestExpenseEntry::new_valid(
+        Uuid::parse_str("019b5a9c3f1e7b0a8d4c2e6f1a3b5c7d").unwrap(),
+        Uuid::parse_str("019b5a9c3f1e7b0a8d4c2e6f1a3b5c7e").unwrap(),
+        super::groups::john_and_bill_shared_expenses().id.value(),
+        super::users::bill().id.value(),
+        HashSet::<Uuid>::new(),
+        ExpenseEntryStatus::Deleted {
+            deleted_by: super::users::johndoe().id,
+            deleted_at: DateTime::parse_from_rfc3339("2025-12-03T09:00:00Z")
+                .unwrap()
+                .to_utc(),
+        },
+        10,
+        super::users::johndoe().id.value(),
+        DateTime::parse_from_rfc3339("2025-12-01T08:00:50Z")
+            .unwrap()
+            .to_utc(),
+        DateTime::parse_from_rfc3339("2025-12-03T09:00:00Z")
+            .unwrap()
+            .to_utc(),
+    )
+}
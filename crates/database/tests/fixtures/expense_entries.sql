@@ -1,10 +1,11 @@
 INSERT INTO expense_entry 
-(id, expense_id, coin_group_id, payer_id, status, total, author_id, occurred_at, created_at)
+(id, expense_id, coin_group_id, title, payer_id, status, total, author_id, occurred_at, created_at)
  VALUES
 ( -- John and Bill shared expenses; Bill paid 10 euros
     X'019b5551261d768c80a3d047d742e141', -- Expense entry Id
     X'019b5648dcf47d7a8fbb2a414de4bcc6', -- Expense Id
     X'019b37f428ff7b9dbe10b91f7a0dec74', -- Group Id
+    'Test expense', -- Title
     X'019b3752b7d87a208bb28d0a44a1f661', -- Payer Id (Bill)
     NULL, -- Status (active)
     1000,
@@ -16,6 +17,7 @@ INSERT INTO expense_entry
     X'019b5652e5ed7776b05b2a326da8471a', -- Expense entry Id
     X'019b5648dcf47d7a8fbb2a414de4bcc6', -- Expense Id
     X'019b37f428ff7b9dbe10b91f7a0dec74', -- Group Id
+    'Test expense', -- Title
     X'019b3752b7d87a208bb28d0a44a1f661', -- Payer Id (Bill)
     X'019b5551261d768c80a3d047d742e141', -- Status (inactive)
     800,
@@ -35,3 +37,38 @@ INSERT INTO expense_entry_participant (expense_entry_id, participant_id) VALUES
     X'019b14ef290a70d9a2452a4723d9d44a' -- John
 )
 ;
+
+INSERT INTO expense_entry
+(id, expense_id, coin_group_id, title, payer_id, status, total, author_id, occurred_at, created_at, deleted_by, deleted_at)
+ VALUES
+( -- John and Bill shared expenses; duplicated expense deleted by John
+    X'019b5a9c3f1e7b0a8d4c2e6f1a3b5c7d', -- Expense entry Id
+    X'019b5a9c3f1e7b0a8d4c2e6f1a3b5c7e', -- Expense Id
+    X'019b37f428ff7b9dbe10b91f7a0dec74', -- Group Id
+    'Test expense', -- Title
+    X'019b3752b7d87a208bb28d0a44a1f661', -- Payer Id (Bill)
+    NULL, -- Status (deleted)
+    1000,
+    X'019b14ef290a70d9a2452a4723d9d44a', -- Author Id (John)
+    '2025-12-01T08:00:50Z', -- Occured at
+    '2025-12-03T09:00:00Z', -- Created at
+    X'019b14ef290a70d9a2452a4723d9d44a', -- Deleted by (John)
+    '2025-12-03T09:00:00Z' -- Deleted at
+);
+
+INSERT INTO expense_entry_payer (expense_entry_id, payer_id, amount) VALUES
+(
+    X'019b5551261d768c80a3d047d742e141',
+    X'019b3752b7d87a208bb28d0a44a1f661', -- Bill
+    1000
+),
+(
+    X'019b5652e5ed7776b05b2a326da8471a',
+    X'019b3752b7d87a208bb28d0a44a1f661', -- Bill
+    800
+),
+(
+    X'019b5a9c3f1e7b0a8d4c2e6f1a3b5c7d',
+    X'019b3752b7d87a208bb28d0a44a1f661', -- Bill
+    1000
+);
@@ -1,5 +1,8 @@
 #![allow(unused)]
 
+pub mod attachments;
+pub mod categories;
 pub mod expense_entries;
 pub mod groups;
+pub mod settlements;
 pub mod users;
@@ -0,0 +1,44 @@
+use domain::{testutils::settlement::TestSettlement, types::settlement_status::SettlementStatus};
+use sqlx::types::chrono::DateTime;
+use uuid::Uuid;
+
+pub fn john_paid_bill_back() -> domain::entities::Settlement {
+    TestSettlement::new_valid(
+        Uuid::parse_str("019b5a1e4d7e7c2d9a4f1b3e8c6d2a10").unwrap(),
+        super::groups::john_and_bill_shared_expenses().id.value(),
+        super::users::johndoe().id.value(),
+        super::users::bill().id.value(),
+        500,
+        SettlementStatus::Active,
+        super::users::johndoe().id.value(),
+        DateTime::parse_from_rfc3339("2025-12-03T08:00:00Z")
+            .unwrap()
+            .to_utc(),
+        DateTime::parse_from_rfc3339("2025-12-03T09:00:00Z")
+            .unwrap()
+            .to_utc(),
+    )
+}
+
+pub fn john_paid_bill_back_cancelled() -> domain::entities::Settlement {
+    TestSettlement::new_valid(
+        Uuid::parse_str("019b5a2f8b3c7e41a2d95c7f3e1b4d22").unwrap(),
+        super::groups::john_and_bill_shared_expenses().id.value(),
+        super::users::johndoe().id.value(),
+        super::users::bill().id.value(),
+        5000,
+        SettlementStatus::Cancelled {
+            cancelled_by: super::users::bill().id,
+            cancelled_at: DateTime::parse_from_rfc3339("2025-12-02T10:00:00Z")
+                .unwrap()
+                .to_utc(),
+        },
+        super::users::johndoe().id.value(),
+        DateTime::parse_from_rfc3339("2025-12-02T08:00:00Z")
+            .unwrap()
+            .to_utc(),
+        DateTime::parse_from_rfc3339("2025-12-02T09:00:00Z")
+            .unwrap()
+            .to_utc(),
+    )
+}
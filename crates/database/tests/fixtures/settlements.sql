@@ -0,0 +1,27 @@
+INSERT INTO settlement
+(id, coin_group_id, from_user_id, to_user_id, amount, author_id, occurred_at, created_at, cancelled_by, cancelled_at)
+VALUES
+( -- John and Bill shared expenses; John paid Bill back 5 euros
+    X'019b5a1e4d7e7c2d9a4f1b3e8c6d2a10', -- Settlement Id
+    X'019b37f428ff7b9dbe10b91f7a0dec74', -- Group Id
+    X'019b14ef290a70d9a2452a4723d9d44a', -- From (John)
+    X'019b3752b7d87a208bb28d0a44a1f661', -- To (Bill)
+    500,
+    X'019b14ef290a70d9a2452a4723d9d44a', -- Author Id (John)
+    '2025-12-03T08:00:00Z', -- Occured at
+    '2025-12-03T09:00:00Z', -- Created at
+    NULL, -- Cancelled by
+    NULL -- Cancelled at
+),
+( -- John and Bill shared expenses; cancelled by Bill
+    X'019b5a2f8b3c7e41a2d95c7f3e1b4d22', -- Settlement Id
+    X'019b37f428ff7b9dbe10b91f7a0dec74', -- Group Id
+    X'019b14ef290a70d9a2452a4723d9d44a', -- From (John)
+    X'019b3752b7d87a208bb28d0a44a1f661', -- To (Bill)
+    5000,
+    X'019b14ef290a70d9a2452a4723d9d44a', -- Author Id (John)
+    '2025-12-02T08:00:00Z', -- Occured at
+    '2025-12-02T09:00:00Z', -- Created at
+    X'019b3752b7d87a208bb28d0a44a1f661', -- Cancelled by (Bill)
+    '2025-12-02T10:00:00Z' -- Cancelled at
+);
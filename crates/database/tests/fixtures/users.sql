@@ -1,5 +1,5 @@
-INSERT INTO user (id, name, email, role, created_at) VALUES
-(X'019b14ef290a70d9a2452a4723d9d44a', 'johndoe', 'john.doe@gmail.com', 10, '2025-01-01T12:00:00Z'),
-(X'019b3752b7d87a208bb28d0a44a1f661', 'bill', 'bill@gmail.com', 10, '2025-03-01T16:30:00Z'),
-(X'019b375cdc4f757aa3423df76fc97f40', 'marc', 'marc@gmail.com', 10, '2025-03-03T23:59:59Z')
+INSERT INTO user (id, name, handle, email, role, created_at) VALUES
+(X'019b14ef290a70d9a2452a4723d9d44a', 'johndoe', 'johndoe', 'john.doe@gmail.com', 10, '2025-01-01T12:00:00Z'),
+(X'019b3752b7d87a208bb28d0a44a1f661', 'bill', 'bill', 'bill@gmail.com', 10, '2025-03-01T16:30:00Z'),
+(X'019b375cdc4f757aa3423df76fc97f40', 'marc', 'marc', 'marc@gmail.com', 10, '2025-03-03T23:59:59Z')
 ;
@@ -1,8 +1,8 @@
-use std::collections::HashSet;
+use std::collections::HashMap;
 
 use domain::{
     entities::Group,
-    types::{group_id::GroupId, user_id::UserId},
+    types::{currency::Currency, group_id::GroupId, member_role::MemberRole, user_id::UserId},
 };
 use sqlx::{SqlitePool, types::chrono::Utc};
 
@@ -57,8 +57,9 @@ async fn create_ok(pool: SqlitePool) {
     let group = Group::new(
         GroupId::new_random(),
         "Trip to Paris".parse().unwrap(),
+        Currency::default(),
         fixtures::users::marc().id,
-        HashSet::new(),
+        HashMap::new(),
         Utc::now(),
     );
 
@@ -67,6 +68,29 @@ async fn create_ok(pool: SqlitePool) {
     assert!(res.is_ok());
 }
 
+#[sqlx::test(fixtures("users", "groups"))]
+async fn create_with_currency_ok(pool: SqlitePool) {
+    let group = Group::new(
+        GroupId::new_random(),
+        "Trip to New York".parse().unwrap(),
+        "USD".parse().unwrap(),
+        fixtures::users::marc().id,
+        HashMap::new(),
+        Utc::now(),
+    );
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::group::create(&mut tx, &group)
+        .await
+        .unwrap();
+
+    let saved = database::queries::group::get_by_id(&mut tx, &group.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!("USD", saved.currency.value());
+}
+
 #[sqlx::test(fixtures("users", "groups"))]
 async fn create_same_name_different_case_ok(pool: SqlitePool) {
     let name = fixtures::groups::trip_to_europe_2025()
@@ -78,8 +102,9 @@ async fn create_same_name_different_case_ok(pool: SqlitePool) {
     let group = Group::new(
         GroupId::new_random(),
         name,
+        Currency::default(),
         fixtures::groups::trip_to_europe_2025().owner_id,
-        HashSet::new(),
+        HashMap::new(),
         Utc::now(),
     );
 
@@ -93,8 +118,9 @@ async fn create_with_owner_and_single_member_ok(pool: SqlitePool) {
     let group = Group::new(
         GroupId::new_random(),
         "Trip to Paris".parse().unwrap(),
+        Currency::default(),
         fixtures::users::marc().id,
-        HashSet::from_iter(vec![fixtures::users::bill().id]),
+        HashMap::from_iter(vec![(fixtures::users::bill().id, MemberRole::Member)]),
         Utc::now(),
     );
 
@@ -108,10 +134,11 @@ async fn create_with_members_ok(pool: SqlitePool) {
     let group = Group::new(
         GroupId::new_random(),
         "Trip to Paris".parse().unwrap(),
+        Currency::default(),
         fixtures::users::marc().id,
-        HashSet::from_iter(vec![
-            fixtures::users::bill().id,
-            fixtures::users::johndoe().id,
+        HashMap::from_iter(vec![
+            (fixtures::users::bill().id, MemberRole::Admin),
+            (fixtures::users::johndoe().id, MemberRole::Viewer),
         ]),
         Utc::now(),
     );
@@ -119,6 +146,10 @@ async fn create_with_members_ok(pool: SqlitePool) {
     let mut tx = pool.begin().await.unwrap();
     let res = database::queries::group::create(&mut tx, &group).await;
     assert!(res.is_ok());
+    let res = database::queries::group::get_by_id(&mut tx, &group.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(group), res);
 }
 
 #[sqlx::test(fixtures("users", "groups"))]
@@ -126,10 +157,11 @@ async fn create_with_members_err_unique_violation_same_name_for_owner(pool: Sqli
     let group = Group::new(
         GroupId::new_random(),
         fixtures::groups::trip_to_europe_2025().name,
+        Currency::default(),
         fixtures::groups::trip_to_europe_2025().owner_id,
-        HashSet::from_iter(vec![
-            fixtures::users::bill().id,
-            fixtures::users::johndoe().id,
+        HashMap::from_iter(vec![
+            (fixtures::users::bill().id, MemberRole::Member),
+            (fixtures::users::johndoe().id, MemberRole::Member),
         ]),
         Utc::now(),
     );
@@ -183,8 +215,9 @@ async fn create_err_foreign_key_owner_not_existing(pool: SqlitePool) {
     let group = Group::new(
         GroupId::new_random(),
         "New group".parse().unwrap(),
+        Currency::default(),
         UserId::new_random(),
-        HashSet::new(),
+        HashMap::new(),
         Utc::now(),
     );
 
@@ -214,8 +247,9 @@ async fn create_err_foreign_key_member_not_existing(pool: SqlitePool) {
     let group = Group::new(
         GroupId::new_random(),
         "New group".parse().unwrap(),
+        Currency::default(),
         fixtures::users::johndoe().id,
-        HashSet::from_iter(vec![UserId::new_random()]),
+        HashMap::from_iter(vec![(UserId::new_random(), MemberRole::Member)]),
         Utc::now(),
     );
 
@@ -277,8 +311,13 @@ async fn get_by_id_with_members_found(pool: SqlitePool) {
 async fn add_member_ok(pool: SqlitePool) {
     let group = fixtures::groups::john_and_bill_shared_expenses();
     let mut tx = pool.begin().await.unwrap();
-    let res =
-        database::queries::group::add_member(&mut tx, &group.id, &fixtures::users::marc().id).await;
+    let res = database::queries::group::add_member(
+        &mut tx,
+        &group.id,
+        &fixtures::users::marc().id,
+        &MemberRole::Viewer,
+    )
+    .await;
     assert!(res.is_ok());
 }
 
@@ -286,9 +325,14 @@ async fn add_member_ok(pool: SqlitePool) {
 async fn add_member_err_unique_violation(pool: SqlitePool) {
     let group = fixtures::groups::john_and_bill_shared_expenses();
     let mut tx = pool.begin().await.unwrap();
-    let err = database::queries::group::add_member(&mut tx, &group.id, &fixtures::users::bill().id)
-        .await
-        .unwrap_err();
+    let err = database::queries::group::add_member(
+        &mut tx,
+        &group.id,
+        &fixtures::users::bill().id,
+        &MemberRole::Member,
+    )
+    .await
+    .unwrap_err();
     match err {
         database::Error::SqlxError(error) => {
             assert_eq!(
@@ -310,9 +354,14 @@ async fn add_member_err_unique_violation(pool: SqlitePool) {
 async fn add_member_err_fk_constraint(pool: SqlitePool) {
     let group = fixtures::groups::john_and_bill_shared_expenses();
     let mut tx = pool.begin().await.unwrap();
-    let err = database::queries::group::add_member(&mut tx, &group.id, &UserId::new_random())
-        .await
-        .unwrap_err();
+    let err = database::queries::group::add_member(
+        &mut tx,
+        &group.id,
+        &UserId::new_random(),
+        &MemberRole::Member,
+    )
+    .await
+    .unwrap_err();
     match err {
         database::Error::SqlxError(error) => {
             assert_eq!(
@@ -329,3 +378,195 @@ async fn add_member_err_fk_constraint(pool: SqlitePool) {
         ),
     };
 }
+
+// -- set_member_role
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn set_member_role_ok(pool: SqlitePool) {
+    let mut expected = fixtures::groups::john_and_bill_shared_expenses();
+    let bill_id = fixtures::users::bill().id;
+    expected.members.insert(bill_id, MemberRole::Viewer);
+    let mut tx = pool.begin().await.unwrap();
+    let updated = database::queries::group::set_member_role(
+        &mut tx,
+        &expected.id,
+        &bill_id,
+        &MemberRole::Viewer,
+    )
+    .await
+    .unwrap();
+    assert!(updated);
+    let res = database::queries::group::get_by_id(&mut tx, &expected.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(expected), res);
+}
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn set_member_role_not_member(pool: SqlitePool) {
+    let group = fixtures::groups::john_and_bill_shared_expenses();
+    let mut tx = pool.begin().await.unwrap();
+    let updated = database::queries::group::set_member_role(
+        &mut tx,
+        &group.id,
+        &fixtures::users::johndoe().id,
+        &MemberRole::Admin,
+    )
+    .await
+    .unwrap();
+    assert!(!updated);
+}
+
+// -- remove_member
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn remove_member_ok(pool: SqlitePool) {
+    let mut expected = fixtures::groups::john_and_bill_shared_expenses();
+    let bill_id = fixtures::users::bill().id;
+    expected.members.remove(&bill_id);
+    let mut tx = pool.begin().await.unwrap();
+    let removed = database::queries::group::remove_member(&mut tx, &expected.id, &bill_id)
+        .await
+        .unwrap();
+    assert!(removed);
+    let res = database::queries::group::get_by_id(&mut tx, &expected.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(expected), res);
+}
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn remove_member_not_member(pool: SqlitePool) {
+    let group = fixtures::groups::john_and_bill_shared_expenses();
+    let mut tx = pool.begin().await.unwrap();
+    let removed =
+        database::queries::group::remove_member(&mut tx, &group.id, &fixtures::users::marc().id)
+            .await
+            .unwrap();
+    assert!(!removed);
+}
+
+// -- transfer_ownership
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn transfer_ownership_ok(pool: SqlitePool) {
+    let mut expected = fixtures::groups::john_and_bill_shared_expenses();
+    let john_id = fixtures::users::johndoe().id;
+    let bill_id = fixtures::users::bill().id;
+    expected.owner_id = bill_id;
+    expected.members.remove(&bill_id);
+    expected.members.insert(john_id, MemberRole::Admin);
+    let mut tx = pool.begin().await.unwrap();
+    let transferred =
+        database::queries::group::transfer_ownership(&mut tx, &expected.id, &john_id, &bill_id)
+            .await
+            .unwrap();
+    assert!(transferred);
+    let res = database::queries::group::get_by_id(&mut tx, &expected.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(expected), res);
+}
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn transfer_ownership_not_owner(pool: SqlitePool) {
+    let expected = fixtures::groups::john_and_bill_shared_expenses();
+    let mut tx = pool.begin().await.unwrap();
+    let transferred = database::queries::group::transfer_ownership(
+        &mut tx,
+        &expected.id,
+        &fixtures::users::bill().id,
+        &fixtures::users::marc().id,
+    )
+    .await
+    .unwrap();
+    assert!(!transferred);
+    let res = database::queries::group::get_by_id(&mut tx, &expected.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(expected), res);
+}
+
+// -- set_archived_at
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn set_archived_at_ok(pool: SqlitePool) {
+    let mut expected = fixtures::groups::john_and_bill_shared_expenses();
+    expected.archived_at = Some(Utc::now());
+    let mut tx = pool.begin().await.unwrap();
+    let updated =
+        database::queries::group::set_archived_at(&mut tx, &expected.id, expected.archived_at)
+            .await
+            .unwrap();
+    assert!(updated);
+
+    let res = database::queries::group::get_by_id(&mut tx, &expected.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(expected), res);
+}
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn set_archived_at_not_found(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let updated = database::queries::group::set_archived_at(&mut tx, &GroupId::new_random(), None)
+        .await
+        .unwrap();
+    assert!(!updated);
+}
+
+// -- get_all_for_user
+
+fn first_page() -> database::DbPagination {
+    database::DbPagination {
+        limit: 10,
+        offset: 0,
+    }
+}
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn get_all_for_user_filters_by_archived_state(pool: SqlitePool) {
+    let group = fixtures::groups::john_and_bill_shared_expenses();
+    let bill_id = fixtures::users::bill().id;
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::group::set_archived_at(&mut tx, &group.id, Some(Utc::now()))
+        .await
+        .unwrap();
+
+    let groups = database::queries::group::get_all_for_user(&mut tx, &bill_id, false, first_page())
+        .await
+        .unwrap();
+    assert!(groups.is_empty());
+    let count = database::queries::group::count_all_for_user(&mut tx, &bill_id, false)
+        .await
+        .unwrap();
+    assert_eq!(0, count);
+
+    let groups = database::queries::group::get_all_for_user(&mut tx, &bill_id, true, first_page())
+        .await
+        .unwrap();
+    assert_eq!(
+        vec![group.id],
+        groups.iter().map(|group| group.id).collect::<Vec<_>>()
+    );
+    let count = database::queries::group::count_all_for_user(&mut tx, &bill_id, true)
+        .await
+        .unwrap();
+    assert_eq!(1, count);
+}
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn get_all_for_user_includes_groups_without_members(pool: SqlitePool) {
+    let john_id = fixtures::users::johndoe().id;
+    let mut tx = pool.begin().await.unwrap();
+
+    let groups = database::queries::group::get_all_for_user(&mut tx, &john_id, false, first_page())
+        .await
+        .unwrap();
+
+    let trip_to_europe_2025 = fixtures::groups::trip_to_europe_2025();
+    let john_and_bill_shared_expenses = fixtures::groups::john_and_bill_shared_expenses();
+    assert_eq!(2, groups.len());
+    assert!(groups.contains(&trip_to_europe_2025));
+    assert!(groups.contains(&john_and_bill_shared_expenses));
+}
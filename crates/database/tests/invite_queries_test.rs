@@ -0,0 +1,190 @@
+use domain::{
+    entities::Invite,
+    types::{invite_id::InviteId, invite_token::InviteToken, member_role::MemberRole},
+};
+use sqlx::{
+    SqlitePool,
+    types::chrono::{DateTime, Utc},
+};
+
+mod fixtures;
+
+fn at(rfc3339: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(rfc3339).unwrap().to_utc()
+}
+
+fn invite(max_uses: Option<u32>) -> Invite {
+    Invite::new(
+        InviteId::new_random(),
+        fixtures::groups::john_and_bill_shared_expenses().id,
+        InviteToken::new_random(),
+        MemberRole::Viewer,
+        max_uses,
+        0,
+        fixtures::users::johndoe().id,
+        at("2026-03-08T10:00:00Z"),
+        at("2026-03-01T10:00:00Z"),
+    )
+}
+
+// -- create
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn create_ok(pool: SqlitePool) {
+    let invite = invite(Some(1));
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::invite::create(&mut tx, &invite)
+        .await
+        .unwrap();
+
+    let saved = database::queries::invite::get_by_id(&mut tx, &invite.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(invite), saved);
+}
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn create_duplicate_token(pool: SqlitePool) {
+    let invite1 = invite(None);
+    let mut invite2 = invite(None);
+    invite2.token = invite1.token.clone();
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::invite::create(&mut tx, &invite1)
+        .await
+        .unwrap();
+    let res = database::queries::invite::create(&mut tx, &invite2).await;
+    assert!(res.is_err());
+}
+
+// -- get_by_token
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn get_by_token_ok(pool: SqlitePool) {
+    let invite = invite(None);
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::invite::create(&mut tx, &invite)
+        .await
+        .unwrap();
+
+    let saved = database::queries::invite::get_by_token(&mut tx, &invite.token)
+        .await
+        .unwrap();
+    assert_eq!(Some(invite), saved);
+}
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn get_by_token_not_found(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let saved = database::queries::invite::get_by_token(&mut tx, &InviteToken::new_random())
+        .await
+        .unwrap();
+    assert_eq!(None, saved);
+}
+
+// -- get_all_for_group
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn get_all_for_group_ok(pool: SqlitePool) {
+    let older = invite(None);
+    let mut newer = invite(Some(3));
+    newer.created_at = at("2026-03-01T10:00:01Z");
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::invite::create(&mut tx, &older)
+        .await
+        .unwrap();
+    database::queries::invite::create(&mut tx, &newer)
+        .await
+        .unwrap();
+
+    let invites = database::queries::invite::get_all_for_group(
+        &mut tx,
+        &fixtures::groups::john_and_bill_shared_expenses().id,
+    )
+    .await
+    .unwrap();
+    assert_eq!(vec![newer, older], invites);
+
+    let invites = database::queries::invite::get_all_for_group(
+        &mut tx,
+        &fixtures::groups::trip_to_europe_2025().id,
+    )
+    .await
+    .unwrap();
+    assert!(invites.is_empty());
+}
+
+// -- increment_uses
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn increment_uses_ok(pool: SqlitePool) {
+    let invite = invite(Some(2));
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::invite::create(&mut tx, &invite)
+        .await
+        .unwrap();
+
+    for _ in 0..2 {
+        let incremented = database::queries::invite::increment_uses(&mut tx, &invite.id)
+            .await
+            .unwrap();
+        assert!(incremented);
+    }
+
+    let saved = database::queries::invite::get_by_id(&mut tx, &invite.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(2, saved.uses);
+}
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn increment_uses_exhausted(pool: SqlitePool) {
+    let invite = invite(Some(1));
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::invite::create(&mut tx, &invite)
+        .await
+        .unwrap();
+    database::queries::invite::increment_uses(&mut tx, &invite.id)
+        .await
+        .unwrap();
+
+    let incremented = database::queries::invite::increment_uses(&mut tx, &invite.id)
+        .await
+        .unwrap();
+    assert!(!incremented);
+}
+
+// -- revoke
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn revoke_ok(pool: SqlitePool) {
+    let invite = invite(None);
+    let revoked_at = at("2026-03-01T11:00:00Z");
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::invite::create(&mut tx, &invite)
+        .await
+        .unwrap();
+
+    let revoked = database::queries::invite::revoke(&mut tx, &invite.id, revoked_at)
+        .await
+        .unwrap();
+    assert!(revoked);
+
+    let saved = database::queries::invite::get_by_id(&mut tx, &invite.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(Some(revoked_at), saved.revoked_at);
+
+    let revoked = database::queries::invite::revoke(&mut tx, &invite.id, revoked_at)
+        .await
+        .unwrap();
+    assert!(!revoked);
+}
@@ -0,0 +1,388 @@
+use std::collections::{HashMap, HashSet};
+
+use domain::{
+    entities::RecurringExpense,
+    types::{
+        currency::Currency,
+        exchange_rate::ExchangeRate,
+        expense_category::ExpenseCategory,
+        expense_id::ExpenseId,
+        expense_split::ExpenseSplit,
+        money::Money,
+        recurrence::{Frequency, Recurrence},
+        recurring_expense_id::RecurringExpenseId,
+    },
+};
+use sqlx::{
+    SqlitePool,
+    types::chrono::{DateTime, Utc},
+};
+
+mod fixtures;
+
+fn at(rfc3339: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(rfc3339).unwrap().to_utc()
+}
+
+fn monthly_rent(split: ExpenseSplit, starts_at: DateTime<Utc>) -> RecurringExpense {
+    let recurrence = Recurrence::new(Frequency::Monthly, 1, starts_at, None).unwrap();
+    RecurringExpense::new(
+        RecurringExpenseId::new_random(),
+        fixtures::groups::john_and_bill_shared_expenses().id,
+        "Rent".parse().unwrap(),
+        Some("Flat on Main Street".parse().unwrap()),
+        ExpenseCategory::Lodging,
+        Some(fixtures::categories::bars().id),
+        fixtures::users::johndoe().id,
+        HashSet::from_iter(vec![fixtures::users::bill().id]),
+        split,
+        Money::from_euros(1_200),
+        Currency::EUR,
+        ExchangeRate::IDENTITY,
+        fixtures::users::johndoe().id,
+        recurrence.clone(),
+        recurrence.next_after(None),
+        Utc::now(),
+    )
+}
+
+// -- create
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn create_ok(pool: SqlitePool) {
+    let recurring_expense = monthly_rent(ExpenseSplit::Equal, at("2026-01-01T09:00:00Z"));
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::recurring_expense::create(&mut tx, &recurring_expense)
+        .await
+        .unwrap();
+
+    let saved = database::queries::recurring_expense::get_by_id(&mut tx, &recurring_expense.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(recurring_expense), saved);
+}
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn create_ok_shares(pool: SqlitePool) {
+    let john = fixtures::users::johndoe().id;
+    let bill = fixtures::users::bill().id;
+    let recurring_expense = monthly_rent(
+        ExpenseSplit::Shares {
+            shares: HashMap::from_iter(vec![(john, 2), (bill, 1)]),
+        },
+        at("2026-01-01T09:00:00Z"),
+    );
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::recurring_expense::create(&mut tx, &recurring_expense)
+        .await
+        .unwrap();
+
+    let saved = database::queries::recurring_expense::get_by_id(&mut tx, &recurring_expense.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(recurring_expense), saved);
+}
+
+// -- get_by_id
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn get_by_id_not_found(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let saved =
+        database::queries::recurring_expense::get_by_id(&mut tx, &RecurringExpenseId::new_random())
+            .await
+            .unwrap();
+    assert_eq!(None, saved);
+}
+
+// -- get_all_for_group
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn get_all_for_group_ok(pool: SqlitePool) {
+    let mut first = monthly_rent(ExpenseSplit::Equal, at("2026-01-01T09:00:00Z"));
+    first.created_at = at("2025-12-01T09:00:00Z");
+    let mut second = monthly_rent(ExpenseSplit::Equal, at("2026-02-01T09:00:00Z"));
+    second.created_at = at("2025-12-02T09:00:00Z");
+
+    let mut tx = pool.begin().await.unwrap();
+    for recurring_expense in [&second, &first] {
+        database::queries::recurring_expense::create(&mut tx, recurring_expense)
+            .await
+            .unwrap();
+    }
+
+    let all = database::queries::recurring_expense::get_all_for_group(
+        &mut tx,
+        &fixtures::groups::john_and_bill_shared_expenses().id,
+    )
+    .await
+    .unwrap();
+    assert_eq!(vec![first, second], all);
+    let all = database::queries::recurring_expense::get_all_for_group(
+        &mut tx,
+        &fixtures::groups::trip_to_europe_2025().id,
+    )
+    .await
+    .unwrap();
+    assert!(all.is_empty());
+}
+
+// -- get_all_due
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn get_all_due_ok(pool: SqlitePool) {
+    let due = monthly_rent(ExpenseSplit::Equal, at("2026-01-01T09:00:00Z"));
+    let not_yet_due = monthly_rent(ExpenseSplit::Equal, at("2026-03-01T09:00:00Z"));
+    let mut ended = monthly_rent(ExpenseSplit::Equal, at("2025-01-01T09:00:00Z"));
+    ended.next_occurrence_at = None;
+
+    let mut tx = pool.begin().await.unwrap();
+    for recurring_expense in [&due, &not_yet_due, &ended] {
+        database::queries::recurring_expense::create(&mut tx, recurring_expense)
+            .await
+            .unwrap();
+    }
+
+    let all =
+        database::queries::recurring_expense::get_all_due(&mut tx, at("2026-02-15T00:00:00Z"))
+            .await
+            .unwrap();
+    assert_eq!(vec![due], all);
+}
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn get_all_due_skips_archived_groups(pool: SqlitePool) {
+    let due = monthly_rent(ExpenseSplit::Equal, at("2026-01-01T09:00:00Z"));
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::recurring_expense::create(&mut tx, &due)
+        .await
+        .unwrap();
+    database::queries::group::set_archived_at(&mut tx, &due.group_id, Some(Utc::now()))
+        .await
+        .unwrap();
+
+    let all =
+        database::queries::recurring_expense::get_all_due(&mut tx, at("2026-02-15T00:00:00Z"))
+            .await
+            .unwrap();
+    assert!(all.is_empty());
+}
+
+// -- update
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn update_ok(pool: SqlitePool) {
+    let mut recurring_expense = monthly_rent(ExpenseSplit::Equal, at("2026-01-01T09:00:00Z"));
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::recurring_expense::create(&mut tx, &recurring_expense)
+        .await
+        .unwrap();
+
+    let john = fixtures::users::johndoe().id;
+    let bill = fixtures::users::bill().id;
+    recurring_expense.title = "Internet".parse().unwrap();
+    recurring_expense.note = None;
+    recurring_expense.category_id = None;
+    recurring_expense.total = Money::from_cents(3_999);
+    recurring_expense.split = ExpenseSplit::Exact {
+        amounts: HashMap::from_iter(vec![
+            (john, Money::from_cents(2_000)),
+            (bill, Money::from_cents(1_999)),
+        ]),
+    };
+    recurring_expense.recurrence = Recurrence::new(
+        Frequency::Weekly,
+        2,
+        at("2026-01-05T09:00:00Z"),
+        Some(at("2026-06-30T00:00:00Z")),
+    )
+    .unwrap();
+    recurring_expense.next_occurrence_at = recurring_expense.recurrence.next_after(None);
+
+    let updated = database::queries::recurring_expense::update(&mut tx, &recurring_expense)
+        .await
+        .unwrap();
+    assert!(updated);
+
+    let saved = database::queries::recurring_expense::get_by_id(&mut tx, &recurring_expense.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(recurring_expense), saved);
+}
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn update_not_found(pool: SqlitePool) {
+    let recurring_expense = monthly_rent(ExpenseSplit::Equal, at("2026-01-01T09:00:00Z"));
+
+    let mut tx = pool.begin().await.unwrap();
+    let updated = database::queries::recurring_expense::update(&mut tx, &recurring_expense)
+        .await
+        .unwrap();
+    assert!(!updated);
+}
+
+// -- delete
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn delete_ok(pool: SqlitePool) {
+    let recurring_expense = monthly_rent(ExpenseSplit::Equal, at("2026-01-01T09:00:00Z"));
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::recurring_expense::create(&mut tx, &recurring_expense)
+        .await
+        .unwrap();
+
+    let deleted = database::queries::recurring_expense::delete(&mut tx, &recurring_expense.id)
+        .await
+        .unwrap();
+    assert!(deleted);
+    let deleted = database::queries::recurring_expense::delete(&mut tx, &recurring_expense.id)
+        .await
+        .unwrap();
+    assert!(!deleted);
+
+    let saved = database::queries::recurring_expense::get_by_id(&mut tx, &recurring_expense.id)
+        .await
+        .unwrap();
+    assert_eq!(None, saved);
+}
+
+// -- update_next_occurrence
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn update_next_occurrence_only_from_expected(pool: SqlitePool) {
+    let recurring_expense = monthly_rent(ExpenseSplit::Equal, at("2026-01-01T09:00:00Z"));
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::recurring_expense::create(&mut tx, &recurring_expense)
+        .await
+        .unwrap();
+
+    let claimed = database::queries::recurring_expense::update_next_occurrence(
+        &mut tx,
+        &recurring_expense.id,
+        Some(at("2026-01-01T09:00:00Z")),
+        Some(at("2026-02-01T09:00:00Z")),
+    )
+    .await
+    .unwrap();
+    assert!(claimed);
+
+    // the same occurrence cannot be claimed twice
+    let claimed = database::queries::recurring_expense::update_next_occurrence(
+        &mut tx,
+        &recurring_expense.id,
+        Some(at("2026-01-01T09:00:00Z")),
+        Some(at("2026-02-01T09:00:00Z")),
+    )
+    .await
+    .unwrap();
+    assert!(!claimed);
+
+    let claimed = database::queries::recurring_expense::update_next_occurrence(
+        &mut tx,
+        &recurring_expense.id,
+        Some(at("2026-02-01T09:00:00Z")),
+        None,
+    )
+    .await
+    .unwrap();
+    assert!(claimed);
+
+    let saved = database::queries::recurring_expense::get_by_id(&mut tx, &recurring_expense.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(None, saved.next_occurrence_at);
+}
+
+// -- record_occurrence / get_last_occurrence_at
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn record_occurrence_ok(pool: SqlitePool) {
+    let recurring_expense = monthly_rent(ExpenseSplit::Equal, at("2026-01-01T09:00:00Z"));
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::recurring_expense::create(&mut tx, &recurring_expense)
+        .await
+        .unwrap();
+
+    let last = database::queries::recurring_expense::get_last_occurrence_at(
+        &mut tx,
+        &recurring_expense.id,
+    )
+    .await
+    .unwrap();
+    assert_eq!(None, last);
+
+    database::queries::recurring_expense::record_occurrence(
+        &mut tx,
+        &recurring_expense.id,
+        at("2026-01-01T09:00:00Z"),
+        Some(&ExpenseId::new_random()),
+    )
+    .await
+    .unwrap();
+    database::queries::recurring_expense::record_occurrence(
+        &mut tx,
+        &recurring_expense.id,
+        at("2026-02-01T09:00:00Z"),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let last = database::queries::recurring_expense::get_last_occurrence_at(
+        &mut tx,
+        &recurring_expense.id,
+    )
+    .await
+    .unwrap();
+    assert_eq!(Some(at("2026-02-01T09:00:00Z")), last);
+}
+
+#[sqlx::test(fixtures("users", "groups", "categories"))]
+async fn record_occurrence_err_unique_violation_same_occurrence(pool: SqlitePool) {
+    let recurring_expense = monthly_rent(ExpenseSplit::Equal, at("2026-01-01T09:00:00Z"));
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::recurring_expense::create(&mut tx, &recurring_expense)
+        .await
+        .unwrap();
+    database::queries::recurring_expense::record_occurrence(
+        &mut tx,
+        &recurring_expense.id,
+        at("2026-01-01T09:00:00Z"),
+        Some(&ExpenseId::new_random()),
+    )
+    .await
+    .unwrap();
+
+    let err = database::queries::recurring_expense::record_occurrence(
+        &mut tx,
+        &recurring_expense.id,
+        at("2026-01-01T09:00:00Z"),
+        Some(&ExpenseId::new_random()),
+    )
+    .await
+    .unwrap_err();
+    match err {
+        database::Error::SqlxError(error) => {
+            assert_eq!(
+                sqlx::error::ErrorKind::UniqueViolation,
+                error.as_database_error().unwrap().kind()
+            );
+        }
+        unexpected => panic!(
+            "{}",
+            format!(
+                "expected database::error::SqlxError but received {}",
+                unexpected
+            )
+        ),
+    };
+}
@@ -0,0 +1,193 @@
+use domain::{
+    testutils::settlement::TestSettlement,
+    types::{settlement_id::SettlementId, settlement_status::SettlementStatus},
+};
+use sqlx::{SqlitePool, types::chrono::Utc};
+use uuid::Uuid;
+
+mod fixtures;
+
+// -- create
+
+#[sqlx::test(fixtures("users", "groups", "settlements"))]
+async fn create_ok(pool: SqlitePool) {
+    let settlement = TestSettlement::new_valid(
+        Uuid::now_v7(),
+        fixtures::groups::john_and_bill_shared_expenses().id.value(),
+        fixtures::users::bill().id.value(),
+        fixtures::users::johndoe().id.value(),
+        1_234,
+        SettlementStatus::Active,
+        fixtures::users::bill().id.value(),
+        Utc::now(),
+        Utc::now(),
+    );
+
+    let mut tx = pool.begin().await.unwrap();
+    let res = database::queries::settlement::create(&mut tx, &settlement).await;
+    assert!(res.is_ok());
+}
+
+#[sqlx::test(fixtures("users", "groups", "settlements"))]
+async fn create_err_pk_violation(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let err = database::queries::settlement::create(
+        &mut tx,
+        &fixtures::settlements::john_paid_bill_back(),
+    )
+    .await
+    .unwrap_err();
+    match err {
+        database::Error::SqlxError(error) => {
+            assert_eq!(
+                sqlx::error::ErrorKind::UniqueViolation,
+                error.as_database_error().unwrap().kind()
+            );
+        }
+        unexpected => panic!(
+            "{}",
+            format!(
+                "expected database::error::SqlxError but received {}",
+                unexpected
+            )
+        ),
+    };
+}
+
+// -- get_by_id
+
+#[sqlx::test(fixtures("users", "groups", "settlements"))]
+async fn get_by_id_not_found(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let res = database::queries::settlement::get_by_id(&mut tx, &SettlementId::new_random())
+        .await
+        .unwrap();
+    assert_eq!(None, res);
+}
+
+#[sqlx::test(fixtures("users", "groups", "settlements"))]
+async fn get_by_id_found(pool: SqlitePool) {
+    let expected = fixtures::settlements::john_paid_bill_back();
+    let mut tx = pool.begin().await.unwrap();
+    let actual = database::queries::settlement::get_by_id(&mut tx, &expected.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(expected), actual);
+}
+
+#[sqlx::test(fixtures("users", "groups", "settlements"))]
+async fn get_by_id_found_cancelled(pool: SqlitePool) {
+    let expected = fixtures::settlements::john_paid_bill_back_cancelled();
+    let mut tx = pool.begin().await.unwrap();
+    let actual = database::queries::settlement::get_by_id(&mut tx, &expected.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(expected), actual);
+}
+
+// -- cancel
+
+#[sqlx::test(fixtures("users", "groups", "settlements"))]
+async fn cancel_ok(pool: SqlitePool) {
+    let settlement = fixtures::settlements::john_paid_bill_back();
+    let mut tx = pool.begin().await.unwrap();
+    let cancelled = database::queries::settlement::cancel(
+        &mut tx,
+        &settlement.id,
+        &fixtures::users::bill().id,
+        Utc::now(),
+    )
+    .await
+    .unwrap();
+    assert!(cancelled);
+
+    let settlement = database::queries::settlement::get_by_id(&mut tx, &settlement.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!settlement.is_active());
+}
+
+#[sqlx::test(fixtures("users", "groups", "settlements"))]
+async fn cancel_already_cancelled(pool: SqlitePool) {
+    let settlement = fixtures::settlements::john_paid_bill_back_cancelled();
+    let mut tx = pool.begin().await.unwrap();
+    let cancelled = database::queries::settlement::cancel(
+        &mut tx,
+        &settlement.id,
+        &fixtures::users::johndoe().id,
+        Utc::now(),
+    )
+    .await
+    .unwrap();
+    assert!(!cancelled);
+
+    let actual = database::queries::settlement::get_by_id(&mut tx, &settlement.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(settlement), actual);
+}
+
+// -- get_all_active_for_group_unpaginated
+
+#[sqlx::test(fixtures("users", "groups", "settlements"))]
+async fn get_all_active_for_group_unpaginated_only_active(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let actual = database::queries::settlement::get_all_active_for_group_unpaginated(
+        &mut tx,
+        &fixtures::groups::john_and_bill_shared_expenses().id,
+    )
+    .await
+    .unwrap();
+    assert_eq!(vec![fixtures::settlements::john_paid_bill_back()], actual);
+}
+
+// -- get_all_for_group_unpaginated
+
+#[sqlx::test(fixtures("users", "groups", "settlements"))]
+async fn get_all_for_group_unpaginated_sorted_by_creation(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let actual = database::queries::settlement::get_all_for_group_unpaginated(
+        &mut tx,
+        &fixtures::groups::john_and_bill_shared_expenses().id,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        vec![
+            fixtures::settlements::john_paid_bill_back_cancelled(),
+            fixtures::settlements::john_paid_bill_back(),
+        ],
+        actual
+    );
+}
+
+// -- get_all_for_group
+
+#[sqlx::test(fixtures("users", "groups", "settlements"))]
+async fn get_all_for_group_sorted_by_occurrence(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let group_id = fixtures::groups::john_and_bill_shared_expenses().id;
+    let actual = database::queries::settlement::get_all_for_group(
+        &mut tx,
+        &group_id,
+        database::DbPagination {
+            limit: 10,
+            offset: 0,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        vec![
+            fixtures::settlements::john_paid_bill_back(),
+            fixtures::settlements::john_paid_bill_back_cancelled(),
+        ],
+        actual
+    );
+
+    let count = database::queries::settlement::count_all_for_group(&mut tx, &group_id)
+        .await
+        .unwrap();
+    assert_eq!(2, count);
+}
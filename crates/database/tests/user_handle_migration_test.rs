@@ -0,0 +1,100 @@
+use std::path::PathBuf;
+
+use sqlx::SqlitePool;
+
+const HANDLE_MIGRATION: &str = "00019_add_user_handle.sql";
+
+/// Runs the migrations preceding the one adding handles, in order.
+async fn migrate_until_handles(pool: &SqlitePool) {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("migrations");
+    let mut files: Vec<_> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| name.as_str() < HANDLE_MIGRATION)
+        .collect();
+    files.sort();
+    for file in files {
+        let sql = std::fs::read_to_string(dir.join(file)).unwrap();
+        sqlx::raw_sql(&sql).execute(pool).await.unwrap();
+    }
+}
+
+async fn insert_user(pool: &SqlitePool, id: &str, name: &str, created_at: &str) {
+    sqlx::query(
+        r#"
+    INSERT INTO user (id, name, email, role, created_at)
+    VALUES (?, ?, ?, 0, ?)
+    "#,
+    )
+    .bind(uuid::Uuid::parse_str(id).unwrap())
+    .bind(name)
+    .bind(format!("{id}@gmail.com"))
+    .bind(created_at)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+#[sqlx::test(migrations = false)]
+async fn backfilled_handles_do_not_clash_with_names(pool: SqlitePool) {
+    migrate_until_handles(&pool).await;
+    insert_user(
+        &pool,
+        "019b6c1a-0000-7000-8000-00000000aa01",
+        "bob",
+        "2025-01-01T00:00:00Z",
+    )
+    .await;
+    insert_user(
+        &pool,
+        "019b6c1a-0000-7000-8000-00000000aa02",
+        "Bob",
+        "2025-01-02T00:00:00Z",
+    )
+    .await;
+    insert_user(
+        &pool,
+        "019b6c1a-0000-7000-8000-00000000aa03",
+        "bob_2",
+        "2025-01-03T00:00:00Z",
+    )
+    .await;
+    insert_user(
+        &pool,
+        "019b6c1a-0000-7000-8000-00000000aa04",
+        "abcdefghijklmnopqrstuvwx",
+        "2025-01-04T00:00:00Z",
+    )
+    .await;
+    insert_user(
+        &pool,
+        "019b6c1a-0000-7000-8000-00000000aa05",
+        "ABCDEFGHIJKLMNOPQRSTUVWX",
+        "2025-01-05T00:00:00Z",
+    )
+    .await;
+
+    let sql = std::fs::read_to_string(
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("migrations")
+            .join(HANDLE_MIGRATION),
+    )
+    .unwrap();
+    sqlx::raw_sql(&sql).execute(&pool).await.unwrap();
+
+    let handles: Vec<(String,)> = sqlx::query_as("SELECT handle FROM user ORDER BY created_at")
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+    let handles: Vec<String> = handles.into_iter().map(|(handle,)| handle).collect();
+    assert_eq!(
+        vec![
+            "bob",
+            "bob_0000aa02",
+            "bob_2",
+            "abcdefghijklmnopqrstuvwx",
+            "abcdefghijklmnopqrstuvw_0000aa05",
+        ],
+        handles
+    );
+}
@@ -1,12 +1,17 @@
+use std::collections::HashSet;
+
 use domain::{
-    testutils::user::TestUser,
-    types::{role::Role, user_id::UserId},
+    testutils::{expense_entry::TestExpenseEntry, user::TestUser},
+    types::{
+        expense_entry_status::ExpenseEntryStatus, handle::Handle, member_role::MemberRole,
+        role::Role, user_id::UserId,
+    },
 };
 use email_address::EmailAddress;
 use sqlx::{SqlitePool, types::chrono::Utc};
 use uuid::Uuid;
 
-use crate::fixtures::users::johndoe;
+use crate::fixtures::users::{bill, johndoe, marc};
 
 mod fixtures;
 
@@ -76,13 +81,14 @@ async fn create_with_fixtures_ok(pool: SqlitePool) {
 
 #[sqlx::test(fixtures("users"))]
 async fn create_with_fixtures_same_username_ok(pool: SqlitePool) {
-    let user = TestUser::new_valid(
+    let mut user = TestUser::new_valid(
         Uuid::now_v7(),
         "johndoe",
         "bob@gmail.com",
         Role::User,
         Utc::now(),
     );
+    user.handle = "johndoe_2".parse().unwrap();
 
     let mut tx = pool.begin().await.unwrap();
     let res = database::queries::user::create(&mut tx, &user).await;
@@ -121,11 +127,60 @@ async fn create_with_fixtures_err_unique_violation(pool: SqlitePool) {
     };
 }
 
+#[sqlx::test(fixtures("users"))]
+async fn create_with_fixtures_err_same_handle(pool: SqlitePool) {
+    let mut user = TestUser::new_valid(
+        Uuid::now_v7(),
+        "Bob",
+        "bob@gmail.com",
+        Role::User,
+        Utc::now(),
+    );
+    user.handle = johndoe().handle;
+
+    let mut tx = pool.begin().await.unwrap();
+    let err = database::queries::user::create(&mut tx, &user)
+        .await
+        .unwrap_err();
+
+    match err {
+        database::Error::SqlxError(error) => {
+            assert_eq!(
+                sqlx::error::ErrorKind::UniqueViolation,
+                error.as_database_error().unwrap().kind()
+            );
+        }
+        unexpected => panic!("expected database::error::SqlxError but received {unexpected}"),
+    };
+}
+
+// -- handle_exists
+
+#[sqlx::test(fixtures("users"))]
+async fn handle_exists_with_fixtures_true(pool: SqlitePool) {
+    let handle: Handle = "@JohnDoe".parse().unwrap();
+    let mut tx = pool.begin().await.unwrap();
+    let exists = database::queries::user::handle_exists(&mut tx, &handle)
+        .await
+        .unwrap();
+    assert!(exists);
+}
+
+#[sqlx::test(fixtures("users"))]
+async fn handle_exists_with_fixtures_false(pool: SqlitePool) {
+    let handle: Handle = "yannick".parse().unwrap();
+    let mut tx = pool.begin().await.unwrap();
+    let exists = database::queries::user::handle_exists(&mut tx, &handle)
+        .await
+        .unwrap();
+    assert!(!exists);
+}
+
 // -- get_by_email
 
 #[sqlx::test]
 async fn get_by_email_not_found(pool: SqlitePool) {
-    let email = johndoe().email;
+    let email = johndoe().email.unwrap();
     let mut tx = pool.begin().await.unwrap();
     let maybe_user = database::queries::user::get_by_email(&mut tx, &email)
         .await
@@ -135,7 +190,7 @@ async fn get_by_email_not_found(pool: SqlitePool) {
 
 #[sqlx::test(fixtures("users"))]
 async fn get_by_email_with_fixtures_found(pool: SqlitePool) {
-    let email = johndoe().email;
+    let email = johndoe().email.unwrap();
     let mut tx = pool.begin().await.unwrap();
     let maybe_user = database::queries::user::get_by_email(&mut tx, &email)
         .await
@@ -153,6 +208,28 @@ async fn get_by_email_with_fixtures_not_found(pool: SqlitePool) {
     assert!(maybe_user.is_none());
 }
 
+// -- get_by_handle
+
+#[sqlx::test(fixtures("users"))]
+async fn get_by_handle_with_fixtures_found(pool: SqlitePool) {
+    let handle: Handle = "@Bill".parse().unwrap();
+    let mut tx = pool.begin().await.unwrap();
+    let maybe_user = database::queries::user::get_by_handle(&mut tx, &handle)
+        .await
+        .unwrap();
+    assert_eq!(Some(bill()), maybe_user);
+}
+
+#[sqlx::test(fixtures("users"))]
+async fn get_by_handle_with_fixtures_not_found(pool: SqlitePool) {
+    let handle: Handle = "yannick".parse().unwrap();
+    let mut tx = pool.begin().await.unwrap();
+    let maybe_user = database::queries::user::get_by_handle(&mut tx, &handle)
+        .await
+        .unwrap();
+    assert!(maybe_user.is_none());
+}
+
 // -- get_by_id
 
 #[sqlx::test]
@@ -216,3 +293,246 @@ async fn exists_by_id_with_fixtures_false(pool: SqlitePool) {
         .unwrap();
     assert!(!exists);
 }
+
+// -- search
+
+#[sqlx::test(fixtures("users"))]
+async fn search_by_handle_prefix(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let users = database::queries::user::search(&mut tx, "JOHN", 10)
+        .await
+        .unwrap();
+    assert_eq!(vec![johndoe()], users);
+}
+
+#[sqlx::test(fixtures("users"))]
+async fn search_by_name_prefix(pool: SqlitePool) {
+    let mut user = TestUser::new_valid(
+        Uuid::now_v7(),
+        "Marcel",
+        "marcel@gmail.com",
+        Role::User,
+        Utc::now(),
+    );
+    user.handle = "zorro".parse().unwrap();
+
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::user::create(&mut tx, &user)
+        .await
+        .unwrap();
+
+    let users = database::queries::user::search(&mut tx, "mar", 10)
+        .await
+        .unwrap();
+    assert_eq!(vec![marc(), user], users);
+}
+
+#[sqlx::test(fixtures("users"))]
+async fn search_escapes_wildcards(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let users = database::queries::user::search(&mut tx, "_il", 10)
+        .await
+        .unwrap();
+    assert!(users.is_empty());
+    let users = database::queries::user::search(&mut tx, "%", 10)
+        .await
+        .unwrap();
+    assert!(users.is_empty());
+}
+
+#[sqlx::test(fixtures("users"))]
+async fn search_limit(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let users = database::queries::user::search(&mut tx, "", 2)
+        .await
+        .unwrap();
+    assert_eq!(vec![bill(), johndoe()], users);
+}
+
+// -- guests
+
+#[sqlx::test(fixtures("users"))]
+async fn create_guest_ok(pool: SqlitePool) {
+    let guest = TestUser::new_guest(Uuid::now_v7(), "Gabe", Utc::now());
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::user::create(&mut tx, &guest)
+        .await
+        .unwrap();
+    let maybe_user = database::queries::user::get_by_id(&mut tx, &guest.id)
+        .await
+        .unwrap();
+    assert_eq!(Some(guest), maybe_user);
+}
+
+#[sqlx::test(fixtures("users"))]
+async fn guests_are_not_found_by_handle_nor_search(pool: SqlitePool) {
+    let guest = TestUser::new_guest(Uuid::now_v7(), "Gabe", Utc::now());
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::user::create(&mut tx, &guest)
+        .await
+        .unwrap();
+    let maybe_user = database::queries::user::get_by_handle(&mut tx, &guest.handle)
+        .await
+        .unwrap();
+    assert!(maybe_user.is_none());
+    let users = database::queries::user::search(&mut tx, "gab", 10)
+        .await
+        .unwrap();
+    assert!(users.is_empty());
+}
+
+// -- get_co_member_ids
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn get_co_member_ids_ok(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let co_members = database::queries::user::get_co_member_ids(&mut tx, &bill().id)
+        .await
+        .unwrap();
+    assert_eq!(HashSet::from_iter(vec![johndoe().id]), co_members);
+}
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn get_co_member_ids_without_group(pool: SqlitePool) {
+    let mut tx = pool.begin().await.unwrap();
+    let co_members = database::queries::user::get_co_member_ids(&mut tx, &marc().id)
+        .await
+        .unwrap();
+    assert!(co_members.is_empty());
+}
+
+// -- takes_part_in_group
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn takes_part_in_group_true(pool: SqlitePool) {
+    let group_id = fixtures::groups::john_and_bill_shared_expenses().id;
+    let mut tx = pool.begin().await.unwrap();
+    let takes_part = database::queries::user::takes_part_in_group(&mut tx, &group_id, &bill().id)
+        .await
+        .unwrap();
+    assert!(takes_part);
+}
+
+#[sqlx::test(fixtures("users", "groups", "expense_entries"))]
+async fn takes_part_in_group_false(pool: SqlitePool) {
+    let group_id = fixtures::groups::trip_to_europe_2025().id;
+    let mut tx = pool.begin().await.unwrap();
+    let takes_part = database::queries::user::takes_part_in_group(&mut tx, &group_id, &bill().id)
+        .await
+        .unwrap();
+    assert!(!takes_part);
+}
+
+// -- claim_guest
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn claim_guest_ok(pool: SqlitePool) {
+    let group_id = fixtures::groups::trip_to_europe_2025().id;
+    let guest = TestUser::new_guest(Uuid::now_v7(), "Gabe", Utc::now());
+    let expense_entry = TestExpenseEntry::new_valid(
+        Uuid::now_v7(),
+        Uuid::now_v7(),
+        group_id.value(),
+        guest.id.value(),
+        HashSet::from_iter(vec![johndoe().id.value()]),
+        ExpenseEntryStatus::Active,
+        120,
+        guest.id.value(),
+        Utc::now(),
+        Utc::now(),
+    );
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::user::create(&mut tx, &guest)
+        .await
+        .unwrap();
+    database::queries::group::add_member(&mut tx, &group_id, &guest.id, &MemberRole::Member)
+        .await
+        .unwrap();
+    database::queries::expense_entry::create(&mut tx, &expense_entry)
+        .await
+        .unwrap();
+
+    let claimed = database::queries::user::claim_guest(&mut tx, &group_id, &guest.id, &marc().id)
+        .await
+        .unwrap();
+
+    assert!(claimed);
+    let maybe_guest = database::queries::user::get_by_id(&mut tx, &guest.id)
+        .await
+        .unwrap();
+    assert!(maybe_guest.is_none());
+    let expense_entry = database::queries::expense_entry::get_by_id(&mut tx, &expense_entry.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(marc().id, expense_entry.payer_id);
+    assert_eq!(marc().id, expense_entry.author_id);
+    assert!(expense_entry.payers.contains(&marc().id));
+    let group = database::queries::group::get_by_id(&mut tx, &group_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!group.contains_user(&guest.id));
+}
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn claim_guest_not_a_guest(pool: SqlitePool) {
+    let group_id = fixtures::groups::trip_to_europe_2025().id;
+    let mut tx = pool.begin().await.unwrap();
+    let claimed = database::queries::user::claim_guest(&mut tx, &group_id, &bill().id, &marc().id)
+        .await
+        .unwrap();
+    assert!(!claimed);
+    let maybe_user = database::queries::user::get_by_id(&mut tx, &bill().id)
+        .await
+        .unwrap();
+    assert_eq!(Some(bill()), maybe_user);
+}
+
+#[sqlx::test(fixtures("users", "groups"))]
+async fn claim_guest_of_several_groups(pool: SqlitePool) {
+    let group_id = fixtures::groups::trip_to_europe_2025().id;
+    let other_group_id = fixtures::groups::john_and_bill_shared_expenses().id;
+    let guest = TestUser::new_guest(Uuid::now_v7(), "Gabe", Utc::now());
+    let expense_entry = TestExpenseEntry::new_valid(
+        Uuid::now_v7(),
+        Uuid::now_v7(),
+        other_group_id.value(),
+        guest.id.value(),
+        HashSet::from_iter(vec![johndoe().id.value()]),
+        ExpenseEntryStatus::Active,
+        120,
+        johndoe().id.value(),
+        Utc::now(),
+        Utc::now(),
+    );
+    let mut tx = pool.begin().await.unwrap();
+    database::queries::user::create(&mut tx, &guest)
+        .await
+        .unwrap();
+    database::queries::group::add_member(&mut tx, &group_id, &guest.id, &MemberRole::Member)
+        .await
+        .unwrap();
+    database::queries::group::add_member(&mut tx, &other_group_id, &guest.id, &MemberRole::Member)
+        .await
+        .unwrap();
+    database::queries::expense_entry::create(&mut tx, &expense_entry)
+        .await
+        .unwrap();
+
+    let claimed = database::queries::user::claim_guest(&mut tx, &group_id, &guest.id, &marc().id)
+        .await
+        .unwrap();
+
+    assert!(!claimed);
+    let expense_entry = database::queries::expense_entry::get_by_id(&mut tx, &expense_entry.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(guest.id, expense_entry.payer_id);
+    let other_group = database::queries::group::get_by_id(&mut tx, &other_group_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(other_group.contains_user(&guest.id));
+}
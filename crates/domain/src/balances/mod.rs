@@ -0,0 +1,352 @@
+use std::collections::HashMap;
+
+pub use settlement_plan::Transfer;
+
+use crate::{
+    entities::{ExpenseEntry, Settlement},
+    types::{money::Money, user_id::UserId},
+};
+
+mod settlement_plan;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("balance is too large")]
+    Overflow,
+}
+
+/// Net balance of each user of a group.
+///
+/// A positive balance means the user is owed money by the group,
+/// a negative balance means the user owes money to the group.
+/// The sum of all balances is always zero.
+#[derive(Debug, Default, PartialEq)]
+pub struct GroupBalances {
+    balances: HashMap<UserId, Money>,
+}
+
+impl GroupBalances {
+    /// Creates balances where all provided users are settled up.
+    pub fn new(users: impl IntoIterator<Item = UserId>) -> Self {
+        Self {
+            balances: users.into_iter().map(|u| (u, Money::zero())).collect(),
+        }
+    }
+
+    /// Applies an expense entry to the balances, in the group currency.
+    /// Only active expense entries are taken into account, others are ignored.
+    pub fn add_expense_entry(&mut self, expense_entry: &ExpenseEntry) -> Result<(), Error> {
+        if !expense_entry.is_active() {
+            return Ok(());
+        }
+
+        for (user_id, paid) in expense_entry.paid_in_group_currency() {
+            self.credit(user_id, paid)?;
+        }
+        for (user_id, share) in expense_entry.shares_in_group_currency() {
+            self.debit(user_id, share)?;
+        }
+        Ok(())
+    }
+
+    /// Applies a settlement to the balances.
+    /// Cancelled settlements are ignored.
+    pub fn add_settlement(&mut self, settlement: &Settlement) -> Result<(), Error> {
+        if !settlement.is_active() {
+            return Ok(());
+        }
+
+        self.credit(settlement.from, settlement.amount)?;
+        self.debit(settlement.to, settlement.amount)
+    }
+
+    pub fn balance_of(&self, user_id: &UserId) -> Money {
+        self.balances.get(user_id).copied().unwrap_or_default()
+    }
+
+    /// Returns all balances, sorted by user id.
+    pub fn to_sorted_vec(&self) -> Vec<(UserId, Money)> {
+        let mut balances: Vec<(UserId, Money)> =
+            self.balances.iter().map(|(u, m)| (*u, *m)).collect();
+        balances.sort_by_key(|(user_id, _)| *user_id);
+        balances
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.balances.values().all(Money::is_zero)
+    }
+
+    fn credit(&mut self, user_id: UserId, amount: Money) -> Result<(), Error> {
+        let balance = self.balances.entry(user_id).or_default();
+        *balance = balance.checked_add(amount).ok_or(Error::Overflow)?;
+        Ok(())
+    }
+
+    fn debit(&mut self, user_id: UserId, amount: Money) -> Result<(), Error> {
+        let balance = self.balances.entry(user_id).or_default();
+        *balance = balance.checked_sub(amount).ok_or(Error::Overflow)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use super::{Error, GroupBalances};
+    use crate::{
+        entities::{ExpenseEntry, Settlement},
+        testutils::{expense_entry::TestExpenseEntry, settlement::TestSettlement},
+        types::{
+            expense_entry_id::ExpenseEntryId, expense_entry_status::ExpenseEntryStatus,
+            money::Money, settlement_status::SettlementStatus, user_id::UserId,
+        },
+    };
+
+    fn expense_entry(
+        payer: UserId,
+        participants: Vec<UserId>,
+        total_euros: i64,
+        status: ExpenseEntryStatus,
+    ) -> ExpenseEntry {
+        TestExpenseEntry::new_valid(
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            payer.value(),
+            participants
+                .into_iter()
+                .map(|p| p.value())
+                .collect::<HashSet<_>>(),
+            status,
+            total_euros,
+            payer.value(),
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    fn settlement(
+        from: UserId,
+        to: UserId,
+        amount_cents: i64,
+        status: SettlementStatus,
+    ) -> Settlement {
+        TestSettlement::new_valid(
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            from.value(),
+            to.value(),
+            amount_cents,
+            status,
+            from.value(),
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    fn sorted_users(count: usize) -> Vec<UserId> {
+        let mut users: Vec<UserId> = (0..count).map(|_| UserId::new_random()).collect();
+        users.sort();
+        users
+    }
+
+    #[test]
+    fn new_is_settled() {
+        let balances = GroupBalances::new(sorted_users(3));
+        assert!(balances.is_settled());
+        assert_eq!(3, balances.to_sorted_vec().len());
+    }
+
+    #[test]
+    fn payer_is_part_of_the_split() {
+        let users = sorted_users(2);
+        let mut balances = GroupBalances::new(users.clone());
+
+        balances
+            .add_expense_entry(&expense_entry(
+                users[0],
+                vec![users[1]],
+                100,
+                ExpenseEntryStatus::Active,
+            ))
+            .unwrap();
+
+        assert_eq!(Money::from_euros(50), balances.balance_of(&users[0]));
+        assert_eq!(Money::from_euros(-50), balances.balance_of(&users[1]));
+    }
+
+    #[test]
+    fn payer_alone_has_no_impact() {
+        let users = sorted_users(2);
+        let mut balances = GroupBalances::new(users.clone());
+
+        balances
+            .add_expense_entry(&expense_entry(
+                users[0],
+                vec![],
+                100,
+                ExpenseEntryStatus::Active,
+            ))
+            .unwrap();
+
+        assert!(balances.is_settled());
+    }
+
+    #[test]
+    fn remainder_cents_are_deterministic_and_sum_to_zero() {
+        let users = sorted_users(3);
+        let mut balances = GroupBalances::new(users.clone());
+
+        // 10 euros split in 3: 3.34 + 3.33 + 3.33
+        balances
+            .add_expense_entry(&expense_entry(
+                users[2],
+                vec![users[0], users[1]],
+                10,
+                ExpenseEntryStatus::Active,
+            ))
+            .unwrap();
+
+        assert_eq!(Money::from_cents(-334), balances.balance_of(&users[0]));
+        assert_eq!(Money::from_cents(-333), balances.balance_of(&users[1]));
+        assert_eq!(Money::from_cents(667), balances.balance_of(&users[2]));
+        let sum: Money = balances.to_sorted_vec().into_iter().map(|(_, m)| m).sum();
+        assert!(sum.is_zero());
+    }
+
+    #[test]
+    fn inactive_entries_are_ignored() {
+        let users = sorted_users(2);
+        let mut balances = GroupBalances::new(users.clone());
+
+        balances
+            .add_expense_entry(&expense_entry(
+                users[0],
+                vec![users[1]],
+                100,
+                ExpenseEntryStatus::Inactive {
+                    overwritten_by: ExpenseEntryId::new_random(),
+                },
+            ))
+            .unwrap();
+
+        assert!(balances.is_settled());
+    }
+
+    #[test]
+    fn deleted_entries_are_ignored() {
+        let users = sorted_users(2);
+        let mut balances = GroupBalances::new(users.clone());
+
+        balances
+            .add_expense_entry(&expense_entry(
+                users[0],
+                vec![users[1]],
+                100,
+                ExpenseEntryStatus::Deleted {
+                    deleted_by: users[0],
+                    deleted_at: Utc::now(),
+                },
+            ))
+            .unwrap();
+
+        assert!(balances.is_settled());
+    }
+
+    #[test]
+    fn several_expenses_cancel_each_other() {
+        let users = sorted_users(2);
+        let mut balances = GroupBalances::new(users.clone());
+
+        balances
+            .add_expense_entry(&expense_entry(
+                users[0],
+                vec![users[1]],
+                30,
+                ExpenseEntryStatus::Active,
+            ))
+            .unwrap();
+        balances
+            .add_expense_entry(&expense_entry(
+                users[1],
+                vec![users[0]],
+                30,
+                ExpenseEntryStatus::Active,
+            ))
+            .unwrap();
+
+        assert!(balances.is_settled());
+    }
+
+    #[test]
+    fn settlement_pays_debt_back() {
+        let users = sorted_users(2);
+        let mut balances = GroupBalances::new(users.clone());
+
+        balances
+            .add_expense_entry(&expense_entry(
+                users[0],
+                vec![users[1]],
+                100,
+                ExpenseEntryStatus::Active,
+            ))
+            .unwrap();
+        balances
+            .add_settlement(&settlement(
+                users[1],
+                users[0],
+                2_000,
+                SettlementStatus::Active,
+            ))
+            .unwrap();
+
+        assert_eq!(Money::from_euros(30), balances.balance_of(&users[0]));
+        assert_eq!(Money::from_euros(-30), balances.balance_of(&users[1]));
+
+        balances
+            .add_settlement(&settlement(
+                users[1],
+                users[0],
+                3_000,
+                SettlementStatus::Active,
+            ))
+            .unwrap();
+        assert!(balances.is_settled());
+    }
+
+    #[test]
+    fn cancelled_settlements_are_ignored() {
+        let users = sorted_users(2);
+        let mut balances = GroupBalances::new(users.clone());
+
+        balances
+            .add_settlement(&settlement(
+                users[1],
+                users[0],
+                2_000,
+                SettlementStatus::Cancelled {
+                    cancelled_by: users[0],
+                    cancelled_at: Utc::now(),
+                },
+            ))
+            .unwrap();
+
+        assert!(balances.is_settled());
+    }
+
+    #[test]
+    fn overflowing_balances_are_rejected() {
+        let users = sorted_users(2);
+        let mut balances = GroupBalances::new(users.clone());
+        let settle =
+            |amount_cents| settlement(users[1], users[0], amount_cents, SettlementStatus::Active);
+
+        balances.add_settlement(&settle(i64::MAX)).unwrap();
+
+        assert_eq!(Err(Error::Overflow), balances.add_settlement(&settle(1)));
+    }
+}
@@ -0,0 +1,292 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::{
+    balances::GroupBalances,
+    types::{money::Money, user_id::UserId},
+};
+
+/// Maximum number of unsettled users for which the exact solver is used.
+/// Above this limit, the plan is computed using the greedy algorithm only.
+const MAX_USERS_FOR_EXACT_SOLVER: usize = 12;
+
+/// A single payment to make in order to settle up a group.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transfer {
+    pub from: UserId,
+    pub to: UserId,
+    pub amount: Money,
+}
+
+impl GroupBalances {
+    /// Returns a list of transfers that settles up every balance.
+    ///
+    /// For small groups, the plan uses the minimal number of transfers:
+    /// unsettled users are partitioned into as many zero-sum subsets as
+    /// possible, and each subset is settled independently.
+    /// For bigger groups, creditors and debtors are greedily matched,
+    /// which uses at most `n - 1` transfers.
+    ///
+    /// The plan is deterministic: ties are broken by user id, and transfers
+    /// are sorted by amount (DESC), then payer and receiver ids.
+    pub fn settlement_plan(&self) -> Vec<Transfer> {
+        let unsettled: Vec<(UserId, Money)> = self
+            .to_sorted_vec()
+            .into_iter()
+            .filter(|(_, balance)| !balance.is_zero())
+            .collect();
+
+        let mut transfers = if unsettled.len() <= MAX_USERS_FOR_EXACT_SOLVER {
+            zero_sum_subsets(&unsettled)
+                .iter()
+                .flat_map(|subset| greedy(subset))
+                .collect()
+        } else {
+            greedy(&unsettled)
+        };
+
+        transfers.sort_by(|t1, t2| {
+            t2.amount
+                .cmp(&t1.amount)
+                .then(t1.from.cmp(&t2.from))
+                .then(t1.to.cmp(&t2.to))
+        });
+        transfers
+    }
+}
+
+/// Matches the biggest creditor with the biggest debtor until everyone is settled.
+/// `balances` must sum up to zero.
+fn greedy(balances: &[(UserId, Money)]) -> Vec<Transfer> {
+    let mut creditors: BinaryHeap<(Money, Reverse<UserId>)> = BinaryHeap::new();
+    let mut debtors: BinaryHeap<(Money, Reverse<UserId>)> = BinaryHeap::new();
+    for (user_id, balance) in balances {
+        if balance.is_positive() {
+            creditors.push((*balance, Reverse(*user_id)));
+        } else if balance.is_negative() {
+            debtors.push((balance.abs(), Reverse(*user_id)));
+        }
+    }
+
+    let mut transfers = Vec::new();
+    while let (Some((credit, Reverse(creditor))), Some((debt, Reverse(debtor)))) =
+        (creditors.pop(), debtors.pop())
+    {
+        let amount = credit.min(debt);
+        transfers.push(Transfer {
+            from: debtor,
+            to: creditor,
+            amount,
+        });
+
+        if credit > amount {
+            creditors.push((credit - amount, Reverse(creditor)));
+        }
+        if debt > amount {
+            debtors.push((debt - amount, Reverse(debtor)));
+        }
+    }
+    transfers
+}
+
+/// Partitions `balances` into the maximum number of subsets summing up to zero.
+///
+/// Settling a subset of `k` users requires at most `k - 1` transfers, so
+/// maximizing the number of subsets minimizes the number of transfers.
+/// Complexity is `O(2^n * n)`, only use it for small inputs.
+fn zero_sum_subsets(balances: &[(UserId, Money)]) -> Vec<Vec<(UserId, Money)>> {
+    let n = balances.len();
+    if n == 0 {
+        return vec![];
+    }
+    let full = (1usize << n) - 1;
+
+    let mut sums = vec![Money::zero(); full + 1];
+    for mask in 1..=full {
+        let lowest = mask.trailing_zeros() as usize;
+        sums[mask] = sums[mask & (mask - 1)] + balances[lowest].1;
+    }
+
+    // dp[mask] = maximum number of zero-sum subsets found while removing
+    // users one by one from mask
+    let mut dp = vec![0usize; full + 1];
+    for mask in 1..=full {
+        let best = (0..n)
+            .filter(|i| mask & (1 << i) != 0)
+            .map(|i| dp[mask & !(1 << i)])
+            .max()
+            .unwrap_or(0);
+        dp[mask] = best + usize::from(sums[mask].is_zero());
+    }
+
+    // walk back from the full set; every zero-sum mask met on the way closes a subset
+    let mut subsets = Vec::new();
+    let mut boundary = full;
+    let mut mask = full;
+    while mask != 0 {
+        if sums[mask].is_zero() && mask != boundary {
+            subsets.push(boundary & !mask);
+            boundary = mask;
+        }
+        let expected = dp[mask] - usize::from(sums[mask].is_zero());
+        let i = (0..n)
+            .find(|i| mask & (1 << i) != 0 && dp[mask & !(1 << i)] == expected)
+            .expect("dp is consistent");
+        mask &= !(1 << i);
+    }
+    subsets.push(boundary);
+
+    subsets
+        .into_iter()
+        .map(|subset| {
+            (0..n)
+                .filter(|i| subset & (1 << i) != 0)
+                .map(|i| balances[i])
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{Transfer, greedy};
+    use crate::{
+        balances::GroupBalances,
+        types::{money::Money, user_id::UserId},
+    };
+
+    fn sorted_users(count: usize) -> Vec<UserId> {
+        let mut users: Vec<UserId> = (0..count).map(|_| UserId::new_random()).collect();
+        users.sort();
+        users
+    }
+
+    fn balances(users: &[UserId], cents: &[i64]) -> GroupBalances {
+        GroupBalances {
+            balances: users
+                .iter()
+                .zip(cents)
+                .map(|(u, c)| (*u, Money::from_cents(*c)))
+                .collect(),
+        }
+    }
+
+    fn assert_settles(balances: &GroupBalances, transfers: &[Transfer]) {
+        let mut remaining: HashMap<UserId, Money> = balances.to_sorted_vec().into_iter().collect();
+        for transfer in transfers {
+            assert!(transfer.amount.is_positive());
+            *remaining.get_mut(&transfer.from).unwrap() += transfer.amount;
+            *remaining.get_mut(&transfer.to).unwrap() -= transfer.amount;
+        }
+        assert!(remaining.values().all(Money::is_zero));
+    }
+
+    #[test]
+    fn settled_group_has_no_transfer() {
+        let users = sorted_users(3);
+        assert!(balances(&users, &[0, 0, 0]).settlement_plan().is_empty());
+    }
+
+    #[test]
+    fn single_debt() {
+        let users = sorted_users(2);
+        let balances = balances(&users, &[-2_340, 2_340]);
+        assert_eq!(
+            vec![Transfer {
+                from: users[0],
+                to: users[1],
+                amount: Money::from_cents(2_340),
+            }],
+            balances.settlement_plan()
+        );
+    }
+
+    #[test]
+    fn one_creditor_several_debtors() {
+        let users = sorted_users(4);
+        let balances = balances(&users, &[3_000, -1_000, -1_500, -500]);
+        let plan = balances.settlement_plan();
+        assert_eq!(3, plan.len());
+        assert_settles(&balances, &plan);
+        assert_eq!(
+            Transfer {
+                from: users[2],
+                to: users[0],
+                amount: Money::from_cents(1_500),
+            },
+            plan[0]
+        );
+    }
+
+    #[test]
+    fn exact_solver_beats_greedy() {
+        let users = sorted_users(6);
+        // (a: 5, d: -5), (b: 3, c: 4, e: -6, f: -1)
+        let balances = balances(&users, &[500, 300, 400, -500, -600, -100]);
+
+        let plan = balances.settlement_plan();
+
+        assert_settles(&balances, &plan);
+        assert_eq!(4, plan.len());
+        assert!(plan.contains(&Transfer {
+            from: users[3],
+            to: users[0],
+            amount: Money::from_cents(500),
+        }));
+    }
+
+    #[test]
+    fn greedy_settles_everyone() {
+        let users = sorted_users(6);
+        let balances = balances(&users, &[500, 300, 400, -500, -600, -100]);
+        let plan = greedy(&balances.to_sorted_vec());
+        assert_settles(&balances, &plan);
+        assert!(plan.len() <= 5);
+    }
+
+    #[test]
+    fn ties_are_broken_by_user_id() {
+        let users = sorted_users(4);
+        let balances = balances(&users, &[1_000, 1_000, -1_000, -1_000]);
+        assert_eq!(
+            vec![
+                Transfer {
+                    from: users[2],
+                    to: users[0],
+                    amount: Money::from_cents(1_000),
+                },
+                Transfer {
+                    from: users[3],
+                    to: users[1],
+                    amount: Money::from_cents(1_000),
+                },
+            ],
+            balances.settlement_plan()
+        );
+    }
+
+    #[test]
+    fn plan_is_deterministic() {
+        let users = sorted_users(7);
+        let balances = balances(&users, &[1_234, -333, -333, -334, 2_000, -1_000, -1_234]);
+        let plan = balances.settlement_plan();
+        assert_settles(&balances, &plan);
+        for _ in 0..10 {
+            assert_eq!(plan, balances.settlement_plan());
+        }
+    }
+
+    #[test]
+    fn big_group_uses_greedy() {
+        let users = sorted_users(20);
+        let mut cents: Vec<i64> = (1..20).map(|i| -i * 100).collect();
+        cents.push((1..20).map(|i| i * 100).sum());
+        let balances = balances(&users, &cents);
+
+        let plan = balances.settlement_plan();
+
+        assert_settles(&balances, &plan);
+        assert_eq!(19, plan.len());
+    }
+}
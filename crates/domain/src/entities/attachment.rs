@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+
+use crate::types::{
+    attachment_content_type::AttachmentContentType, attachment_file_name::AttachmentFileName,
+    attachment_id::AttachmentId, expense_id::ExpenseId, group_id::GroupId, user_id::UserId,
+};
+
+/// File attached to an expense, such as a photo of the receipt.
+///
+/// Attachments are linked to the expense itself rather than to one of its
+/// entries, so that they are kept when a new version of the expense is
+/// created. Their content is stored outside of the database.
+#[allow(clippy::too_many_arguments)]
+#[derive(derive_new::new, Debug, Clone, PartialEq)]
+pub struct Attachment {
+    pub id: AttachmentId,
+
+    /// Expense to which this file is attached.
+    pub expense_id: ExpenseId,
+
+    /// Group of the expense.
+    pub group_id: GroupId,
+
+    pub file_name: AttachmentFileName,
+
+    pub content_type: AttachmentContentType,
+
+    /// Size of the file content, in bytes.
+    pub size_bytes: u64,
+
+    /// User who uploaded the file.
+    pub uploaded_by: UserId,
+
+    /// System time at which the file was uploaded.
+    pub uploaded_at: DateTime<Utc>,
+}
@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+
+use crate::types::{
+    budget_id::BudgetId, budget_period::BudgetPeriod, budget_threshold::BudgetThreshold,
+    category_id::CategoryId, expense_id::ExpenseId, group_id::GroupId, money::Money,
+};
+
+/// Spending limit of a group over a period, on all its expenses or on the
+/// ones of a custom category.
+#[derive(derive_new::new, Debug, Clone, PartialEq)]
+pub struct Budget {
+    pub id: BudgetId,
+
+    /// Group to which this budget belongs.
+    pub group_id: GroupId,
+
+    /// Custom category whose expenses consume this budget,
+    /// `None` for a budget on every expense of the group.
+    pub category_id: Option<CategoryId>,
+
+    /// Limit in the group currency.
+    pub amount: Money,
+
+    pub period: BudgetPeriod,
+
+    /// System time at which this budget was created.
+    pub created_at: DateTime<Utc>,
+}
+
+/// Record of a budget reaching a threshold during one of its periods.
+#[derive(derive_new::new, Debug, Clone, PartialEq)]
+pub struct BudgetAlert {
+    pub budget_id: BudgetId,
+
+    pub threshold: BudgetThreshold,
+
+    /// Start of the period during which the threshold was reached.
+    pub period_starts_at: DateTime<Utc>,
+
+    /// Expense whose creation reached the threshold.
+    pub expense_id: ExpenseId,
+
+    /// Consumption of the budget once the threshold was reached.
+    pub consumed: Money,
+
+    /// System time at which this alert was raised.
+    pub created_at: DateTime<Utc>,
+}
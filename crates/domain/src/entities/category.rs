@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+
+use crate::types::{
+    category_color::CategoryColor, category_emoji::CategoryEmoji, category_id::CategoryId,
+    category_name::CategoryName, group_id::GroupId,
+};
+
+/// Custom category defined by a group to classify its expenses,
+/// on top of the built-in [ExpenseCategory](crate::types::expense_category::ExpenseCategory).
+#[derive(derive_new::new, Debug, Clone, PartialEq)]
+pub struct Category {
+    pub id: CategoryId,
+
+    /// Group to which this category belongs.
+    pub group_id: GroupId,
+
+    /// Name of the category, unique within the group.
+    pub name: CategoryName,
+
+    pub color: CategoryColor,
+
+    pub emoji: CategoryEmoji,
+
+    /// System time at which this category was created.
+    pub created_at: DateTime<Utc>,
+}
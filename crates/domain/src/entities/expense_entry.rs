@@ -1,10 +1,13 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use chrono::{DateTime, Utc};
 
 use crate::types::{
-    expense_entry_id::ExpenseEntryId, expense_entry_status::ExpenseEntryStatus,
-    expense_id::ExpenseId, group_id::GroupId, money::Money, user_id::UserId,
+    category_id::CategoryId, currency::Currency, exchange_rate::ExchangeRate,
+    expense_category::ExpenseCategory, expense_entry_id::ExpenseEntryId,
+    expense_entry_status::ExpenseEntryStatus, expense_id::ExpenseId, expense_note::ExpenseNote,
+    expense_payers::ExpensePayers, expense_split::ExpenseSplit, expense_title::ExpenseTitle,
+    group_id::GroupId, money::Money, user_id::UserId,
 };
 
 /// Represents a versioned snapshot of an [Expense](crate::entities::expense::Expense).
@@ -14,8 +17,9 @@ use crate::types::{
 /// results in the creation of a new ExpenseEntry with a new `id`,
 /// while sharing the same `expense_id`.
 ///
-/// At any time, exactly one ExpenseEntry per `expense_id` must be
-/// marked as Active. Older versions are kept for audit and history.
+/// At any time, at most one ExpenseEntry per `expense_id` can be
+/// marked as Active; there is none only if the expense has been deleted.
+/// Older versions are kept for audit and history.
 #[derive(Debug, PartialEq)]
 pub struct ExpenseEntry {
     /// Unique identifier for this specific version of the expense.
@@ -29,20 +33,52 @@ pub struct ExpenseEntry {
     /// Group to which this expense belongs.
     pub group_id: GroupId,
 
-    /// User who actually paid the expense in real life.
+    /// What the expense was about.
+    pub title: ExpenseTitle,
+
+    /// Optional free-text details about the expense.
+    pub note: Option<ExpenseNote>,
+
+    pub category: ExpenseCategory,
+
+    /// Optional custom category of the group, see [Category](crate::entities::Category).
+    pub category_id: Option<CategoryId>,
+
+    /// User who actually paid the expense in real life; if several users
+    /// paid, the main one among them.
     pub payer_id: UserId,
 
+    /// Users who paid the expense, with the amount each of them paid in
+    /// `currency`. Always includes `payer_id`.
+    pub payers: ExpensePayers,
+
     /// Users participating in this expense.
+    /// The payer is never part of this set: with an equal split they
+    /// implicitly participate, otherwise the split tells whether they bear
+    /// a part of the expense (see [ExpenseEntry::shares]).
     pub participants: HashSet<UserId>,
 
+    /// How the total is split between the users involved.
+    /// For non-equal splits, participants are exactly the users listed
+    /// in the split, except the payer.
+    pub split: ExpenseSplit,
+
     /// Status of this expense entry.
-    /// Exactly one entry per expense_id must be Active at any time.
+    /// At most one entry per expense_id can be Active at any time.
     pub status: ExpenseEntryStatus,
 
-    /// Total amount of the expense.
+    /// Total amount of the expense, in `currency`.
     /// Must be strictly greater than zero.
     pub total: Money,
 
+    /// Currency in which the expense was paid.
+    pub currency: Currency,
+
+    /// Rate from `currency` to the group currency, captured when the
+    /// expense entry was created. Identity if the expense was paid in the
+    /// group currency.
+    pub exchange_rate: ExchangeRate,
+
     /// User who created this version of the expense entry.
     /// Used for audit trail and permission checks.
     pub author_id: UserId,
@@ -59,6 +95,21 @@ pub struct ExpenseEntry {
 pub enum Error {
     #[error("total must be > 0")]
     NegativeTotal,
+
+    #[error("invalid split: {0}")]
+    InvalidSplit(#[from] crate::types::expense_split::Error),
+
+    #[error("participants do not match the users listed in the split")]
+    SplitParticipantsMismatch,
+
+    #[error("invalid payers: {0}")]
+    InvalidPayers(#[from] crate::types::expense_payers::Error),
+
+    #[error("payer must be one of the payers")]
+    PayerNotInPayers,
+
+    #[error("total is too large once converted to the group currency")]
+    ConvertedTotalOverflow,
 }
 
 impl ExpenseEntry {
@@ -67,10 +118,18 @@ impl ExpenseEntry {
         id: ExpenseEntryId,
         expense_id: ExpenseId,
         group_id: GroupId,
+        title: ExpenseTitle,
+        note: Option<ExpenseNote>,
+        category: ExpenseCategory,
+        category_id: Option<CategoryId>,
         payer_id: UserId,
+        payers: ExpensePayers,
         participants: HashSet<UserId>,
+        split: ExpenseSplit,
         status: ExpenseEntryStatus,
         total: Money,
+        currency: Currency,
+        exchange_rate: ExchangeRate,
         author_id: UserId,
         occurred_at: DateTime<Utc>,
         created_at: DateTime<Utc>,
@@ -78,18 +137,647 @@ impl ExpenseEntry {
         if total.is_negative() {
             return Err(Error::NegativeTotal);
         }
+        if exchange_rate.convert(total).is_none() {
+            return Err(Error::ConvertedTotalOverflow);
+        }
+
+        split.validate(total)?;
+        payers.validate(total)?;
+        if !payers.contains(&payer_id) {
+            return Err(Error::PayerNotInPayers);
+        }
+        if let Some(mut users) = split.users() {
+            users.remove(&payer_id);
+            if users != participants {
+                return Err(Error::SplitParticipantsMismatch);
+            }
+        }
 
         Ok(Self {
             id,
             expense_id,
             group_id,
+            title,
+            note,
+            category,
+            category_id,
             payer_id,
+            payers,
             participants,
+            split,
             status,
             total,
+            currency,
+            exchange_rate,
             author_id,
             occurred_at,
             created_at,
         })
     }
+
+    pub fn is_active(&self) -> bool {
+        self.status == ExpenseEntryStatus::Active
+    }
+
+    /// Returns the total converted to the group currency.
+    pub fn total_in_group_currency(&self) -> Money {
+        self.exchange_rate
+            .convert(self.total)
+            .expect("checked on creation")
+    }
+
+    /// Returns how much each payer paid, in the group currency.
+    ///
+    /// Amounts paid in the expense currency are used as weights to split the
+    /// converted total, so the result always sums up exactly to
+    /// [ExpenseEntry::total_in_group_currency].
+    pub fn paid_in_group_currency(&self) -> HashMap<UserId, Money> {
+        if self.exchange_rate.is_identity() {
+            return self.payers.amounts().clone();
+        }
+
+        let weights = self
+            .payers
+            .amounts()
+            .iter()
+            .map(|(user_id, amount)| (*user_id, amount.cents().unsigned_abs()))
+            .collect();
+        allocate(self.total_in_group_currency(), &weights)
+    }
+
+    /// Returns how much each user involved in this expense has to bear,
+    /// in the group currency.
+    ///
+    /// Shares in the expense currency (see [ExpenseEntry::shares]) are used as
+    /// weights to split the converted total, so the result always sums up
+    /// exactly to [ExpenseEntry::total_in_group_currency].
+    pub fn shares_in_group_currency(&self) -> HashMap<UserId, Money> {
+        let shares = self.shares();
+        if self.exchange_rate.is_identity() {
+            return shares;
+        }
+
+        let weights = shares
+            .into_iter()
+            .map(|(user_id, share)| (user_id, share.cents().unsigned_abs()))
+            .collect();
+        allocate(self.total_in_group_currency(), &weights)
+    }
+
+    /// Returns how much each user involved in this expense has to bear,
+    /// in the expense currency.
+    ///
+    /// With an equal split, the total is split equally between the payer and
+    /// the participants. Shares and percentages split the total proportionally.
+    /// Remaining cents are given to users sorted by id, so the result is
+    /// deterministic and always sums up exactly to `total`.
+    pub fn shares(&self) -> HashMap<UserId, Money> {
+        match &self.split {
+            ExpenseSplit::Equal => {
+                let weights = self
+                    .participants
+                    .iter()
+                    .chain(std::iter::once(&self.payer_id))
+                    .map(|user_id| (*user_id, 1))
+                    .collect();
+                allocate(self.total, &weights)
+            }
+            ExpenseSplit::Shares { shares } => allocate(self.total, shares),
+            ExpenseSplit::Percentages { percentages } => allocate(self.total, percentages),
+            ExpenseSplit::Exact { amounts } => amounts.clone(),
+        }
+    }
+
+    /// Returns the changes between `previous` and this version of the expense.
+    pub fn diff_from(&self, previous: &ExpenseEntry) -> ExpenseEntryDiff {
+        let mut participants_added: Vec<UserId> = self
+            .participants
+            .difference(&previous.participants)
+            .copied()
+            .collect();
+        participants_added.sort();
+
+        let mut participants_removed: Vec<UserId> = previous
+            .participants
+            .difference(&self.participants)
+            .copied()
+            .collect();
+        participants_removed.sort();
+
+        ExpenseEntryDiff {
+            title: Change::between(previous.title.clone(), self.title.clone()),
+            note: Change::between(previous.note.clone(), self.note.clone()),
+            category: Change::between(previous.category, self.category),
+            category_id: Change::between(previous.category_id, self.category_id),
+            total: Change::between(previous.total, self.total),
+            currency: Change::between(previous.currency, self.currency),
+            exchange_rate: Change::between(previous.exchange_rate, self.exchange_rate),
+            payer: Change::between(previous.payer_id, self.payer_id),
+            payers: Change::between(previous.payers.clone(), self.payers.clone()),
+            split: Change::between(previous.split.clone(), self.split.clone()),
+            participants_added,
+            participants_removed,
+            occurred_at: Change::between(previous.occurred_at, self.occurred_at),
+        }
+    }
+}
+
+/// Splits `total` proportionally to `weights`, giving remaining cents to
+/// users sorted by id.
+fn allocate(total: Money, weights: &HashMap<UserId, u64>) -> HashMap<UserId, Money> {
+    let mut users: Vec<(UserId, u64)> = weights.iter().map(|(u, w)| (*u, *w)).collect();
+    users.sort();
+
+    let weights: Vec<u64> = users.iter().map(|(_, w)| *w).collect();
+    users
+        .into_iter()
+        .map(|(user_id, _)| user_id)
+        .zip(total.allocate(&weights))
+        .collect()
+}
+
+/// Changes between two consecutive versions of an expense.
+/// Participants are sorted by user id.
+#[derive(Debug, PartialEq)]
+pub struct ExpenseEntryDiff {
+    pub title: Option<Change<ExpenseTitle>>,
+    pub note: Option<Change<Option<ExpenseNote>>>,
+    pub category: Option<Change<ExpenseCategory>>,
+    pub category_id: Option<Change<Option<CategoryId>>>,
+    pub total: Option<Change<Money>>,
+    pub currency: Option<Change<Currency>>,
+    pub exchange_rate: Option<Change<ExchangeRate>>,
+    pub payer: Option<Change<UserId>>,
+    pub payers: Option<Change<ExpensePayers>>,
+    pub split: Option<Change<ExpenseSplit>>,
+    pub participants_added: Vec<UserId>,
+    pub participants_removed: Vec<UserId>,
+    pub occurred_at: Option<Change<DateTime<Utc>>>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Change<T> {
+    pub before: T,
+    pub after: T,
+}
+
+impl<T: PartialEq> Change<T> {
+    /// Returns `None` if the value did not change.
+    fn between(before: T, after: T) -> Option<Self> {
+        if before == after {
+            return None;
+        }
+        Some(Self { before, after })
+    }
+}
+
+impl ExpenseEntryDiff {
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none()
+            && self.note.is_none()
+            && self.category.is_none()
+            && self.category_id.is_none()
+            && self.total.is_none()
+            && self.currency.is_none()
+            && self.exchange_rate.is_none()
+            && self.payer.is_none()
+            && self.payers.is_none()
+            && self.split.is_none()
+            && self.participants_added.is_empty()
+            && self.participants_removed.is_empty()
+            && self.occurred_at.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use chrono::{DateTime, Duration, Utc};
+    use uuid::Uuid;
+
+    use super::{Change, Error, ExpenseEntry};
+    use crate::{
+        testutils::expense_entry::TestExpenseEntry,
+        types::{
+            currency::Currency, exchange_rate::ExchangeRate, expense_category::ExpenseCategory,
+            expense_entry_id::ExpenseEntryId, expense_entry_status::ExpenseEntryStatus,
+            expense_id::ExpenseId, expense_payers::ExpensePayers, expense_split::ExpenseSplit,
+            group_id::GroupId, money::Money, user_id::UserId,
+        },
+    };
+
+    fn sorted_users(count: usize) -> Vec<UserId> {
+        let mut users: Vec<UserId> = (0..count).map(|_| UserId::new_random()).collect();
+        users.sort();
+        users
+    }
+
+    fn expense_entry(
+        payer: UserId,
+        participants: &[UserId],
+        total_euros: i64,
+        occurred_at: DateTime<Utc>,
+    ) -> ExpenseEntry {
+        TestExpenseEntry::new_valid(
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            Uuid::now_v7(),
+            payer.value(),
+            participants
+                .iter()
+                .map(|p| p.value())
+                .collect::<HashSet<_>>(),
+            ExpenseEntryStatus::Active,
+            total_euros,
+            payer.value(),
+            occurred_at,
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn diff_no_change() {
+        let users = sorted_users(2);
+        let now = Utc::now();
+        let previous = expense_entry(users[0], &[users[1]], 10, now);
+        let current = expense_entry(users[0], &[users[1]], 10, now);
+
+        assert!(current.diff_from(&previous).is_empty());
+    }
+
+    #[test]
+    fn diff_every_field() {
+        let users = sorted_users(4);
+        let now = Utc::now();
+        let previous = expense_entry(users[0], &[users[1], users[2]], 10, now);
+        let current = expense_entry(users[1], &[users[2], users[3]], 12, now + Duration::days(1));
+
+        let diff = current.diff_from(&previous);
+
+        assert_eq!(
+            Some(Change {
+                before: Money::from_euros(10),
+                after: Money::from_euros(12),
+            }),
+            diff.total
+        );
+        assert_eq!(
+            Some(Change {
+                before: users[0],
+                after: users[1],
+            }),
+            diff.payer
+        );
+        assert_eq!(vec![users[3]], diff.participants_added);
+        assert_eq!(vec![users[1]], diff.participants_removed);
+        assert_eq!(
+            Some(Change {
+                before: now,
+                after: now + Duration::days(1),
+            }),
+            diff.occurred_at
+        );
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn diff_metadata() {
+        let users = sorted_users(2);
+        let now = Utc::now();
+        let previous = expense_entry(users[0], &[users[1]], 10, now);
+        let mut current = expense_entry(users[0], &[users[1]], 10, now);
+        current.title = "Dinner".parse().unwrap();
+        current.note = Some("Tip included".parse().unwrap());
+        current.category = ExpenseCategory::Food;
+
+        let diff = current.diff_from(&previous);
+
+        assert_eq!(
+            Some(Change {
+                before: previous.title.clone(),
+                after: current.title.clone(),
+            }),
+            diff.title
+        );
+        assert_eq!(
+            Some(Change {
+                before: None,
+                after: current.note.clone(),
+            }),
+            diff.note
+        );
+        assert_eq!(
+            Some(Change {
+                before: ExpenseCategory::Other,
+                after: ExpenseCategory::Food,
+            }),
+            diff.category
+        );
+        assert!(diff.total.is_none());
+    }
+
+    #[test]
+    fn diff_split_only() {
+        let users = sorted_users(2);
+        let now = Utc::now();
+        let previous = expense_entry(users[0], &[users[1]], 10, now);
+        let mut current = expense_entry(users[0], &[users[1]], 10, now);
+        current.split = ExpenseSplit::Shares {
+            shares: HashMap::from_iter(vec![(users[0], 1), (users[1], 2)]),
+        };
+
+        let diff = current.diff_from(&previous);
+
+        assert_eq!(
+            Some(Change {
+                before: ExpenseSplit::Equal,
+                after: current.split.clone(),
+            }),
+            diff.split
+        );
+        assert!(diff.participants_added.is_empty());
+        assert!(diff.participants_removed.is_empty());
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn diff_participants_are_sorted() {
+        let users = sorted_users(4);
+        let now = Utc::now();
+        let previous = expense_entry(users[0], &[], 10, now);
+        let current = expense_entry(users[0], &[users[3], users[1], users[2]], 10, now);
+
+        let diff = current.diff_from(&previous);
+
+        assert_eq!(vec![users[1], users[2], users[3]], diff.participants_added);
+        assert!(diff.participants_removed.is_empty());
+    }
+
+    fn expense_entry_with_split(
+        payer: UserId,
+        participants: &[UserId],
+        split: ExpenseSplit,
+        total_cents: i64,
+    ) -> Result<ExpenseEntry, Error> {
+        expense_entry_in_currency(
+            payer,
+            participants,
+            split,
+            total_cents,
+            Currency::EUR,
+            ExchangeRate::IDENTITY,
+        )
+    }
+
+    fn expense_entry_in_currency(
+        payer: UserId,
+        participants: &[UserId],
+        split: ExpenseSplit,
+        total_cents: i64,
+        currency: Currency,
+        exchange_rate: ExchangeRate,
+    ) -> Result<ExpenseEntry, Error> {
+        expense_entry_paid_by(
+            payer,
+            ExpensePayers::single(payer, Money::from_cents(total_cents)),
+            participants,
+            split,
+            total_cents,
+            currency,
+            exchange_rate,
+        )
+    }
+
+    fn expense_entry_paid_by(
+        payer: UserId,
+        payers: ExpensePayers,
+        participants: &[UserId],
+        split: ExpenseSplit,
+        total_cents: i64,
+        currency: Currency,
+        exchange_rate: ExchangeRate,
+    ) -> Result<ExpenseEntry, Error> {
+        ExpenseEntry::new(
+            ExpenseEntryId::new_random(),
+            ExpenseId::new_random(),
+            GroupId::new_random(),
+            "Dinner".parse().unwrap(),
+            None,
+            ExpenseCategory::Food,
+            None,
+            payer,
+            payers,
+            participants.iter().copied().collect(),
+            split,
+            ExpenseEntryStatus::Active,
+            Money::from_cents(total_cents),
+            currency,
+            exchange_rate,
+            payer,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    fn cents(shares: HashMap<UserId, Money>, users: &[UserId]) -> Vec<i64> {
+        users.iter().map(|u| shares[u].cents()).collect()
+    }
+
+    #[test]
+    fn shares_equal_split() {
+        let users = sorted_users(3);
+        let entry =
+            expense_entry_with_split(users[1], &[users[0], users[2]], ExpenseSplit::Equal, 1_000)
+                .unwrap();
+        assert_eq!(vec![334, 333, 333], cents(entry.shares(), &users));
+    }
+
+    #[test]
+    fn shares_weighted_split() {
+        let users = sorted_users(3);
+        let split = ExpenseSplit::Shares {
+            shares: HashMap::from_iter(vec![(users[0], 2), (users[1], 1), (users[2], 1)]),
+        };
+        let entry =
+            expense_entry_with_split(users[0], &[users[1], users[2]], split, 1_001).unwrap();
+        assert_eq!(vec![501, 250, 250], cents(entry.shares(), &users));
+    }
+
+    #[test]
+    fn shares_exact_split_payer_does_not_participate() {
+        let users = sorted_users(3);
+        let split = ExpenseSplit::Exact {
+            amounts: HashMap::from_iter(vec![
+                (users[1], Money::from_cents(1_250)),
+                (users[2], Money::from_cents(750)),
+            ]),
+        };
+        let entry =
+            expense_entry_with_split(users[0], &[users[1], users[2]], split, 2_000).unwrap();
+        let shares = entry.shares();
+        assert!(!shares.contains_key(&users[0]));
+        assert_eq!(vec![1_250, 750], cents(shares, &users[1..]));
+    }
+
+    #[test]
+    fn shares_percentages_split() {
+        let users = sorted_users(2);
+        let split = ExpenseSplit::Percentages {
+            percentages: HashMap::from_iter(vec![(users[0], 70), (users[1], 30)]),
+        };
+        let entry = expense_entry_with_split(users[0], &[users[1]], split, 999).unwrap();
+        assert_eq!(vec![699, 300], cents(entry.shares(), &users));
+    }
+
+    #[test]
+    fn split_participants_mismatch() {
+        let users = sorted_users(3);
+        let split = ExpenseSplit::Shares {
+            shares: HashMap::from_iter(vec![(users[0], 1), (users[1], 1)]),
+        };
+        let err =
+            expense_entry_with_split(users[0], &[users[1], users[2]], split, 1_000).unwrap_err();
+        assert!(matches!(err, Error::SplitParticipantsMismatch));
+    }
+
+    #[test]
+    fn split_is_validated() {
+        let users = sorted_users(2);
+        let split = ExpenseSplit::Exact {
+            amounts: HashMap::from_iter(vec![(users[1], Money::from_cents(500))]),
+        };
+        let err = expense_entry_with_split(users[0], &[users[1]], split, 1_000).unwrap_err();
+        assert!(matches!(err, Error::InvalidSplit(_)));
+    }
+
+    #[test]
+    fn shares_in_group_currency_sum_up_to_converted_total() {
+        let users = sorted_users(3);
+        let split = ExpenseSplit::Exact {
+            amounts: HashMap::from_iter(vec![
+                (users[0], Money::from_cents(1_000)),
+                (users[1], Money::from_cents(1_000)),
+                (users[2], Money::from_cents(1_001)),
+            ]),
+        };
+        let entry = expense_entry_in_currency(
+            users[0],
+            &[users[1], users[2]],
+            split,
+            3_001,
+            "USD".parse().unwrap(),
+            "0.9".parse().unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(Money::from_cents(2_701), entry.total_in_group_currency());
+        let shares = entry.shares_in_group_currency();
+        assert_eq!(vec![900, 900, 901], cents(shares.clone(), &users));
+        assert_eq!(entry.total_in_group_currency(), shares.into_values().sum());
+    }
+
+    #[test]
+    fn shares_in_group_currency_with_identity_rate() {
+        let users = sorted_users(2);
+        let entry =
+            expense_entry_with_split(users[0], &[users[1]], ExpenseSplit::Equal, 1_001).unwrap();
+        assert_eq!(entry.shares(), entry.shares_in_group_currency());
+        assert_eq!(entry.total, entry.total_in_group_currency());
+    }
+
+    #[test]
+    fn converted_total_overflow() {
+        let users = sorted_users(2);
+        let err = expense_entry_in_currency(
+            users[0],
+            &[users[1]],
+            ExpenseSplit::Equal,
+            i64::MAX,
+            "JPY".parse().unwrap(),
+            "2".parse().unwrap(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::ConvertedTotalOverflow));
+    }
+
+    fn paid(amounts: &[(UserId, i64)]) -> ExpensePayers {
+        ExpensePayers::new(
+            amounts
+                .iter()
+                .map(|(user_id, cents)| (*user_id, Money::from_cents(*cents)))
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn several_payers() {
+        let users = sorted_users(3);
+        let entry = expense_entry_paid_by(
+            users[0],
+            paid(&[(users[0], 600), (users[1], 400)]),
+            &[users[1], users[2]],
+            ExpenseSplit::Equal,
+            1_000,
+            Currency::EUR,
+            ExchangeRate::IDENTITY,
+        )
+        .unwrap();
+        assert_eq!(
+            vec![600, 400],
+            cents(entry.paid_in_group_currency(), &users[..2])
+        );
+    }
+
+    #[test]
+    fn amounts_paid_must_sum_up_to_total() {
+        let users = sorted_users(2);
+        let err = expense_entry_paid_by(
+            users[0],
+            paid(&[(users[0], 600), (users[1], 300)]),
+            &[users[1]],
+            ExpenseSplit::Equal,
+            1_000,
+            Currency::EUR,
+            ExchangeRate::IDENTITY,
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::InvalidPayers(_)));
+    }
+
+    #[test]
+    fn payer_must_be_one_of_the_payers() {
+        let users = sorted_users(3);
+        let err = expense_entry_paid_by(
+            users[0],
+            paid(&[(users[1], 600), (users[2], 400)]),
+            &[users[1], users[2]],
+            ExpenseSplit::Equal,
+            1_000,
+            Currency::EUR,
+            ExchangeRate::IDENTITY,
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::PayerNotInPayers));
+    }
+
+    #[test]
+    fn paid_in_group_currency_sum_up_to_converted_total() {
+        let users = sorted_users(2);
+        let entry = expense_entry_paid_by(
+            users[0],
+            paid(&[(users[0], 1_001), (users[1], 2_000)]),
+            &[users[1]],
+            ExpenseSplit::Equal,
+            3_001,
+            "USD".parse().unwrap(),
+            "0.9".parse().unwrap(),
+        )
+        .unwrap();
+
+        let paid = entry.paid_in_group_currency();
+        assert_eq!(vec![901, 1_800], cents(paid.clone(), &users));
+        assert_eq!(entry.total_in_group_currency(), paid.into_values().sum());
+    }
 }
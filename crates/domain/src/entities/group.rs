@@ -1,16 +1,28 @@
-use std::collections::HashSet;
+use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
 
-use crate::types::{group_id::GroupId, groupname::Groupname, user_id::UserId};
+use crate::types::{
+    currency::Currency, group_id::GroupId, groupname::Groupname, member_role::MemberRole,
+    user_id::UserId,
+};
 
 #[derive(derive_new::new, Debug, PartialEq)]
 pub struct Group {
     pub id: GroupId,
     pub name: Groupname,
+    /// Currency in which balances and settlements of the group are expressed.
+    pub currency: Currency,
     pub owner_id: UserId,
-    pub members: HashSet<UserId>,
+    /// Members of the group, along with their role. The owner is not part
+    /// of them.
+    pub members: HashMap<UserId, MemberRole>,
     pub created_at: DateTime<Utc>,
+    /// Time at which the group was archived, `None` while it is in use.
+    /// Expenses cannot be added to an archived group, nor can its members
+    /// change.
+    #[new(default)]
+    pub archived_at: Option<DateTime<Utc>>,
 }
 
 impl Group {
@@ -19,7 +31,17 @@ impl Group {
     }
 
     pub fn is_user_member(&self, user_id: &UserId) -> bool {
-        self.members.iter().any(|member_id| member_id == user_id)
+        self.members.contains_key(user_id)
+    }
+
+    /// Returns the role of the provided member, `None` for the owner and
+    /// users outside of the group.
+    pub fn member_role(&self, user_id: &UserId) -> Option<MemberRole> {
+        self.members.get(user_id).copied()
+    }
+
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
     }
 
     pub fn contains_user(&self, user_id: &UserId) -> bool {
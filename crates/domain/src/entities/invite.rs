@@ -0,0 +1,123 @@
+use chrono::{DateTime, Utc};
+
+use crate::types::{
+    group_id::GroupId, invite_id::InviteId, invite_token::InviteToken, member_role::MemberRole,
+    user_id::UserId,
+};
+
+/// Link that lets anyone knowing its token join a group, without the
+/// group administrators having to know their user id.
+#[allow(clippy::too_many_arguments)]
+#[derive(derive_new::new, Debug, Clone, PartialEq)]
+pub struct Invite {
+    pub id: InviteId,
+
+    /// Group that this invite lets users join.
+    pub group_id: GroupId,
+
+    pub token: InviteToken,
+
+    /// Role given to the users accepting this invite.
+    pub role: MemberRole,
+
+    /// Number of times this invite can be accepted, `Some(1)` for a
+    /// single-use invite and `None` for no limit.
+    pub max_uses: Option<u32>,
+
+    /// Number of times this invite has been accepted.
+    pub uses: u32,
+
+    /// User who created this invite.
+    pub author_id: UserId,
+
+    /// System time after which this invite cannot be accepted anymore.
+    pub expires_at: DateTime<Utc>,
+
+    /// System time at which this invite was created.
+    pub created_at: DateTime<Utc>,
+
+    /// System time at which this invite was revoked, `None` unless it was.
+    #[new(default)]
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+/// Whether an invite can still be accepted, or why it cannot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InviteStatus {
+    Active,
+    Revoked,
+    Expired,
+    Exhausted,
+}
+
+impl Invite {
+    pub fn status(&self, now: DateTime<Utc>) -> InviteStatus {
+        if self.revoked_at.is_some() {
+            InviteStatus::Revoked
+        } else if now >= self.expires_at {
+            InviteStatus::Expired
+        } else if self.max_uses.is_some_and(|max| self.uses >= max) {
+            InviteStatus::Exhausted
+        } else {
+            InviteStatus::Active
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Duration, Utc};
+
+    use super::{Invite, InviteStatus};
+    use crate::types::{
+        group_id::GroupId, invite_id::InviteId, invite_token::InviteToken, member_role::MemberRole,
+        user_id::UserId,
+    };
+
+    fn invite(max_uses: Option<u32>, uses: u32, now: DateTime<Utc>) -> Invite {
+        Invite::new(
+            InviteId::new_random(),
+            GroupId::new_random(),
+            InviteToken::new_random(),
+            MemberRole::Member,
+            max_uses,
+            uses,
+            UserId::new_random(),
+            now + Duration::days(1),
+            now,
+        )
+    }
+
+    #[rstest::rstest]
+    #[case(None, 12, InviteStatus::Active)]
+    #[case(Some(1), 0, InviteStatus::Active)]
+    #[case(Some(1), 1, InviteStatus::Exhausted)]
+    #[case(Some(3), 2, InviteStatus::Active)]
+    #[case(Some(3), 3, InviteStatus::Exhausted)]
+    fn status_depends_on_uses(
+        #[case] max_uses: Option<u32>,
+        #[case] uses: u32,
+        #[case] expected: InviteStatus,
+    ) {
+        let now = Utc::now();
+        assert_eq!(expected, invite(max_uses, uses, now).status(now));
+    }
+
+    #[test]
+    fn status_expired() {
+        let now = Utc::now();
+        let invite = invite(None, 0, now);
+        assert_eq!(InviteStatus::Expired, invite.status(invite.expires_at));
+    }
+
+    #[test]
+    fn status_revoked_takes_precedence() {
+        let now = Utc::now();
+        let mut invite = invite(Some(1), 1, now);
+        invite.revoked_at = Some(now);
+        assert_eq!(
+            InviteStatus::Revoked,
+            invite.status(now + Duration::days(2))
+        );
+    }
+}
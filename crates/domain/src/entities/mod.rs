@@ -1,7 +1,19 @@
+mod attachment;
+mod budget;
+mod category;
 mod expense_entry;
 mod group;
+mod invite;
+mod recurring_expense;
+pub mod settlement;
 mod user;
 
+pub use attachment::*;
+pub use budget::*;
+pub use category::*;
 pub use expense_entry::*;
 pub use group::*;
+pub use invite::*;
+pub use recurring_expense::*;
+pub use settlement::Settlement;
 pub use user::*;
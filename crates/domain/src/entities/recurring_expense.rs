@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+
+use crate::types::{
+    category_id::CategoryId, currency::Currency, exchange_rate::ExchangeRate,
+    expense_category::ExpenseCategory, expense_note::ExpenseNote, expense_split::ExpenseSplit,
+    expense_title::ExpenseTitle, group_id::GroupId, money::Money, recurrence::Recurrence,
+    recurring_expense_id::RecurringExpenseId, user_id::UserId,
+};
+
+/// Template of an expense that is automatically added to a group following
+/// a schedule (rent, subscriptions, etc.).
+///
+/// Each occurrence is materialized as a regular expense, authored by the
+/// user who defined the recurring expense.
+#[derive(derive_new::new, Debug, Clone, PartialEq)]
+#[allow(clippy::too_many_arguments)]
+pub struct RecurringExpense {
+    pub id: RecurringExpenseId,
+
+    /// Group to which occurrences are added.
+    pub group_id: GroupId,
+
+    pub title: ExpenseTitle,
+
+    pub note: Option<ExpenseNote>,
+
+    pub category: ExpenseCategory,
+
+    pub category_id: Option<CategoryId>,
+
+    pub payer_id: UserId,
+
+    /// Users participating in each occurrence, payer excluded
+    /// (see [ExpenseEntry::participants](crate::entities::ExpenseEntry::participants)).
+    pub participants: HashSet<UserId>,
+
+    pub split: ExpenseSplit,
+
+    /// Total of each occurrence, in `currency`.
+    pub total: Money,
+
+    pub currency: Currency,
+
+    /// Rate to the group currency used for every occurrence.
+    pub exchange_rate: ExchangeRate,
+
+    /// User who defined this recurring expense.
+    pub author_id: UserId,
+
+    pub recurrence: Recurrence,
+
+    /// Next occurrence to materialize, `None` once the schedule has ended.
+    pub next_occurrence_at: Option<DateTime<Utc>>,
+
+    /// System time at which this recurring expense was created.
+    pub created_at: DateTime<Utc>,
+}
+
+impl RecurringExpense {
+    /// Returns whether an occurrence is due at `now`.
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.next_occurrence_at.is_some_and(|at| at <= now)
+    }
+}
@@ -0,0 +1,86 @@
+use chrono::{DateTime, Utc};
+
+use crate::types::{
+    group_id::GroupId, money::Money, settlement_id::SettlementId,
+    settlement_status::SettlementStatus, user_id::UserId,
+};
+
+/// Represents a reimbursement payment made in real life between two
+/// members of a group (e.g. "Bob paid Alice back").
+#[derive(Debug, PartialEq)]
+pub struct Settlement {
+    pub id: SettlementId,
+
+    /// Group to which this settlement belongs.
+    pub group_id: GroupId,
+
+    /// User who sent the money.
+    pub from: UserId,
+
+    /// User who received the money.
+    pub to: UserId,
+
+    /// Amount paid back.
+    /// Must be strictly greater than zero.
+    pub amount: Money,
+
+    /// Status of this settlement.
+    /// Cancelled settlements are kept for audit but ignored in balances.
+    pub status: SettlementStatus,
+
+    /// User who recorded this settlement.
+    pub author_id: UserId,
+
+    /// Real-world time at which the payment occurred.
+    pub occurred_at: DateTime<Utc>,
+
+    /// System time at which this settlement was recorded.
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("amount must be > 0")]
+    NotPositiveAmount,
+
+    #[error("a user cannot settle with themselves")]
+    SameUser,
+}
+
+impl Settlement {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: SettlementId,
+        group_id: GroupId,
+        from: UserId,
+        to: UserId,
+        amount: Money,
+        status: SettlementStatus,
+        author_id: UserId,
+        occurred_at: DateTime<Utc>,
+        created_at: DateTime<Utc>,
+    ) -> Result<Self, Error> {
+        if !amount.is_positive() {
+            return Err(Error::NotPositiveAmount);
+        }
+        if from == to {
+            return Err(Error::SameUser);
+        }
+
+        Ok(Self {
+            id,
+            group_id,
+            from,
+            to,
+            amount,
+            status,
+            author_id,
+            occurred_at,
+            created_at,
+        })
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status == SettlementStatus::Active
+    }
+}
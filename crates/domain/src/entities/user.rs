@@ -1,13 +1,23 @@
 use chrono::{DateTime, Utc};
 use email_address::EmailAddress;
 
-use crate::types::{role::Role, user_id::UserId, username::Username};
+use crate::types::{handle::Handle, role::Role, user_id::UserId, username::Username};
 
 #[derive(derive_new::new, Debug, PartialEq, Clone)]
 pub struct User {
     pub id: UserId,
     pub name: Username,
-    pub email: EmailAddress,
+    /// Unique name by which other users can find this user.
+    pub handle: Handle,
+    /// Email with which the user logs in, `None` for guests, who are added
+    /// to a group by name and have no account.
+    pub email: Option<EmailAddress>,
     pub role: Role,
     pub created_at: DateTime<Utc>,
 }
+
+impl User {
+    pub fn is_guest(&self) -> bool {
+        self.email.is_none()
+    }
+}
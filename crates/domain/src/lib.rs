@@ -1,3 +1,4 @@
+pub mod balances;
 pub mod entities;
 pub mod types;
 
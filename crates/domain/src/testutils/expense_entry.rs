@@ -5,7 +5,11 @@ use uuid::Uuid;
 
 use crate::{
     entities::ExpenseEntry,
-    types::{expense_entry_status::ExpenseEntryStatus, money::Money, user_id::UserId},
+    types::{
+        currency::Currency, exchange_rate::ExchangeRate, expense_category::ExpenseCategory,
+        expense_entry_status::ExpenseEntryStatus, expense_payers::ExpensePayers,
+        expense_split::ExpenseSplit, money::Money, user_id::UserId,
+    },
 };
 
 pub struct TestExpenseEntry;
@@ -23,6 +27,35 @@ impl TestExpenseEntry {
         author_id: impl Into<Uuid>,
         occurred_at: DateTime<Utc>,
         created_at: DateTime<Utc>,
+    ) -> ExpenseEntry {
+        Self::new_valid_with_split(
+            id,
+            expense_id,
+            group_id,
+            payer_id,
+            participants,
+            ExpenseSplit::Equal,
+            status,
+            total_euros,
+            author_id,
+            occurred_at,
+            created_at,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_valid_with_split(
+        id: impl Into<Uuid>,
+        expense_id: impl Into<Uuid>,
+        group_id: impl Into<Uuid>,
+        payer_id: impl Into<Uuid>,
+        participants: HashSet<impl Into<Uuid>>,
+        split: ExpenseSplit,
+        status: ExpenseEntryStatus,
+        total_euros: i64,
+        author_id: impl Into<Uuid>,
+        occurred_at: DateTime<Utc>,
+        created_at: DateTime<Utc>,
     ) -> ExpenseEntry {
         let id = id.into().to_string().parse().unwrap();
         let expense_id = expense_id.into().to_string().parse().unwrap();
@@ -38,10 +71,18 @@ impl TestExpenseEntry {
             id,
             expense_id,
             group_id,
+            "Test expense".parse().unwrap(),
+            None,
+            ExpenseCategory::default(),
+            None,
             payer_id,
+            ExpensePayers::single(payer_id, total),
             participants,
+            split,
             status,
             total,
+            Currency::default(),
+            ExchangeRate::IDENTITY,
             author_id,
             occurred_at,
             created_at,
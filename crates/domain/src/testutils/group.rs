@@ -3,7 +3,7 @@ use uuid::Uuid;
 
 use crate::{
     entities::Group,
-    types::{group_id::GroupId, user_id::UserId},
+    types::{currency::Currency, group_id::GroupId, member_role::MemberRole, user_id::UserId},
 };
 
 pub struct TestGroup;
@@ -21,8 +21,8 @@ impl TestGroup {
         let owner_id = UserId::new(owner_id.into()).unwrap();
         let members = members
             .into_iter()
-            .map(|member_id| UserId::new(member_id.into()).unwrap())
+            .map(|member_id| (UserId::new(member_id.into()).unwrap(), MemberRole::Member))
             .collect();
-        Group::new(id, name, owner_id, members, created_at)
+        Group::new(id, name, Currency::default(), owner_id, members, created_at)
     }
 }
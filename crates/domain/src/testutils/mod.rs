@@ -1,3 +1,4 @@
 pub mod expense_entry;
 pub mod group;
+pub mod settlement;
 pub mod user;
@@ -0,0 +1,42 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    entities::Settlement,
+    types::{money::Money, settlement_status::SettlementStatus},
+};
+
+pub struct TestSettlement;
+
+impl TestSettlement {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_valid(
+        id: impl Into<Uuid>,
+        group_id: impl Into<Uuid>,
+        from: impl Into<Uuid>,
+        to: impl Into<Uuid>,
+        amount_cents: i64,
+        status: SettlementStatus,
+        author_id: impl Into<Uuid>,
+        occurred_at: DateTime<Utc>,
+        created_at: DateTime<Utc>,
+    ) -> Settlement {
+        let id = id.into().to_string().parse().unwrap();
+        let group_id = group_id.into().to_string().parse().unwrap();
+        let from = from.into().to_string().parse().unwrap();
+        let to = to.into().to_string().parse().unwrap();
+        let author_id = author_id.into().to_string().parse().unwrap();
+        Settlement::new(
+            id,
+            group_id,
+            from,
+            to,
+            Money::from_cents(amount_cents),
+            status,
+            author_id,
+            occurred_at,
+            created_at,
+        )
+        .unwrap()
+    }
+}
@@ -9,6 +9,7 @@ use crate::{
 pub struct TestUser;
 
 impl TestUser {
+    /// Builds a user whose handle is its name, in lowercase.
     pub fn new_valid(
         id: impl Into<Uuid>,
         name: impl Into<String>,
@@ -17,8 +18,23 @@ impl TestUser {
         created_at: DateTime<Utc>,
     ) -> User {
         let id = UserId::new(id.into()).unwrap();
-        let name = name.into().parse().unwrap();
-        let email = email.into().parse().unwrap();
-        User::new(id, name, email, role, created_at)
+        let name: String = name.into();
+        let handle = name.parse().unwrap();
+        let name = name.parse().unwrap();
+        let email = Some(email.into().parse().unwrap());
+        User::new(id, name, handle, email, role, created_at)
+    }
+
+    /// Builds a guest, without email, whose handle is its name, in lowercase.
+    pub fn new_guest(
+        id: impl Into<Uuid>,
+        name: impl Into<String>,
+        created_at: DateTime<Utc>,
+    ) -> User {
+        let id = UserId::new(id.into()).unwrap();
+        let name: String = name.into();
+        let handle = name.parse().unwrap();
+        let name = name.parse().unwrap();
+        User::new(id, name, handle, None, Role::User, created_at)
     }
 }
@@ -0,0 +1,128 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Media type of an attachment, among the formats accepted for receipts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttachmentContentType {
+    Jpeg,
+    Png,
+    Webp,
+    Heic,
+    Pdf,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("unsupported attachment content type, expected a JPEG, PNG, WebP, HEIC image or a PDF")]
+    Unsupported,
+}
+
+impl AttachmentContentType {
+    /// MIME type of the attachment, such as `image/jpeg`.
+    pub fn value(&self) -> &'static str {
+        match self {
+            AttachmentContentType::Jpeg => "image/jpeg",
+            AttachmentContentType::Png => "image/png",
+            AttachmentContentType::Webp => "image/webp",
+            AttachmentContentType::Heic => "image/heic",
+            AttachmentContentType::Pdf => "application/pdf",
+        }
+    }
+
+    /// Detects the format of a file from its first bytes, so that a file is
+    /// not trusted to be what its uploader declared.
+    /// Returns `None` if the content is not in any of the accepted formats.
+    pub fn sniff(content: &[u8]) -> Option<Self> {
+        if content.starts_with(&[0xFF, 0xD8, 0xFF]) {
+            return Some(Self::Jpeg);
+        }
+        if content.starts_with(b"\x89PNG\r\n\x1a\n") {
+            return Some(Self::Png);
+        }
+        if content.starts_with(b"RIFF") && content.get(8..12) == Some(b"WEBP") {
+            return Some(Self::Webp);
+        }
+        if content.get(4..8) == Some(b"ftyp")
+            && content.get(8..12).is_some_and(|brand| {
+                HEIC_BRANDS
+                    .iter()
+                    .any(|heic_brand| heic_brand[..] == *brand)
+            })
+        {
+            return Some(Self::Heic);
+        }
+        if content.starts_with(b"%PDF-") {
+            return Some(Self::Pdf);
+        }
+        None
+    }
+}
+
+/// Major brands of the ISO base media files holding HEIF/HEIC images.
+const HEIC_BRANDS: [&[u8; 4]; 8] = [
+    b"heic", b"heix", b"hevc", b"hevx", b"heim", b"heis", b"mif1", b"msf1",
+];
+
+impl FromStr for AttachmentContentType {
+    type Err = Error;
+
+    /// Parses a MIME type, ignoring its parameters (e.g. `; charset=...`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let essence = s.split(';').next().unwrap_or_default();
+        match essence.trim().to_lowercase().as_str() {
+            "image/jpeg" | "image/jpg" => Ok(Self::Jpeg),
+            "image/png" => Ok(Self::Png),
+            "image/webp" => Ok(Self::Webp),
+            "image/heic" => Ok(Self::Heic),
+            "application/pdf" => Ok(Self::Pdf),
+            _ => Err(Error::Unsupported),
+        }
+    }
+}
+
+impl Display for AttachmentContentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AttachmentContentType, Error};
+
+    #[rstest::rstest]
+    #[case("image/jpeg", AttachmentContentType::Jpeg)]
+    #[case("image/jpg", AttachmentContentType::Jpeg)]
+    #[case(" IMAGE/PNG ", AttachmentContentType::Png)]
+    #[case("image/webp", AttachmentContentType::Webp)]
+    #[case("image/heic", AttachmentContentType::Heic)]
+    #[case("application/pdf; name=receipt.pdf", AttachmentContentType::Pdf)]
+    fn valid_content_type(#[case] input: &str, #[case] expected: AttachmentContentType) {
+        assert_eq!(expected, input.parse().unwrap());
+    }
+
+    #[rstest::rstest]
+    #[case(b"\xFF\xD8\xFF\xE0\x00\x10JFIF", Some(AttachmentContentType::Jpeg))]
+    #[case(b"\x89PNG\r\n\x1a\n\x00\x00", Some(AttachmentContentType::Png))]
+    #[case(b"RIFF\x24\x00\x00\x00WEBPVP8 ", Some(AttachmentContentType::Webp))]
+    #[case(b"\x00\x00\x00\x18ftypheic\x00\x00", Some(AttachmentContentType::Heic))]
+    #[case(b"%PDF-1.7\n", Some(AttachmentContentType::Pdf))]
+    #[case(b"<html><script>alert(1)</script></html>", None)]
+    #[case(b"<svg xmlns=\"http://www.w3.org/2000/svg\"/>", None)]
+    #[case(b"RIFF\x24\x00\x00\x00WAVEfmt ", None)]
+    #[case(b"", None)]
+    fn sniff(#[case] content: &[u8], #[case] expected: Option<AttachmentContentType>) {
+        assert_eq!(expected, AttachmentContentType::sniff(content));
+    }
+
+    #[rstest::rstest]
+    #[case("")]
+    #[case("text/html")]
+    #[case("application/octet-stream")]
+    #[case("image/svg+xml")]
+    fn unsupported_content_type(#[case] input: &str) {
+        assert_eq!(
+            Error::Unsupported,
+            input.parse::<AttachmentContentType>().unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use std::str::FromStr;
+
+/// Name of an uploaded file, as provided by the client.
+///
+/// Only the last path component is kept, so that the name can safely be
+/// sent back in a `Content-Disposition` header. It is never used to build
+/// a path on disk.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttachmentFileName {
+    val: String,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("attachment file name cannot be empty")]
+    Empty,
+
+    #[error(
+        "attachment file name cannot exceed {} characters long",
+        AttachmentFileName::MAX_LENGTH
+    )]
+    TooLong,
+
+    #[error("attachment file name cannot contain control characters or quotes")]
+    InvalidCharacters,
+}
+
+impl AttachmentFileName {
+    const MAX_LENGTH: usize = 255;
+
+    pub fn value(&self) -> String {
+        self.val.clone()
+    }
+}
+
+impl FromStr for AttachmentFileName {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.rsplit(['/', '\\']).next().unwrap_or_default().trim();
+        if s.is_empty() || s == "." || s == ".." {
+            return Err(Error::Empty);
+        }
+        if s.chars().count() > Self::MAX_LENGTH {
+            return Err(Error::TooLong);
+        }
+        if s.chars().any(|c| c.is_control() || c == '"') {
+            return Err(Error::InvalidCharacters);
+        }
+        Ok(Self { val: s.to_string() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AttachmentFileName, Error};
+
+    #[rstest::rstest]
+    #[case("receipt.pdf", "receipt.pdf")]
+    #[case("  Dinner at Luigi's.jpg ", "Dinner at Luigi's.jpg")]
+    #[case("../../etc/passwd", "passwd")]
+    #[case("C:\\Users\\bob\\ticket.png", "ticket.png")]
+    #[case(&"é".repeat(255), &"é".repeat(255))]
+    fn valid_file_name(#[case] input: &str, #[case] expected: &str) {
+        let file_name: AttachmentFileName = input.parse().unwrap();
+        assert_eq!(expected, file_name.value());
+    }
+
+    #[rstest::rstest]
+    #[case("", Error::Empty)]
+    #[case("   ", Error::Empty)]
+    #[case("photos/", Error::Empty)]
+    #[case("..", Error::Empty)]
+    #[case(&"a".repeat(256), Error::TooLong)]
+    #[case("receipt\n.pdf", Error::InvalidCharacters)]
+    #[case("my \"receipt\".pdf", Error::InvalidCharacters)]
+    fn invalid_file_name(#[case] input: &str, #[case] expected: Error) {
+        assert_eq!(expected, input.parse::<AttachmentFileName>().unwrap_err());
+    }
+}
@@ -0,0 +1,3 @@
+use crate::id_type;
+
+id_type!(AttachmentId);
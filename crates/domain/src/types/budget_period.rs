@@ -0,0 +1,129 @@
+use std::ops::Range;
+
+use chrono::{DateTime, Datelike, Duration, Months, NaiveTime, TimeZone, Utc};
+
+/// Span of time over which expenses consume a budget.
+///
+/// Calendar periods are renewed every week (starting on Monday), month or
+/// year, in UTC.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BudgetPeriod {
+    Weekly,
+    Monthly,
+    Yearly,
+    /// Single span of time, from `starts_at` (included) to `ends_at` (excluded).
+    Range {
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+    },
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("end of the range must be after its start")]
+    EmptyRange,
+}
+
+impl BudgetPeriod {
+    pub fn range(starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> Result<Self, Error> {
+        if ends_at <= starts_at {
+            return Err(Error::EmptyRange);
+        }
+        Ok(Self::Range { starts_at, ends_at })
+    }
+
+    /// Returns the span of time of the period containing `at`, or `None` if
+    /// `at` is outside of a date range.
+    pub fn window(&self, at: DateTime<Utc>) -> Option<Range<DateTime<Utc>>> {
+        let midnight =
+            |date: chrono::NaiveDate| Utc.from_utc_datetime(&date.and_time(NaiveTime::MIN));
+        match *self {
+            BudgetPeriod::Weekly => {
+                let start = midnight(at.date_naive())
+                    - Duration::days(at.weekday().num_days_from_monday().into());
+                Some(start..start.checked_add_signed(Duration::weeks(1))?)
+            }
+            BudgetPeriod::Monthly => {
+                let start = midnight(at.date_naive().with_day(1)?);
+                Some(start..start.checked_add_months(Months::new(1))?)
+            }
+            BudgetPeriod::Yearly => {
+                let start = midnight(at.date_naive().with_ordinal(1)?);
+                Some(start..start.checked_add_months(Months::new(12))?)
+            }
+            BudgetPeriod::Range { starts_at, ends_at } => (starts_at..ends_at)
+                .contains(&at)
+                .then_some(starts_at..ends_at),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+
+    use super::{BudgetPeriod, Error};
+
+    fn date(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().to_utc()
+    }
+
+    #[rstest::rstest]
+    // 2025-03-12 is a Wednesday
+    #[case(
+        BudgetPeriod::Weekly,
+        "2025-03-12T18:30:00Z",
+        "2025-03-10T00:00:00Z",
+        "2025-03-17T00:00:00Z"
+    )]
+    #[case(
+        BudgetPeriod::Weekly,
+        "2025-03-10T00:00:00Z",
+        "2025-03-10T00:00:00Z",
+        "2025-03-17T00:00:00Z"
+    )]
+    #[case(
+        BudgetPeriod::Monthly,
+        "2025-02-28T23:59:59Z",
+        "2025-02-01T00:00:00Z",
+        "2025-03-01T00:00:00Z"
+    )]
+    #[case(
+        BudgetPeriod::Yearly,
+        "2024-12-31T12:00:00Z",
+        "2024-01-01T00:00:00Z",
+        "2025-01-01T00:00:00Z"
+    )]
+    fn calendar_window(
+        #[case] period: BudgetPeriod,
+        #[case] at: &str,
+        #[case] expected_start: &str,
+        #[case] expected_end: &str,
+    ) {
+        assert_eq!(
+            Some(date(expected_start)..date(expected_end)),
+            period.window(date(at))
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("2025-03-01T00:00:00Z", true)]
+    #[case("2025-03-09T23:59:59Z", true)]
+    #[case("2025-03-10T00:00:00Z", false)]
+    #[case("2025-02-28T23:59:59Z", false)]
+    fn range_window(#[case] at: &str, #[case] expected_inside: bool) {
+        let period =
+            BudgetPeriod::range(date("2025-03-01T00:00:00Z"), date("2025-03-10T00:00:00Z"))
+                .unwrap();
+        assert_eq!(
+            expected_inside.then(|| date("2025-03-01T00:00:00Z")..date("2025-03-10T00:00:00Z")),
+            period.window(date(at))
+        );
+    }
+
+    #[test]
+    fn empty_range() {
+        let at = date("2025-03-01T00:00:00Z");
+        assert_eq!(Err(Error::EmptyRange), BudgetPeriod::range(at, at));
+    }
+}
@@ -0,0 +1,51 @@
+use crate::types::money::Money;
+
+/// Share of a budget whose consumption raises an alert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BudgetThreshold {
+    /// 80% of the budget is consumed.
+    Warning,
+    /// The whole budget is consumed.
+    Exceeded,
+}
+
+impl BudgetThreshold {
+    pub const ALL: [BudgetThreshold; 2] = [BudgetThreshold::Warning, BudgetThreshold::Exceeded];
+
+    pub fn percent(&self) -> u8 {
+        match self {
+            BudgetThreshold::Warning => 80,
+            BudgetThreshold::Exceeded => 100,
+        }
+    }
+
+    /// Returns whether `consumed` reaches this threshold of `amount`.
+    pub fn is_reached(&self, amount: Money, consumed: Money) -> bool {
+        i128::from(consumed.cents()) * 100
+            >= i128::from(amount.cents()) * i128::from(self.percent())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::money::Money;
+
+    use super::BudgetThreshold;
+
+    #[rstest::rstest]
+    #[case(BudgetThreshold::Warning, 7_999, false)]
+    #[case(BudgetThreshold::Warning, 8_000, true)]
+    #[case(BudgetThreshold::Exceeded, 9_999, false)]
+    #[case(BudgetThreshold::Exceeded, 10_000, true)]
+    #[case(BudgetThreshold::Exceeded, 12_000, true)]
+    fn is_reached(
+        #[case] threshold: BudgetThreshold,
+        #[case] consumed_cents: i64,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(
+            expected,
+            threshold.is_reached(Money::from_cents(10_000), Money::from_cents(consumed_cents))
+        );
+    }
+}
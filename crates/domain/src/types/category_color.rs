@@ -0,0 +1,71 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Color used to display a category, as a `#RRGGBB` hex code.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CategoryColor {
+    rgb: [u8; 3],
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("category color must be a hex code, such as #FF8800")]
+    InvalidFormat,
+}
+
+impl CategoryColor {
+    pub fn value(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Parses a `#RRGGBB` hex code, case-insensitively.
+impl FromStr for CategoryColor {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.trim().strip_prefix('#').ok_or(Error::InvalidFormat)?;
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(Error::InvalidFormat);
+        }
+        let component =
+            |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| Error::InvalidFormat);
+        Ok(Self {
+            rgb: [component(0)?, component(2)?, component(4)?],
+        })
+    }
+}
+
+impl Display for CategoryColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [r, g, b] = self.rgb;
+        write!(f, "#{r:02X}{g:02X}{b:02X}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CategoryColor, Error};
+
+    #[rstest::rstest]
+    #[case("#FF8800", "#FF8800")]
+    #[case("#ff8800", "#FF8800")]
+    #[case(" #00aBcD ", "#00ABCD")]
+    fn valid_color(#[case] input: &str, #[case] expected: &str) {
+        let color: CategoryColor = input.parse().unwrap();
+        assert_eq!(expected, color.value());
+    }
+
+    #[rstest::rstest]
+    #[case("")]
+    #[case("FF8800")]
+    #[case("#FF880")]
+    #[case("#FF88000")]
+    #[case("#GG8800")]
+    #[case("#+F8800")]
+    fn invalid_color(#[case] input: &str) {
+        assert_eq!(
+            Error::InvalidFormat,
+            input.parse::<CategoryColor>().unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use std::str::FromStr;
+
+/// Emoji used to display a category, such as "🍕".
+///
+/// Only the length is checked: emojis made of several code points (flags,
+/// skin tones, etc.) are accepted as long as they stay short.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryEmoji {
+    val: String,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("category emoji cannot be empty")]
+    Empty,
+
+    #[error("category emoji must be a single emoji")]
+    Invalid,
+}
+
+impl CategoryEmoji {
+    const MAX_CHARS: usize = 8;
+
+    pub fn value(&self) -> String {
+        self.val.clone()
+    }
+}
+
+impl FromStr for CategoryEmoji {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(Error::Empty);
+        }
+        if s.chars().count() > Self::MAX_CHARS
+            || s.chars().any(|c| c.is_ascii() || c.is_whitespace())
+        {
+            return Err(Error::Invalid);
+        }
+        Ok(Self { val: s.to_string() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CategoryEmoji, Error};
+
+    #[rstest::rstest]
+    #[case("🍕")]
+    #[case(" 🏖️ ")]
+    #[case("🇫🇷")]
+    #[case("👍🏽")]
+    fn valid_emoji(#[case] input: &str) {
+        let emoji: CategoryEmoji = input.parse().unwrap();
+        assert_eq!(input.trim(), emoji.value());
+    }
+
+    #[rstest::rstest]
+    #[case("", Error::Empty)]
+    #[case("  ", Error::Empty)]
+    #[case("a", Error::Invalid)]
+    #[case("🍕 pizza", Error::Invalid)]
+    #[case(&"🍕".repeat(9), Error::Invalid)]
+    fn invalid_emoji(#[case] input: &str, #[case] expected: Error) {
+        assert_eq!(expected, input.parse::<CategoryEmoji>().unwrap_err());
+    }
+}
@@ -0,0 +1,64 @@
+use std::str::FromStr;
+
+/// Name of a custom category, unique within a group (case-insensitive).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryName {
+    val: String,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("category name cannot be empty")]
+    Empty,
+
+    #[error(
+        "category name cannot exceed {} characters long",
+        CategoryName::MAX_LENGTH
+    )]
+    TooLong,
+}
+
+impl CategoryName {
+    const MAX_LENGTH: usize = 50;
+
+    pub fn value(&self) -> String {
+        self.val.clone()
+    }
+}
+
+impl FromStr for CategoryName {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(Error::Empty);
+        }
+        if s.chars().count() > Self::MAX_LENGTH {
+            return Err(Error::TooLong);
+        }
+        Ok(Self { val: s.to_string() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CategoryName, Error};
+
+    #[rstest::rstest]
+    #[case("Bars", "Bars")]
+    #[case("  Ski passes ", "Ski passes")]
+    #[case(&"é".repeat(50), &"é".repeat(50))]
+    fn valid_name(#[case] input: &str, #[case] expected: &str) {
+        let name: CategoryName = input.parse().unwrap();
+        assert_eq!(expected, name.value());
+    }
+
+    #[rstest::rstest]
+    #[case("", Error::Empty)]
+    #[case("   ", Error::Empty)]
+    #[case(&"a".repeat(51), Error::TooLong)]
+    fn invalid_name(#[case] input: &str, #[case] expected: Error) {
+        assert_eq!(expected, input.parse::<CategoryName>().unwrap_err());
+    }
+}
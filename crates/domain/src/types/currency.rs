@@ -0,0 +1,86 @@
+use std::{fmt::Display, str::FromStr};
+
+/// ISO 4217 currency code, such as `EUR` or `USD`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Currency {
+    code: [u8; 3],
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("currency must be a 3-letter ISO 4217 code, such as EUR")]
+    InvalidCode,
+}
+
+impl Currency {
+    pub const EUR: Currency = Currency { code: *b"EUR" };
+
+    pub fn value(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Groups created without an explicit currency use euros.
+impl Default for Currency {
+    fn default() -> Self {
+        Self::EUR
+    }
+}
+
+/// Parses a currency code, case-insensitively.
+impl FromStr for Currency {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code: [u8; 3] = s
+            .trim()
+            .as_bytes()
+            .try_into()
+            .map_err(|_| Error::InvalidCode)?;
+        if !code.iter().all(u8::is_ascii_alphabetic) {
+            return Err(Error::InvalidCode);
+        }
+        Ok(Self {
+            code: code.map(|c| c.to_ascii_uppercase()),
+        })
+    }
+}
+
+impl Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let code = std::str::from_utf8(&self.code).expect("code is ascii");
+        f.write_str(code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{Currency, Error};
+
+    #[rstest::rstest]
+    #[case("EUR", "EUR")]
+    #[case("usd", "USD")]
+    #[case(" Chf ", "CHF")]
+    fn parse_ok(#[case] s: &str, #[case] expected: &str) {
+        assert_eq!(expected, Currency::from_str(s).unwrap().value());
+    }
+
+    #[rstest::rstest]
+    #[case("")]
+    #[case("EU")]
+    #[case("EURO")]
+    #[case("E1R")]
+    #[case("€")]
+    #[case("ÉUR")]
+    fn parse_err(#[case] s: &str) {
+        assert_eq!(Error::InvalidCode, Currency::from_str(s).unwrap_err());
+    }
+
+    #[test]
+    fn default_is_eur() {
+        assert_eq!(Currency::EUR, Currency::default());
+        assert_eq!("EUR", Currency::default().to_string());
+    }
+}
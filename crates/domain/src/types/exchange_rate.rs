@@ -0,0 +1,184 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::types::money::Money;
+
+/// Exchange rate from an expense currency to the group currency:
+/// 1 unit of the expense currency is worth `rate` units of the group currency.
+///
+/// The rate is stored as a fixed-point number with 6 decimal places
+/// and is always strictly positive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExchangeRate {
+    micros: i64,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("exchange rate must be a decimal number, such as 1.0845")]
+    InvalidFormat,
+
+    #[error(
+        "exchange rate cannot have more than {} decimal places",
+        ExchangeRate::DECIMALS
+    )]
+    TooManyDecimals,
+
+    #[error("exchange rate must be > 0")]
+    NotPositive,
+
+    #[error("exchange rate cannot exceed {}", ExchangeRate::MAX_UNITS)]
+    TooLarge,
+}
+
+impl ExchangeRate {
+    const DECIMALS: usize = 6;
+    const SCALE: i64 = 1_000_000;
+    const MAX_UNITS: i64 = 1_000_000;
+
+    /// Rate between identical currencies.
+    pub const IDENTITY: ExchangeRate = ExchangeRate {
+        micros: Self::SCALE,
+    };
+
+    /// Builds a rate from its value in millionths.
+    pub fn from_micros(micros: i64) -> Result<Self, Error> {
+        if micros <= 0 {
+            return Err(Error::NotPositive);
+        }
+        if micros > Self::MAX_UNITS * Self::SCALE {
+            return Err(Error::TooLarge);
+        }
+        Ok(Self { micros })
+    }
+
+    pub fn micros(&self) -> i64 {
+        self.micros
+    }
+
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// Converts `amount` to the group currency, rounding half away from zero.
+    /// Returns `None` if the converted amount does not fit in [Money].
+    pub fn convert(&self, amount: Money) -> Option<Money> {
+        let scaled = amount.cents() as i128 * self.micros as i128;
+        let half = (Self::SCALE / 2) as i128;
+        let rounded = if scaled < 0 {
+            (scaled - half) / Self::SCALE as i128
+        } else {
+            (scaled + half) / Self::SCALE as i128
+        };
+        i64::try_from(rounded).ok().map(Money::from_cents)
+    }
+}
+
+impl FromStr for ExchangeRate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (units, decimals) = s.split_once('.').unwrap_or((s, ""));
+
+        let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if units.is_empty() || !is_digits(units) || !is_digits(decimals) {
+            return Err(Error::InvalidFormat);
+        }
+        if s.contains('.') && decimals.is_empty() {
+            return Err(Error::InvalidFormat);
+        }
+        if decimals.len() > Self::DECIMALS {
+            return Err(Error::TooManyDecimals);
+        }
+
+        let units: i64 = units.parse().map_err(|_| Error::TooLarge)?;
+        let decimals: i64 = format!("{:0<6}", decimals)
+            .parse()
+            .expect("at most six digits");
+        let micros = units
+            .checked_mul(Self::SCALE)
+            .and_then(|m| m.checked_add(decimals))
+            .ok_or(Error::TooLarge)?;
+        Self::from_micros(micros)
+    }
+}
+
+/// Formats the rate without trailing zeros (e.g. `1.0845`, `2`).
+impl Display for ExchangeRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let units = self.micros / Self::SCALE;
+        let decimals = self.micros % Self::SCALE;
+        if decimals == 0 {
+            return write!(f, "{}", units);
+        }
+        let decimals = format!("{:06}", decimals);
+        write!(f, "{}.{}", units, decimals.trim_end_matches('0'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{Error, ExchangeRate};
+    use crate::types::money::Money;
+
+    #[rstest::rstest]
+    #[case("1", 1_000_000)]
+    #[case("1.0845", 1_084_500)]
+    #[case("0.000001", 1)]
+    #[case(" 156.25 ", 156_250_000)]
+    #[case("1000000", 1_000_000_000_000)]
+    fn parse_ok(#[case] s: &str, #[case] expected_micros: i64) {
+        assert_eq!(expected_micros, ExchangeRate::from_str(s).unwrap().micros());
+    }
+
+    #[rstest::rstest]
+    #[case("", Error::InvalidFormat)]
+    #[case("-1", Error::InvalidFormat)]
+    #[case("1,5", Error::InvalidFormat)]
+    #[case("1.", Error::InvalidFormat)]
+    #[case(".5", Error::InvalidFormat)]
+    #[case("1.0000001", Error::TooManyDecimals)]
+    #[case("0", Error::NotPositive)]
+    #[case("0.000000", Error::NotPositive)]
+    #[case("1000000.000001", Error::TooLarge)]
+    #[case("99999999999999999999", Error::TooLarge)]
+    fn parse_err(#[case] s: &str, #[case] expected: Error) {
+        assert_eq!(expected, ExchangeRate::from_str(s).unwrap_err());
+    }
+
+    #[rstest::rstest]
+    #[case(1_000_000, "1")]
+    #[case(1_084_500, "1.0845")]
+    #[case(1, "0.000001")]
+    #[case(2_500_000, "2.5")]
+    fn display(#[case] micros: i64, #[case] expected: &str) {
+        assert_eq!(
+            expected,
+            ExchangeRate::from_micros(micros).unwrap().to_string()
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("1", 1234, 1234)]
+    #[case("1.0845", 10_000, 10_845)]
+    #[case("0.5", 3, 2)]
+    #[case("0.5", 1, 1)]
+    #[case("0.4", 1, 0)]
+    #[case("0.5", -3, -2)]
+    #[case("0.006521", 1_500_000, 9_782)]
+    fn convert(#[case] rate: &str, #[case] cents: i64, #[case] expected_cents: i64) {
+        let rate = ExchangeRate::from_str(rate).unwrap();
+        assert_eq!(
+            Some(Money::from_cents(expected_cents)),
+            rate.convert(Money::from_cents(cents))
+        );
+    }
+
+    #[test]
+    fn convert_overflow() {
+        let rate = ExchangeRate::from_str("2").unwrap();
+        assert_eq!(None, rate.convert(Money::from_cents(i64::MAX)));
+    }
+}
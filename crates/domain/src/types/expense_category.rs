@@ -0,0 +1,102 @@
+use std::{fmt::Display, str::FromStr};
+
+/// Category of an expense, among a fixed list.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExpenseCategory {
+    Food,
+    Groceries,
+    Transport,
+    Lodging,
+    Entertainment,
+    Shopping,
+    Utilities,
+    Health,
+    #[default]
+    Other,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("unknown expense category")]
+    Unknown,
+}
+
+impl ExpenseCategory {
+    pub fn value(&self) -> &'static str {
+        match self {
+            ExpenseCategory::Food => "food",
+            ExpenseCategory::Groceries => "groceries",
+            ExpenseCategory::Transport => "transport",
+            ExpenseCategory::Lodging => "lodging",
+            ExpenseCategory::Entertainment => "entertainment",
+            ExpenseCategory::Shopping => "shopping",
+            ExpenseCategory::Utilities => "utilities",
+            ExpenseCategory::Health => "health",
+            ExpenseCategory::Other => "other",
+        }
+    }
+}
+
+impl FromStr for ExpenseCategory {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "food" => Ok(Self::Food),
+            "groceries" => Ok(Self::Groceries),
+            "transport" => Ok(Self::Transport),
+            "lodging" => Ok(Self::Lodging),
+            "entertainment" => Ok(Self::Entertainment),
+            "shopping" => Ok(Self::Shopping),
+            "utilities" => Ok(Self::Utilities),
+            "health" => Ok(Self::Health),
+            "other" => Ok(Self::Other),
+            _ => Err(Error::Unknown),
+        }
+    }
+}
+
+impl Display for ExpenseCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, ExpenseCategory};
+
+    #[rstest::rstest]
+    #[case("food", ExpenseCategory::Food)]
+    #[case(" Transport ", ExpenseCategory::Transport)]
+    #[case("LODGING", ExpenseCategory::Lodging)]
+    #[case("other", ExpenseCategory::Other)]
+    fn valid_category(#[case] input: &str, #[case] expected: ExpenseCategory) {
+        assert_eq!(expected, input.parse().unwrap());
+    }
+
+    #[rstest::rstest]
+    #[case("")]
+    #[case("restaurant")]
+    #[case("foods")]
+    fn invalid_category(#[case] input: &str) {
+        assert_eq!(
+            Error::Unknown,
+            input.parse::<ExpenseCategory>().unwrap_err()
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(ExpenseCategory::Food)]
+    #[case(ExpenseCategory::Groceries)]
+    #[case(ExpenseCategory::Transport)]
+    #[case(ExpenseCategory::Lodging)]
+    #[case(ExpenseCategory::Entertainment)]
+    #[case(ExpenseCategory::Shopping)]
+    #[case(ExpenseCategory::Utilities)]
+    #[case(ExpenseCategory::Health)]
+    #[case(ExpenseCategory::Other)]
+    fn display_then_parse_roundtrip(#[case] category: ExpenseCategory) {
+        assert_eq!(category, category.to_string().parse().unwrap());
+    }
+}
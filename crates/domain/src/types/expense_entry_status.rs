@@ -1,7 +1,18 @@
-use crate::types::expense_entry_id::ExpenseEntryId;
+use chrono::{DateTime, Utc};
+
+use crate::types::{expense_entry_id::ExpenseEntryId, user_id::UserId};
 
 #[derive(Debug, PartialEq)]
 pub enum ExpenseEntryStatus {
     Active,
-    Inactive { overwritten_by: ExpenseEntryId },
+    Inactive {
+        overwritten_by: ExpenseEntryId,
+    },
+    /// Tombstone version of a deleted expense.
+    /// It keeps the content of the last active version, so that the expense
+    /// can be restored later.
+    Deleted {
+        deleted_by: UserId,
+        deleted_at: DateTime<Utc>,
+    },
 }
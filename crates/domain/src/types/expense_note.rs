@@ -0,0 +1,63 @@
+use std::str::FromStr;
+
+/// Free-text note attached to an expense.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpenseNote {
+    val: String,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("expense note cannot be empty")]
+    Empty,
+
+    #[error(
+        "expense note cannot exceed {} characters long",
+        ExpenseNote::MAX_LENGTH
+    )]
+    TooLong,
+}
+
+impl ExpenseNote {
+    const MAX_LENGTH: usize = 1_000;
+
+    pub fn value(&self) -> String {
+        self.val.clone()
+    }
+}
+
+impl FromStr for ExpenseNote {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(Error::Empty);
+        }
+        if s.chars().count() > Self::MAX_LENGTH {
+            return Err(Error::TooLong);
+        }
+        Ok(Self { val: s.to_string() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, ExpenseNote};
+
+    #[rstest::rstest]
+    #[case("Paid with the shared card", "Paid with the shared card")]
+    #[case("\n  line 1\nline 2  \n", "line 1\nline 2")]
+    fn valid_note(#[case] input: &str, #[case] expected: &str) {
+        let note: ExpenseNote = input.parse().unwrap();
+        assert_eq!(expected, note.value());
+    }
+
+    #[rstest::rstest]
+    #[case("", Error::Empty)]
+    #[case(" \n ", Error::Empty)]
+    #[case(&"a".repeat(1_001), Error::TooLong)]
+    fn invalid_note(#[case] input: &str, #[case] expected: Error) {
+        assert_eq!(expected, input.parse::<ExpenseNote>().unwrap_err());
+    }
+}
@@ -0,0 +1,93 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::types::{money::Money, user_id::UserId};
+
+/// Users who paid an expense in real life, with the amount each of them
+/// paid, in the expense currency.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpensePayers {
+    amounts: HashMap<UserId, Money>,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("an expense must have at least one payer")]
+    Empty,
+
+    #[error("amounts paid cannot be negative")]
+    InvalidAmount,
+
+    #[error("amounts paid must sum up to the total")]
+    AmountsMismatch,
+}
+
+impl ExpensePayers {
+    /// A single user paid the whole `total`.
+    pub fn single(payer_id: UserId, total: Money) -> Self {
+        Self {
+            amounts: HashMap::from_iter(vec![(payer_id, total)]),
+        }
+    }
+
+    pub fn new(amounts: HashMap<UserId, Money>) -> Result<Self, Error> {
+        if amounts.is_empty() {
+            return Err(Error::Empty);
+        }
+        if amounts.values().any(Money::is_negative) {
+            return Err(Error::InvalidAmount);
+        }
+        Ok(Self { amounts })
+    }
+
+    /// Checks that amounts paid are consistent with the expense `total`.
+    pub fn validate(&self, total: Money) -> Result<(), Error> {
+        if self.amounts.values().copied().sum::<Money>() != total {
+            return Err(Error::AmountsMismatch);
+        }
+        Ok(())
+    }
+
+    pub fn amounts(&self) -> &HashMap<UserId, Money> {
+        &self.amounts
+    }
+
+    pub fn contains(&self, user_id: &UserId) -> bool {
+        self.amounts.contains_key(user_id)
+    }
+
+    pub fn users(&self) -> HashSet<UserId> {
+        self.amounts.keys().copied().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{Error, ExpensePayers};
+    use crate::types::{money::Money, user_id::UserId};
+
+    #[rstest::rstest]
+    #[case(vec![1_250, 750], Ok(()))]
+    #[case(vec![2_000], Ok(()))]
+    #[case(vec![1_250, 700], Err(Error::AmountsMismatch))]
+    #[case(vec![2_100, -100], Err(Error::InvalidAmount))]
+    #[case(vec![], Err(Error::Empty))]
+    fn new_and_validate(#[case] cents: Vec<i64>, #[case] expected: Result<(), Error>) {
+        let amounts: HashMap<UserId, Money> = cents
+            .into_iter()
+            .map(|c| (UserId::new_random(), Money::from_cents(c)))
+            .collect();
+        let actual = ExpensePayers::new(amounts)
+            .and_then(|payers| payers.validate(Money::from_cents(2_000)));
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn single() {
+        let payer = UserId::new_random();
+        let payers = ExpensePayers::single(payer, Money::from_cents(2_000));
+        assert!(payers.contains(&payer));
+        assert_eq!(Ok(()), payers.validate(Money::from_cents(2_000)));
+    }
+}
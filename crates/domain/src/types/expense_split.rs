@@ -0,0 +1,160 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::types::{money::Money, user_id::UserId};
+
+/// Describes how the total of an expense is split between users.
+///
+/// Except for [ExpenseSplit::Equal], the split explicitly lists every user
+/// bearing a part of the expense, the payer included if they bear one.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExpenseSplit {
+    /// Total is split equally between the payer and the participants.
+    Equal,
+
+    /// Total is split proportionally to the number of shares of each user.
+    Shares { shares: HashMap<UserId, u64> },
+
+    /// Each user bears an exact amount; amounts sum up to the total.
+    Exact { amounts: HashMap<UserId, Money> },
+
+    /// Each user bears a whole percentage of the total; percentages sum up to 100.
+    Percentages { percentages: HashMap<UserId, u64> },
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("split must involve at least one user")]
+    Empty,
+
+    #[error("shares must be between 1 and {}", ExpenseSplit::MAX_SHARES)]
+    InvalidShares,
+
+    #[error("exact amounts must be > 0")]
+    InvalidAmount,
+
+    #[error("exact amounts must sum up to the total")]
+    AmountsMismatch,
+
+    #[error("percentages must be > 0")]
+    InvalidPercentage,
+
+    #[error("percentages must sum up to 100")]
+    PercentagesMismatch,
+}
+
+impl ExpenseSplit {
+    /// Maximum number of shares a single user can hold.
+    pub const MAX_SHARES: u64 = 1_000_000;
+
+    /// Checks that this split is consistent with the expense `total`.
+    pub fn validate(&self, total: Money) -> Result<(), Error> {
+        match self {
+            ExpenseSplit::Equal => Ok(()),
+            ExpenseSplit::Shares { shares } => {
+                if shares.is_empty() {
+                    return Err(Error::Empty);
+                }
+                if shares.values().any(|s| *s == 0 || *s > Self::MAX_SHARES) {
+                    return Err(Error::InvalidShares);
+                }
+                Ok(())
+            }
+            ExpenseSplit::Exact { amounts } => {
+                if amounts.is_empty() {
+                    return Err(Error::Empty);
+                }
+                if amounts.values().any(|a| !a.is_positive()) {
+                    return Err(Error::InvalidAmount);
+                }
+                if amounts.values().copied().sum::<Money>() != total {
+                    return Err(Error::AmountsMismatch);
+                }
+                Ok(())
+            }
+            ExpenseSplit::Percentages { percentages } => {
+                if percentages.is_empty() {
+                    return Err(Error::Empty);
+                }
+                if percentages.values().any(|p| *p == 0) {
+                    return Err(Error::InvalidPercentage);
+                }
+                if percentages
+                    .values()
+                    .try_fold(0u64, |acc, p| acc.checked_add(*p))
+                    != Some(100)
+                {
+                    return Err(Error::PercentagesMismatch);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the users bearing a part of the expense.
+    /// Returns `None` for an equal split, as users are not listed in the split.
+    pub fn users(&self) -> Option<HashSet<UserId>> {
+        match self {
+            ExpenseSplit::Equal => None,
+            ExpenseSplit::Shares { shares } => Some(shares.keys().copied().collect()),
+            ExpenseSplit::Exact { amounts } => Some(amounts.keys().copied().collect()),
+            ExpenseSplit::Percentages { percentages } => {
+                Some(percentages.keys().copied().collect())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::{Error, ExpenseSplit};
+    use crate::types::{money::Money, user_id::UserId};
+
+    fn users_with<T: Copy>(values: &[T]) -> HashMap<UserId, T> {
+        values.iter().map(|v| (UserId::new_random(), *v)).collect()
+    }
+
+    #[rstest::rstest]
+    #[case(vec![2, 1], Ok(()))]
+    #[case(vec![], Err(Error::Empty))]
+    #[case(vec![2, 0], Err(Error::InvalidShares))]
+    #[case(vec![ExpenseSplit::MAX_SHARES, 1], Ok(()))]
+    #[case(vec![ExpenseSplit::MAX_SHARES + 1, 1], Err(Error::InvalidShares))]
+    #[case(vec![u64::MAX, 1], Err(Error::InvalidShares))]
+    fn validate_shares(#[case] shares: Vec<u64>, #[case] expected: Result<(), Error>) {
+        let split = ExpenseSplit::Shares {
+            shares: users_with(&shares),
+        };
+        assert_eq!(expected, split.validate(Money::from_cents(2_000)));
+    }
+
+    #[rstest::rstest]
+    #[case(vec![1_250, 750], Ok(()))]
+    #[case(vec![1_250, 700], Err(Error::AmountsMismatch))]
+    #[case(vec![2_100, -100], Err(Error::InvalidAmount))]
+    #[case(vec![2_000, 0], Err(Error::InvalidAmount))]
+    #[case(vec![], Err(Error::Empty))]
+    fn validate_exact(#[case] cents: Vec<i64>, #[case] expected: Result<(), Error>) {
+        let amounts: Vec<Money> = cents.into_iter().map(Money::from_cents).collect();
+        let split = ExpenseSplit::Exact {
+            amounts: users_with(&amounts),
+        };
+        assert_eq!(expected, split.validate(Money::from_cents(2_000)));
+    }
+
+    #[rstest::rstest]
+    #[case(vec![50, 30, 20], Ok(()))]
+    #[case(vec![100], Ok(()))]
+    #[case(vec![50, 30], Err(Error::PercentagesMismatch))]
+    #[case(vec![100, 10], Err(Error::PercentagesMismatch))]
+    #[case(vec![100, 0], Err(Error::InvalidPercentage))]
+    #[case(vec![u64::MAX, 101], Err(Error::PercentagesMismatch))]
+    #[case(vec![], Err(Error::Empty))]
+    fn validate_percentages(#[case] percentages: Vec<u64>, #[case] expected: Result<(), Error>) {
+        let split = ExpenseSplit::Percentages {
+            percentages: users_with(&percentages),
+        };
+        assert_eq!(expected, split.validate(Money::from_cents(2_000)));
+    }
+}
@@ -0,0 +1,64 @@
+use std::str::FromStr;
+
+/// Short description of an expense, such as "Dinner at Luigi's".
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpenseTitle {
+    val: String,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("expense title cannot be empty")]
+    Empty,
+
+    #[error(
+        "expense title cannot exceed {} characters long",
+        ExpenseTitle::MAX_LENGTH
+    )]
+    TooLong,
+}
+
+impl ExpenseTitle {
+    const MAX_LENGTH: usize = 100;
+
+    pub fn value(&self) -> String {
+        self.val.clone()
+    }
+}
+
+impl FromStr for ExpenseTitle {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(Error::Empty);
+        }
+        if s.chars().count() > Self::MAX_LENGTH {
+            return Err(Error::TooLong);
+        }
+        Ok(Self { val: s.to_string() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, ExpenseTitle};
+
+    #[rstest::rstest]
+    #[case("Dinner", "Dinner")]
+    #[case("  Train tickets ", "Train tickets")]
+    #[case(&"é".repeat(100), &"é".repeat(100))]
+    fn valid_title(#[case] input: &str, #[case] expected: &str) {
+        let title: ExpenseTitle = input.parse().unwrap();
+        assert_eq!(expected, title.value());
+    }
+
+    #[rstest::rstest]
+    #[case("", Error::Empty)]
+    #[case("   ", Error::Empty)]
+    #[case(&"a".repeat(101), Error::TooLong)]
+    fn invalid_title(#[case] input: &str, #[case] expected: Error) {
+        assert_eq!(expected, input.parse::<ExpenseTitle>().unwrap_err());
+    }
+}
@@ -0,0 +1,115 @@
+use std::str::FromStr;
+
+/// Unique public name of a user (`@alice`), by which other users can find
+/// them. Handles are case-insensitive, so they are kept in lowercase.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Handle {
+    val: String,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("handle cannot be empty")]
+    Empty,
+
+    #[error(
+        "handle length must be at least {} characters long",
+        Handle::MIN_LENGTH
+    )]
+    TooSmall,
+
+    #[error("handle length cannot exceed {} characters", Handle::MAX_LENGTH)]
+    TooLong,
+
+    #[error("handle must start with letter")]
+    MustStartWithLetter,
+
+    #[error("handle can only contain ASCII letters, numbers, dashes and underscores")]
+    InvalidChars,
+}
+
+impl Handle {
+    pub const MIN_LENGTH: usize = 3;
+    pub const MAX_LENGTH: usize = 32;
+
+    /// Value without the leading `@`.
+    pub fn value(&self) -> String {
+        self.val.clone()
+    }
+}
+
+/// Parses a handle, with or without its leading `@`.
+impl FromStr for Handle {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.strip_prefix('@').unwrap_or(s);
+
+        if s.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        if s.len() < Self::MIN_LENGTH {
+            return Err(Error::TooSmall);
+        }
+        if s.len() > Self::MAX_LENGTH {
+            return Err(Error::TooLong);
+        }
+
+        let first_char = s.chars().next().expect("s.len() > Self::MIN_LENGTH");
+        if !first_char.is_ascii_alphabetic() {
+            return Err(Error::MustStartWithLetter);
+        }
+
+        if !s
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(Error::InvalidChars);
+        }
+
+        Ok(Self {
+            val: s.to_ascii_lowercase(),
+        })
+    }
+}
+
+impl std::fmt::Display for Handle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "@{}", self.val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, Handle};
+
+    #[rstest::rstest]
+    #[case("abc", "abc")]
+    #[case("@alice", "alice")]
+    #[case("John_Doe", "john_doe")]
+    #[case(" @Bob-Builder ", "bob-builder")]
+    #[case("xYz_09", "xyz_09")]
+    fn valid_handle(#[case] input: &str, #[case] expected: &str) {
+        let handle: Handle = input.parse().unwrap();
+        assert_eq!(expected, handle.value());
+        assert_eq!(format!("@{expected}"), handle.to_string());
+    }
+
+    #[rstest::rstest]
+    #[case("", Error::Empty)]
+    #[case("@", Error::Empty)]
+    #[case("ab", Error::TooSmall)]
+    #[case(&"a".repeat(33), Error::TooLong)]
+    #[case("1abc", Error::MustStartWithLetter)]
+    #[case("_abc", Error::MustStartWithLetter)]
+    #[case("@@abc", Error::MustStartWithLetter)]
+    #[case("john doe", Error::InvalidChars)]
+    #[case("jo@n", Error::InvalidChars)]
+    #[case("usér", Error::InvalidChars)]
+    fn invalid_handle(#[case] input: &str, #[case] expected_err: Error) {
+        let err = input.parse::<Handle>().unwrap_err();
+        assert_eq!(expected_err, err);
+    }
+}
@@ -0,0 +1,85 @@
+use std::{fmt::Display, str::FromStr};
+
+use uuid::Uuid;
+
+const LEN: usize = 64;
+
+/// Secret part of an invitation URL, as 64 lowercase hex characters.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InviteToken {
+    val: String,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("invite token must be 64 hex characters")]
+    InvalidFormat,
+}
+
+impl InviteToken {
+    /// Generates a token from 244 random bits, taken from two random UUIDs.
+    pub fn new_random() -> Self {
+        let val = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+        Self { val }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.val
+    }
+}
+
+/// Parses a token, case-insensitively.
+impl FromStr for InviteToken {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.len() != LEN || !s.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(Error::InvalidFormat);
+        }
+        Ok(Self {
+            val: s.to_ascii_lowercase(),
+        })
+    }
+}
+
+impl Display for InviteToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, InviteToken};
+
+    #[test]
+    fn random_tokens_are_valid_and_distinct() {
+        let a = InviteToken::new_random();
+        let b = InviteToken::new_random();
+        assert_eq!(Ok(a.clone()), a.value().parse());
+        assert_ne!(a, b);
+    }
+
+    #[rstest::rstest]
+    #[case(&"a".repeat(64), &"a".repeat(64))]
+    #[case(&"AB".repeat(32), &"ab".repeat(32))]
+    #[case(&format!(" {} ", "0f".repeat(32)), &"0f".repeat(32))]
+    fn valid_token(#[case] input: &str, #[case] expected: &str) {
+        let token: InviteToken = input.parse().unwrap();
+        assert_eq!(expected, token.value());
+    }
+
+    #[rstest::rstest]
+    #[case("")]
+    #[case(&"a".repeat(63))]
+    #[case(&"a".repeat(65))]
+    #[case(&"g".repeat(64))]
+    #[case(&format!("{}-", "a".repeat(63)))]
+    fn invalid_token(#[case] input: &str) {
+        assert_eq!(
+            Error::InvalidFormat,
+            input.parse::<InviteToken>().unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use std::str::FromStr;
+
+/// Role of a member within a group. The group owner has no role: they are
+/// allowed to do everything in their group.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum MemberRole {
+    /// Manages members, categories and budgets along with the owner.
+    Admin,
+    /// Adds and edits expenses and settlements.
+    #[default]
+    Member,
+    /// Only reads the group.
+    Viewer,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("unknown member role")]
+    Unknown,
+}
+
+impl FromStr for MemberRole {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "admin" => Ok(Self::Admin),
+            "member" => Ok(Self::Member),
+            "viewer" => Ok(Self::Viewer),
+            _ => Err(Error::Unknown),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, MemberRole};
+
+    #[rstest::rstest]
+    #[case("admin", MemberRole::Admin)]
+    #[case("member", MemberRole::Member)]
+    #[case("viewer", MemberRole::Viewer)]
+    #[case(" Viewer ", MemberRole::Viewer)]
+    fn valid_member_role(#[case] input: &str, #[case] expected_role: MemberRole) {
+        let role: MemberRole = input.parse().unwrap();
+        assert_eq!(expected_role, role);
+    }
+
+    #[rstest::rstest]
+    #[case("")]
+    #[case("owner")]
+    #[case("moderator")]
+    #[case("view")]
+    fn invalid_member_role(#[case] input: &str) {
+        let err = input.parse::<MemberRole>().unwrap_err();
+        assert_eq!(Error::Unknown, err);
+    }
+}
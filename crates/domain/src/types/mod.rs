@@ -1,13 +1,45 @@
 pub mod group_id;
 pub mod groupname;
 
+pub mod handle;
+pub mod member_role;
 pub mod role;
 pub mod user_id;
 pub mod username;
 
+pub mod attachment_content_type;
+pub mod attachment_file_name;
+pub mod attachment_id;
+
+pub mod budget_id;
+pub mod budget_period;
+pub mod budget_threshold;
+
+pub mod category_color;
+pub mod category_emoji;
+pub mod category_id;
+pub mod category_name;
+
+pub mod currency;
+pub mod exchange_rate;
+
+pub mod expense_category;
 pub mod expense_entry_id;
 pub mod expense_entry_status;
 pub mod expense_id;
+pub mod expense_note;
+pub mod expense_payers;
+pub mod expense_split;
+pub mod expense_title;
 pub mod money;
 
+pub mod invite_id;
+pub mod invite_token;
+
+pub mod recurrence;
+pub mod recurring_expense_id;
+
+pub mod settlement_id;
+pub mod settlement_status;
+
 mod utils;
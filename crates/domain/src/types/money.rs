@@ -1,10 +1,48 @@
-/// Money represents a monetary amount, in euros.
-#[derive(Debug, PartialEq)]
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// Money represents a monetary amount, in cents.
+///
+/// It is not tied to a currency: the currency is carried by whatever holds
+/// the amount (e.g. the group or the expense).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Money {
     cents: i64,
 }
 
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("amount cannot be empty")]
+    Empty,
+
+    #[error("amount must be a decimal number, such as 12.34")]
+    InvalidFormat,
+
+    #[error("amount cannot have more than {} decimal places", Money::MAX_DECIMALS)]
+    TooManyDecimals,
+
+    #[error("amount is too large")]
+    Overflow,
+}
+
 impl Money {
+    const MAX_DECIMALS: usize = 2;
+
+    /// Largest amount, in absolute value, accepted as an input (10 billion).
+    ///
+    /// It is far below the range of an `i64`, so that adding up the amounts
+    /// of a group does not overflow in practice.
+    pub const MAX_INPUT: Money = Money {
+        cents: 1_000_000_000_000,
+    };
+
+    pub fn zero() -> Self {
+        Self::from_cents(0)
+    }
+
     pub fn from_cents(cents: i64) -> Self {
         Self { cents }
     }
@@ -24,4 +62,291 @@ impl Money {
     pub fn is_negative(&self) -> bool {
         self.cents < 0
     }
+
+    pub fn is_positive(&self) -> bool {
+        self.cents > 0
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.cents == 0
+    }
+
+    pub fn abs(&self) -> Self {
+        Self::from_cents(self.cents.abs())
+    }
+
+    /// Returns this amount if it does not exceed [Money::MAX_INPUT] in
+    /// absolute value.
+    pub fn within_input_bounds(self) -> Result<Self, Error> {
+        if self.cents.unsigned_abs() > Self::MAX_INPUT.cents.unsigned_abs() {
+            return Err(Error::Overflow);
+        }
+        Ok(self)
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.cents.checked_add(rhs.cents).map(Self::from_cents)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.cents.checked_sub(rhs.cents).map(Self::from_cents)
+    }
+
+    /// Adds up all `amounts`, returning `None` on overflow.
+    pub fn checked_sum(amounts: impl IntoIterator<Item = Self>) -> Option<Self> {
+        amounts
+            .into_iter()
+            .try_fold(Self::zero(), |acc, amount| acc.checked_add(amount))
+    }
+
+    /// Splits this amount proportionally to the provided `weights`.
+    ///
+    /// The returned amounts are in the same order as `weights` and always sum
+    /// up exactly to `self`. Remaining cents are given one by one to the
+    /// largest fractional parts; ties are broken by position, so callers must
+    /// provide weights in a deterministic order.
+    ///
+    /// Returns an empty list if `weights` is empty or only contains zeros.
+    pub fn allocate(&self, weights: &[u64]) -> Vec<Money> {
+        let total_weight: u128 = weights.iter().map(|w| *w as u128).sum();
+        if total_weight == 0 {
+            return vec![];
+        }
+
+        let sign = self.cents.signum();
+        let amount = self.cents.unsigned_abs() as u128;
+
+        let mut parts = Vec::with_capacity(weights.len());
+        let mut remainders = Vec::with_capacity(weights.len());
+        for (idx, weight) in weights.iter().enumerate() {
+            let scaled = amount * (*weight as u128);
+            parts.push(scaled / total_weight);
+            remainders.push((scaled % total_weight, idx));
+        }
+
+        let allocated: u128 = parts.iter().sum();
+        let leftover = (amount - allocated) as usize;
+
+        // largest remainder first, lowest position first on ties
+        remainders.sort_by(|(r1, i1), (r2, i2)| r2.cmp(r1).then(i1.cmp(i2)));
+        for (_, idx) in remainders.into_iter().take(leftover) {
+            parts[idx] += 1;
+        }
+
+        parts
+            .into_iter()
+            .map(|part| Money::from_cents(sign * part as i64))
+            .collect()
+    }
+}
+
+/// Parses a decimal amount, such as `12`, `12.3` or `-12.34`.
+impl FromStr for Money {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(Error::Empty);
+        }
+
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, s),
+        };
+        let (units, decimals) = match unsigned.split_once('.') {
+            Some((units, decimals)) => (units, decimals),
+            None => (unsigned, ""),
+        };
+
+        let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if units.is_empty() || !is_digits(units) || !is_digits(decimals) {
+            return Err(Error::InvalidFormat);
+        }
+        if unsigned.contains('.') && decimals.is_empty() {
+            return Err(Error::InvalidFormat);
+        }
+        if decimals.len() > Self::MAX_DECIMALS {
+            return Err(Error::TooManyDecimals);
+        }
+
+        // accumulate with the sign applied to every digit, so that
+        // `i64::MIN` cents can be parsed back
+        let sign = if negative { -1 } else { 1 };
+        let cents = format!("{}{:0<2}", units, decimals)
+            .bytes()
+            .try_fold(0i64, |acc, digit| {
+                acc.checked_mul(10)?
+                    .checked_add(sign * i64::from(digit - b'0'))
+            })
+            .ok_or(Error::Overflow)?;
+
+        Ok(Money::from_cents(cents))
+    }
+}
+
+/// Formats the amount with two decimal places (e.g. `-12.30`).
+impl Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.is_negative() { "-" } else { "" };
+        let cents = self.cents.unsigned_abs();
+        write!(f, "{}{}.{:02}", sign, cents / 100, cents % 100)
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Money::from_cents(self.cents + rhs.cents)
+    }
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, rhs: Self) {
+        self.cents += rhs.cents;
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Money::from_cents(self.cents - rhs.cents)
+    }
+}
+
+impl SubAssign for Money {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.cents -= rhs.cents;
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+
+    fn neg(self) -> Self::Output {
+        Money::from_cents(-self.cents)
+    }
+}
+
+impl std::iter::Sum for Money {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Money::zero(), Add::add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{Error, Money};
+
+    #[rstest::rstest]
+    #[case("12.34", 1234)]
+    #[case("12.3", 1230)]
+    #[case("12", 1200)]
+    #[case("0.01", 1)]
+    #[case("0", 0)]
+    #[case("-12.34", -1234)]
+    #[case("  7.05 ", 705)]
+    #[case("0012.50", 1250)]
+    #[case("92233720368547758.07", i64::MAX)]
+    #[case("-92233720368547758.08", i64::MIN)]
+    fn parse_ok(#[case] s: &str, #[case] expected_cents: i64) {
+        assert_eq!(
+            Money::from_cents(expected_cents),
+            Money::from_str(s).unwrap()
+        );
+    }
+
+    #[rstest::rstest]
+    #[case("", Error::Empty)]
+    #[case("   ", Error::Empty)]
+    #[case("12.345", Error::TooManyDecimals)]
+    #[case("0.001", Error::TooManyDecimals)]
+    #[case("12.", Error::InvalidFormat)]
+    #[case(".5", Error::InvalidFormat)]
+    #[case("-", Error::InvalidFormat)]
+    #[case("+12", Error::InvalidFormat)]
+    #[case("12,34", Error::InvalidFormat)]
+    #[case("1e3", Error::InvalidFormat)]
+    #[case("--1", Error::InvalidFormat)]
+    #[case("92233720368547758.08", Error::Overflow)]
+    #[case("-92233720368547758.09", Error::Overflow)]
+    #[case("99999999999999999999", Error::Overflow)]
+    fn parse_err(#[case] s: &str, #[case] expected: Error) {
+        assert_eq!(expected, Money::from_str(s).unwrap_err());
+    }
+
+    #[rstest::rstest]
+    #[case(1234, "12.34")]
+    #[case(1230, "12.30")]
+    #[case(5, "0.05")]
+    #[case(0, "0.00")]
+    #[case(-5, "-0.05")]
+    #[case(-1234, "-12.34")]
+    #[case(i64::MIN, "-92233720368547758.08")]
+    fn display(#[case] cents: i64, #[case] expected: &str) {
+        assert_eq!(expected, Money::from_cents(cents).to_string());
+    }
+
+    #[rstest::rstest]
+    #[case(1234)]
+    #[case(-1)]
+    #[case(i64::MAX)]
+    #[case(i64::MIN)]
+    fn display_then_parse_roundtrip(#[case] cents: i64) {
+        let money = Money::from_cents(cents);
+        assert_eq!(money, Money::from_str(&money.to_string()).unwrap());
+    }
+
+    #[rstest::rstest]
+    #[case(1_000_000_000_000, true)]
+    #[case(-1_000_000_000_000, true)]
+    #[case(1_000_000_000_001, false)]
+    #[case(i64::MIN, false)]
+    fn within_input_bounds(#[case] cents: i64, #[case] expected: bool) {
+        let result = Money::from_cents(cents).within_input_bounds();
+        assert_eq!(expected, result.is_ok());
+    }
+
+    #[test]
+    fn checked_sum() {
+        let amounts = [Money::from_cents(i64::MAX - 1), Money::from_cents(1)];
+        assert_eq!(
+            Some(Money::from_cents(i64::MAX)),
+            Money::checked_sum(amounts)
+        );
+        assert_eq!(
+            None,
+            Money::checked_sum([Money::from_cents(i64::MAX), Money::from_cents(1)])
+        );
+        assert_eq!(Some(Money::zero()), Money::checked_sum([]));
+    }
+
+    #[rstest::rstest]
+    #[case(1000, vec![1, 1], vec![500, 500])]
+    #[case(1000, vec![1, 1, 1], vec![334, 333, 333])]
+    #[case(1001, vec![1, 1, 1], vec![334, 334, 333])]
+    #[case(1000, vec![2, 1], vec![667, 333])]
+    #[case(1000, vec![1, 2], vec![333, 667])]
+    #[case(-1000, vec![1, 1, 1], vec![-334, -333, -333])]
+    #[case(1, vec![1, 1, 1], vec![1, 0, 0])]
+    #[case(0, vec![1, 1], vec![0, 0])]
+    #[case(1000, vec![1, 0], vec![1000, 0])]
+    #[case(1000, vec![3333, 3333, 3334], vec![333, 333, 334])]
+    fn allocate(#[case] cents: i64, #[case] weights: Vec<u64>, #[case] expected: Vec<i64>) {
+        let parts = Money::from_cents(cents).allocate(&weights);
+        let parts: Vec<i64> = parts.into_iter().map(|p| p.cents()).collect();
+        assert_eq!(expected, parts);
+        assert_eq!(cents, parts.iter().sum::<i64>());
+    }
+
+    #[rstest::rstest]
+    #[case(vec![])]
+    #[case(vec![0, 0])]
+    fn allocate_without_weight(#[case] weights: Vec<u64>) {
+        assert!(Money::from_cents(1000).allocate(&weights).is_empty());
+    }
 }
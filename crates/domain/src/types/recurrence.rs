@@ -0,0 +1,291 @@
+use std::{fmt::Display, str::FromStr};
+
+use chrono::{DateTime, Datelike, Duration, Months, Utc};
+
+/// How often a recurring expense occurs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum FrequencyError {
+    #[error("frequency must be one of daily, weekly, monthly or yearly")]
+    Unknown,
+}
+
+impl Frequency {
+    pub fn value(&self) -> &'static str {
+        match self {
+            Frequency::Daily => "daily",
+            Frequency::Weekly => "weekly",
+            Frequency::Monthly => "monthly",
+            Frequency::Yearly => "yearly",
+        }
+    }
+}
+
+impl FromStr for Frequency {
+    type Err = FrequencyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "daily" => Ok(Self::Daily),
+            "weekly" => Ok(Self::Weekly),
+            "monthly" => Ok(Self::Monthly),
+            "yearly" => Ok(Self::Yearly),
+            _ => Err(FrequencyError::Unknown),
+        }
+    }
+}
+
+impl Display for Frequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.value())
+    }
+}
+
+/// Schedule of a recurring expense, similar to a simplified RRULE:
+/// an occurrence every `interval` days, weeks, months or years, starting
+/// at `starts_at` and until `ends_at` (inclusive), if any.
+///
+/// Occurrences are always computed from `starts_at`, so monthly occurrences
+/// starting on the 31st happen on the last day of shorter months without
+/// drifting afterwards.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recurrence {
+    frequency: Frequency,
+    interval: u32,
+    starts_at: DateTime<Utc>,
+    ends_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("interval must be between 1 and {}", Recurrence::MAX_INTERVAL)]
+    InvalidInterval,
+
+    #[error("end date must not be before start date")]
+    EndsBeforeStart,
+}
+
+impl Recurrence {
+    const MAX_INTERVAL: u32 = 1_000;
+
+    pub fn new(
+        frequency: Frequency,
+        interval: u32,
+        starts_at: DateTime<Utc>,
+        ends_at: Option<DateTime<Utc>>,
+    ) -> Result<Self, Error> {
+        if interval == 0 || interval > Self::MAX_INTERVAL {
+            return Err(Error::InvalidInterval);
+        }
+        if ends_at.is_some_and(|ends_at| ends_at < starts_at) {
+            return Err(Error::EndsBeforeStart);
+        }
+        Ok(Self {
+            frequency,
+            interval,
+            starts_at,
+            ends_at,
+        })
+    }
+
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    pub fn starts_at(&self) -> DateTime<Utc> {
+        self.starts_at
+    }
+
+    pub fn ends_at(&self) -> Option<DateTime<Utc>> {
+        self.ends_at
+    }
+
+    /// Returns the first occurrence strictly after `after`, or the very first
+    /// occurrence if `after` is `None`.
+    /// Returns `None` if the schedule has ended.
+    pub fn next_after(&self, after: Option<DateTime<Utc>>) -> Option<DateTime<Utc>> {
+        let Some(after) = after else {
+            return self.nth(0);
+        };
+
+        // start from an estimate that is never past the expected occurrence
+        let mut n = self.estimate_index(after).saturating_sub(1);
+        loop {
+            let occurrence = self.nth(n)?;
+            if occurrence > after {
+                return Some(occurrence);
+            }
+            n = n.checked_add(1)?;
+        }
+    }
+
+    /// Returns the `n`-th occurrence (starting at 0), or `None` if it is
+    /// after the end of the schedule.
+    fn nth(&self, n: u32) -> Option<DateTime<Utc>> {
+        let steps = n.checked_mul(self.interval)?;
+        let occurrence = match self.frequency {
+            Frequency::Daily => self
+                .starts_at
+                .checked_add_signed(Duration::try_days(steps.into())?)?,
+            Frequency::Weekly => self
+                .starts_at
+                .checked_add_signed(Duration::try_weeks(steps.into())?)?,
+            Frequency::Monthly => self.starts_at.checked_add_months(Months::new(steps))?,
+            Frequency::Yearly => self
+                .starts_at
+                .checked_add_months(Months::new(steps.checked_mul(12)?))?,
+        };
+        match self.ends_at {
+            Some(ends_at) if occurrence > ends_at => None,
+            _ => Some(occurrence),
+        }
+    }
+
+    /// Returns an approximate index of the last occurrence at or before `at`.
+    fn estimate_index(&self, at: DateTime<Utc>) -> u32 {
+        if at < self.starts_at {
+            return 0;
+        }
+        let steps = match self.frequency {
+            Frequency::Daily => (at - self.starts_at).num_days(),
+            Frequency::Weekly => (at - self.starts_at).num_weeks(),
+            Frequency::Monthly => months_between(self.starts_at, at),
+            Frequency::Yearly => months_between(self.starts_at, at) / 12,
+        };
+        u32::try_from(steps / i64::from(self.interval)).unwrap_or(u32::MAX)
+    }
+}
+
+fn months_between(from: DateTime<Utc>, to: DateTime<Utc>) -> i64 {
+    i64::from(to.year() - from.year()) * 12 + i64::from(to.month()) - i64::from(from.month())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+
+    use super::{Error, Frequency, Recurrence};
+
+    fn date(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().to_utc()
+    }
+
+    fn occurrences(recurrence: &Recurrence, count: usize) -> Vec<DateTime<Utc>> {
+        let mut out = Vec::new();
+        let mut previous = None;
+        while out.len() < count {
+            let Some(next) = recurrence.next_after(previous) else {
+                break;
+            };
+            out.push(next);
+            previous = Some(next);
+        }
+        out
+    }
+
+    #[test]
+    fn monthly_on_last_day_does_not_drift() {
+        let recurrence =
+            Recurrence::new(Frequency::Monthly, 1, date("2025-01-31T09:00:00Z"), None).unwrap();
+        assert_eq!(
+            vec![
+                date("2025-01-31T09:00:00Z"),
+                date("2025-02-28T09:00:00Z"),
+                date("2025-03-31T09:00:00Z"),
+                date("2025-04-30T09:00:00Z"),
+            ],
+            occurrences(&recurrence, 4)
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(Frequency::Daily, 3, "2025-01-01T00:00:00Z", "2025-01-04T00:00:00Z")]
+    #[case(Frequency::Weekly, 2, "2025-01-01T00:00:00Z", "2025-01-15T00:00:00Z")]
+    #[case(Frequency::Monthly, 3, "2025-01-15T00:00:00Z", "2025-04-15T00:00:00Z")]
+    #[case(Frequency::Yearly, 1, "2024-02-29T00:00:00Z", "2025-02-28T00:00:00Z")]
+    fn second_occurrence(
+        #[case] frequency: Frequency,
+        #[case] interval: u32,
+        #[case] starts_at: &str,
+        #[case] expected: &str,
+    ) {
+        let recurrence = Recurrence::new(frequency, interval, date(starts_at), None).unwrap();
+        assert_eq!(
+            Some(date(expected)),
+            recurrence.next_after(Some(date(starts_at)))
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(None, Some("2025-01-10T00:00:00Z"))]
+    #[case(Some("2024-12-01T00:00:00Z"), Some("2025-01-10T00:00:00Z"))]
+    #[case(Some("2025-01-10T00:00:00Z"), Some("2025-02-10T00:00:00Z"))]
+    #[case(Some("2025-06-09T23:59:59Z"), Some("2025-06-10T00:00:00Z"))]
+    #[case(Some("2025-06-10T00:00:00Z"), Some("2025-07-10T00:00:00Z"))]
+    #[case(Some("2027-03-25T00:00:00Z"), Some("2027-04-10T00:00:00Z"))]
+    fn next_after(#[case] after: Option<&str>, #[case] expected: Option<&str>) {
+        let recurrence =
+            Recurrence::new(Frequency::Monthly, 1, date("2025-01-10T00:00:00Z"), None).unwrap();
+        assert_eq!(expected.map(date), recurrence.next_after(after.map(date)));
+    }
+
+    #[test]
+    fn ends_at_is_inclusive() {
+        let recurrence = Recurrence::new(
+            Frequency::Weekly,
+            1,
+            date("2025-01-01T00:00:00Z"),
+            Some(date("2025-01-15T00:00:00Z")),
+        )
+        .unwrap();
+        assert_eq!(3, occurrences(&recurrence, 10).len());
+        assert_eq!(
+            None,
+            recurrence.next_after(Some(date("2025-01-15T00:00:00Z")))
+        );
+    }
+
+    #[rstest::rstest]
+    #[case(0, None, Error::InvalidInterval)]
+    #[case(1_001, None, Error::InvalidInterval)]
+    #[case(1, Some("2024-12-31T00:00:00Z"), Error::EndsBeforeStart)]
+    fn invalid_recurrence(
+        #[case] interval: u32,
+        #[case] ends_at: Option<&str>,
+        #[case] expected: Error,
+    ) {
+        let err = Recurrence::new(
+            Frequency::Daily,
+            interval,
+            date("2025-01-01T00:00:00Z"),
+            ends_at.map(date),
+        )
+        .unwrap_err();
+        assert_eq!(expected, err);
+    }
+
+    #[rstest::rstest]
+    #[case("daily", Frequency::Daily)]
+    #[case(" Weekly ", Frequency::Weekly)]
+    #[case("MONTHLY", Frequency::Monthly)]
+    #[case("yearly", Frequency::Yearly)]
+    fn valid_frequency(#[case] input: &str, #[case] expected: Frequency) {
+        assert_eq!(expected, input.parse().unwrap());
+    }
+
+    #[test]
+    fn invalid_frequency() {
+        assert!("hourly".parse::<Frequency>().is_err());
+    }
+}
@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+
+use crate::types::user_id::UserId;
+
+#[derive(Debug, PartialEq)]
+pub enum SettlementStatus {
+    Active,
+    Cancelled {
+        cancelled_by: UserId,
+        cancelled_at: DateTime<Utc>,
+    },
+}
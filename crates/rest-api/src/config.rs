@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{path::PathBuf, str::FromStr};
 
 use serde::Deserialize;
 
@@ -13,6 +13,14 @@ pub struct Config {
 
     /// Logging configuration.
     pub log: LogConfig,
+
+    /// Background tasks configuration.
+    #[serde(default)]
+    pub scheduler: SchedulerConfig,
+
+    /// Expense attachments configuration.
+    #[serde(default)]
+    pub attachments: AttachmentsConfig,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -21,6 +29,49 @@ pub struct LogConfig {
     pub level: String,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct SchedulerConfig {
+    /// Seconds between two runs of the recurring expenses task.
+    pub recurring_expenses_period_secs: u64,
+
+    /// Seconds between two removals of orphaned attachment files.
+    pub attachments_cleanup_period_secs: u64,
+}
+
+impl Default for SchedulerConfig {
+    fn default() -> Self {
+        Self {
+            recurring_expenses_period_secs: 60,
+            attachments_cleanup_period_secs: 3600,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct AttachmentsConfig {
+    /// Directory in which the content of attachments is stored.
+    pub dir: PathBuf,
+
+    /// Maximum size of an attachment, in bytes.
+    pub max_size_bytes: u64,
+
+    /// Days during which the attachments of a deleted expense are kept, in
+    /// case the expense is restored.
+    pub deleted_expense_retention_days: u64,
+}
+
+impl Default for AttachmentsConfig {
+    fn default() -> Self {
+        Self {
+            dir: PathBuf::from("attachments"),
+            max_size_bytes: 10 * 1024 * 1024,
+            deleted_expense_retention_days: 30,
+        }
+    }
+}
+
 impl FromStr for Config {
     type Err = serde_yaml_ng::Error;
 
@@ -117,6 +117,16 @@ impl From<domain::types::username::Error> for ApiError {
     }
 }
 
+impl From<domain::types::handle::Error> for ApiError {
+    fn from(err: domain::types::handle::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
 impl From<email_address::Error> for ApiError {
     fn from(err: email_address::Error) -> Self {
         Self {
@@ -147,6 +157,226 @@ impl From<domain::types::groupname::Error> for ApiError {
     }
 }
 
+impl From<domain::types::member_role::Error> for ApiError {
+    fn from(err: domain::types::member_role::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::currency::Error> for ApiError {
+    fn from(err: domain::types::currency::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::exchange_rate::Error> for ApiError {
+    fn from(err: domain::types::exchange_rate::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::expense_title::Error> for ApiError {
+    fn from(err: domain::types::expense_title::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::expense_note::Error> for ApiError {
+    fn from(err: domain::types::expense_note::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::expense_category::Error> for ApiError {
+    fn from(err: domain::types::expense_category::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::money::Error> for ApiError {
+    fn from(err: domain::types::money::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::expense_id::Error> for ApiError {
+    fn from(err: domain::types::expense_id::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::budget_id::Error> for ApiError {
+    fn from(err: domain::types::budget_id::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::budget_period::Error> for ApiError {
+    fn from(err: domain::types::budget_period::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::category_color::Error> for ApiError {
+    fn from(err: domain::types::category_color::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::category_emoji::Error> for ApiError {
+    fn from(err: domain::types::category_emoji::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::category_name::Error> for ApiError {
+    fn from(err: domain::types::category_name::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::category_id::Error> for ApiError {
+    fn from(err: domain::types::category_id::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::invite_id::Error> for ApiError {
+    fn from(err: domain::types::invite_id::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::invite_token::Error> for ApiError {
+    fn from(err: domain::types::invite_token::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::recurrence::Error> for ApiError {
+    fn from(err: domain::types::recurrence::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::recurrence::FrequencyError> for ApiError {
+    fn from(err: domain::types::recurrence::FrequencyError) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::recurring_expense_id::Error> for ApiError {
+    fn from(err: domain::types::recurring_expense_id::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::attachment_id::Error> for ApiError {
+    fn from(err: domain::types::attachment_id::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::attachment_file_name::Error> for ApiError {
+    fn from(err: domain::types::attachment_file_name::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
+impl From<domain::types::attachment_content_type::Error> for ApiError {
+    fn from(err: domain::types::attachment_content_type::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
 impl From<domain::types::group_id::Error> for ApiError {
     fn from(err: domain::types::group_id::Error) -> Self {
         Self {
@@ -157,6 +387,16 @@ impl From<domain::types::group_id::Error> for ApiError {
     }
 }
 
+impl From<domain::types::settlement_id::Error> for ApiError {
+    fn from(err: domain::types::settlement_id::Error) -> Self {
+        Self {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        }
+    }
+}
+
 impl From<domain::types::user_id::Error> for ApiError {
     fn from(err: domain::types::user_id::Error) -> Self {
         Self {
@@ -19,15 +19,20 @@ pub async fn register(
     Json(body): Json<RegisterBody>,
 ) -> Result<Json<RegisterResponse>, ApiError> {
     let name = body.username.parse()?;
+    let handle = body.handle.map(|handle| handle.parse()).transpose()?;
     let email = body.email.parse()?;
     let password = body.password.parse()?;
 
     let mut tx = state.db_pool.begin().await?;
 
-    let user_id = CreateUserCommand { email, name }
-        .handle(&mut tx)
-        .await
-        .map_err(create_user_err_to_api_error)?;
+    let user_id = CreateUserCommand {
+        email,
+        name,
+        handle,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(create_user_err_to_api_error)?;
 
     let entry = crate::auth::register::Register { user_id, password }
         .handle(&mut tx)
@@ -103,6 +108,8 @@ pub async fn logout(
 #[derive(Deserialize)]
 pub struct RegisterBody {
     username: String,
+    /// Derived from `username` if missing.
+    handle: Option<String>,
     email: String,
     password: String,
 }
@@ -126,6 +133,11 @@ fn create_user_err_to_api_error(err: CreateUserError) -> ApiError {
             message: Some("email already taken".to_string()),
             detail: None,
         },
+        CreateUserError::HandleAlreadyTaken => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some("handle already taken".to_string()),
+            detail: None,
+        },
         CreateUserError::Database(error) => ApiError {
             kind: ErrorKind::Internal,
             message: None,
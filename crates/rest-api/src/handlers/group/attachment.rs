@@ -0,0 +1,452 @@
+use application::{
+    commands::{
+        create_attachment::{CreateAttachmentCommand, CreateAttachmentError},
+        delete_attachment::{DeleteAttachmentCommand, DeleteAttachmentError},
+    },
+    queries::{
+        get_attachment::{GetAttachmentError, GetAttachmentQuery},
+        get_attachments_for_expense::{
+            GetAttachmentsForExpenseError, GetAttachmentsForExpenseQuery,
+        },
+    },
+};
+use axum::{
+    Json,
+    extract::{Multipart, Path, State, multipart::MultipartError},
+    http::{HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use domain::{
+    entities::Attachment,
+    types::{
+        attachment_content_type::AttachmentContentType, attachment_file_name::AttachmentFileName,
+        attachment_id::AttachmentId, expense_id::ExpenseId, group_id::GroupId,
+    },
+};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ErrorKind},
+    extractors::user::User,
+    state::AppState,
+};
+
+/// Name of the multipart field holding the uploaded file.
+const FILE_FIELD: &str = "file";
+
+pub async fn upload(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path((group_id, expense_id)): Path<(Uuid, Uuid)>,
+    multipart: Multipart,
+) -> Result<Json<CreateResponse>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let expense_id = ExpenseId::new(expense_id)?;
+    let file = read_file(multipart, state.config.attachments.max_size_bytes).await?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let attachment_id = CreateAttachmentCommand {
+        group_id,
+        expense_id,
+        current_user_id: user.id,
+        file_name: file.file_name,
+        content_type: file.content_type,
+        size_bytes: file.content.len() as u64,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(create_attachment_err_to_api_error)?;
+
+    // if the transaction fails to commit, the stored file is orphaned
+    // and removed by the periodic cleanup
+    state
+        .attachments
+        .save(&attachment_id, &file.content)
+        .await
+        .map_err(storage_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(Json(CreateResponse {
+        attachment_id: attachment_id.value(),
+    }))
+}
+
+pub async fn get_all(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path((group_id, expense_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<GetAllResponse>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let expense_id = ExpenseId::new(expense_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let output = GetAttachmentsForExpenseQuery {
+        group_id,
+        expense_id,
+        current_user: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(get_attachments_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    let attachments = output
+        .attachments
+        .into_iter()
+        .map(AttachmentDto::from)
+        .collect();
+    Ok(Json(GetAllResponse { data: attachments }))
+}
+
+pub async fn download(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path((group_id, expense_id, attachment_id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<Response, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let expense_id = ExpenseId::new(expense_id)?;
+    let attachment_id = AttachmentId::new(attachment_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let attachment = GetAttachmentQuery {
+        group_id,
+        expense_id,
+        attachment_id,
+        current_user: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(get_attachment_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    let content = state
+        .attachments
+        .read(&attachment.id)
+        .await
+        .map_err(storage_err_to_api_error)?;
+
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                HeaderValue::from_static(attachment.content_type.value()),
+            ),
+            (
+                header::CONTENT_DISPOSITION,
+                content_disposition(&attachment.file_name),
+            ),
+            (
+                header::X_CONTENT_TYPE_OPTIONS,
+                HeaderValue::from_static("nosniff"),
+            ),
+        ],
+        content,
+    )
+        .into_response())
+}
+
+pub async fn delete(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path((group_id, expense_id, attachment_id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let expense_id = ExpenseId::new(expense_id)?;
+    let attachment_id = AttachmentId::new(attachment_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    DeleteAttachmentCommand {
+        group_id,
+        expense_id,
+        attachment_id,
+        current_user_id: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(delete_attachment_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    // the periodic cleanup removes the file if this fails
+    if let Err(err) = state.attachments.remove(&attachment_id).await {
+        tracing::warn!(
+            attachment_id = %attachment_id,
+            error = %err,
+            "failed to remove attachment content"
+        );
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateResponse {
+    attachment_id: Uuid,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAllResponse {
+    data: Vec<AttachmentDto>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AttachmentDto {
+    id: Uuid,
+    file_name: String,
+    content_type: String,
+    size_bytes: u64,
+    uploaded_by: Uuid,
+    uploaded_at: DateTime<Utc>,
+}
+
+impl From<Attachment> for AttachmentDto {
+    fn from(attachment: Attachment) -> Self {
+        Self {
+            id: attachment.id.value(),
+            file_name: attachment.file_name.value(),
+            content_type: attachment.content_type.to_string(),
+            size_bytes: attachment.size_bytes,
+            uploaded_by: attachment.uploaded_by.value(),
+            uploaded_at: attachment.uploaded_at,
+        }
+    }
+}
+
+struct UploadedFile {
+    file_name: AttachmentFileName,
+    content_type: AttachmentContentType,
+    content: Vec<u8>,
+}
+
+/// Reads the file of a multipart body, rejecting it as soon as it
+/// exceeds `max_size_bytes` or if its content does not match its declared
+/// content type. Other fields are ignored.
+async fn read_file(
+    mut multipart: Multipart,
+    max_size_bytes: u64,
+) -> Result<UploadedFile, ApiError> {
+    while let Some(mut field) = multipart
+        .next_field()
+        .await
+        .map_err(multipart_err_to_api_error)?
+    {
+        if field.name() != Some(FILE_FIELD) {
+            continue;
+        }
+
+        let file_name = field
+            .file_name()
+            .ok_or_else(|| invalid_input("attached file must have a name"))?
+            .parse()?;
+        let content_type: AttachmentContentType = field
+            .content_type()
+            .ok_or_else(|| invalid_input("attached file must have a content type"))?
+            .parse()?;
+
+        let mut content = Vec::new();
+        while let Some(chunk) = field.chunk().await.map_err(multipart_err_to_api_error)? {
+            if (content.len() + chunk.len()) as u64 > max_size_bytes {
+                return Err(invalid_input(&format!(
+                    "attached file cannot exceed {} bytes",
+                    max_size_bytes
+                )));
+            }
+            content.extend_from_slice(&chunk);
+        }
+
+        // the declared type is served back on download, so it must match
+        // what the file actually is
+        if AttachmentContentType::sniff(&content) != Some(content_type) {
+            return Err(invalid_input(&format!(
+                "attached file content is not a valid {} file",
+                content_type
+            )));
+        }
+
+        return Ok(UploadedFile {
+            file_name,
+            content_type,
+            content,
+        });
+    }
+
+    Err(invalid_input(&format!(
+        "missing '{}' field in multipart body",
+        FILE_FIELD
+    )))
+}
+
+/// Builds a `Content-Disposition` header prompting a download under the
+/// original file name, with an ASCII fallback for older clients.
+fn content_disposition(file_name: &AttachmentFileName) -> HeaderValue {
+    let file_name = file_name.value();
+    let ascii_fallback: String = file_name
+        .chars()
+        .map(|c| if c.is_ascii() { c } else { '_' })
+        .collect();
+    let encoded: String = file_name
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect();
+    HeaderValue::from_str(&format!(
+        "attachment; filename=\"{}\"; filename*=UTF-8''{}",
+        ascii_fallback, encoded
+    ))
+    .unwrap_or_else(|_| HeaderValue::from_static("attachment"))
+}
+
+fn invalid_input(message: &str) -> ApiError {
+    ApiError {
+        kind: ErrorKind::InvalidInput,
+        message: Some(message.to_string()),
+        detail: None,
+    }
+}
+
+fn multipart_err_to_api_error(err: MultipartError) -> ApiError {
+    ApiError {
+        kind: ErrorKind::InvalidInput,
+        message: Some(err.body_text()),
+        detail: None,
+    }
+}
+
+fn storage_err_to_api_error(err: std::io::Error) -> ApiError {
+    ApiError {
+        kind: ErrorKind::Internal,
+        message: None,
+        detail: Some(format!("attachment storage error: {}", err)),
+    }
+}
+
+fn create_attachment_err_to_api_error(err: CreateAttachmentError) -> ApiError {
+    match err {
+        CreateAttachmentError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        CreateAttachmentError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        CreateAttachmentError::ExpenseNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("expense not found".to_string()),
+            detail: None,
+        },
+        CreateAttachmentError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("current user is not in group".to_string()),
+        },
+        CreateAttachmentError::EmptyFile => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        CreateAttachmentError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn get_attachments_err_to_api_error(err: GetAttachmentsForExpenseError) -> ApiError {
+    match err {
+        GetAttachmentsForExpenseError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        GetAttachmentsForExpenseError::ExpenseNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("expense not found".to_string()),
+            detail: None,
+        },
+        GetAttachmentsForExpenseError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("current user is not in group".to_string()),
+        },
+        GetAttachmentsForExpenseError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn get_attachment_err_to_api_error(err: GetAttachmentError) -> ApiError {
+    match err {
+        GetAttachmentError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        GetAttachmentError::AttachmentNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("attachment not found".to_string()),
+            detail: None,
+        },
+        GetAttachmentError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("current user is not in group".to_string()),
+        },
+        GetAttachmentError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn delete_attachment_err_to_api_error(err: DeleteAttachmentError) -> ApiError {
+    match err {
+        DeleteAttachmentError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        DeleteAttachmentError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        DeleteAttachmentError::AttachmentNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("attachment not found".to_string()),
+            detail: None,
+        },
+        DeleteAttachmentError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("current user is not in group".to_string()),
+        },
+        DeleteAttachmentError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use application::{
+    backup::BackupDocument,
+    commands::restore_group_backup::{RestoreGroupBackupCommand, RestoreGroupBackupError},
+    queries::get_group_backup::{GetGroupBackupError, GetGroupBackupQuery},
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use domain::types::{group_id::GroupId, user_id::UserId};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ErrorKind},
+    extractors::user::User,
+    state::AppState,
+};
+
+/// Exports the whole group, history included, as a versioned JSON document.
+pub async fn get(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+) -> Result<Json<BackupDocument>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let document = GetGroupBackupQuery {
+        group_id,
+        current_user: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(get_group_backup_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(Json(document))
+}
+
+/// Recreates a group from a document exported by [`get`], owned by the
+/// current user.
+pub async fn restore(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Json(body): Json<RestoreBody>,
+) -> Result<Json<RestoreResponse>, ApiError> {
+    let user_mapping = body
+        .user_mapping
+        .into_iter()
+        .map(|mapping| Ok((mapping.from_user_id, UserId::new(mapping.to_user_id)?)))
+        .collect::<Result<HashMap<_, _>, ApiError>>()?;
+    let name = body.name.map(|name| name.parse()).transpose()?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let group_id = RestoreGroupBackupCommand {
+        current_user_id: user.id,
+        document: body.document,
+        user_mapping,
+        name,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(restore_group_backup_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(Json(RestoreResponse {
+        group_id: group_id.value(),
+    }))
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreBody {
+    document: serde_json::Value,
+    /// User standing for each user of the document.
+    user_mapping: Vec<UserMappingDto>,
+    /// Name of the restored group; defaults to the one in the document.
+    name: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UserMappingDto {
+    from_user_id: Uuid,
+    to_user_id: Uuid,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreResponse {
+    group_id: Uuid,
+}
+
+fn get_group_backup_err_to_api_error(err: GetGroupBackupError) -> ApiError {
+    match err {
+        GetGroupBackupError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        GetGroupBackupError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("only the group owner can back it up".to_string()),
+        },
+        GetGroupBackupError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn restore_group_backup_err_to_api_error(err: RestoreGroupBackupError) -> ApiError {
+    match err {
+        RestoreGroupBackupError::InvalidDocument(_)
+        | RestoreGroupBackupError::UnmappedUser(_)
+        | RestoreGroupBackupError::UserMappedTwice(_)
+        | RestoreGroupBackupError::UserNotFound(_)
+        | RestoreGroupBackupError::GuestUser(_)
+        | RestoreGroupBackupError::UserNotCoMember(_) => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        RestoreGroupBackupError::NameNotAvailable => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        RestoreGroupBackupError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
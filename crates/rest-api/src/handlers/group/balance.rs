@@ -0,0 +1,189 @@
+use application::queries::{
+    get_group_balances::{GetGroupBalancesError, GetGroupBalancesQuery},
+    get_settlement_plan::{GetSettlementPlanError, GetSettlementPlanQuery},
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use domain::types::group_id::GroupId;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ErrorKind},
+    extractors::user::User,
+    state::AppState,
+};
+
+pub async fn get_all(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+) -> Result<Json<GetAllResponse>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let output = GetGroupBalancesQuery {
+        group_id,
+        current_user: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(get_group_balances_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    let balances = output.balances.into_iter().map(BalanceDto::from).collect();
+    Ok(Json(GetAllResponse {
+        currency: output.currency.value(),
+        data: balances,
+    }))
+}
+
+pub async fn get_settlement_plan(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+) -> Result<Json<GetSettlementPlanResponse>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let output = GetSettlementPlanQuery {
+        group_id,
+        current_user: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(get_settlement_plan_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    let transfers = output
+        .transfers
+        .into_iter()
+        .map(TransferDto::from)
+        .collect();
+    Ok(Json(GetSettlementPlanResponse {
+        currency: output.currency.value(),
+        data: transfers,
+    }))
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAllResponse {
+    /// Group currency, in which balances are expressed.
+    currency: String,
+    data: Vec<BalanceDto>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BalanceDto {
+    user: UserDto,
+    /// Positive if the user is owed money, negative if the user owes money.
+    balance_cents: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetSettlementPlanResponse {
+    /// Group currency, in which transfers are expressed.
+    currency: String,
+    data: Vec<TransferDto>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TransferDto {
+    from: UserDto,
+    to: UserDto,
+    amount_cents: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UserDto {
+    id: Uuid,
+    name: String,
+}
+
+impl From<application::queries::get_group_balances::MemberBalance> for BalanceDto {
+    fn from(member_balance: application::queries::get_group_balances::MemberBalance) -> Self {
+        Self {
+            user: member_balance.user.into(),
+            balance_cents: member_balance.balance.cents(),
+        }
+    }
+}
+
+impl From<application::queries::get_group_balances::UserSummary> for UserDto {
+    fn from(user_summary: application::queries::get_group_balances::UserSummary) -> Self {
+        Self {
+            id: user_summary.id.value(),
+            name: user_summary.name.value(),
+        }
+    }
+}
+
+impl From<application::queries::get_settlement_plan::PlannedTransfer> for TransferDto {
+    fn from(transfer: application::queries::get_settlement_plan::PlannedTransfer) -> Self {
+        Self {
+            from: transfer.from.into(),
+            to: transfer.to.into(),
+            amount_cents: transfer.amount.cents(),
+        }
+    }
+}
+
+impl From<application::queries::get_settlement_plan::UserSummary> for UserDto {
+    fn from(user_summary: application::queries::get_settlement_plan::UserSummary) -> Self {
+        Self {
+            id: user_summary.id.value(),
+            name: user_summary.name.value(),
+        }
+    }
+}
+
+fn get_group_balances_err_to_api_error(err: GetGroupBalancesError) -> ApiError {
+    match err {
+        GetGroupBalancesError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        GetGroupBalancesError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("user is not allowed access group balances".to_string()),
+        },
+        GetGroupBalancesError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn get_settlement_plan_err_to_api_error(err: GetSettlementPlanError) -> ApiError {
+    match err {
+        GetSettlementPlanError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        GetSettlementPlanError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("user is not allowed access group settlement plan".to_string()),
+        },
+        GetSettlementPlanError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
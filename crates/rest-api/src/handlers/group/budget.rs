@@ -0,0 +1,454 @@
+use std::ops::Range;
+
+use application::{
+    commands::{
+        create_budget::{CreateBudgetCommand, CreateBudgetError},
+        delete_budget::{DeleteBudgetCommand, DeleteBudgetError},
+        update_budget::{UpdateBudgetCommand, UpdateBudgetError},
+    },
+    queries::{
+        get_budget_alerts_for_group::{GetBudgetAlertsForGroupError, GetBudgetAlertsForGroupQuery},
+        get_budgets_for_group::{
+            BudgetConsumption, GetBudgetsForGroupError, GetBudgetsForGroupQuery,
+        },
+    },
+};
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use chrono::{DateTime, Utc};
+use domain::{
+    entities::BudgetAlert,
+    types::{
+        budget_id::BudgetId, budget_period::BudgetPeriod, category_id::CategoryId,
+        group_id::GroupId, money::Money,
+    },
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ErrorKind},
+    extractors::user::User,
+    state::AppState,
+};
+
+pub async fn create(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+    Json(body): Json<BudgetBody>,
+) -> Result<Json<CreateResponse>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let category_id = body.category_id.map(CategoryId::new).transpose()?;
+    let period = body.period.try_into()?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let budget_id = CreateBudgetCommand {
+        group_id,
+        current_user_id: user.id,
+        category_id,
+        amount: Money::from_cents(body.amount_cents).within_input_bounds()?,
+        period,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(create_budget_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(Json(CreateResponse {
+        budget_id: budget_id.value(),
+    }))
+}
+
+/// Lists the budgets of a group, with their consumption during the period
+/// containing `at` (now by default).
+pub async fn get_all(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+    Query(query): Query<GetAllQuery>,
+) -> Result<Json<GetAllResponse>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let output = GetBudgetsForGroupQuery {
+        group_id,
+        current_user: user.id,
+        at: query.at.unwrap_or_else(Utc::now),
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(get_group_budgets_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(Json(GetAllResponse {
+        currency: output.currency.value(),
+        data: output.budgets.into_iter().map(BudgetDto::from).collect(),
+    }))
+}
+
+pub async fn update(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path((group_id, budget_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<BudgetBody>,
+) -> Result<StatusCode, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let budget_id = BudgetId::new(budget_id)?;
+    let category_id = body.category_id.map(CategoryId::new).transpose()?;
+    let period = body.period.try_into()?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    UpdateBudgetCommand {
+        group_id,
+        budget_id,
+        current_user_id: user.id,
+        category_id,
+        amount: Money::from_cents(body.amount_cents).within_input_bounds()?,
+        period,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(update_budget_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn delete(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path((group_id, budget_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let budget_id = BudgetId::new(budget_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    DeleteBudgetCommand {
+        group_id,
+        budget_id,
+        current_user_id: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(delete_budget_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn get_alerts(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+) -> Result<Json<GetAlertsResponse>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let output = GetBudgetAlertsForGroupQuery {
+        group_id,
+        current_user: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(get_group_budget_alerts_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(Json(GetAlertsResponse {
+        data: output
+            .alerts
+            .into_iter()
+            .map(BudgetAlertDto::from)
+            .collect(),
+    }))
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetBody {
+    /// Custom category whose expenses consume the budget; all expenses of
+    /// the group if missing.
+    category_id: Option<Uuid>,
+    /// Limit in the group currency.
+    amount_cents: i64,
+    period: BudgetPeriodDto,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAllQuery {
+    at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+enum BudgetPeriodDto {
+    Weekly,
+    Monthly,
+    Yearly,
+    /// `startsAt` is inclusive, `endsAt` is exclusive.
+    #[serde(rename_all = "camelCase")]
+    Range {
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+    },
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateResponse {
+    budget_id: Uuid,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAllResponse {
+    /// Group currency, in which amounts are expressed.
+    currency: String,
+    data: Vec<BudgetDto>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BudgetDto {
+    id: Uuid,
+    category_id: Option<Uuid>,
+    amount_cents: i64,
+    period: BudgetPeriodDto,
+    /// Current period, missing outside of the range of the budget.
+    window: Option<WindowDto>,
+    consumed_cents: i64,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WindowDto {
+    starts_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAlertsResponse {
+    data: Vec<BudgetAlertDto>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BudgetAlertDto {
+    budget_id: Uuid,
+    /// Share of the budget reached, in percent.
+    threshold: u8,
+    period_starts_at: DateTime<Utc>,
+    expense_id: Uuid,
+    consumed_cents: i64,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<BudgetPeriodDto> for BudgetPeriod {
+    type Error = domain::types::budget_period::Error;
+
+    fn try_from(period: BudgetPeriodDto) -> Result<Self, Self::Error> {
+        match period {
+            BudgetPeriodDto::Weekly => Ok(BudgetPeriod::Weekly),
+            BudgetPeriodDto::Monthly => Ok(BudgetPeriod::Monthly),
+            BudgetPeriodDto::Yearly => Ok(BudgetPeriod::Yearly),
+            BudgetPeriodDto::Range { starts_at, ends_at } => {
+                BudgetPeriod::range(starts_at, ends_at)
+            }
+        }
+    }
+}
+
+impl From<BudgetPeriod> for BudgetPeriodDto {
+    fn from(period: BudgetPeriod) -> Self {
+        match period {
+            BudgetPeriod::Weekly => BudgetPeriodDto::Weekly,
+            BudgetPeriod::Monthly => BudgetPeriodDto::Monthly,
+            BudgetPeriod::Yearly => BudgetPeriodDto::Yearly,
+            BudgetPeriod::Range { starts_at, ends_at } => {
+                BudgetPeriodDto::Range { starts_at, ends_at }
+            }
+        }
+    }
+}
+
+impl From<BudgetConsumption> for BudgetDto {
+    fn from(consumption: BudgetConsumption) -> Self {
+        let budget = consumption.budget;
+        Self {
+            id: budget.id.value(),
+            category_id: budget.category_id.map(|id| id.value()),
+            amount_cents: budget.amount.cents(),
+            period: budget.period.into(),
+            window: consumption.window.map(|Range { start, end }| WindowDto {
+                starts_at: start,
+                ends_at: end,
+            }),
+            consumed_cents: consumption.consumed.cents(),
+            created_at: budget.created_at,
+        }
+    }
+}
+
+impl From<BudgetAlert> for BudgetAlertDto {
+    fn from(alert: BudgetAlert) -> Self {
+        Self {
+            budget_id: alert.budget_id.value(),
+            threshold: alert.threshold.percent(),
+            period_starts_at: alert.period_starts_at,
+            expense_id: alert.expense_id.value(),
+            consumed_cents: alert.consumed.cents(),
+            created_at: alert.created_at,
+        }
+    }
+}
+
+fn create_budget_err_to_api_error(err: CreateBudgetError) -> ApiError {
+    match err {
+        CreateBudgetError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        CreateBudgetError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        CreateBudgetError::NotAdmin => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("only group owner and admins can manage budgets".to_string()),
+        },
+        CreateBudgetError::CategoryNotFound | CreateBudgetError::InvalidAmount => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        CreateBudgetError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn get_group_budgets_err_to_api_error(err: GetBudgetsForGroupError) -> ApiError {
+    match err {
+        GetBudgetsForGroupError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        GetBudgetsForGroupError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("user is not allowed access group budgets".to_string()),
+        },
+        GetBudgetsForGroupError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn update_budget_err_to_api_error(err: UpdateBudgetError) -> ApiError {
+    match err {
+        UpdateBudgetError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        UpdateBudgetError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        UpdateBudgetError::BudgetNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("budget not found".to_string()),
+            detail: None,
+        },
+        UpdateBudgetError::NotAdmin => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("only group owner and admins can manage budgets".to_string()),
+        },
+        UpdateBudgetError::CategoryNotFound | UpdateBudgetError::InvalidAmount => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        UpdateBudgetError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn delete_budget_err_to_api_error(err: DeleteBudgetError) -> ApiError {
+    match err {
+        DeleteBudgetError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        DeleteBudgetError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        DeleteBudgetError::BudgetNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("budget not found".to_string()),
+            detail: None,
+        },
+        DeleteBudgetError::NotAdmin => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("only group owner and admins can manage budgets".to_string()),
+        },
+        DeleteBudgetError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn get_group_budget_alerts_err_to_api_error(err: GetBudgetAlertsForGroupError) -> ApiError {
+    match err {
+        GetBudgetAlertsForGroupError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        GetBudgetAlertsForGroupError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("user is not allowed access group budgets".to_string()),
+        },
+        GetBudgetAlertsForGroupError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
@@ -0,0 +1,297 @@
+use application::{
+    commands::{
+        create_category::{CreateCategoryCommand, CreateCategoryError},
+        delete_category::{DeleteCategoryCommand, DeleteCategoryError},
+        update_category::{UpdateCategoryCommand, UpdateCategoryError},
+    },
+    queries::get_categories_for_group::{GetCategoriesForGroupError, GetCategoriesForGroupQuery},
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use chrono::{DateTime, Utc};
+use domain::{
+    entities::Category,
+    types::{category_id::CategoryId, group_id::GroupId},
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ErrorKind},
+    extractors::user::User,
+    state::AppState,
+};
+
+pub async fn create(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+    Json(body): Json<CategoryBody>,
+) -> Result<Json<CreateResponse>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let name = body.name.parse()?;
+    let color = body.color.parse()?;
+    let emoji = body.emoji.parse()?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let category_id = CreateCategoryCommand {
+        group_id,
+        current_user_id: user.id,
+        name,
+        color,
+        emoji,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(create_category_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(Json(CreateResponse {
+        category_id: category_id.value(),
+    }))
+}
+
+pub async fn get_all(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+) -> Result<Json<GetAllResponse>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let output = GetCategoriesForGroupQuery {
+        group_id,
+        current_user: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(get_group_categories_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    let categories = output
+        .categories
+        .into_iter()
+        .map(CategoryDto::from)
+        .collect();
+    Ok(Json(GetAllResponse { data: categories }))
+}
+
+pub async fn update(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path((group_id, category_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<CategoryBody>,
+) -> Result<StatusCode, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let category_id = CategoryId::new(category_id)?;
+    let name = body.name.parse()?;
+    let color = body.color.parse()?;
+    let emoji = body.emoji.parse()?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    UpdateCategoryCommand {
+        group_id,
+        category_id,
+        current_user_id: user.id,
+        name,
+        color,
+        emoji,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(update_category_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn delete(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path((group_id, category_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let category_id = CategoryId::new(category_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    DeleteCategoryCommand {
+        group_id,
+        category_id,
+        current_user_id: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(delete_category_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryBody {
+    name: String,
+    /// Hex code, such as `#FF8800`.
+    color: String,
+    emoji: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateResponse {
+    category_id: Uuid,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAllResponse {
+    data: Vec<CategoryDto>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CategoryDto {
+    id: Uuid,
+    name: String,
+    color: String,
+    emoji: String,
+    created_at: DateTime<Utc>,
+}
+
+impl From<Category> for CategoryDto {
+    fn from(category: Category) -> Self {
+        Self {
+            id: category.id.value(),
+            name: category.name.value(),
+            color: category.color.value(),
+            emoji: category.emoji.value(),
+            created_at: category.created_at,
+        }
+    }
+}
+
+fn create_category_err_to_api_error(err: CreateCategoryError) -> ApiError {
+    match err {
+        CreateCategoryError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        CreateCategoryError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        CreateCategoryError::NotAdmin => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("only group owner and admins can manage categories".to_string()),
+        },
+        CreateCategoryError::NameNotAvailable => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some("another category of the group has the same name".to_string()),
+            detail: None,
+        },
+        CreateCategoryError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn get_group_categories_err_to_api_error(err: GetCategoriesForGroupError) -> ApiError {
+    match err {
+        GetCategoriesForGroupError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        GetCategoriesForGroupError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("user is not allowed access group categories".to_string()),
+        },
+        GetCategoriesForGroupError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn update_category_err_to_api_error(err: UpdateCategoryError) -> ApiError {
+    match err {
+        UpdateCategoryError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        UpdateCategoryError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        UpdateCategoryError::CategoryNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("category not found".to_string()),
+            detail: None,
+        },
+        UpdateCategoryError::NotAdmin => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("only group owner and admins can manage categories".to_string()),
+        },
+        UpdateCategoryError::NameNotAvailable => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some("another category of the group has the same name".to_string()),
+            detail: None,
+        },
+        UpdateCategoryError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn delete_category_err_to_api_error(err: DeleteCategoryError) -> ApiError {
+    match err {
+        DeleteCategoryError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        DeleteCategoryError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        DeleteCategoryError::CategoryNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("category not found".to_string()),
+            detail: None,
+        },
+        DeleteCategoryError::NotAdmin => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("only group owner and admins can manage categories".to_string()),
+        },
+        DeleteCategoryError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
@@ -1,16 +1,33 @@
-use std::num::NonZeroUsize;
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    num::NonZeroUsize,
+};
 
 use application::{
-    commands::create_expense::{CreateExpenseCommand, CreateExpenseError, IncludeParticipants},
+    commands::{
+        create_expense::{
+            CreateExpenseCommand, CreateExpenseError, IncludeParticipants, SplitSpecification,
+        },
+        delete_expense::{DeleteExpenseCommand, DeleteExpenseError},
+        restore_expense::{RestoreExpenseCommand, RestoreExpenseError},
+        update_expense::{UpdateExpenseCommand, UpdateExpenseError},
+    },
     pagination::Pagination,
-    queries::get_expenses_for_group::{GetExpensesForGroupError, GetExpensesForGroupQuery},
+    queries::{
+        get_expense_history::{GetExpenseHistoryError, GetExpenseHistoryQuery, VersionStatus},
+        get_expenses_for_group::{GetExpensesForGroupError, GetExpensesForGroupQuery},
+    },
 };
 use axum::{
     Json,
     extract::{Path, Query, State},
+    http::StatusCode,
 };
 use chrono::{DateTime, Utc};
-use domain::types::{group_id::GroupId, money::Money, user_id::UserId};
+use domain::types::{
+    category_id::CategoryId, expense_id::ExpenseId, expense_split::ExpenseSplit, group_id::GroupId,
+    money::Money, user_id::UserId,
+};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -27,27 +44,37 @@ pub async fn create(
     Json(body): Json<CreateBody>,
 ) -> Result<Json<CreateResponse>, ApiError> {
     let group_id = GroupId::new(group_id)?;
-    let total = Money::from_euros(body.total_euros as i64);
-    let participants = match body.participants {
-        Some(participants) => IncludeParticipants::List {
-            participants: participants
-                .into_iter()
-                .map(UserId::new)
-                .collect::<Result<_, _>>()?,
-        },
-        None => IncludeParticipants::All,
-    };
+    let total = body.total.try_into()?;
+    let split = build_split_specification(body.participants, body.split)?;
     let payer_id = UserId::new(body.payer_id)?;
+    let payers = body.payers.map(build_payers).transpose()?;
+    let title = body.title.parse()?;
+    let note = body.note.map(|n| n.parse()).transpose()?;
+    let category = body
+        .category
+        .map(|c| c.parse())
+        .transpose()?
+        .unwrap_or_default();
+    let category_id = body.category_id.map(CategoryId::new).transpose()?;
+    let currency = body.currency.map(|c| c.parse()).transpose()?;
+    let exchange_rate = body.exchange_rate.map(|r| r.parse()).transpose()?;
 
     let mut tx = state.db_pool.begin().await?;
 
     let expense_id = CreateExpenseCommand {
         group_id,
         payer_id,
-        participants,
+        payers,
+        title,
+        note,
+        category,
+        category_id,
+        split,
         total,
         author_id: user.id,
         occured_at: body.occurred_at,
+        currency,
+        exchange_rate,
     }
     .handle(&mut tx)
     .await
@@ -60,6 +87,104 @@ pub async fn create(
     }))
 }
 
+pub async fn update(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path((group_id, expense_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<UpdateBody>,
+) -> Result<StatusCode, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let expense_id = ExpenseId::new(expense_id)?;
+    let total = body.total.try_into()?;
+    let split = build_split_specification(body.participants, body.split)?;
+    let payer_id = UserId::new(body.payer_id)?;
+    let payers = body.payers.map(build_payers).transpose()?;
+    let title = body.title.parse()?;
+    let note = body.note.map(|n| n.parse()).transpose()?;
+    let category = body
+        .category
+        .map(|c| c.parse())
+        .transpose()?
+        .unwrap_or_default();
+    let category_id = body.category_id.map(CategoryId::new).transpose()?;
+    let currency = body.currency.map(|c| c.parse()).transpose()?;
+    let exchange_rate = body.exchange_rate.map(|r| r.parse()).transpose()?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    UpdateExpenseCommand {
+        group_id,
+        expense_id,
+        payer_id,
+        payers,
+        title,
+        note,
+        category,
+        category_id,
+        split,
+        total,
+        author_id: user.id,
+        occured_at: body.occurred_at,
+        currency,
+        exchange_rate,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(update_expense_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn delete(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path((group_id, expense_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let expense_id = ExpenseId::new(expense_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    DeleteExpenseCommand {
+        group_id,
+        expense_id,
+        current_user_id: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(delete_expense_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn restore(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path((group_id, expense_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let expense_id = ExpenseId::new(expense_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    RestoreExpenseCommand {
+        group_id,
+        expense_id,
+        current_user_id: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(restore_expense_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 pub async fn get_all(
     State(state): State<AppState>,
     User(user, _, _): User,
@@ -96,15 +221,66 @@ pub async fn get_all(
     }))
 }
 
+pub async fn get_history(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path((group_id, expense_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<GetHistoryResponse>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let expense_id = ExpenseId::new(expense_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let output = GetExpenseHistoryQuery {
+        group_id,
+        expense_id,
+        current_user: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(get_expense_history_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    let versions = output
+        .versions
+        .into_iter()
+        .map(ExpenseVersionDto::from)
+        .collect();
+    Ok(Json(GetHistoryResponse { data: versions }))
+}
+
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateBody {
     /// If participants is `None`, all group members will be considered
     /// as participants for the new expense.
+    /// Only allowed with an equal split.
     participants: Option<Vec<Uuid>>,
-    total_euros: u64,
+    /// If split is `None`, the total is split equally.
+    split: Option<SplitDto>,
+    title: String,
+    note: Option<String>,
+    /// One of the fixed expense categories, `other` if `None`.
+    category: Option<String>,
+    /// Optional custom category of the group.
+    category_id: Option<Uuid>,
+    /// Either a decimal amount in euros (e.g. `"12.34"`) or an integer
+    /// amount in cents (e.g. `1234`).
+    total: AmountDto,
+    /// ISO 4217 code of the currency the expense was paid in; defaults to
+    /// the group currency.
+    currency: Option<String>,
+    /// Rate to the group currency, as a decimal string (e.g. `"1.0845"`).
+    /// Required if the expense is not in the group currency.
+    exchange_rate: Option<String>,
     occurred_at: DateTime<Utc>,
+    /// Main payer of the expense.
     payer_id: Uuid,
+    /// Amounts paid by each payer, in the expense currency. Must include
+    /// the main payer and sum up to the total. If `None`, the main payer
+    /// paid the whole total.
+    payers: Option<Vec<UserAmountDto>>,
 }
 
 #[derive(Serialize)]
@@ -113,6 +289,78 @@ pub struct CreateResponse {
     expense_id: Uuid,
 }
 
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateBody {
+    /// If participants is `None`, all group members will be considered
+    /// as participants for the new version of the expense.
+    /// Only allowed with an equal split.
+    participants: Option<Vec<Uuid>>,
+    /// If split is `None`, the total is split equally.
+    split: Option<SplitDto>,
+    title: String,
+    note: Option<String>,
+    /// One of the fixed expense categories, `other` if `None`.
+    category: Option<String>,
+    /// Optional custom category of the group.
+    category_id: Option<Uuid>,
+    /// Either a decimal amount in euros (e.g. `"12.34"`) or an integer
+    /// amount in cents (e.g. `1234`).
+    total: AmountDto,
+    /// ISO 4217 code of the currency the expense was paid in; defaults to
+    /// the group currency.
+    currency: Option<String>,
+    /// Rate to the group currency, as a decimal string (e.g. `"1.0845"`).
+    /// Required if the expense is not in the group currency.
+    exchange_rate: Option<String>,
+    occurred_at: DateTime<Utc>,
+    /// Main payer of the expense.
+    payer_id: Uuid,
+    /// Amounts paid by each payer, in the expense currency. Must include
+    /// the main payer and sum up to the total. If `None`, the main payer
+    /// paid the whole total.
+    payers: Option<Vec<UserAmountDto>>,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "mode", rename_all = "camelCase")]
+pub enum SplitDto {
+    Equal,
+    Shares { shares: Vec<UserSharesDto> },
+    Exact { amounts: Vec<UserAmountDto> },
+    Percentages { percentages: Vec<UserPercentageDto> },
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSharesDto {
+    user_id: Uuid,
+    shares: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserAmountDto {
+    user_id: Uuid,
+    amount: AmountDto,
+}
+
+/// An amount provided either as a decimal string in euros or as
+/// an integer number of cents.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub enum AmountDto {
+    Cents(i64),
+    Decimal(String),
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserPercentageDto {
+    user_id: Uuid,
+    percentage: u64,
+}
+
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetAllQuery {
@@ -140,8 +388,16 @@ struct PaginationDto {
 struct ExpenseDto {
     id: Uuid,
     payer: UserDto,
+    payers: Vec<PaidAmountDto>,
     participants: Vec<UserDto>,
-    total_euros: i64,
+    title: String,
+    note: Option<String>,
+    category: String,
+    category_id: Option<Uuid>,
+    total_cents: i64,
+    currency: String,
+    exchange_rate: String,
+    total_in_group_currency_cents: i64,
     occurred_at: DateTime<Utc>,
 }
 
@@ -152,17 +408,211 @@ struct UserDto {
     name: String,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PaidAmountDto {
+    user: UserDto,
+    amount_cents: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetHistoryResponse {
+    data: Vec<ExpenseVersionDto>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExpenseVersionDto {
+    id: Uuid,
+    status: VersionStatusDto,
+    author: UserDto,
+    payer: UserDto,
+    payers: Vec<PaidAmountDto>,
+    participants: Vec<UserDto>,
+    title: String,
+    note: Option<String>,
+    category: String,
+    category_id: Option<Uuid>,
+    total_cents: i64,
+    currency: String,
+    exchange_rate: String,
+    occurred_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+    changes: Option<ExpenseChangesDto>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+enum VersionStatusDto {
+    Active,
+    Overwritten,
+    Deleted,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExpenseChangesDto {
+    title: Option<ChangeDto<String>>,
+    note: Option<ChangeDto<Option<String>>>,
+    category: Option<ChangeDto<String>>,
+    category_id: Option<ChangeDto<Option<Uuid>>>,
+    total_cents: Option<ChangeDto<i64>>,
+    currency: Option<ChangeDto<String>>,
+    exchange_rate: Option<ChangeDto<String>>,
+    payer: Option<ChangeDto<UserDto>>,
+    payers: Option<ChangeDto<Vec<PaidAmountDto>>>,
+    split: Option<ChangeDto<SplitDetailsDto>>,
+    participants_added: Vec<UserDto>,
+    participants_removed: Vec<UserDto>,
+    occurred_at: Option<ChangeDto<DateTime<Utc>>>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "mode", rename_all = "camelCase")]
+pub enum SplitDetailsDto {
+    Equal,
+    Shares { shares: Vec<UserWeightDto> },
+    Exact { amounts: Vec<UserWeightDto> },
+    Percentages { percentages: Vec<UserWeightDto> },
+}
+
+/// Number of shares, amount in cents or percentage borne by a user.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserWeightDto {
+    user_id: Uuid,
+    value: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChangeDto<T> {
+    before: T,
+    after: T,
+}
+
+impl From<application::queries::get_expense_history::ExpenseVersion> for ExpenseVersionDto {
+    fn from(version: application::queries::get_expense_history::ExpenseVersion) -> Self {
+        let user_dto = |u: application::queries::get_expense_history::UserSummary| UserDto {
+            id: u.id.value(),
+            name: u.name.value(),
+        };
+        let paid_amount_dtos =
+            |paid: Vec<application::queries::get_expense_history::PaidAmount>| {
+                paid.into_iter()
+                    .map(|p| PaidAmountDto {
+                        user: user_dto(p.user),
+                        amount_cents: p.amount.cents(),
+                    })
+                    .collect::<Vec<_>>()
+            };
+        Self {
+            id: version.id.value(),
+            status: match version.status {
+                VersionStatus::Active => VersionStatusDto::Active,
+                VersionStatus::Overwritten => VersionStatusDto::Overwritten,
+                VersionStatus::Deleted => VersionStatusDto::Deleted,
+            },
+            author: user_dto(version.author),
+            payer: user_dto(version.payer),
+            payers: paid_amount_dtos(version.payers),
+            participants: version.participants.into_iter().map(user_dto).collect(),
+            title: version.title.value(),
+            note: version.note.map(|note| note.value()),
+            category: version.category.to_string(),
+            category_id: version.category_id.map(|id| id.value()),
+            total_cents: version.total.cents(),
+            currency: version.currency.value(),
+            exchange_rate: version.exchange_rate.to_string(),
+            occurred_at: version.occurred_at,
+            created_at: version.created_at,
+            changes: version.changes.map(|changes| ExpenseChangesDto {
+                title: changes.title.map(|c| ChangeDto {
+                    before: c.before.value(),
+                    after: c.after.value(),
+                }),
+                note: changes.note.map(|c| ChangeDto {
+                    before: c.before.map(|note| note.value()),
+                    after: c.after.map(|note| note.value()),
+                }),
+                category: changes.category.map(|c| ChangeDto {
+                    before: c.before.to_string(),
+                    after: c.after.to_string(),
+                }),
+                category_id: changes.category_id.map(|c| ChangeDto {
+                    before: c.before.map(|id| id.value()),
+                    after: c.after.map(|id| id.value()),
+                }),
+                total_cents: changes.total.map(|c| ChangeDto {
+                    before: c.before.cents(),
+                    after: c.after.cents(),
+                }),
+                currency: changes.currency.map(|c| ChangeDto {
+                    before: c.before.value(),
+                    after: c.after.value(),
+                }),
+                exchange_rate: changes.exchange_rate.map(|c| ChangeDto {
+                    before: c.before.to_string(),
+                    after: c.after.to_string(),
+                }),
+                payer: changes.payer.map(|c| ChangeDto {
+                    before: user_dto(c.before),
+                    after: user_dto(c.after),
+                }),
+                payers: changes.payers.map(|c| ChangeDto {
+                    before: paid_amount_dtos(c.before),
+                    after: paid_amount_dtos(c.after),
+                }),
+                split: changes.split.map(|c| ChangeDto {
+                    before: c.before.into(),
+                    after: c.after.into(),
+                }),
+                participants_added: changes
+                    .participants_added
+                    .into_iter()
+                    .map(user_dto)
+                    .collect(),
+                participants_removed: changes
+                    .participants_removed
+                    .into_iter()
+                    .map(user_dto)
+                    .collect(),
+                occurred_at: changes.occurred_at.map(|c| ChangeDto {
+                    before: c.before,
+                    after: c.after,
+                }),
+            }),
+        }
+    }
+}
+
 impl From<application::queries::get_expenses_for_group::GroupExpense> for ExpenseDto {
     fn from(group_expense: application::queries::get_expenses_for_group::GroupExpense) -> Self {
         Self {
             id: group_expense.id.value(),
             payer: group_expense.payer.into(),
+            payers: group_expense
+                .payers
+                .into_iter()
+                .map(|p| PaidAmountDto {
+                    user: p.user.into(),
+                    amount_cents: p.amount.cents(),
+                })
+                .collect(),
             participants: group_expense
                 .participants
                 .into_iter()
                 .map(UserDto::from)
                 .collect(),
-            total_euros: group_expense.total.euros(),
+            title: group_expense.title.value(),
+            note: group_expense.note.map(|note| note.value()),
+            category: group_expense.category.to_string(),
+            category_id: group_expense.category_id.map(|id| id.value()),
+            total_cents: group_expense.total.cents(),
+            currency: group_expense.currency.value(),
+            exchange_rate: group_expense.exchange_rate.to_string(),
+            total_in_group_currency_cents: group_expense.total_in_group_currency.cents(),
             occurred_at: group_expense.occurred_at,
         }
     }
@@ -177,6 +627,39 @@ impl From<application::queries::get_expenses_for_group::UserSummary> for UserDto
     }
 }
 
+impl From<ExpenseSplit> for SplitDetailsDto {
+    fn from(split: ExpenseSplit) -> Self {
+        let weights = |values: Vec<(UserId, i64)>| {
+            let mut weights: Vec<UserWeightDto> = values
+                .into_iter()
+                .map(|(user_id, value)| UserWeightDto {
+                    user_id: user_id.value(),
+                    value,
+                })
+                .collect();
+            weights.sort_by_key(|w| w.user_id);
+            weights
+        };
+        match split {
+            ExpenseSplit::Equal => SplitDetailsDto::Equal,
+            ExpenseSplit::Shares { shares } => SplitDetailsDto::Shares {
+                shares: weights(shares.into_iter().map(|(u, s)| (u, s as i64)).collect()),
+            },
+            ExpenseSplit::Exact { amounts } => SplitDetailsDto::Exact {
+                amounts: weights(amounts.into_iter().map(|(u, a)| (u, a.cents())).collect()),
+            },
+            ExpenseSplit::Percentages { percentages } => SplitDetailsDto::Percentages {
+                percentages: weights(
+                    percentages
+                        .into_iter()
+                        .map(|(u, p)| (u, p as i64))
+                        .collect(),
+                ),
+            },
+        }
+    }
+}
+
 impl From<Pagination> for PaginationDto {
     fn from(p: Pagination) -> Self {
         Self {
@@ -186,6 +669,90 @@ impl From<Pagination> for PaginationDto {
     }
 }
 
+impl TryFrom<AmountDto> for Money {
+    type Error = domain::types::money::Error;
+
+    fn try_from(amount: AmountDto) -> Result<Self, Self::Error> {
+        let amount = match amount {
+            AmountDto::Cents(cents) => Money::from_cents(cents),
+            AmountDto::Decimal(decimal) => decimal.parse()?,
+        };
+        amount.within_input_bounds()
+    }
+}
+
+pub(super) fn build_split_specification(
+    participants: Option<Vec<Uuid>>,
+    split: Option<SplitDto>,
+) -> Result<SplitSpecification, ApiError> {
+    let split = split.unwrap_or(SplitDto::Equal);
+    if participants.is_some() && !matches!(split, SplitDto::Equal) {
+        return Err(ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some("participants can only be provided with an equal split".to_string()),
+            detail: None,
+        });
+    }
+
+    Ok(match split {
+        SplitDto::Equal => SplitSpecification::Equal {
+            participants: match participants {
+                Some(participants) => IncludeParticipants::List {
+                    participants: participants
+                        .into_iter()
+                        .map(UserId::new)
+                        .collect::<Result<_, _>>()?,
+                },
+                None => IncludeParticipants::All,
+            },
+        },
+        SplitDto::Shares { shares } => SplitSpecification::Shares {
+            shares: into_user_map(shares.into_iter().map(|s| (s.user_id, s.shares)))?,
+        },
+        SplitDto::Exact { amounts } => SplitSpecification::Exact {
+            amounts: into_user_map(
+                amounts
+                    .into_iter()
+                    .map(|a| a.amount.try_into().map(|amount| (a.user_id, amount)))
+                    .collect::<Result<Vec<_>, _>>()?,
+            )?,
+        },
+        SplitDto::Percentages { percentages } => SplitSpecification::Percentages {
+            percentages: into_user_map(percentages.into_iter().map(|p| (p.user_id, p.percentage)))?,
+        },
+    })
+}
+
+fn build_payers(payers: Vec<UserAmountDto>) -> Result<HashMap<UserId, Money>, ApiError> {
+    into_user_map(
+        payers
+            .into_iter()
+            .map(|p| p.amount.try_into().map(|amount| (p.user_id, amount)))
+            .collect::<Result<Vec<_>, _>>()?,
+    )
+}
+
+fn into_user_map<T>(
+    values: impl IntoIterator<Item = (Uuid, T)>,
+) -> Result<HashMap<UserId, T>, ApiError> {
+    let mut map = HashMap::new();
+    for (user_id, value) in values {
+        match map.entry(UserId::new(user_id)?) {
+            Entry::Occupied(_) => {
+                return Err(ApiError {
+                    kind: ErrorKind::InvalidInput,
+                    message: Some("a user must appear at most once in the split".to_string()),
+                    detail: None,
+                });
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+            }
+        }
+    }
+    Ok(map)
+}
+
 fn create_expense_err_to_api_error(err: CreateExpenseError) -> ApiError {
     match err {
         CreateExpenseError::GroupNotFound => ApiError {
@@ -193,9 +760,14 @@ fn create_expense_err_to_api_error(err: CreateExpenseError) -> ApiError {
             message: Some("group not found".to_string()),
             detail: None,
         },
+        CreateExpenseError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
         CreateExpenseError::InvalidTotal => ApiError {
             kind: ErrorKind::InvalidInput,
-            message: Some("total_euros must be a positive amount".to_string()),
+            message: Some("total must be a positive amount".to_string()),
             detail: None,
         },
         CreateExpenseError::PayerIsNotGroupMember => ApiError {
@@ -208,6 +780,11 @@ fn create_expense_err_to_api_error(err: CreateExpenseError) -> ApiError {
             message: None,
             detail: Some("current user is not in group".to_string()),
         },
+        CreateExpenseError::ReadOnlyMember => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: Some(err.to_string()),
+            detail: None,
+        },
         CreateExpenseError::ParticipantNotFound => ApiError {
             kind: ErrorKind::InvalidInput,
             message: Some(
@@ -215,6 +792,26 @@ fn create_expense_err_to_api_error(err: CreateExpenseError) -> ApiError {
             ),
             detail: None,
         },
+        CreateExpenseError::CategoryNotFound => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some("category does not exist in the group".to_string()),
+            detail: None,
+        },
+        CreateExpenseError::InvalidSplit(err) => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        CreateExpenseError::InvalidPayers(err) => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        CreateExpenseError::InvalidCurrency(err) => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        },
         CreateExpenseError::Database(error) => ApiError {
             kind: ErrorKind::Internal,
             message: None,
@@ -223,6 +820,178 @@ fn create_expense_err_to_api_error(err: CreateExpenseError) -> ApiError {
     }
 }
 
+fn update_expense_err_to_api_error(err: UpdateExpenseError) -> ApiError {
+    match err {
+        UpdateExpenseError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        UpdateExpenseError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        UpdateExpenseError::ExpenseNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("expense not found".to_string()),
+            detail: None,
+        },
+        UpdateExpenseError::InvalidTotal => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some("total must be a positive amount".to_string()),
+            detail: None,
+        },
+        UpdateExpenseError::PayerIsNotGroupMember => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some("payer does not exist in the group".to_string()),
+            detail: None,
+        },
+        UpdateExpenseError::AuthorNotInGroup => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("current user is not in group".to_string()),
+        },
+        UpdateExpenseError::ReadOnlyMember => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        UpdateExpenseError::ParticipantNotFound => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(
+                "at least one participant in the list does not belong to the group".to_string(),
+            ),
+            detail: None,
+        },
+        UpdateExpenseError::ConcurrentUpdate => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some("expense has been updated concurrently, please retry".to_string()),
+            detail: None,
+        },
+        UpdateExpenseError::CategoryNotFound => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some("category does not exist in the group".to_string()),
+            detail: None,
+        },
+        UpdateExpenseError::InvalidSplit(err) => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        UpdateExpenseError::InvalidPayers(err) => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        UpdateExpenseError::InvalidCurrency(err) => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        UpdateExpenseError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn delete_expense_err_to_api_error(err: DeleteExpenseError) -> ApiError {
+    match err {
+        DeleteExpenseError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        DeleteExpenseError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        DeleteExpenseError::ExpenseNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("expense not found".to_string()),
+            detail: None,
+        },
+        DeleteExpenseError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("current user is not in group".to_string()),
+        },
+        DeleteExpenseError::ConcurrentUpdate => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some("expense has been updated concurrently, please retry".to_string()),
+            detail: None,
+        },
+        DeleteExpenseError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn restore_expense_err_to_api_error(err: RestoreExpenseError) -> ApiError {
+    match err {
+        RestoreExpenseError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        RestoreExpenseError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        RestoreExpenseError::ExpenseNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("expense not found".to_string()),
+            detail: None,
+        },
+        RestoreExpenseError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("current user is not in group".to_string()),
+        },
+        RestoreExpenseError::NotDeleted => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some("expense is not deleted".to_string()),
+            detail: None,
+        },
+        RestoreExpenseError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn get_expense_history_err_to_api_error(err: GetExpenseHistoryError) -> ApiError {
+    match err {
+        GetExpenseHistoryError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        GetExpenseHistoryError::ExpenseNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("expense not found".to_string()),
+            detail: None,
+        },
+        GetExpenseHistoryError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("user is not allowed access group expenses".to_string()),
+        },
+        GetExpenseHistoryError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
 fn get_group_expenses_err_to_api_error(err: GetExpensesForGroupError) -> ApiError {
     match err {
         GetExpensesForGroupError::GroupNotFound => ApiError {
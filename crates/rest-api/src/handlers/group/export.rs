@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use application::queries::{
+    export_expenses_for_group::{
+        ExportExpensesForGroupError, ExportExpensesForGroupQuery, ExportedExpense,
+    },
+    get_expenses_for_group::UserSummary,
+};
+use axum::{
+    extract::{Path, State},
+    http::{HeaderValue, header},
+    response::{IntoResponse, Response},
+};
+use domain::types::{category_id::CategoryId, category_name::CategoryName, group_id::GroupId};
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ErrorKind},
+    extractors::user::User,
+    state::AppState,
+};
+
+/// Returns every active expense of the group as a CSV file, one row per
+/// expense followed by one share column per member.
+///
+/// Amounts are given both as decimals and in cents; shares are expressed in
+/// the group currency. The file is built in memory before being sent.
+pub async fn expenses_csv(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+) -> Result<Response, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let output = ExportExpensesForGroupQuery {
+        group_id,
+        current_user: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(export_expenses_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record(header_record(&output.members, &output.currency.value()))
+        .expect("writing to memory cannot fail");
+    for expense in &output.expenses {
+        writer
+            .write_record(expense_record(expense, &output.members, &output.categories))
+            .expect("writing to memory cannot fail");
+    }
+    let csv = writer.into_inner().expect("writing to memory cannot fail");
+
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("text/csv; charset=utf-8"),
+            ),
+            (
+                header::CONTENT_DISPOSITION,
+                HeaderValue::from_static("attachment; filename=\"expenses.csv\""),
+            ),
+        ],
+        csv,
+    )
+        .into_response())
+}
+
+fn header_record(members: &[UserSummary], group_currency: &str) -> Vec<String> {
+    let mut record: Vec<String> = [
+        "id",
+        "date",
+        "title",
+        "note",
+        "category",
+        "custom_category",
+        "payer",
+        "paid_by",
+        "participants",
+        "total",
+        "total_cents",
+        "currency",
+        "exchange_rate",
+        "total_in_group_currency",
+        "total_in_group_currency_cents",
+        "author",
+        "created_at",
+    ]
+    .into_iter()
+    .map(String::from)
+    .collect();
+
+    let mut name_counts: HashMap<String, usize> = HashMap::new();
+    for member in members {
+        *name_counts.entry(member.name.value()).or_default() += 1;
+    }
+    for member in members {
+        let name = member.name.value();
+        // homonyms are told apart by their id
+        let label = if name_counts[&name] > 1 {
+            format!("{} ({})", name, member.id.value())
+        } else {
+            name
+        };
+        record.push(escape_formula(format!(
+            "share {} ({})",
+            label, group_currency
+        )));
+    }
+    record
+}
+
+fn expense_record(
+    exported: &ExportedExpense,
+    members: &[UserSummary],
+    categories: &HashMap<CategoryId, CategoryName>,
+) -> Vec<String> {
+    let expense = &exported.expense;
+    let mut record = vec![
+        expense.id.value().to_string(),
+        expense.occurred_at.format("%Y-%m-%d").to_string(),
+        escape_formula(expense.title.value()),
+        escape_formula(
+            expense
+                .note
+                .as_ref()
+                .map(|note| note.value())
+                .unwrap_or_default(),
+        ),
+        expense.category.to_string(),
+        escape_formula(
+            expense
+                .category_id
+                .and_then(|id| categories.get(&id))
+                .map(|name| name.value())
+                .unwrap_or_default(),
+        ),
+        escape_formula(expense.payer.name.value()),
+        escape_formula(
+            expense
+                .payers
+                .iter()
+                .map(|paid| format!("{}: {}", paid.user.name.value(), paid.amount))
+                .collect::<Vec<_>>()
+                .join("; "),
+        ),
+        escape_formula(
+            expense
+                .participants
+                .iter()
+                .map(|participant| participant.name.value())
+                .collect::<Vec<_>>()
+                .join("; "),
+        ),
+        expense.total.to_string(),
+        expense.total.cents().to_string(),
+        expense.currency.value(),
+        expense.exchange_rate.to_string(),
+        expense.total_in_group_currency.to_string(),
+        expense.total_in_group_currency.cents().to_string(),
+        escape_formula(exported.author.name.value()),
+        exported.created_at.to_rfc3339(),
+    ];
+    for member in members {
+        record.push(
+            exported
+                .shares
+                .get(&member.id)
+                .map(|share| share.to_string())
+                .unwrap_or_default(),
+        );
+    }
+    record
+}
+
+/// Prevents spreadsheets from evaluating user-provided text as a formula,
+/// by prefixing it with a quote when it starts with a formula trigger.
+fn escape_formula(value: String) -> String {
+    if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{}", value)
+    } else {
+        value
+    }
+}
+
+fn export_expenses_err_to_api_error(err: ExportExpensesForGroupError) -> ApiError {
+    match err {
+        ExportExpensesForGroupError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        ExportExpensesForGroupError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("current user is not in group".to_string()),
+        },
+        ExportExpensesForGroupError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
@@ -0,0 +1,146 @@
+use application::commands::{
+    claim_guest::{ClaimGuestCommand, ClaimGuestError},
+    create_guest::{CreateGuestCommand, CreateGuestError},
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use domain::types::{group_id::GroupId, user_id::UserId};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ErrorKind},
+    extractors::user::User,
+    state::AppState,
+};
+
+/// Adds a member known by name only to the group.
+pub async fn create(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+    Json(body): Json<CreateGuestBody>,
+) -> Result<Json<CreateResponse>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let name = body.name.parse()?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let user_id = CreateGuestCommand {
+        group_id,
+        name,
+        current_user_id: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(create_guest_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(Json(CreateResponse {
+        user_id: user_id.value(),
+    }))
+}
+
+/// Hands the expenses and settlements of a guest over to a registered member.
+pub async fn claim(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path((group_id, guest_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<ClaimGuestBody>,
+) -> Result<StatusCode, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let guest_id = UserId::new(guest_id)?;
+    let member_id = UserId::new(body.member_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    ClaimGuestCommand {
+        group_id,
+        guest_id,
+        member_id,
+        current_user_id: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(claim_guest_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[derive(Deserialize)]
+pub struct CreateGuestBody {
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClaimGuestBody {
+    member_id: Uuid,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateResponse {
+    user_id: Uuid,
+}
+
+fn create_guest_err_to_api_error(err: CreateGuestError) -> ApiError {
+    match err {
+        CreateGuestError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        CreateGuestError::NotAdmin => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("only group owner and admins can add guests".to_string()),
+        },
+        CreateGuestError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        CreateGuestError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn claim_guest_err_to_api_error(err: ClaimGuestError) -> ApiError {
+    match err {
+        ClaimGuestError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        ClaimGuestError::NotGuest | ClaimGuestError::MemberNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        ClaimGuestError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some(err.to_string()),
+        },
+        ClaimGuestError::GroupArchived | ClaimGuestError::AlreadyTakesPart => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        ClaimGuestError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+
+use application::{
+    commands::import_expenses::{
+        ImportExpensesCommand, ImportExpensesError, ImportedRow, RowOutcome,
+    },
+    import::ImportFormat,
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use domain::types::{group_id::GroupId, user_id::UserId};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ErrorKind},
+    extractors::user::User,
+    state::AppState,
+};
+
+/// Imports the expenses of a CSV file exported by another app.
+///
+/// Rows are saved all together, or not at all if any of them is invalid; the
+/// response reports the outcome of every row either way.
+pub async fn import_expenses(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+    Json(body): Json<ImportBody>,
+) -> Result<Json<ImportResponse>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let mapping = body
+        .mapping
+        .into_iter()
+        .map(|person| Ok((person.name, UserId::new(person.user_id)?)))
+        .collect::<Result<HashMap<_, _>, ApiError>>()?;
+    let exchange_rates = body
+        .exchange_rates
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(currency, rate)| Ok((currency.parse()?, rate.parse()?)))
+        .collect::<Result<HashMap<_, _>, ApiError>>()?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let output = ImportExpensesCommand {
+        group_id,
+        current_user_id: user.id,
+        format: body.format.into(),
+        content: body.content,
+        mapping,
+        exchange_rates,
+        dry_run: body.dry_run,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(import_expenses_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(Json(ImportResponse {
+        committed: output.committed,
+        rows: output.rows.into_iter().map(ImportedRowDto::from).collect(),
+    }))
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportBody {
+    format: ImportFormatDto,
+    /// Content of the exported CSV file.
+    content: String,
+    /// Group member standing for each person named in the file.
+    mapping: Vec<PersonMappingDto>,
+    /// Rates to the group currency by ISO 4217 code, as decimal strings, for
+    /// rows in another currency which do not provide theirs.
+    exchange_rates: Option<HashMap<String, String>>,
+    /// If set, rows are only validated.
+    #[serde(default)]
+    dry_run: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ImportFormatDto {
+    Splitwise,
+    Tricount,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PersonMappingDto {
+    name: String,
+    user_id: Uuid,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportResponse {
+    committed: bool,
+    rows: Vec<ImportedRowDto>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ImportedRowDto {
+    line: u64,
+    description: String,
+    outcome: RowOutcomeDto,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expense_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    settlement_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+enum RowOutcomeDto {
+    Expense,
+    Settlement,
+    Ignored,
+    Failed,
+}
+
+impl From<ImportFormatDto> for ImportFormat {
+    fn from(format: ImportFormatDto) -> Self {
+        match format {
+            ImportFormatDto::Splitwise => ImportFormat::Splitwise,
+            ImportFormatDto::Tricount => ImportFormat::Tricount,
+        }
+    }
+}
+
+impl From<ImportedRow> for ImportedRowDto {
+    fn from(row: ImportedRow) -> Self {
+        let mut dto = Self {
+            line: row.line,
+            description: row.description,
+            outcome: RowOutcomeDto::Failed,
+            expense_id: None,
+            settlement_id: None,
+            reason: None,
+        };
+        match row.outcome {
+            RowOutcome::Expense { expense_id } => {
+                dto.outcome = RowOutcomeDto::Expense;
+                dto.expense_id = Some(expense_id.value());
+            }
+            RowOutcome::Settlement { settlement_id } => {
+                dto.outcome = RowOutcomeDto::Settlement;
+                dto.settlement_id = Some(settlement_id.value());
+            }
+            RowOutcome::Ignored { reason } => {
+                dto.outcome = RowOutcomeDto::Ignored;
+                dto.reason = Some(reason.to_string());
+            }
+            RowOutcome::Failed(err) => {
+                dto.reason = Some(err.to_string());
+            }
+        }
+        dto
+    }
+}
+
+fn import_expenses_err_to_api_error(err: ImportExpensesError) -> ApiError {
+    match err {
+        ImportExpensesError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        ImportExpensesError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        ImportExpensesError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("current user is not in group".to_string()),
+        },
+        ImportExpensesError::InvalidFile(_)
+        | ImportExpensesError::UnmappedPerson(_)
+        | ImportExpensesError::PersonNotInGroup(_) => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        ImportExpensesError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
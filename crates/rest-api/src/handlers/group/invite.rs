@@ -0,0 +1,279 @@
+use application::{
+    commands::{
+        create_invite::{CreateInviteCommand, CreateInviteError},
+        revoke_invite::{RevokeInviteCommand, RevokeInviteError},
+    },
+    queries::get_invites_for_group::{GetInvitesForGroupError, GetInvitesForGroupQuery},
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use chrono::{DateTime, Utc};
+use domain::{
+    entities::{Invite, InviteStatus},
+    types::{group_id::GroupId, invite_id::InviteId, member_role::MemberRole},
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    config::Config,
+    error::{ApiError, ErrorKind},
+    extractors::user::User,
+    state::AppState,
+};
+
+pub async fn create(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+    Json(body): Json<CreateInviteBody>,
+) -> Result<Json<CreateResponse>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let role = match body.role {
+        Some(role) => role.parse()?,
+        None => MemberRole::default(),
+    };
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let invite = CreateInviteCommand {
+        group_id,
+        current_user_id: user.id,
+        role,
+        max_uses: body.max_uses,
+        expires_at: body.expires_at,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(create_invite_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(Json(CreateResponse {
+        invite_id: invite.id.value(),
+        url: accept_url(&state.config, invite.token.value()),
+        token: invite.token.to_string(),
+    }))
+}
+
+/// Lists the invites of a group, including revoked and expired ones.
+pub async fn get_all(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+) -> Result<Json<GetAllResponse>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let invites = GetInvitesForGroupQuery {
+        group_id,
+        current_user: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(get_group_invites_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    let now = Utc::now();
+    Ok(Json(GetAllResponse {
+        data: invites
+            .into_iter()
+            .map(|invite| InviteDto::new(&state.config, invite, now))
+            .collect(),
+    }))
+}
+
+pub async fn revoke(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path((group_id, invite_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let invite_id = InviteId::new(invite_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    RevokeInviteCommand {
+        group_id,
+        invite_id,
+        current_user_id: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(revoke_invite_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// URL to which invitees send their request to join the group, relative
+/// unless a domain is configured.
+fn accept_url(config: &Config, token: &str) -> String {
+    let path = format!("/api/invites/{token}/accept");
+    match &config.domain {
+        Some(domain) => format!("https://{domain}{path}"),
+        None => path,
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateInviteBody {
+    /// One of `admin`, `member` or `viewer`, `member` if `None`.
+    role: Option<String>,
+    /// `1` for a single-use invite, no limit if `None`.
+    max_uses: Option<u32>,
+    expires_at: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateResponse {
+    invite_id: Uuid,
+    token: String,
+    url: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAllResponse {
+    data: Vec<InviteDto>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InviteDto {
+    id: Uuid,
+    token: String,
+    url: String,
+    /// One of `admin`, `member` or `viewer`.
+    role: &'static str,
+    max_uses: Option<u32>,
+    uses: u32,
+    author_id: Uuid,
+    /// One of `active`, `revoked`, `expired` or `exhausted`.
+    status: &'static str,
+    expires_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+impl InviteDto {
+    fn new(config: &Config, invite: Invite, now: DateTime<Utc>) -> Self {
+        let status = match invite.status(now) {
+            InviteStatus::Active => "active",
+            InviteStatus::Revoked => "revoked",
+            InviteStatus::Expired => "expired",
+            InviteStatus::Exhausted => "exhausted",
+        };
+        let role = match invite.role {
+            MemberRole::Admin => "admin",
+            MemberRole::Member => "member",
+            MemberRole::Viewer => "viewer",
+        };
+        Self {
+            id: invite.id.value(),
+            url: accept_url(config, invite.token.value()),
+            token: invite.token.to_string(),
+            role,
+            max_uses: invite.max_uses,
+            uses: invite.uses,
+            author_id: invite.author_id.value(),
+            status,
+            expires_at: invite.expires_at,
+            created_at: invite.created_at,
+            revoked_at: invite.revoked_at,
+        }
+    }
+}
+
+fn create_invite_err_to_api_error(err: CreateInviteError) -> ApiError {
+    match err {
+        CreateInviteError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        CreateInviteError::NotAdmin => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("only group owner and admins can manage invites".to_string()),
+        },
+        CreateInviteError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        CreateInviteError::InvalidExpiry | CreateInviteError::InvalidMaxUses => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        CreateInviteError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn get_group_invites_err_to_api_error(err: GetInvitesForGroupError) -> ApiError {
+    match err {
+        GetInvitesForGroupError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        GetInvitesForGroupError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("user is not allowed access group invites".to_string()),
+        },
+        GetInvitesForGroupError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn revoke_invite_err_to_api_error(err: RevokeInviteError) -> ApiError {
+    match err {
+        RevokeInviteError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        RevokeInviteError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        RevokeInviteError::InviteNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("invite not found".to_string()),
+            detail: None,
+        },
+        RevokeInviteError::NotAdmin => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("only group owner and admins can manage invites".to_string()),
+        },
+        RevokeInviteError::AlreadyRevoked => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        RevokeInviteError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
@@ -2,8 +2,14 @@ use std::num::NonZeroUsize;
 
 use application::{
     commands::{
-        add_group_member::{AddGroupMemberCommand, AddGroupMemberError},
+        add_group_member::{AddGroupMemberCommand, AddGroupMemberError, UserReference},
+        archive_group::{ArchiveGroupCommand, ArchiveGroupError},
+        change_member_role::{ChangeMemberRoleCommand, ChangeMemberRoleError},
         create_empty_group::{CreateEmptyGroupCommand, CreateEmptyGroupError},
+        leave_group::{LeaveGroupCommand, LeaveGroupError},
+        remove_group_member::{RemoveGroupMemberCommand, RemoveGroupMemberError},
+        transfer_group_ownership::{TransferGroupOwnershipCommand, TransferGroupOwnershipError},
+        unarchive_group::{UnarchiveGroupCommand, UnarchiveGroupError},
     },
     pagination::Pagination,
     queries::get_groups_for_user::{GetGroupsForUserError, GetGroupsForUserQuery},
@@ -14,7 +20,9 @@ use axum::{
     http::StatusCode,
 };
 use chrono::{DateTime, Utc};
-use domain::types::{group_id::GroupId, user_id::UserId};
+use domain::types::{
+    currency::Currency, group_id::GroupId, member_role::MemberRole, user_id::UserId,
+};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -24,7 +32,19 @@ use crate::{
     state::AppState,
 };
 
+pub mod attachment;
+pub mod backup;
+pub mod balance;
+pub mod budget;
+pub mod category;
 pub mod expense;
+pub mod export;
+pub mod guest;
+pub mod import;
+pub mod invite;
+pub mod recurring_expense;
+pub mod report;
+pub mod settlement;
 
 pub async fn create(
     State(state): State<AppState>,
@@ -32,12 +52,17 @@ pub async fn create(
     Json(body): Json<CreateBody>,
 ) -> Result<Json<CreateResponse>, ApiError> {
     let groupname = body.name.parse()?;
+    let currency = match body.currency {
+        Some(currency) => currency.parse()?,
+        None => Currency::default(),
+    };
 
     let mut tx = state.db_pool.begin().await?;
 
     let group_id = CreateEmptyGroupCommand {
         groupname,
         owner_id: user.id,
+        currency,
     }
     .handle(&mut tx)
     .await
@@ -57,14 +82,30 @@ pub async fn add_member(
     Json(body): Json<AddMemberBody>,
 ) -> Result<StatusCode, ApiError> {
     let group_id = GroupId::new(group_id)?;
-    let new_member_id = UserId::new(body.user_id)?;
+    let user_to_add = match (body.user_id, body.handle, body.email) {
+        (Some(user_id), None, None) => UserReference::Id(UserId::new(user_id)?),
+        (None, Some(handle), None) => UserReference::Handle(handle.parse()?),
+        (None, None, Some(email)) => UserReference::Email(email.parse()?),
+        _ => {
+            return Err(ApiError {
+                kind: ErrorKind::InvalidInput,
+                message: Some("exactly one of userId, handle or email is required".to_string()),
+                detail: None,
+            });
+        }
+    };
+    let role = match body.role {
+        Some(role) => role.parse()?,
+        None => MemberRole::default(),
+    };
     let current_user_id = user.id;
 
     let mut tx = state.db_pool.begin().await?;
 
     AddGroupMemberCommand {
         group_id,
-        user_id_to_add: new_member_id,
+        user_to_add,
+        role,
         current_user_id,
     }
     .handle(&mut tx)
@@ -76,6 +117,150 @@ pub async fn add_member(
     Ok(StatusCode::NO_CONTENT)
 }
 
+pub async fn change_member_role(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path((group_id, user_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<ChangeMemberRoleBody>,
+) -> Result<StatusCode, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let member_id = UserId::new(user_id)?;
+    let role = body.role.parse()?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    ChangeMemberRoleCommand {
+        group_id,
+        member_id,
+        role,
+        current_user_id: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(change_member_role_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn remove_member(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path((group_id, user_id)): Path<(Uuid, Uuid)>,
+    Query(query): Query<RemoveMemberQuery>,
+) -> Result<StatusCode, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let user_id_to_remove = UserId::new(user_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    RemoveGroupMemberCommand {
+        group_id,
+        user_id_to_remove,
+        current_user_id: user.id,
+        force: query.force,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(remove_member_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn leave(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    LeaveGroupCommand {
+        group_id,
+        current_user_id: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(leave_group_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn transfer_ownership(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+    Json(body): Json<TransferOwnershipBody>,
+) -> Result<StatusCode, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let new_owner_id = UserId::new(body.user_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    TransferGroupOwnershipCommand {
+        group_id,
+        new_owner_id,
+        current_user_id: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(transfer_ownership_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn archive(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    ArchiveGroupCommand {
+        group_id,
+        current_user_id: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(archive_group_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn unarchive(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    UnarchiveGroupCommand {
+        group_id,
+        current_user_id: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(unarchive_group_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 pub async fn get_all(
     State(state): State<AppState>,
     User(user, _, _): User,
@@ -87,6 +272,7 @@ pub async fn get_all(
 
     let output = GetGroupsForUserQuery {
         current_user: user.id,
+        archived: query.archived,
         pagination,
     }
     .handle(&mut tx)
@@ -112,6 +298,8 @@ pub async fn get_all(
 #[derive(Deserialize)]
 pub struct CreateBody {
     name: String,
+    /// ISO 4217 code of the group currency, euros if `None`.
+    currency: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -123,14 +311,43 @@ pub struct CreateResponse {
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AddMemberBody {
+    /// The user to add is designated by exactly one of `userId`, `handle`
+    /// (with or without its leading `@`) and `email`.
+    user_id: Option<Uuid>,
+    handle: Option<String>,
+    email: Option<String>,
+    /// One of `admin`, `member` or `viewer`, `member` if `None`.
+    role: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct ChangeMemberRoleBody {
+    /// One of `admin`, `member` or `viewer`.
+    role: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferOwnershipBody {
+    /// Member who becomes the new owner of the group.
     user_id: Uuid,
 }
 
+#[derive(Deserialize)]
+pub struct RemoveMemberQuery {
+    /// Removes the member even if their balance is not settled.
+    #[serde(default)]
+    force: bool,
+}
+
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetAllQuery {
     pub page: Option<NonZeroUsize>,
     pub page_size: Option<NonZeroUsize>,
+    /// Lists archived groups instead of the ones in use.
+    #[serde(default)]
+    pub archived: bool,
 }
 
 #[derive(Serialize)]
@@ -153,8 +370,10 @@ struct PaginationDto {
 struct GroupDto {
     id: Uuid,
     name: String,
+    currency: String,
     owner: UserDto,
     created_at: DateTime<Utc>,
+    archived_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Serialize)]
@@ -169,8 +388,10 @@ impl From<application::queries::get_groups_for_user::GroupSummary> for GroupDto
         Self {
             id: group_summary.id.value(),
             name: group_summary.name.value(),
+            currency: group_summary.currency.value(),
             owner: group_summary.owner.into(),
             created_at: group_summary.created_at,
+            archived_at: group_summary.archived_at,
         }
     }
 }
@@ -219,17 +440,17 @@ fn create_empty_group_err_to_api_error(err: CreateEmptyGroupError) -> ApiError {
 
 fn add_member_err_to_api_error(err: AddGroupMemberError) -> ApiError {
     match err {
-        AddGroupMemberError::NotOwner => ApiError {
+        AddGroupMemberError::NotAdmin => ApiError {
             kind: ErrorKind::ActionForbidden,
             message: None,
-            detail: None,
+            detail: Some("only group owner and admins can add member".to_string()),
         },
         AddGroupMemberError::GroupNotFound => ApiError {
             kind: ErrorKind::NotFound,
             message: Some("group not found".to_string()),
             detail: None,
         },
-        AddGroupMemberError::AlreadyMember => ApiError {
+        AddGroupMemberError::AlreadyMember | AddGroupMemberError::GroupArchived => ApiError {
             kind: ErrorKind::Conflict,
             message: Some(err.to_string()),
             detail: None,
@@ -247,6 +468,178 @@ fn add_member_err_to_api_error(err: AddGroupMemberError) -> ApiError {
     }
 }
 
+fn change_member_role_err_to_api_error(err: ChangeMemberRoleError) -> ApiError {
+    match err {
+        ChangeMemberRoleError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        ChangeMemberRoleError::NotAdmin => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("only group owner and admins can change member roles".to_string()),
+        },
+        ChangeMemberRoleError::NotMember => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        ChangeMemberRoleError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        ChangeMemberRoleError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn remove_member_err_to_api_error(err: RemoveGroupMemberError) -> ApiError {
+    match err {
+        RemoveGroupMemberError::NotAdmin => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("only group owner and admins can remove member".to_string()),
+        },
+        RemoveGroupMemberError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        RemoveGroupMemberError::NotMember => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        RemoveGroupMemberError::ForceNotOwner => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some(err.to_string()),
+        },
+        RemoveGroupMemberError::GroupArchived | RemoveGroupMemberError::BalanceNotSettled => {
+            ApiError {
+                kind: ErrorKind::Conflict,
+                message: Some(err.to_string()),
+                detail: None,
+            }
+        }
+        RemoveGroupMemberError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn leave_group_err_to_api_error(err: LeaveGroupError) -> ApiError {
+    match err {
+        LeaveGroupError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        LeaveGroupError::OwnerCannotLeave
+        | LeaveGroupError::GroupArchived
+        | LeaveGroupError::BalanceNotSettled => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        LeaveGroupError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn transfer_ownership_err_to_api_error(err: TransferGroupOwnershipError) -> ApiError {
+    match err {
+        TransferGroupOwnershipError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        TransferGroupOwnershipError::NotOwner => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("only group owner can transfer ownership".to_string()),
+        },
+        TransferGroupOwnershipError::NotMember | TransferGroupOwnershipError::NewOwnerIsGuest => {
+            ApiError {
+                kind: ErrorKind::InvalidInput,
+                message: Some(err.to_string()),
+                detail: None,
+            }
+        }
+        TransferGroupOwnershipError::GroupArchived
+        | TransferGroupOwnershipError::NameNotAvailable => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        TransferGroupOwnershipError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn archive_group_err_to_api_error(err: ArchiveGroupError) -> ApiError {
+    match err {
+        ArchiveGroupError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        ArchiveGroupError::NotOwner => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("only group owner can archive it".to_string()),
+        },
+        ArchiveGroupError::AlreadyArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        ArchiveGroupError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn unarchive_group_err_to_api_error(err: UnarchiveGroupError) -> ApiError {
+    match err {
+        UnarchiveGroupError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        UnarchiveGroupError::NotOwner => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("only group owner can unarchive it".to_string()),
+        },
+        UnarchiveGroupError::NotArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        UnarchiveGroupError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
 fn get_groups_for_user_err_to_api_error(err: GetGroupsForUserError) -> ApiError {
     match err {
         GetGroupsForUserError::Database(error) => ApiError {
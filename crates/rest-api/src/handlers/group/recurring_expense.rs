@@ -0,0 +1,486 @@
+use application::{
+    commands::{
+        create_recurring_expense::{CreateRecurringExpenseCommand, CreateRecurringExpenseError},
+        delete_recurring_expense::{DeleteRecurringExpenseCommand, DeleteRecurringExpenseError},
+        update_recurring_expense::{UpdateRecurringExpenseCommand, UpdateRecurringExpenseError},
+    },
+    queries::get_recurring_expenses_for_group::{
+        GetRecurringExpensesForGroupError, GetRecurringExpensesForGroupQuery,
+    },
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use chrono::{DateTime, Utc};
+use domain::{
+    entities::RecurringExpense,
+    types::{
+        category_id::CategoryId, group_id::GroupId, recurrence::Recurrence,
+        recurring_expense_id::RecurringExpenseId, user_id::UserId,
+    },
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ErrorKind},
+    extractors::user::User,
+    handlers::group::expense::{AmountDto, SplitDetailsDto, SplitDto, build_split_specification},
+    state::AppState,
+};
+
+pub async fn create(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+    Json(body): Json<RecurringExpenseBody>,
+) -> Result<Json<CreateResponse>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let total = body.total.try_into()?;
+    let split = build_split_specification(body.participants, body.split)?;
+    let payer_id = UserId::new(body.payer_id)?;
+    let title = body.title.parse()?;
+    let note = body.note.map(|n| n.parse()).transpose()?;
+    let category = body
+        .category
+        .map(|c| c.parse())
+        .transpose()?
+        .unwrap_or_default();
+    let category_id = body.category_id.map(CategoryId::new).transpose()?;
+    let currency = body.currency.map(|c| c.parse()).transpose()?;
+    let exchange_rate = body.exchange_rate.map(|r| r.parse()).transpose()?;
+    let recurrence = body.schedule.try_into()?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let recurring_expense_id = CreateRecurringExpenseCommand {
+        group_id,
+        payer_id,
+        author_id: user.id,
+        title,
+        note,
+        category,
+        category_id,
+        split,
+        total,
+        currency,
+        exchange_rate,
+        recurrence,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(create_recurring_expense_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(Json(CreateResponse {
+        recurring_expense_id: recurring_expense_id.value(),
+    }))
+}
+
+pub async fn update(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path((group_id, recurring_expense_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<RecurringExpenseBody>,
+) -> Result<StatusCode, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let recurring_expense_id = RecurringExpenseId::new(recurring_expense_id)?;
+    let total = body.total.try_into()?;
+    let split = build_split_specification(body.participants, body.split)?;
+    let payer_id = UserId::new(body.payer_id)?;
+    let title = body.title.parse()?;
+    let note = body.note.map(|n| n.parse()).transpose()?;
+    let category = body
+        .category
+        .map(|c| c.parse())
+        .transpose()?
+        .unwrap_or_default();
+    let category_id = body.category_id.map(CategoryId::new).transpose()?;
+    let currency = body.currency.map(|c| c.parse()).transpose()?;
+    let exchange_rate = body.exchange_rate.map(|r| r.parse()).transpose()?;
+    let recurrence = body.schedule.try_into()?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    UpdateRecurringExpenseCommand {
+        group_id,
+        recurring_expense_id,
+        current_user_id: user.id,
+        payer_id,
+        title,
+        note,
+        category,
+        category_id,
+        split,
+        total,
+        currency,
+        exchange_rate,
+        recurrence,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(update_recurring_expense_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn delete(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path((group_id, recurring_expense_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let recurring_expense_id = RecurringExpenseId::new(recurring_expense_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    DeleteRecurringExpenseCommand {
+        group_id,
+        recurring_expense_id,
+        current_user_id: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(delete_recurring_expense_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn get_all(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+) -> Result<Json<GetAllResponse>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let output = GetRecurringExpensesForGroupQuery {
+        group_id,
+        current_user: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(get_recurring_expenses_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(Json(GetAllResponse {
+        data: output
+            .recurring_expenses
+            .into_iter()
+            .map(RecurringExpenseDto::from)
+            .collect(),
+    }))
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecurringExpenseBody {
+    /// If participants is `None`, all current group members will be
+    /// considered as participants of every occurrence.
+    /// Only allowed with an equal split.
+    participants: Option<Vec<Uuid>>,
+    /// If split is `None`, the total is split equally.
+    split: Option<SplitDto>,
+    title: String,
+    note: Option<String>,
+    /// One of the fixed expense categories, `other` if `None`.
+    category: Option<String>,
+    /// Optional custom category of the group.
+    category_id: Option<Uuid>,
+    /// Total of each occurrence, either a decimal amount in euros
+    /// (e.g. `"12.34"`) or an integer amount in cents (e.g. `1234`).
+    total: AmountDto,
+    /// ISO 4217 code of the currency; defaults to the group currency.
+    currency: Option<String>,
+    /// Rate to the group currency, as a decimal string (e.g. `"1.0845"`).
+    /// Required if the expense is not in the group currency.
+    exchange_rate: Option<String>,
+    payer_id: Uuid,
+    schedule: ScheduleBody,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduleBody {
+    /// One of `daily`, `weekly`, `monthly` or `yearly`.
+    frequency: String,
+    /// Number of periods between two occurrences, 1 if `None`.
+    interval: Option<u32>,
+    /// First occurrence.
+    starts_at: DateTime<Utc>,
+    /// No occurrence after this date, if any.
+    ends_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateResponse {
+    recurring_expense_id: Uuid,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAllResponse {
+    data: Vec<RecurringExpenseDto>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RecurringExpenseDto {
+    id: Uuid,
+    payer_id: Uuid,
+    /// Participants, payer excluded.
+    participants: Vec<Uuid>,
+    split: SplitDetailsDto,
+    title: String,
+    note: Option<String>,
+    category: String,
+    category_id: Option<Uuid>,
+    total_cents: i64,
+    currency: String,
+    exchange_rate: String,
+    author_id: Uuid,
+    schedule: ScheduleDto,
+    /// `None` once the schedule has ended.
+    next_occurrence_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ScheduleDto {
+    frequency: String,
+    interval: u32,
+    starts_at: DateTime<Utc>,
+    ends_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<ScheduleBody> for Recurrence {
+    type Error = ApiError;
+
+    fn try_from(schedule: ScheduleBody) -> Result<Self, Self::Error> {
+        Ok(Recurrence::new(
+            schedule.frequency.parse()?,
+            schedule.interval.unwrap_or(1),
+            schedule.starts_at,
+            schedule.ends_at,
+        )?)
+    }
+}
+
+impl From<RecurringExpense> for RecurringExpenseDto {
+    fn from(recurring_expense: RecurringExpense) -> Self {
+        let mut participants: Vec<Uuid> = recurring_expense
+            .participants
+            .into_iter()
+            .map(|p| p.value())
+            .collect();
+        participants.sort();
+
+        Self {
+            id: recurring_expense.id.value(),
+            payer_id: recurring_expense.payer_id.value(),
+            participants,
+            split: recurring_expense.split.into(),
+            title: recurring_expense.title.value(),
+            note: recurring_expense.note.map(|note| note.value()),
+            category: recurring_expense.category.to_string(),
+            category_id: recurring_expense.category_id.map(|id| id.value()),
+            total_cents: recurring_expense.total.cents(),
+            currency: recurring_expense.currency.value(),
+            exchange_rate: recurring_expense.exchange_rate.to_string(),
+            author_id: recurring_expense.author_id.value(),
+            schedule: ScheduleDto {
+                frequency: recurring_expense.recurrence.frequency().to_string(),
+                interval: recurring_expense.recurrence.interval(),
+                starts_at: recurring_expense.recurrence.starts_at(),
+                ends_at: recurring_expense.recurrence.ends_at(),
+            },
+            next_occurrence_at: recurring_expense.next_occurrence_at,
+            created_at: recurring_expense.created_at,
+        }
+    }
+}
+
+fn create_recurring_expense_err_to_api_error(err: CreateRecurringExpenseError) -> ApiError {
+    match err {
+        CreateRecurringExpenseError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        CreateRecurringExpenseError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        CreateRecurringExpenseError::InvalidTotal => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some("total must be a positive amount".to_string()),
+            detail: None,
+        },
+        CreateRecurringExpenseError::PayerIsNotGroupMember => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some("payer does not exist in the group".to_string()),
+            detail: None,
+        },
+        CreateRecurringExpenseError::AuthorNotInGroup => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("current user is not in group".to_string()),
+        },
+        CreateRecurringExpenseError::ReadOnlyMember => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        CreateRecurringExpenseError::ParticipantNotFound => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(
+                "at least one participant in the list does not belong to the group".to_string(),
+            ),
+            detail: None,
+        },
+        CreateRecurringExpenseError::CategoryNotFound => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some("category does not exist in the group".to_string()),
+            detail: None,
+        },
+        CreateRecurringExpenseError::InvalidSplit(err) => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        CreateRecurringExpenseError::InvalidCurrency(err) => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        CreateRecurringExpenseError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn update_recurring_expense_err_to_api_error(err: UpdateRecurringExpenseError) -> ApiError {
+    match err {
+        UpdateRecurringExpenseError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        UpdateRecurringExpenseError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        UpdateRecurringExpenseError::RecurringExpenseNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("recurring expense not found".to_string()),
+            detail: None,
+        },
+        UpdateRecurringExpenseError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("current user is not in group".to_string()),
+        },
+        UpdateRecurringExpenseError::InvalidTotal => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some("total must be a positive amount".to_string()),
+            detail: None,
+        },
+        UpdateRecurringExpenseError::PayerIsNotGroupMember => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some("payer does not exist in the group".to_string()),
+            detail: None,
+        },
+        UpdateRecurringExpenseError::ParticipantNotFound => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(
+                "at least one participant in the list does not belong to the group".to_string(),
+            ),
+            detail: None,
+        },
+        UpdateRecurringExpenseError::CategoryNotFound => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some("category does not exist in the group".to_string()),
+            detail: None,
+        },
+        UpdateRecurringExpenseError::InvalidSplit(err) => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        UpdateRecurringExpenseError::InvalidCurrency(err) => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        UpdateRecurringExpenseError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn delete_recurring_expense_err_to_api_error(err: DeleteRecurringExpenseError) -> ApiError {
+    match err {
+        DeleteRecurringExpenseError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        DeleteRecurringExpenseError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        DeleteRecurringExpenseError::RecurringExpenseNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("recurring expense not found".to_string()),
+            detail: None,
+        },
+        DeleteRecurringExpenseError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("current user is not in group".to_string()),
+        },
+        DeleteRecurringExpenseError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn get_recurring_expenses_err_to_api_error(err: GetRecurringExpensesForGroupError) -> ApiError {
+    match err {
+        GetRecurringExpensesForGroupError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        GetRecurringExpensesForGroupError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("user is not allowed access group recurring expenses".to_string()),
+        },
+        GetRecurringExpensesForGroupError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
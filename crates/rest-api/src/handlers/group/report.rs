@@ -0,0 +1,140 @@
+use application::queries::get_category_report::{
+    CategoryTotal, GetCategoryReportError, GetCategoryReportQuery, MemberTotal,
+};
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use chrono::{DateTime, Utc};
+use domain::types::group_id::GroupId;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ErrorKind},
+    extractors::user::User,
+    handlers::group::category::CategoryDto,
+    state::AppState,
+};
+
+pub async fn get_categories(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Query(query): Query<ReportQuery>,
+    Path(group_id): Path<Uuid>,
+) -> Result<Json<GetCategoriesResponse>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let output = GetCategoryReportQuery {
+        group_id,
+        current_user: user.id,
+        from: query.from,
+        to: query.to,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(get_category_report_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(Json(GetCategoriesResponse {
+        currency: output.currency.value(),
+        total_cents: output.total.cents(),
+        categories: output
+            .categories
+            .into_iter()
+            .map(CategoryTotalDto::from)
+            .collect(),
+        members: output
+            .members
+            .into_iter()
+            .map(MemberTotalDto::from)
+            .collect(),
+    }))
+}
+
+/// Date range of the report: `from` is inclusive, `to` is exclusive.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportQuery {
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetCategoriesResponse {
+    /// Group currency, in which totals are expressed.
+    currency: String,
+    total_cents: i64,
+    categories: Vec<CategoryTotalDto>,
+    members: Vec<MemberTotalDto>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CategoryTotalDto {
+    /// `None` for uncategorized expenses.
+    category: Option<CategoryDto>,
+    total_cents: i64,
+    members: Vec<MemberTotalDto>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct MemberTotalDto {
+    id: Uuid,
+    name: String,
+    total_cents: i64,
+}
+
+impl From<CategoryTotal> for CategoryTotalDto {
+    fn from(category_total: CategoryTotal) -> Self {
+        Self {
+            category: category_total.category.map(CategoryDto::from),
+            total_cents: category_total.total.cents(),
+            members: category_total
+                .members
+                .into_iter()
+                .map(MemberTotalDto::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<MemberTotal> for MemberTotalDto {
+    fn from(member_total: MemberTotal) -> Self {
+        Self {
+            id: member_total.user.id.value(),
+            name: member_total.user.name.value(),
+            total_cents: member_total.total.cents(),
+        }
+    }
+}
+
+fn get_category_report_err_to_api_error(err: GetCategoryReportError) -> ApiError {
+    match err {
+        GetCategoryReportError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        GetCategoryReportError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("user is not allowed access group reports".to_string()),
+        },
+        GetCategoryReportError::InvalidRange => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some("from must be before to".to_string()),
+            detail: None,
+        },
+        GetCategoryReportError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
@@ -0,0 +1,314 @@
+use std::num::NonZeroUsize;
+
+use application::{
+    commands::{
+        cancel_settlement::{CancelSettlementCommand, CancelSettlementError},
+        record_settlement::{RecordSettlementCommand, RecordSettlementError},
+    },
+    pagination::Pagination,
+    queries::get_settlements_for_group::{
+        GetSettlementsForGroupError, GetSettlementsForGroupQuery,
+    },
+};
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+};
+use chrono::{DateTime, Utc};
+use domain::types::{
+    group_id::GroupId, money::Money, settlement_id::SettlementId, user_id::UserId,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ErrorKind},
+    extractors::user::User,
+    state::AppState,
+};
+
+pub async fn create(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(group_id): Path<Uuid>,
+    Json(body): Json<CreateBody>,
+) -> Result<Json<CreateResponse>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let from = UserId::new(body.from_user_id)?;
+    let to = UserId::new(body.to_user_id)?;
+    let amount = Money::from_cents(body.amount_cents).within_input_bounds()?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let settlement_id = RecordSettlementCommand {
+        group_id,
+        from,
+        to,
+        amount,
+        author_id: user.id,
+        occurred_at: body.occurred_at,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(record_settlement_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(Json(CreateResponse {
+        settlement_id: settlement_id.value(),
+    }))
+}
+
+pub async fn get_all(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Query(query): Query<GetAllQuery>,
+    Path(group_id): Path<Uuid>,
+) -> Result<Json<GetAllResponse>, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let pagination = Pagination::new_from_optional(query.page, query.page_size)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let output = GetSettlementsForGroupQuery {
+        group_id,
+        current_user: user.id,
+        pagination,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(get_group_settlements_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    let settlements = output
+        .settlements
+        .into_iter()
+        .map(SettlementDto::from)
+        .collect();
+    Ok(Json(GetAllResponse {
+        data: settlements,
+        request_pagination: pagination.into(),
+        total_items: output.total_items,
+    }))
+}
+
+pub async fn cancel(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path((group_id, settlement_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    let group_id = GroupId::new(group_id)?;
+    let settlement_id = SettlementId::new(settlement_id)?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    CancelSettlementCommand {
+        group_id,
+        settlement_id,
+        current_user_id: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(cancel_settlement_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBody {
+    from_user_id: Uuid,
+    to_user_id: Uuid,
+    amount_cents: i64,
+    occurred_at: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateResponse {
+    settlement_id: Uuid,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAllQuery {
+    pub page: Option<NonZeroUsize>,
+    pub page_size: Option<NonZeroUsize>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAllResponse {
+    data: Vec<SettlementDto>,
+    request_pagination: PaginationDto,
+    total_items: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PaginationDto {
+    page: usize,
+    page_size: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SettlementDto {
+    id: Uuid,
+    from: UserDto,
+    to: UserDto,
+    amount_cents: i64,
+    author: UserDto,
+    cancelled: bool,
+    occurred_at: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UserDto {
+    id: Uuid,
+    name: String,
+}
+
+impl From<application::queries::get_settlements_for_group::GroupSettlement> for SettlementDto {
+    fn from(settlement: application::queries::get_settlements_for_group::GroupSettlement) -> Self {
+        Self {
+            id: settlement.id.value(),
+            from: settlement.from.into(),
+            to: settlement.to.into(),
+            amount_cents: settlement.amount.cents(),
+            author: settlement.author.into(),
+            cancelled: settlement.cancelled,
+            occurred_at: settlement.occurred_at,
+        }
+    }
+}
+
+impl From<application::queries::get_settlements_for_group::UserSummary> for UserDto {
+    fn from(user_summary: application::queries::get_settlements_for_group::UserSummary) -> Self {
+        Self {
+            id: user_summary.id.value(),
+            name: user_summary.name.value(),
+        }
+    }
+}
+
+impl From<Pagination> for PaginationDto {
+    fn from(p: Pagination) -> Self {
+        Self {
+            page: p.page().get(),
+            page_size: p.page_size().get(),
+        }
+    }
+}
+
+fn record_settlement_err_to_api_error(err: RecordSettlementError) -> ApiError {
+    match err {
+        RecordSettlementError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        RecordSettlementError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        RecordSettlementError::InvalidAmount => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some("amountCents must be a positive amount".to_string()),
+            detail: None,
+        },
+        RecordSettlementError::SameUser => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some("sender and receiver must be different users".to_string()),
+            detail: None,
+        },
+        RecordSettlementError::SenderNotInGroup => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some("sender does not exist in the group".to_string()),
+            detail: None,
+        },
+        RecordSettlementError::ReceiverNotInGroup => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some("receiver does not exist in the group".to_string()),
+            detail: None,
+        },
+        RecordSettlementError::AuthorNotInGroup => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("current user is not in group".to_string()),
+        },
+        RecordSettlementError::ReadOnlyMember => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        RecordSettlementError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn get_group_settlements_err_to_api_error(err: GetSettlementsForGroupError) -> ApiError {
+    match err {
+        GetSettlementsForGroupError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        GetSettlementsForGroupError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some("user is not allowed access group settlements".to_string()),
+        },
+        GetSettlementsForGroupError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
+
+fn cancel_settlement_err_to_api_error(err: CancelSettlementError) -> ApiError {
+    match err {
+        CancelSettlementError::GroupNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("group not found".to_string()),
+            detail: None,
+        },
+        CancelSettlementError::GroupArchived => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        CancelSettlementError::SettlementNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some("settlement not found".to_string()),
+            detail: None,
+        },
+        CancelSettlementError::Forbidden => ApiError {
+            kind: ErrorKind::ActionForbidden,
+            message: None,
+            detail: Some(
+                "only settlement author or group owner can cancel a settlement".to_string(),
+            ),
+        },
+        CancelSettlementError::AlreadyCancelled => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some("settlement is already cancelled".to_string()),
+            detail: None,
+        },
+        CancelSettlementError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
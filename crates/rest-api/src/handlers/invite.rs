@@ -0,0 +1,70 @@
+use application::commands::accept_invite::{AcceptInviteCommand, AcceptInviteError};
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use domain::types::invite_token::InviteToken;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ErrorKind},
+    extractors::user::User,
+    state::AppState,
+};
+
+/// Makes the current user join the group of the invite.
+pub async fn accept(
+    State(state): State<AppState>,
+    User(user, _, _): User,
+    Path(token): Path<String>,
+) -> Result<Json<AcceptResponse>, ApiError> {
+    let token: InviteToken = token.parse()?;
+
+    let mut tx = state.db_pool.begin().await?;
+
+    let group_id = AcceptInviteCommand {
+        token,
+        current_user_id: user.id,
+    }
+    .handle(&mut tx)
+    .await
+    .map_err(accept_invite_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(Json(AcceptResponse {
+        group_id: group_id.value(),
+    }))
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AcceptResponse {
+    group_id: Uuid,
+}
+
+fn accept_invite_err_to_api_error(err: AcceptInviteError) -> ApiError {
+    match err {
+        AcceptInviteError::InviteNotFound | AcceptInviteError::UserNotFound => ApiError {
+            kind: ErrorKind::NotFound,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        AcceptInviteError::InviteRevoked
+        | AcceptInviteError::InviteExpired
+        | AcceptInviteError::InviteExhausted
+        | AcceptInviteError::AuthorNotAllowed
+        | AcceptInviteError::GroupArchived
+        | AcceptInviteError::AlreadyMember => ApiError {
+            kind: ErrorKind::Conflict,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        AcceptInviteError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
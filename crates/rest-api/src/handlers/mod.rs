@@ -1,3 +1,5 @@
 pub mod auth;
 pub mod dummy;
 pub mod group;
+pub mod invite;
+pub mod user;
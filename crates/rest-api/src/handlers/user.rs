@@ -0,0 +1,77 @@
+use application::queries::search_users::{SearchUsersError, SearchUsersQuery};
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ErrorKind},
+    extractors::user::User,
+    state::AppState,
+};
+
+/// Finds users by the start of their handle or name, for instance to add
+/// them to a group.
+pub async fn search(
+    State(state): State<AppState>,
+    User(_, _, _): User,
+    Query(query): Query<SearchQuery>,
+) -> Result<Json<SearchResponse>, ApiError> {
+    let mut tx = state.db_pool.begin().await?;
+
+    let users = SearchUsersQuery { text: query.q }
+        .handle(&mut tx)
+        .await
+        .map_err(search_users_err_to_api_error)?;
+
+    tx.commit().await?;
+
+    Ok(Json(SearchResponse {
+        data: users
+            .into_iter()
+            .map(|user| UserProfileDto {
+                id: user.id.value(),
+                handle: user.handle.value(),
+                name: user.name.value(),
+            })
+            .collect(),
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct SearchQuery {
+    q: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResponse {
+    data: Vec<UserProfileDto>,
+}
+
+/// Public part of a user, which leaves out their email.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UserProfileDto {
+    id: Uuid,
+    /// Without its leading `@`.
+    handle: String,
+    name: String,
+}
+
+fn search_users_err_to_api_error(err: SearchUsersError) -> ApiError {
+    match err {
+        SearchUsersError::TextTooShort => ApiError {
+            kind: ErrorKind::InvalidInput,
+            message: Some(err.to_string()),
+            detail: None,
+        },
+        SearchUsersError::Database(error) => ApiError {
+            kind: ErrorKind::Internal,
+            message: None,
+            detail: Some(error.to_string()),
+        },
+    }
+}
@@ -1,8 +1,10 @@
-use std::str::FromStr;
+use std::{str::FromStr, time::Duration};
 
 use axum::{
-    Router, middleware,
-    routing::{get, post},
+    Router,
+    extract::DefaultBodyLimit,
+    middleware,
+    routing::{delete, get, post, put},
 };
 use tower::ServiceBuilder;
 use tower_http::cors::CorsLayer;
@@ -16,7 +18,9 @@ mod error;
 mod extractors;
 mod handlers;
 mod middlewares;
+mod scheduler;
 mod state;
+mod storage;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -35,7 +39,27 @@ async fn main() -> anyhow::Result<()> {
     setup_logger(&config.log);
 
     let db_pool = database::setup::setup_database(&config.db_file).await?;
-    let app_state = AppState { db_pool, config };
+    tokio::spawn(scheduler::run_recurring_expenses(
+        db_pool.clone(),
+        Duration::from_secs(config.scheduler.recurring_expenses_period_secs.max(1)),
+    ));
+    let attachments = storage::AttachmentStorage::new(&config.attachments.dir);
+    tokio::spawn(scheduler::run_attachments_cleanup(
+        db_pool.clone(),
+        attachments.clone(),
+        Duration::from_secs(config.scheduler.attachments_cleanup_period_secs.max(1)),
+        Duration::from_secs(
+            config
+                .attachments
+                .deleted_expense_retention_days
+                .saturating_mul(24 * 60 * 60),
+        ),
+    ));
+    let app_state = AppState {
+        db_pool,
+        config,
+        attachments,
+    };
 
     // TODO: more strict CORS layer (can be configured)
     let router = routes(app_state).layer(CorsLayer::very_permissive());
@@ -45,17 +69,72 @@ async fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Exports of other apps, like backups, can hold years of history.
+const IMPORT_BODY_LIMIT: usize = 16 * 1024 * 1024;
+
 fn routes(state: AppState) -> Router {
+    // leaves room for the multipart boundaries and headers
+    let attachment_body_limit = usize::try_from(state.config.attachments.max_size_bytes)
+        .unwrap_or(usize::MAX)
+        .saturating_add(64 * 1024);
     let router = Router::new()
         .route("/auth/register", post(handlers::auth::register))
         .route("/auth/login", post(handlers::auth::login))
         .route("/auth/logout", post(handlers::auth::logout))
+        .route("/users/search", get(handlers::user::search))
         .route("/groups", post(handlers::group::create))
         .route("/groups", get(handlers::group::get_all))
+        .route(
+            "/groups/restore",
+            post(handlers::group::backup::restore).layer(DefaultBodyLimit::max(IMPORT_BODY_LIMIT)),
+        )
+        .route(
+            "/groups/{group_id}/backup",
+            get(handlers::group::backup::get),
+        )
+        .route("/groups/{group_id}/archive", post(handlers::group::archive))
+        .route(
+            "/groups/{group_id}/unarchive",
+            post(handlers::group::unarchive),
+        )
         .route(
             "/groups/{group_id}/members",
             post(handlers::group::add_member),
         )
+        .route(
+            "/groups/{group_id}/members/{user_id}",
+            delete(handlers::group::remove_member),
+        )
+        .route(
+            "/groups/{group_id}/members/{user_id}/role",
+            put(handlers::group::change_member_role),
+        )
+        .route(
+            "/groups/{group_id}/guests",
+            post(handlers::group::guest::create),
+        )
+        .route(
+            "/groups/{group_id}/guests/{guest_id}/claim",
+            post(handlers::group::guest::claim),
+        )
+        .route("/groups/{group_id}/leave", post(handlers::group::leave))
+        .route(
+            "/groups/{group_id}/ownership",
+            post(handlers::group::transfer_ownership),
+        )
+        .route(
+            "/groups/{group_id}/invites",
+            post(handlers::group::invite::create),
+        )
+        .route(
+            "/groups/{group_id}/invites",
+            get(handlers::group::invite::get_all),
+        )
+        .route(
+            "/groups/{group_id}/invites/{invite_id}",
+            delete(handlers::group::invite::revoke),
+        )
+        .route("/invites/{token}/accept", post(handlers::invite::accept))
         .route(
             "/groups/{group_id}/expenses",
             post(handlers::group::expense::create),
@@ -64,6 +143,124 @@ fn routes(state: AppState) -> Router {
             "/groups/{group_id}/expenses",
             get(handlers::group::expense::get_all),
         )
+        .route(
+            "/groups/{group_id}/expenses/export.csv",
+            get(handlers::group::export::expenses_csv),
+        )
+        .route(
+            "/groups/{group_id}/expenses/import",
+            post(handlers::group::import::import_expenses)
+                .layer(DefaultBodyLimit::max(IMPORT_BODY_LIMIT)),
+        )
+        .route(
+            "/groups/{group_id}/expenses/{expense_id}",
+            put(handlers::group::expense::update),
+        )
+        .route(
+            "/groups/{group_id}/expenses/{expense_id}",
+            delete(handlers::group::expense::delete),
+        )
+        .route(
+            "/groups/{group_id}/expenses/{expense_id}/restore",
+            post(handlers::group::expense::restore),
+        )
+        .route(
+            "/groups/{group_id}/expenses/{expense_id}/history",
+            get(handlers::group::expense::get_history),
+        )
+        .route(
+            "/groups/{group_id}/expenses/{expense_id}/attachments",
+            post(handlers::group::attachment::upload)
+                .layer(DefaultBodyLimit::max(attachment_body_limit)),
+        )
+        .route(
+            "/groups/{group_id}/expenses/{expense_id}/attachments",
+            get(handlers::group::attachment::get_all),
+        )
+        .route(
+            "/groups/{group_id}/expenses/{expense_id}/attachments/{attachment_id}",
+            get(handlers::group::attachment::download),
+        )
+        .route(
+            "/groups/{group_id}/expenses/{expense_id}/attachments/{attachment_id}",
+            delete(handlers::group::attachment::delete),
+        )
+        .route(
+            "/groups/{group_id}/recurring-expenses",
+            post(handlers::group::recurring_expense::create),
+        )
+        .route(
+            "/groups/{group_id}/recurring-expenses",
+            get(handlers::group::recurring_expense::get_all),
+        )
+        .route(
+            "/groups/{group_id}/recurring-expenses/{recurring_expense_id}",
+            put(handlers::group::recurring_expense::update),
+        )
+        .route(
+            "/groups/{group_id}/recurring-expenses/{recurring_expense_id}",
+            delete(handlers::group::recurring_expense::delete),
+        )
+        .route(
+            "/groups/{group_id}/balances",
+            get(handlers::group::balance::get_all),
+        )
+        .route(
+            "/groups/{group_id}/settlement-plan",
+            get(handlers::group::balance::get_settlement_plan),
+        )
+        .route(
+            "/groups/{group_id}/settlements",
+            post(handlers::group::settlement::create),
+        )
+        .route(
+            "/groups/{group_id}/settlements",
+            get(handlers::group::settlement::get_all),
+        )
+        .route(
+            "/groups/{group_id}/settlements/{settlement_id}",
+            delete(handlers::group::settlement::cancel),
+        )
+        .route(
+            "/groups/{group_id}/categories",
+            post(handlers::group::category::create),
+        )
+        .route(
+            "/groups/{group_id}/categories",
+            get(handlers::group::category::get_all),
+        )
+        .route(
+            "/groups/{group_id}/categories/{category_id}",
+            put(handlers::group::category::update),
+        )
+        .route(
+            "/groups/{group_id}/categories/{category_id}",
+            delete(handlers::group::category::delete),
+        )
+        .route(
+            "/groups/{group_id}/budgets",
+            post(handlers::group::budget::create),
+        )
+        .route(
+            "/groups/{group_id}/budgets",
+            get(handlers::group::budget::get_all),
+        )
+        .route(
+            "/groups/{group_id}/budgets/alerts",
+            get(handlers::group::budget::get_alerts),
+        )
+        .route(
+            "/groups/{group_id}/budgets/{budget_id}",
+            put(handlers::group::budget::update),
+        )
+        .route(
+            "/groups/{group_id}/budgets/{budget_id}",
+            delete(handlers::group::budget::delete),
+        )
+        .route(
+            "/groups/{group_id}/reports/categories",
+            get(handlers::group::report::get_categories),
+        )
         .route("/hello", get(handlers::dummy::hello_user))
         .with_state(state);
 
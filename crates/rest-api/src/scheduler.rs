@@ -0,0 +1,130 @@
+use std::time::Duration;
+
+use application::commands::{
+    materialize_recurring_expenses::MaterializeRecurringExpensesCommand,
+    purge_deleted_expense_attachments::PurgeDeletedExpenseAttachmentsCommand,
+};
+use chrono::Utc;
+
+use crate::storage::AttachmentStorage;
+
+/// Periodically adds due occurrences of recurring expenses.
+///
+/// The first run happens right away, so that occurrences missed while the
+/// server was down are added on startup.
+pub async fn run_recurring_expenses(db_pool: database::SqlitePool, period: Duration) {
+    let mut interval = tokio::time::interval(period);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        interval.tick().await;
+        if let Err(err) = materialize_recurring_expenses(&db_pool).await {
+            tracing::error!(error = %err, "failed to materialize recurring expenses");
+        }
+    }
+}
+
+async fn materialize_recurring_expenses(db_pool: &database::SqlitePool) -> anyhow::Result<()> {
+    let mut tx = db_pool.begin().await?;
+    let output = MaterializeRecurringExpensesCommand { now: Utc::now() }
+        .handle(&mut tx)
+        .await?;
+    tx.commit().await?;
+
+    for skipped in &output.skipped {
+        tracing::warn!(
+            recurring_expense_id = %skipped.recurring_expense_id.value(),
+            occurred_at = %skipped.occurred_at,
+            reason = %skipped.reason,
+            "skipped occurrence of recurring expense"
+        );
+    }
+    if !output.created.is_empty() {
+        tracing::info!(
+            created = output.created.len(),
+            "materialized recurring expenses"
+        );
+    }
+    Ok(())
+}
+
+/// Minimum age of a stored file before it can be considered orphaned,
+/// leaving time for the upload transaction to be committed.
+const ATTACHMENT_GRACE_PERIOD: Duration = Duration::from_secs(10 * 60);
+
+/// Periodically removes the content of attachments that are no longer
+/// recorded, e.g. because they or their group have been deleted.
+///
+/// Attachments of expenses deleted for longer than `retention` are purged
+/// beforehand, so that their content is removed as well.
+pub async fn run_attachments_cleanup(
+    db_pool: database::SqlitePool,
+    storage: AttachmentStorage,
+    period: Duration,
+    retention: Duration,
+) {
+    let mut interval = tokio::time::interval(period);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        interval.tick().await;
+        if let Err(err) = purge_deleted_expense_attachments(&db_pool, retention).await {
+            tracing::error!(error = %err, "failed to purge attachments of deleted expenses");
+        }
+        if let Err(err) = remove_orphaned_attachments(&db_pool, &storage).await {
+            tracing::error!(error = %err, "failed to remove orphaned attachments");
+        }
+    }
+}
+
+async fn purge_deleted_expense_attachments(
+    db_pool: &database::SqlitePool,
+    retention: Duration,
+) -> anyhow::Result<()> {
+    // nothing can have been deleted that long ago
+    let Some(deleted_before) = chrono::Duration::from_std(retention)
+        .ok()
+        .and_then(|retention| Utc::now().checked_sub_signed(retention))
+    else {
+        return Ok(());
+    };
+
+    let mut tx = db_pool.begin().await?;
+    let purged = PurgeDeletedExpenseAttachmentsCommand { deleted_before }
+        .handle(&mut tx)
+        .await?;
+    tx.commit().await?;
+
+    if purged > 0 {
+        tracing::info!(purged, "purged attachments of deleted expenses");
+    }
+    Ok(())
+}
+
+async fn remove_orphaned_attachments(
+    db_pool: &database::SqlitePool,
+    storage: &AttachmentStorage,
+) -> anyhow::Result<()> {
+    let (stored, leftovers) = storage.list_older_than(ATTACHMENT_GRACE_PERIOD).await?;
+
+    let mut orphans = Vec::new();
+    let mut tx = db_pool.begin().await?;
+    for id in stored {
+        if !database::queries::attachment::exists(&mut tx, &id).await? {
+            orphans.push(id);
+        }
+    }
+    tx.commit().await?;
+
+    for id in &orphans {
+        storage.remove(id).await?;
+    }
+    for path in &leftovers {
+        tokio::fs::remove_file(path).await?;
+    }
+    if !orphans.is_empty() || !leftovers.is_empty() {
+        tracing::info!(
+            removed = orphans.len() + leftovers.len(),
+            "removed orphaned attachments"
+        );
+    }
+    Ok(())
+}
@@ -1,7 +1,8 @@
-use crate::config;
+use crate::{config, storage::AttachmentStorage};
 
 #[derive(Clone)]
 pub struct AppState {
     pub db_pool: database::SqlitePool,
     pub config: config::Config,
+    pub attachments: AttachmentStorage,
 }
@@ -0,0 +1,93 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use domain::types::attachment_id::AttachmentId;
+
+/// Stores the content of attachments on local disk, in a single directory,
+/// one file per attachment named after its id.
+#[derive(Clone)]
+pub struct AttachmentStorage {
+    dir: PathBuf,
+}
+
+impl AttachmentStorage {
+    /// Suffix of files being written, renamed once complete so that
+    /// a partially written file is never served.
+    const PARTIAL_SUFFIX: &str = ".part";
+
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    pub async fn save(&self, id: &AttachmentId, content: &[u8]) -> io::Result<()> {
+        tokio::fs::create_dir_all(&self.dir).await?;
+        let partial_path = self
+            .dir
+            .join(format!("{}{}", id.value(), Self::PARTIAL_SUFFIX));
+        tokio::fs::write(&partial_path, content).await?;
+        tokio::fs::rename(&partial_path, self.path(id)).await
+    }
+
+    pub async fn read(&self, id: &AttachmentId) -> io::Result<Vec<u8>> {
+        tokio::fs::read(self.path(id)).await
+    }
+
+    /// Removes the content of an attachment, if it exists.
+    pub async fn remove(&self, id: &AttachmentId) -> io::Result<()> {
+        match tokio::fs::remove_file(self.path(id)).await {
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            res => res,
+        }
+    }
+
+    /// Returns the attachments stored for at least `min_age`, along with
+    /// the leftovers of interrupted writes, which can be removed right away.
+    ///
+    /// Recent files are ignored as they may belong to an upload whose
+    /// transaction is not committed yet.
+    pub async fn list_older_than(
+        &self,
+        min_age: Duration,
+    ) -> io::Result<(Vec<AttachmentId>, Vec<PathBuf>)> {
+        let mut attachments = Vec::new();
+        let mut leftovers = Vec::new();
+
+        let mut entries = match tokio::fs::read_dir(&self.dir).await {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Ok((attachments, leftovers));
+            }
+            Err(err) => return Err(err),
+        };
+        while let Some(entry) = entries.next_entry().await? {
+            let modified_at = entry.metadata().await?.modified()?;
+            let age = SystemTime::now()
+                .duration_since(modified_at)
+                .unwrap_or_default();
+            if age < min_age {
+                continue;
+            }
+
+            let file_name = entry.file_name();
+            let Some(file_name) = file_name.to_str() else {
+                continue;
+            };
+            if file_name.ends_with(Self::PARTIAL_SUFFIX) {
+                leftovers.push(entry.path());
+            } else if let Ok(id) = file_name.parse() {
+                attachments.push(id);
+            }
+        }
+
+        Ok((attachments, leftovers))
+    }
+
+    fn path(&self, id: &AttachmentId) -> PathBuf {
+        self.dir.join(id.value().to_string())
+    }
+}